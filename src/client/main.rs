@@ -15,7 +15,9 @@ use gtk::{
     ScrolledWindow, SignalListItemFactory, SingleSelection,
 };
 use spreadsheet_cell_object::SpreadsheetCellObject;
+use std::cell::RefCell;
 use std::cmp::{max, min};
+use std::rc::Rc;
 use std::sync::{Arc, RwLock};
 
 const NUM_COLS: i32 = 36;
@@ -91,6 +93,11 @@ fn build_grid<T: service::CellsService + 'static>(
         .into_iter()
         .map(|c| {
             let idx = row_major_idx(c.row, c.col) as usize;
+            let style = service.read().unwrap().style(c.effective_style_id);
+            vector
+                .get(idx)
+                .expect("needs to be a SpreadsheetCellObject")
+                .set_style(&style, &c.typed_value);
             vector
                 .get(idx)
                 .expect("needs to be a SpreadsheetCellObject")
@@ -205,16 +212,21 @@ fn build_grid<T: service::CellsService + 'static>(
         .vexpand(true)
         .build();
 
+    // model indices of the cells outlined by the last trace
+    let traced: Rc<RefCell<Vec<u32>>> = Rc::new(RefCell::new(vec![]));
     let key_controller = EventControllerKey::builder().build();
     key_controller.set_propagation_phase(PropagationPhase::Capture);
     key_controller.connect_key_pressed(
-        clone!(@weak selection_model => @default-return Inhibit(false), move |_, key_val, _, _| {
+        clone!(@weak selection_model, @strong traced => @default-return Inhibit(false), move |_, key_val, _, state| {
             if key_val.name().is_none() {
                 return Inhibit(false);
             }
             let name = key_val.name().unwrap();
             let mut inhibit = true;
-            if name == "Left" {
+            let ctrl = state.contains(gdk::ModifierType::CONTROL_MASK);
+            if ctrl && (name == "b" || name == "i") {
+                toggle_font_style(&name, &selection_model, Arc::clone(&service));
+            } else if name == "Left" {
                 selection_model.select_item(clamp_selection(selection_model.selected() as i32 - 1) as u32, true);
             } else if name == "Right" || name == "Tab"{
                 selection_model.select_item(clamp_selection(selection_model.selected() as i32 + 1) as u32, true);
@@ -231,6 +243,12 @@ fn build_grid<T: service::CellsService + 'static>(
                 item.set_property("value", cell_val.clone());
                 insert_cell(&cell, &selection_model, Arc::clone(&service));
                 selection_model.select_item(clamp_selection(selection_model.selected() as i32 + NUM_COLS) as u32, true);
+            } else if name == "F9" {
+                recalculate(&selection_model, Arc::clone(&service));
+            } else if ctrl && matches!(name.as_str(), "bracketleft" | "bracketright" | "braceleft" | "braceright") {
+                trace_cell(&name, &selection_model, Arc::clone(&service), &traced);
+            } else if name == "Escape" {
+                clear_traced(&selection_model, &traced);
             } else {
                 inhibit = false;
             }
@@ -286,35 +304,158 @@ fn insert_cell<T: service::CellsService>(
     let idx = view_idx_to_ss_idx(cell.property_value("idx").get::<i32>().unwrap() as u32);
     println!("insert idx {}", idx);
     let cell_val = cell.property_value("value").get::<String>().unwrap();
+    let (row, col) = (idx / NUM_EDIT_COLS, idx % NUM_EDIT_COLS);
     if cell_val == "" {
+        let cells = service.write().unwrap().clear_cells(models::Rect {
+            start_row: row,
+            stop_row: row + 1,
+            start_col: col,
+            stop_col: col + 1,
+        });
+        update_display_values(selection_model, cells, service);
         return;
     }
 
     println!("{} / {} = {}", idx, NUM_EDIT_ROWS, idx / NUM_EDIT_ROWS);
-    let cells = vec![models::Cell::new(
-        idx / NUM_EDIT_COLS,
-        idx % NUM_EDIT_COLS,
-        cell_val,
-    )];
+    let cells = vec![models::Cell::new(row, col, cell_val)];
     println!("inserting cells {:#?}", cells);
+    // values rejected by a validation rule are left out of the insert
     let resp = service.write().unwrap().insert_cells(&cells);
     match resp {
-        Ok(cells) => {
-            for cell in cells {
-                let model_idx = ss_cell_to_model_idx(cell.row, cell.col);
-                let (num_removed, num_added) = (0 as u32, 0 as u32);
-                let item = selection_model
-                    .item(model_idx)
-                    .expect("item needs to be a GObject");
-                item.set_property("displayvalue", cell.display_value);
-                selection_model
-                    .emit_by_name::<()>("items-changed", &[&model_idx, &num_removed, &num_added]);
+        Ok((cells, errors)) => {
+            for e in errors {
+                println!("invalid value at ({}, {}): {}", e.row, e.col, e.message);
             }
+            update_display_values(selection_model, cells, service)
         }
         Err(e) => println!("error inserting cells: {:?}", e),
     }
 }
 
+// toggle_font_style makes the selected cell bold with "b" or italic with "i", or back to
+// normal when it already is.
+fn toggle_font_style<T: service::CellsService>(
+    name: &str,
+    selection_model: &SingleSelection,
+    service: Arc<RwLock<T>>,
+) {
+    let idx = view_idx_to_ss_idx(selection_model.selected());
+    let (row, col) = (idx / NUM_EDIT_COLS, idx % NUM_EDIT_COLS);
+    let rect = || models::Rect {
+        start_row: row,
+        stop_row: row + 1,
+        start_col: col,
+        stop_col: col + 1,
+    };
+    let resp = {
+        let mut svc = service.write().unwrap();
+        let current = svc
+            .get_cells(rect())
+            .first()
+            .map_or_else(models::Style::default, |c| svc.style(c.style_id));
+        let (style, field) = match name {
+            "b" => (
+                models::Style {
+                    bold: !current.bold,
+                    ..Default::default()
+                },
+                "bold",
+            ),
+            _ => (
+                models::Style {
+                    italic: !current.italic,
+                    ..Default::default()
+                },
+                "italic",
+            ),
+        };
+        svc.set_styles(rect(), &style, &[field.to_owned()])
+    };
+    match resp {
+        Ok(cells) => update_display_values(selection_model, cells, service),
+        Err(e) => println!("error styling cells: {:?}", e),
+    }
+}
+
+// recalculate recomputes volatile cells such as =RAND() along with their dependents
+fn recalculate<T: service::CellsService>(
+    selection_model: &SingleSelection,
+    service: Arc<RwLock<T>>,
+) {
+    let cells = service.write().unwrap().recalculate();
+    update_display_values(selection_model, cells, service);
+}
+
+// trace_cell outlines the cells the selected cell's formula reads with Ctrl+[ or the cells
+// reading it with Ctrl+], and with Shift those reached through any number of references.
+// The cells outlined by the previous trace are cleared.
+fn trace_cell<T: service::CellsService>(
+    name: &str,
+    selection_model: &SingleSelection,
+    service: Arc<RwLock<T>>,
+    traced: &RefCell<Vec<u32>>,
+) {
+    clear_traced(selection_model, traced);
+    let idx = view_idx_to_ss_idx(selection_model.selected());
+    let (row, col) = (idx / NUM_EDIT_COLS, idx % NUM_EDIT_COLS);
+    let max_depth = match name {
+        "bracketleft" | "bracketright" => 1,
+        _ => 0,
+    };
+    let ranges = {
+        let svc = service.read().unwrap();
+        match name {
+            "bracketleft" | "braceleft" => svc.trace_precedents(row, col, max_depth),
+            _ => svc.trace_dependents(row, col, max_depth),
+        }
+    };
+    let mut traced = traced.borrow_mut();
+    for r in ranges {
+        // ranges such as whole columns reach past the grid
+        for loc in r.rect.clamp(NUM_EDIT_ROWS, NUM_EDIT_COLS).locations() {
+            let model_idx = ss_cell_to_model_idx(loc.row, loc.col);
+            if let Some(item) = selection_model.item(model_idx) {
+                item.downcast_ref::<SpreadsheetCellObject>()
+                    .expect("The item must be a `SpreadsheetCellObject`.")
+                    .set_traced(true);
+                traced.push(model_idx);
+            }
+        }
+    }
+}
+
+// clear_traced removes the outlines of the last trace
+fn clear_traced(selection_model: &SingleSelection, traced: &RefCell<Vec<u32>>) {
+    for model_idx in traced.borrow_mut().drain(..) {
+        if let Some(item) = selection_model.item(model_idx) {
+            item.downcast_ref::<SpreadsheetCellObject>()
+                .expect("The item must be a `SpreadsheetCellObject`.")
+                .set_traced(false);
+        }
+    }
+}
+
+fn update_display_values<T: service::CellsService>(
+    selection_model: &SingleSelection,
+    cells: Vec<models::Cell>,
+    service: Arc<RwLock<T>>,
+) {
+    for cell in cells {
+        let model_idx = ss_cell_to_model_idx(cell.row, cell.col);
+        let (num_removed, num_added) = (0 as u32, 0 as u32);
+        let item = selection_model
+            .item(model_idx)
+            .expect("item needs to be a GObject");
+        let style = service.read().unwrap().style(cell.effective_style_id);
+        item.downcast_ref::<SpreadsheetCellObject>()
+            .expect("The item must be a `SpreadsheetCellObject`.")
+            .set_style(&style, &cell.typed_value);
+        item.set_property("displayvalue", cell.display_value);
+        selection_model
+            .emit_by_name::<()>("items-changed", &[&model_idx, &num_removed, &num_added]);
+    }
+}
+
 fn view_idx_to_ss_idx(idx: u32) -> i32 {
     let row = idx / NUM_COLS as u32;
     let col = idx % NUM_COLS as u32;
@@ -6,7 +6,7 @@ use glib::{Binding, SignalHandlerId};
 use glib::{ParamSpec, ParamSpecBoolean, ParamSpecInt, ParamSpecString, Value};
 use gtk::prelude::*;
 use gtk::subclass::prelude::*;
-use gtk::{glib, CompositeTemplate, Entry, EventControllerFocus, GestureClick};
+use gtk::{glib, CompositeTemplate, CssProvider, Entry, EventControllerFocus, GestureClick};
 use once_cell::sync::Lazy;
 
 use super::SpreadsheetCell;
@@ -22,6 +22,8 @@ pub struct SpreadsheetCellObject {
     pub gesture_handler: Rc<RefCell<Option<GestureClick>>>,
     pub focus_handler: RefCell<Option<EventControllerFocus>>,
     pub click_signal: RefCell<Option<SignalHandlerId>>,
+    // style_provider draws the cell's style, see SpreadsheetCellObject::set_style
+    pub style_provider: RefCell<Option<CssProvider>>,
 }
 
 // The central trait for subclassing a GObject
@@ -10,6 +10,7 @@ use gtk::prelude::*;
 use gtk::subclass::prelude::*;
 use gtk::{glib, Entry, EventControllerFocus, GestureClick, PropagationPhase, SingleSelection};
 use rpc_client::api;
+use spreadsheet_service::models;
 
 const ALPHABET: [char; 26] = [
     'A', 'B', 'C', 'D', 'E', 'F', 'G', 'H', 'I', 'J', 'K', 'L', 'M', 'N', 'O', 'P', 'Q', 'R', 'S',
@@ -134,6 +135,105 @@ impl SpreadsheetCellObject {
     pub fn set_text(&self, txt: String) {
         self.imp().entry.set_text(&txt);
     }
+
+    // set_style draws the cell with the provided style. Cells aligned with General show
+    // numbers on the right and text on the left.
+    pub fn set_style(&self, style: &models::Style, value: &models::Value) {
+        let entry = self.imp().entry.get();
+        if let Some(provider) = self.imp().style_provider.take() {
+            entry.style_context().remove_provider(&provider);
+        }
+        if !style.is_default() {
+            let provider = gtk::CssProvider::new();
+            provider.load_from_data(&style_css(style));
+            entry
+                .style_context()
+                .add_provider(&provider, gtk::STYLE_PROVIDER_PRIORITY_USER);
+            self.imp().style_provider.replace(Some(provider));
+        }
+
+        let numeric = matches!(value, models::Value::Number(_) | models::Value::Date(_));
+        entry.set_alignment(match style.horizontal_alignment {
+            models::HorizontalAlignment::General if numeric => 1.0,
+            models::HorizontalAlignment::General | models::HorizontalAlignment::Left => 0.0,
+            models::HorizontalAlignment::Center => 0.5,
+            models::HorizontalAlignment::Right => 1.0,
+        });
+        // entries show a single line, so wrapped text is only aligned vertically
+        entry.set_valign(match style.vertical_alignment {
+            models::VerticalAlignment::Bottom => gtk::Align::End,
+            models::VerticalAlignment::Middle => gtk::Align::Center,
+            models::VerticalAlignment::Top => gtk::Align::Start,
+        });
+    }
+
+    // set_traced outlines the cell when it's found tracing the precedents or dependents of
+    // the selected cell
+    pub fn set_traced(&self, traced: bool) {
+        let entry = self.imp().entry.get();
+        if traced {
+            entry.add_css_class("ss_cell_traced");
+        } else {
+            entry.remove_css_class("ss_cell_traced");
+        }
+    }
+}
+
+// style_css returns the CSS drawing a cell's entry with the provided style. Fonts and
+// colors are inherited by the entry's text and placeholder.
+fn style_css(style: &models::Style) -> String {
+    let mut css = String::new();
+    if style.bold {
+        css.push_str("font-weight: bold;");
+    }
+    if style.italic {
+        css.push_str("font-style: italic;");
+    }
+    if style.font_size > 0 {
+        css.push_str(&format!("font-size: {}pt;", style.font_size));
+    }
+    if !style.text_color.is_empty() {
+        css.push_str(&format!("color: {};", style.text_color));
+    }
+    if !style.fill_color.is_empty() {
+        css.push_str(&format!("background-color: {};", style.fill_color));
+    }
+    // data bars are drawn over the fill, from the left of the cell
+    if let Some(bar) = &style.data_bar {
+        css.push_str(&format!(
+            "background-image: linear-gradient(to right, {} {}%, transparent {}%);",
+            bar.color, bar.percent, bar.percent
+        ));
+    }
+    let borders = [
+        ("top", &style.top_border),
+        ("bottom", &style.bottom_border),
+        ("left", &style.left_border),
+        ("right", &style.right_border),
+    ];
+    for (side, border) in borders {
+        if let Some(b) = border_css(border) {
+            css.push_str(&format!("border-{}: {};", side, b));
+        }
+    }
+    format!("entry {{ {} }}", css)
+}
+
+fn border_css(border: &models::Border) -> Option<String> {
+    let (width, line) = match border.style {
+        models::BorderStyle::None => return None,
+        models::BorderStyle::Thin => (1, "solid"),
+        models::BorderStyle::Medium => (2, "solid"),
+        models::BorderStyle::Thick => (3, "solid"),
+        models::BorderStyle::Dashed => (1, "dashed"),
+        models::BorderStyle::Dotted => (1, "dotted"),
+        models::BorderStyle::Double => (3, "double"),
+    };
+    let color = match border.color.as_str() {
+        "" => "black",
+        c => c,
+    };
+    Some(format!("{}px {} {}", width, line, color))
 }
 
 #[derive(Default)]
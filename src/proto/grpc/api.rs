@@ -1,4 +1,4 @@
-// This file is generated by rust-protobuf 2.22.1. Do not edit
+// This file is generated by rust-protobuf 2.28.0. Do not edit
 // @generated
 
 // https://github.com/rust-lang/rust-clippy/issues/702
@@ -21,7 +21,7 @@
 
 /// Generated files are compatible only with the same version
 /// of protobuf runtime.
-// const _PROTOBUF_VERSION_CHECK: () = ::protobuf::VERSION_2_22_1;
+// const _PROTOBUF_VERSION_CHECK: () = ::protobuf::VERSION_2_28_0;
 
 #[derive(PartialEq,Clone,Default)]
 pub struct Rect {
@@ -516,6 +516,11 @@ pub struct Cell {
     pub col: i32,
     pub value: ::std::string::String,
     pub display_value: ::std::string::String,
+    pub style_id: u32,
+    pub effective_style_id: u32,
+    pub stale: bool,
+    // message oneof groups
+    pub typed_value: ::std::option::Option<Cell_oneof_typed_value>,
     // special fields
     pub unknown_fields: ::protobuf::UnknownFields,
     pub cached_size: ::protobuf::CachedSize,
@@ -527,6 +532,16 @@ impl<'a> ::std::default::Default for &'a Cell {
     }
 }
 
+#[derive(Clone,PartialEq,Debug)]
+pub enum Cell_oneof_typed_value {
+    empty(EmptyValue),
+    number(f64),
+    text(::std::string::String),
+    bool(bool),
+    error(::std::string::String),
+    date(f64),
+}
+
 impl Cell {
     pub fn new() -> Cell {
         ::std::default::Default::default()
@@ -613,10 +628,282 @@ impl Cell {
     pub fn take_display_value(&mut self) -> ::std::string::String {
         ::std::mem::replace(&mut self.display_value, ::std::string::String::new())
     }
+
+    // .spreadsheet.EmptyValue empty = 5;
+
+
+    pub fn get_empty(&self) -> &EmptyValue {
+        match self.typed_value {
+            ::std::option::Option::Some(Cell_oneof_typed_value::empty(ref v)) => v,
+            _ => <EmptyValue as ::protobuf::Message>::default_instance(),
+        }
+    }
+    pub fn clear_empty(&mut self) {
+        self.typed_value = ::std::option::Option::None;
+    }
+
+    pub fn has_empty(&self) -> bool {
+        match self.typed_value {
+            ::std::option::Option::Some(Cell_oneof_typed_value::empty(..)) => true,
+            _ => false,
+        }
+    }
+
+    // Param is passed by value, moved
+    pub fn set_empty(&mut self, v: EmptyValue) {
+        self.typed_value = ::std::option::Option::Some(Cell_oneof_typed_value::empty(v))
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_empty(&mut self) -> &mut EmptyValue {
+        if let ::std::option::Option::Some(Cell_oneof_typed_value::empty(_)) = self.typed_value {
+        } else {
+            self.typed_value = ::std::option::Option::Some(Cell_oneof_typed_value::empty(EmptyValue::new()));
+        }
+        match self.typed_value {
+            ::std::option::Option::Some(Cell_oneof_typed_value::empty(ref mut v)) => v,
+            _ => panic!(),
+        }
+    }
+
+    // Take field
+    pub fn take_empty(&mut self) -> EmptyValue {
+        if self.has_empty() {
+            match self.typed_value.take() {
+                ::std::option::Option::Some(Cell_oneof_typed_value::empty(v)) => v,
+                _ => panic!(),
+            }
+        } else {
+            EmptyValue::new()
+        }
+    }
+
+    // double number = 6;
+
+
+    pub fn get_number(&self) -> f64 {
+        match self.typed_value {
+            ::std::option::Option::Some(Cell_oneof_typed_value::number(v)) => v,
+            _ => 0.,
+        }
+    }
+    pub fn clear_number(&mut self) {
+        self.typed_value = ::std::option::Option::None;
+    }
+
+    pub fn has_number(&self) -> bool {
+        match self.typed_value {
+            ::std::option::Option::Some(Cell_oneof_typed_value::number(..)) => true,
+            _ => false,
+        }
+    }
+
+    // Param is passed by value, moved
+    pub fn set_number(&mut self, v: f64) {
+        self.typed_value = ::std::option::Option::Some(Cell_oneof_typed_value::number(v))
+    }
+
+    // string text = 7;
+
+
+    pub fn get_text(&self) -> &str {
+        match self.typed_value {
+            ::std::option::Option::Some(Cell_oneof_typed_value::text(ref v)) => v,
+            _ => "",
+        }
+    }
+    pub fn clear_text(&mut self) {
+        self.typed_value = ::std::option::Option::None;
+    }
+
+    pub fn has_text(&self) -> bool {
+        match self.typed_value {
+            ::std::option::Option::Some(Cell_oneof_typed_value::text(..)) => true,
+            _ => false,
+        }
+    }
+
+    // Param is passed by value, moved
+    pub fn set_text(&mut self, v: ::std::string::String) {
+        self.typed_value = ::std::option::Option::Some(Cell_oneof_typed_value::text(v))
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_text(&mut self) -> &mut ::std::string::String {
+        if let ::std::option::Option::Some(Cell_oneof_typed_value::text(_)) = self.typed_value {
+        } else {
+            self.typed_value = ::std::option::Option::Some(Cell_oneof_typed_value::text(::std::string::String::new()));
+        }
+        match self.typed_value {
+            ::std::option::Option::Some(Cell_oneof_typed_value::text(ref mut v)) => v,
+            _ => panic!(),
+        }
+    }
+
+    // Take field
+    pub fn take_text(&mut self) -> ::std::string::String {
+        if self.has_text() {
+            match self.typed_value.take() {
+                ::std::option::Option::Some(Cell_oneof_typed_value::text(v)) => v,
+                _ => panic!(),
+            }
+        } else {
+            ::std::string::String::new()
+        }
+    }
+
+    // bool bool = 8;
+
+
+    pub fn get_bool(&self) -> bool {
+        match self.typed_value {
+            ::std::option::Option::Some(Cell_oneof_typed_value::bool(v)) => v,
+            _ => false,
+        }
+    }
+    pub fn clear_bool(&mut self) {
+        self.typed_value = ::std::option::Option::None;
+    }
+
+    pub fn has_bool(&self) -> bool {
+        match self.typed_value {
+            ::std::option::Option::Some(Cell_oneof_typed_value::bool(..)) => true,
+            _ => false,
+        }
+    }
+
+    // Param is passed by value, moved
+    pub fn set_bool(&mut self, v: bool) {
+        self.typed_value = ::std::option::Option::Some(Cell_oneof_typed_value::bool(v))
+    }
+
+    // string error = 9;
+
+
+    pub fn get_error(&self) -> &str {
+        match self.typed_value {
+            ::std::option::Option::Some(Cell_oneof_typed_value::error(ref v)) => v,
+            _ => "",
+        }
+    }
+    pub fn clear_error(&mut self) {
+        self.typed_value = ::std::option::Option::None;
+    }
+
+    pub fn has_error(&self) -> bool {
+        match self.typed_value {
+            ::std::option::Option::Some(Cell_oneof_typed_value::error(..)) => true,
+            _ => false,
+        }
+    }
+
+    // Param is passed by value, moved
+    pub fn set_error(&mut self, v: ::std::string::String) {
+        self.typed_value = ::std::option::Option::Some(Cell_oneof_typed_value::error(v))
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_error(&mut self) -> &mut ::std::string::String {
+        if let ::std::option::Option::Some(Cell_oneof_typed_value::error(_)) = self.typed_value {
+        } else {
+            self.typed_value = ::std::option::Option::Some(Cell_oneof_typed_value::error(::std::string::String::new()));
+        }
+        match self.typed_value {
+            ::std::option::Option::Some(Cell_oneof_typed_value::error(ref mut v)) => v,
+            _ => panic!(),
+        }
+    }
+
+    // Take field
+    pub fn take_error(&mut self) -> ::std::string::String {
+        if self.has_error() {
+            match self.typed_value.take() {
+                ::std::option::Option::Some(Cell_oneof_typed_value::error(v)) => v,
+                _ => panic!(),
+            }
+        } else {
+            ::std::string::String::new()
+        }
+    }
+
+    // double date = 10;
+
+
+    pub fn get_date(&self) -> f64 {
+        match self.typed_value {
+            ::std::option::Option::Some(Cell_oneof_typed_value::date(v)) => v,
+            _ => 0.,
+        }
+    }
+    pub fn clear_date(&mut self) {
+        self.typed_value = ::std::option::Option::None;
+    }
+
+    pub fn has_date(&self) -> bool {
+        match self.typed_value {
+            ::std::option::Option::Some(Cell_oneof_typed_value::date(..)) => true,
+            _ => false,
+        }
+    }
+
+    // Param is passed by value, moved
+    pub fn set_date(&mut self, v: f64) {
+        self.typed_value = ::std::option::Option::Some(Cell_oneof_typed_value::date(v))
+    }
+
+    // uint32 style_id = 11;
+
+
+    pub fn get_style_id(&self) -> u32 {
+        self.style_id
+    }
+    pub fn clear_style_id(&mut self) {
+        self.style_id = 0;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_style_id(&mut self, v: u32) {
+        self.style_id = v;
+    }
+
+    // uint32 effective_style_id = 12;
+
+
+    pub fn get_effective_style_id(&self) -> u32 {
+        self.effective_style_id
+    }
+    pub fn clear_effective_style_id(&mut self) {
+        self.effective_style_id = 0;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_effective_style_id(&mut self, v: u32) {
+        self.effective_style_id = v;
+    }
+
+    // bool stale = 13;
+
+
+    pub fn get_stale(&self) -> bool {
+        self.stale
+    }
+    pub fn clear_stale(&mut self) {
+        self.stale = false;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_stale(&mut self, v: bool) {
+        self.stale = v;
+    }
 }
 
 impl ::protobuf::Message for Cell {
     fn is_initialized(&self) -> bool {
+        if let Some(Cell_oneof_typed_value::empty(ref v)) = self.typed_value {
+            if !v.is_initialized() {
+                return false;
+            }
+        }
         true
     }
 
@@ -644,6 +931,63 @@ impl ::protobuf::Message for Cell {
                 4 => {
                     ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.display_value)?;
                 },
+                5 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeLengthDelimited {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    self.typed_value = ::std::option::Option::Some(Cell_oneof_typed_value::empty(is.read_message()?));
+                },
+                6 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeFixed64 {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    self.typed_value = ::std::option::Option::Some(Cell_oneof_typed_value::number(is.read_double()?));
+                },
+                7 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeLengthDelimited {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    self.typed_value = ::std::option::Option::Some(Cell_oneof_typed_value::text(is.read_string()?));
+                },
+                8 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    self.typed_value = ::std::option::Option::Some(Cell_oneof_typed_value::bool(is.read_bool()?));
+                },
+                9 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeLengthDelimited {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    self.typed_value = ::std::option::Option::Some(Cell_oneof_typed_value::error(is.read_string()?));
+                },
+                10 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeFixed64 {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    self.typed_value = ::std::option::Option::Some(Cell_oneof_typed_value::date(is.read_double()?));
+                },
+                11 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_uint32()?;
+                    self.style_id = tmp;
+                },
+                12 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_uint32()?;
+                    self.effective_style_id = tmp;
+                },
+                13 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_bool()?;
+                    self.stale = tmp;
+                },
                 _ => {
                     ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
                 },
@@ -668,6 +1012,38 @@ impl ::protobuf::Message for Cell {
         if !self.display_value.is_empty() {
             my_size += ::protobuf::rt::string_size(4, &self.display_value);
         }
+        if self.style_id != 0 {
+            my_size += ::protobuf::rt::value_size(11, self.style_id, ::protobuf::wire_format::WireTypeVarint);
+        }
+        if self.effective_style_id != 0 {
+            my_size += ::protobuf::rt::value_size(12, self.effective_style_id, ::protobuf::wire_format::WireTypeVarint);
+        }
+        if self.stale != false {
+            my_size += 2;
+        }
+        if let ::std::option::Option::Some(ref v) = self.typed_value {
+            match v {
+                &Cell_oneof_typed_value::empty(ref v) => {
+                    let len = v.compute_size();
+                    my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+                },
+                &Cell_oneof_typed_value::number(v) => {
+                    my_size += 9;
+                },
+                &Cell_oneof_typed_value::text(ref v) => {
+                    my_size += ::protobuf::rt::string_size(7, &v);
+                },
+                &Cell_oneof_typed_value::bool(v) => {
+                    my_size += 2;
+                },
+                &Cell_oneof_typed_value::error(ref v) => {
+                    my_size += ::protobuf::rt::string_size(9, &v);
+                },
+                &Cell_oneof_typed_value::date(v) => {
+                    my_size += 9;
+                },
+            };
+        }
         my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
         self.cached_size.set(my_size);
         my_size
@@ -686,6 +1062,39 @@ impl ::protobuf::Message for Cell {
         if !self.display_value.is_empty() {
             os.write_string(4, &self.display_value)?;
         }
+        if self.style_id != 0 {
+            os.write_uint32(11, self.style_id)?;
+        }
+        if self.effective_style_id != 0 {
+            os.write_uint32(12, self.effective_style_id)?;
+        }
+        if self.stale != false {
+            os.write_bool(13, self.stale)?;
+        }
+        if let ::std::option::Option::Some(ref v) = self.typed_value {
+            match v {
+                &Cell_oneof_typed_value::empty(ref v) => {
+                    os.write_tag(5, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+                    os.write_raw_varint32(v.get_cached_size())?;
+                    v.write_to_with_cached_sizes(os)?;
+                },
+                &Cell_oneof_typed_value::number(v) => {
+                    os.write_double(6, v)?;
+                },
+                &Cell_oneof_typed_value::text(ref v) => {
+                    os.write_string(7, v)?;
+                },
+                &Cell_oneof_typed_value::bool(v) => {
+                    os.write_bool(8, v)?;
+                },
+                &Cell_oneof_typed_value::error(ref v) => {
+                    os.write_string(9, v)?;
+                },
+                &Cell_oneof_typed_value::date(v) => {
+                    os.write_double(10, v)?;
+                },
+            };
+        }
         os.write_unknown_fields(self.get_unknown_fields())?;
         ::std::result::Result::Ok(())
     }
@@ -744,6 +1153,51 @@ impl ::protobuf::Message for Cell {
                 |m: &Cell| { &m.display_value },
                 |m: &mut Cell| { &mut m.display_value },
             ));
+            fields.push(::protobuf::reflect::accessor::make_singular_message_accessor::<_, EmptyValue>(
+                "empty",
+                Cell::has_empty,
+                Cell::get_empty,
+            ));
+            fields.push(::protobuf::reflect::accessor::make_singular_f64_accessor::<_>(
+                "number",
+                Cell::has_number,
+                Cell::get_number,
+            ));
+            fields.push(::protobuf::reflect::accessor::make_singular_string_accessor::<_>(
+                "text",
+                Cell::has_text,
+                Cell::get_text,
+            ));
+            fields.push(::protobuf::reflect::accessor::make_singular_bool_accessor::<_>(
+                "bool",
+                Cell::has_bool,
+                Cell::get_bool,
+            ));
+            fields.push(::protobuf::reflect::accessor::make_singular_string_accessor::<_>(
+                "error",
+                Cell::has_error,
+                Cell::get_error,
+            ));
+            fields.push(::protobuf::reflect::accessor::make_singular_f64_accessor::<_>(
+                "date",
+                Cell::has_date,
+                Cell::get_date,
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeUint32>(
+                "style_id",
+                |m: &Cell| { &m.style_id },
+                |m: &mut Cell| { &mut m.style_id },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeUint32>(
+                "effective_style_id",
+                |m: &Cell| { &m.effective_style_id },
+                |m: &mut Cell| { &mut m.effective_style_id },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeBool>(
+                "stale",
+                |m: &Cell| { &m.stale },
+                |m: &mut Cell| { &mut m.stale },
+            ));
             ::protobuf::reflect::MessageDescriptor::new_pb_name::<Cell>(
                 "Cell",
                 fields,
@@ -764,6 +1218,15 @@ impl ::protobuf::Clear for Cell {
         self.col = 0;
         self.value.clear();
         self.display_value.clear();
+        self.typed_value = ::std::option::Option::None;
+        self.typed_value = ::std::option::Option::None;
+        self.typed_value = ::std::option::Option::None;
+        self.typed_value = ::std::option::Option::None;
+        self.typed_value = ::std::option::Option::None;
+        self.typed_value = ::std::option::Option::None;
+        self.style_id = 0;
+        self.effective_style_id = 0;
+        self.stale = false;
         self.unknown_fields.clear();
     }
 }
@@ -781,60 +1244,176 @@ impl ::protobuf::reflect::ProtobufValue for Cell {
 }
 
 #[derive(PartialEq,Clone,Default)]
-pub struct InsertCellsRequest {
-    // message fields
-    pub tableId: ::std::string::String,
-    pub cells: ::protobuf::RepeatedField<InsertCell>,
+pub struct EmptyValue {
     // special fields
     pub unknown_fields: ::protobuf::UnknownFields,
     pub cached_size: ::protobuf::CachedSize,
 }
 
-impl<'a> ::std::default::Default for &'a InsertCellsRequest {
-    fn default() -> &'a InsertCellsRequest {
-        <InsertCellsRequest as ::protobuf::Message>::default_instance()
+impl<'a> ::std::default::Default for &'a EmptyValue {
+    fn default() -> &'a EmptyValue {
+        <EmptyValue as ::protobuf::Message>::default_instance()
     }
 }
 
-impl InsertCellsRequest {
-    pub fn new() -> InsertCellsRequest {
+impl EmptyValue {
+    pub fn new() -> EmptyValue {
         ::std::default::Default::default()
     }
+}
 
-    // string tableId = 1;
-
-
-    pub fn get_tableId(&self) -> &str {
-        &self.tableId
-    }
-    pub fn clear_tableId(&mut self) {
-        self.tableId.clear();
+impl ::protobuf::Message for EmptyValue {
+    fn is_initialized(&self) -> bool {
+        true
     }
 
-    // Param is passed by value, moved
-    pub fn set_tableId(&mut self, v: ::std::string::String) {
-        self.tableId = v;
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
     }
 
-    // Mutable pointer to the field.
-    // If field is not initialized, it is initialized with default value first.
-    pub fn mut_tableId(&mut self) -> &mut ::std::string::String {
-        &mut self.tableId
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
     }
 
-    // Take field
-    pub fn take_tableId(&mut self) -> ::std::string::String {
-        ::std::mem::replace(&mut self.tableId, ::std::string::String::new())
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
     }
 
-    // repeated .spreadsheet.InsertCell cells = 2;
-
-
-    pub fn get_cells(&self) -> &[InsertCell] {
-        &self.cells
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
     }
-    pub fn clear_cells(&mut self) {
-        self.cells.clear();
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> EmptyValue {
+        EmptyValue::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            let fields = ::std::vec::Vec::new();
+            ::protobuf::reflect::MessageDescriptor::new_pb_name::<EmptyValue>(
+                "EmptyValue",
+                fields,
+                file_descriptor_proto()
+            )
+        })
+    }
+
+    fn default_instance() -> &'static EmptyValue {
+        static instance: ::protobuf::rt::LazyV2<EmptyValue> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(EmptyValue::new)
+    }
+}
+
+impl ::protobuf::Clear for EmptyValue {
+    fn clear(&mut self) {
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for EmptyValue {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for EmptyValue {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct InsertCellsRequest {
+    // message fields
+    pub tableId: ::std::string::String,
+    pub cells: ::protobuf::RepeatedField<InsertCell>,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a InsertCellsRequest {
+    fn default() -> &'a InsertCellsRequest {
+        <InsertCellsRequest as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl InsertCellsRequest {
+    pub fn new() -> InsertCellsRequest {
+        ::std::default::Default::default()
+    }
+
+    // string tableId = 1;
+
+
+    pub fn get_tableId(&self) -> &str {
+        &self.tableId
+    }
+    pub fn clear_tableId(&mut self) {
+        self.tableId.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_tableId(&mut self, v: ::std::string::String) {
+        self.tableId = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_tableId(&mut self) -> &mut ::std::string::String {
+        &mut self.tableId
+    }
+
+    // Take field
+    pub fn take_tableId(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.tableId, ::std::string::String::new())
+    }
+
+    // repeated .spreadsheet.InsertCell cells = 2;
+
+
+    pub fn get_cells(&self) -> &[InsertCell] {
+        &self.cells
+    }
+    pub fn clear_cells(&mut self) {
+        self.cells.clear();
     }
 
     // Param is passed by value, moved
@@ -992,6 +1571,8 @@ impl ::protobuf::reflect::ProtobufValue for InsertCellsRequest {
 pub struct InsertCellsResponse {
     // message fields
     pub cells: ::protobuf::RepeatedField<Cell>,
+    pub styles: ::std::collections::HashMap<u32, Style>,
+    pub errors: ::protobuf::RepeatedField<ValidationError>,
     // special fields
     pub unknown_fields: ::protobuf::UnknownFields,
     pub cached_size: ::protobuf::CachedSize,
@@ -1032,6 +1613,56 @@ impl InsertCellsResponse {
     pub fn take_cells(&mut self) -> ::protobuf::RepeatedField<Cell> {
         ::std::mem::replace(&mut self.cells, ::protobuf::RepeatedField::new())
     }
+
+    // repeated .spreadsheet.InsertCellsResponse.StylesEntry styles = 2;
+
+
+    pub fn get_styles(&self) -> &::std::collections::HashMap<u32, Style> {
+        &self.styles
+    }
+    pub fn clear_styles(&mut self) {
+        self.styles.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_styles(&mut self, v: ::std::collections::HashMap<u32, Style>) {
+        self.styles = v;
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_styles(&mut self) -> &mut ::std::collections::HashMap<u32, Style> {
+        &mut self.styles
+    }
+
+    // Take field
+    pub fn take_styles(&mut self) -> ::std::collections::HashMap<u32, Style> {
+        ::std::mem::replace(&mut self.styles, ::std::collections::HashMap::new())
+    }
+
+    // repeated .spreadsheet.ValidationError errors = 3;
+
+
+    pub fn get_errors(&self) -> &[ValidationError] {
+        &self.errors
+    }
+    pub fn clear_errors(&mut self) {
+        self.errors.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_errors(&mut self, v: ::protobuf::RepeatedField<ValidationError>) {
+        self.errors = v;
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_errors(&mut self) -> &mut ::protobuf::RepeatedField<ValidationError> {
+        &mut self.errors
+    }
+
+    // Take field
+    pub fn take_errors(&mut self) -> ::protobuf::RepeatedField<ValidationError> {
+        ::std::mem::replace(&mut self.errors, ::protobuf::RepeatedField::new())
+    }
 }
 
 impl ::protobuf::Message for InsertCellsResponse {
@@ -1041,6 +1672,11 @@ impl ::protobuf::Message for InsertCellsResponse {
                 return false;
             }
         };
+        for v in &self.errors {
+            if !v.is_initialized() {
+                return false;
+            }
+        };
         true
     }
 
@@ -1051,6 +1687,12 @@ impl ::protobuf::Message for InsertCellsResponse {
                 1 => {
                     ::protobuf::rt::read_repeated_message_into(wire_type, is, &mut self.cells)?;
                 },
+                2 => {
+                    ::protobuf::rt::read_map_into::<::protobuf::types::ProtobufTypeUint32, ::protobuf::types::ProtobufTypeMessage<Style>>(wire_type, is, &mut self.styles)?;
+                },
+                3 => {
+                    ::protobuf::rt::read_repeated_message_into(wire_type, is, &mut self.errors)?;
+                },
                 _ => {
                     ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
                 },
@@ -1067,6 +1709,11 @@ impl ::protobuf::Message for InsertCellsResponse {
             let len = value.compute_size();
             my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
         };
+        my_size += ::protobuf::rt::compute_map_size::<::protobuf::types::ProtobufTypeUint32, ::protobuf::types::ProtobufTypeMessage<Style>>(2, &self.styles);
+        for value in &self.errors {
+            let len = value.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        };
         my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
         self.cached_size.set(my_size);
         my_size
@@ -1078,6 +1725,12 @@ impl ::protobuf::Message for InsertCellsResponse {
             os.write_raw_varint32(v.get_cached_size())?;
             v.write_to_with_cached_sizes(os)?;
         };
+        ::protobuf::rt::write_map_with_cached_sizes::<::protobuf::types::ProtobufTypeUint32, ::protobuf::types::ProtobufTypeMessage<Style>>(2, &self.styles, os)?;
+        for v in &self.errors {
+            os.write_tag(3, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+            os.write_raw_varint32(v.get_cached_size())?;
+            v.write_to_with_cached_sizes(os)?;
+        };
         os.write_unknown_fields(self.get_unknown_fields())?;
         ::std::result::Result::Ok(())
     }
@@ -1121,6 +1774,16 @@ impl ::protobuf::Message for InsertCellsResponse {
                 |m: &InsertCellsResponse| { &m.cells },
                 |m: &mut InsertCellsResponse| { &mut m.cells },
             ));
+            fields.push(::protobuf::reflect::accessor::make_map_accessor::<_, ::protobuf::types::ProtobufTypeUint32, ::protobuf::types::ProtobufTypeMessage<Style>>(
+                "styles",
+                |m: &InsertCellsResponse| { &m.styles },
+                |m: &mut InsertCellsResponse| { &mut m.styles },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_repeated_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<ValidationError>>(
+                "errors",
+                |m: &InsertCellsResponse| { &m.errors },
+                |m: &mut InsertCellsResponse| { &mut m.errors },
+            ));
             ::protobuf::reflect::MessageDescriptor::new_pb_name::<InsertCellsResponse>(
                 "InsertCellsResponse",
                 fields,
@@ -1138,6 +1801,8 @@ impl ::protobuf::Message for InsertCellsResponse {
 impl ::protobuf::Clear for InsertCellsResponse {
     fn clear(&mut self) {
         self.cells.clear();
+        self.styles.clear();
+        self.errors.clear();
         self.unknown_fields.clear();
     }
 }
@@ -1374,6 +2039,7 @@ impl ::protobuf::reflect::ProtobufValue for GetCellsRequest {
 pub struct GetCellsResponse {
     // message fields
     pub cells: ::protobuf::RepeatedField<Cell>,
+    pub styles: ::std::collections::HashMap<u32, Style>,
     // special fields
     pub unknown_fields: ::protobuf::UnknownFields,
     pub cached_size: ::protobuf::CachedSize,
@@ -1414,6 +2080,31 @@ impl GetCellsResponse {
     pub fn take_cells(&mut self) -> ::protobuf::RepeatedField<Cell> {
         ::std::mem::replace(&mut self.cells, ::protobuf::RepeatedField::new())
     }
+
+    // repeated .spreadsheet.GetCellsResponse.StylesEntry styles = 2;
+
+
+    pub fn get_styles(&self) -> &::std::collections::HashMap<u32, Style> {
+        &self.styles
+    }
+    pub fn clear_styles(&mut self) {
+        self.styles.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_styles(&mut self, v: ::std::collections::HashMap<u32, Style>) {
+        self.styles = v;
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_styles(&mut self) -> &mut ::std::collections::HashMap<u32, Style> {
+        &mut self.styles
+    }
+
+    // Take field
+    pub fn take_styles(&mut self) -> ::std::collections::HashMap<u32, Style> {
+        ::std::mem::replace(&mut self.styles, ::std::collections::HashMap::new())
+    }
 }
 
 impl ::protobuf::Message for GetCellsResponse {
@@ -1433,6 +2124,9 @@ impl ::protobuf::Message for GetCellsResponse {
                 1 => {
                     ::protobuf::rt::read_repeated_message_into(wire_type, is, &mut self.cells)?;
                 },
+                2 => {
+                    ::protobuf::rt::read_map_into::<::protobuf::types::ProtobufTypeUint32, ::protobuf::types::ProtobufTypeMessage<Style>>(wire_type, is, &mut self.styles)?;
+                },
                 _ => {
                     ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
                 },
@@ -1449,6 +2143,7 @@ impl ::protobuf::Message for GetCellsResponse {
             let len = value.compute_size();
             my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
         };
+        my_size += ::protobuf::rt::compute_map_size::<::protobuf::types::ProtobufTypeUint32, ::protobuf::types::ProtobufTypeMessage<Style>>(2, &self.styles);
         my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
         self.cached_size.set(my_size);
         my_size
@@ -1460,6 +2155,7 @@ impl ::protobuf::Message for GetCellsResponse {
             os.write_raw_varint32(v.get_cached_size())?;
             v.write_to_with_cached_sizes(os)?;
         };
+        ::protobuf::rt::write_map_with_cached_sizes::<::protobuf::types::ProtobufTypeUint32, ::protobuf::types::ProtobufTypeMessage<Style>>(2, &self.styles, os)?;
         os.write_unknown_fields(self.get_unknown_fields())?;
         ::std::result::Result::Ok(())
     }
@@ -1503,6 +2199,11 @@ impl ::protobuf::Message for GetCellsResponse {
                 |m: &GetCellsResponse| { &m.cells },
                 |m: &mut GetCellsResponse| { &mut m.cells },
             ));
+            fields.push(::protobuf::reflect::accessor::make_map_accessor::<_, ::protobuf::types::ProtobufTypeUint32, ::protobuf::types::ProtobufTypeMessage<Style>>(
+                "styles",
+                |m: &GetCellsResponse| { &m.styles },
+                |m: &mut GetCellsResponse| { &mut m.styles },
+            ));
             ::protobuf::reflect::MessageDescriptor::new_pb_name::<GetCellsResponse>(
                 "GetCellsResponse",
                 fields,
@@ -1520,6 +2221,7 @@ impl ::protobuf::Message for GetCellsResponse {
 impl ::protobuf::Clear for GetCellsResponse {
     fn clear(&mut self) {
         self.cells.clear();
+        self.styles.clear();
         self.unknown_fields.clear();
     }
 }
@@ -1536,26 +2238,15115 @@ impl ::protobuf::reflect::ProtobufValue for GetCellsResponse {
     }
 }
 
+#[derive(PartialEq,Clone,Default)]
+pub struct RecalculateRequest {
+    // message fields
+    pub tableId: ::std::string::String,
+    pub rect: ::protobuf::SingularPtrField<Rect>,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a RecalculateRequest {
+    fn default() -> &'a RecalculateRequest {
+        <RecalculateRequest as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl RecalculateRequest {
+    pub fn new() -> RecalculateRequest {
+        ::std::default::Default::default()
+    }
+
+    // string tableId = 1;
+
+
+    pub fn get_tableId(&self) -> &str {
+        &self.tableId
+    }
+    pub fn clear_tableId(&mut self) {
+        self.tableId.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_tableId(&mut self, v: ::std::string::String) {
+        self.tableId = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_tableId(&mut self) -> &mut ::std::string::String {
+        &mut self.tableId
+    }
+
+    // Take field
+    pub fn take_tableId(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.tableId, ::std::string::String::new())
+    }
+
+    // .spreadsheet.Rect rect = 2;
+
+
+    pub fn get_rect(&self) -> &Rect {
+        self.rect.as_ref().unwrap_or_else(|| <Rect as ::protobuf::Message>::default_instance())
+    }
+    pub fn clear_rect(&mut self) {
+        self.rect.clear();
+    }
+
+    pub fn has_rect(&self) -> bool {
+        self.rect.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_rect(&mut self, v: Rect) {
+        self.rect = ::protobuf::SingularPtrField::some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_rect(&mut self) -> &mut Rect {
+        if self.rect.is_none() {
+            self.rect.set_default();
+        }
+        self.rect.as_mut().unwrap()
+    }
+
+    // Take field
+    pub fn take_rect(&mut self) -> Rect {
+        self.rect.take().unwrap_or_else(|| Rect::new())
+    }
+}
+
+impl ::protobuf::Message for RecalculateRequest {
+    fn is_initialized(&self) -> bool {
+        for v in &self.rect {
+            if !v.is_initialized() {
+                return false;
+            }
+        };
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.tableId)?;
+                },
+                2 => {
+                    ::protobuf::rt::read_singular_message_into(wire_type, is, &mut self.rect)?;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if !self.tableId.is_empty() {
+            my_size += ::protobuf::rt::string_size(1, &self.tableId);
+        }
+        if let Some(ref v) = self.rect.as_ref() {
+            let len = v.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        if !self.tableId.is_empty() {
+            os.write_string(1, &self.tableId)?;
+        }
+        if let Some(ref v) = self.rect.as_ref() {
+            os.write_tag(2, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+            os.write_raw_varint32(v.get_cached_size())?;
+            v.write_to_with_cached_sizes(os)?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> RecalculateRequest {
+        RecalculateRequest::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            let mut fields = ::std::vec::Vec::new();
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "tableId",
+                |m: &RecalculateRequest| { &m.tableId },
+                |m: &mut RecalculateRequest| { &mut m.tableId },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_singular_ptr_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<Rect>>(
+                "rect",
+                |m: &RecalculateRequest| { &m.rect },
+                |m: &mut RecalculateRequest| { &mut m.rect },
+            ));
+            ::protobuf::reflect::MessageDescriptor::new_pb_name::<RecalculateRequest>(
+                "RecalculateRequest",
+                fields,
+                file_descriptor_proto()
+            )
+        })
+    }
+
+    fn default_instance() -> &'static RecalculateRequest {
+        static instance: ::protobuf::rt::LazyV2<RecalculateRequest> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(RecalculateRequest::new)
+    }
+}
+
+impl ::protobuf::Clear for RecalculateRequest {
+    fn clear(&mut self) {
+        self.tableId.clear();
+        self.rect.clear();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for RecalculateRequest {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for RecalculateRequest {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct RecalculateResponse {
+    // message fields
+    pub cells: ::protobuf::RepeatedField<Cell>,
+    pub styles: ::std::collections::HashMap<u32, Style>,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a RecalculateResponse {
+    fn default() -> &'a RecalculateResponse {
+        <RecalculateResponse as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl RecalculateResponse {
+    pub fn new() -> RecalculateResponse {
+        ::std::default::Default::default()
+    }
+
+    // repeated .spreadsheet.Cell cells = 1;
+
+
+    pub fn get_cells(&self) -> &[Cell] {
+        &self.cells
+    }
+    pub fn clear_cells(&mut self) {
+        self.cells.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_cells(&mut self, v: ::protobuf::RepeatedField<Cell>) {
+        self.cells = v;
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_cells(&mut self) -> &mut ::protobuf::RepeatedField<Cell> {
+        &mut self.cells
+    }
+
+    // Take field
+    pub fn take_cells(&mut self) -> ::protobuf::RepeatedField<Cell> {
+        ::std::mem::replace(&mut self.cells, ::protobuf::RepeatedField::new())
+    }
+
+    // repeated .spreadsheet.RecalculateResponse.StylesEntry styles = 2;
+
+
+    pub fn get_styles(&self) -> &::std::collections::HashMap<u32, Style> {
+        &self.styles
+    }
+    pub fn clear_styles(&mut self) {
+        self.styles.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_styles(&mut self, v: ::std::collections::HashMap<u32, Style>) {
+        self.styles = v;
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_styles(&mut self) -> &mut ::std::collections::HashMap<u32, Style> {
+        &mut self.styles
+    }
+
+    // Take field
+    pub fn take_styles(&mut self) -> ::std::collections::HashMap<u32, Style> {
+        ::std::mem::replace(&mut self.styles, ::std::collections::HashMap::new())
+    }
+}
+
+impl ::protobuf::Message for RecalculateResponse {
+    fn is_initialized(&self) -> bool {
+        for v in &self.cells {
+            if !v.is_initialized() {
+                return false;
+            }
+        };
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_repeated_message_into(wire_type, is, &mut self.cells)?;
+                },
+                2 => {
+                    ::protobuf::rt::read_map_into::<::protobuf::types::ProtobufTypeUint32, ::protobuf::types::ProtobufTypeMessage<Style>>(wire_type, is, &mut self.styles)?;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        for value in &self.cells {
+            let len = value.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        };
+        my_size += ::protobuf::rt::compute_map_size::<::protobuf::types::ProtobufTypeUint32, ::protobuf::types::ProtobufTypeMessage<Style>>(2, &self.styles);
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        for v in &self.cells {
+            os.write_tag(1, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+            os.write_raw_varint32(v.get_cached_size())?;
+            v.write_to_with_cached_sizes(os)?;
+        };
+        ::protobuf::rt::write_map_with_cached_sizes::<::protobuf::types::ProtobufTypeUint32, ::protobuf::types::ProtobufTypeMessage<Style>>(2, &self.styles, os)?;
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> RecalculateResponse {
+        RecalculateResponse::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            let mut fields = ::std::vec::Vec::new();
+            fields.push(::protobuf::reflect::accessor::make_repeated_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<Cell>>(
+                "cells",
+                |m: &RecalculateResponse| { &m.cells },
+                |m: &mut RecalculateResponse| { &mut m.cells },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_map_accessor::<_, ::protobuf::types::ProtobufTypeUint32, ::protobuf::types::ProtobufTypeMessage<Style>>(
+                "styles",
+                |m: &RecalculateResponse| { &m.styles },
+                |m: &mut RecalculateResponse| { &mut m.styles },
+            ));
+            ::protobuf::reflect::MessageDescriptor::new_pb_name::<RecalculateResponse>(
+                "RecalculateResponse",
+                fields,
+                file_descriptor_proto()
+            )
+        })
+    }
+
+    fn default_instance() -> &'static RecalculateResponse {
+        static instance: ::protobuf::rt::LazyV2<RecalculateResponse> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(RecalculateResponse::new)
+    }
+}
+
+impl ::protobuf::Clear for RecalculateResponse {
+    fn clear(&mut self) {
+        self.cells.clear();
+        self.styles.clear();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for RecalculateResponse {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for RecalculateResponse {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct ClearCellsRequest {
+    // message fields
+    pub tableId: ::std::string::String,
+    pub rect: ::protobuf::SingularPtrField<Rect>,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a ClearCellsRequest {
+    fn default() -> &'a ClearCellsRequest {
+        <ClearCellsRequest as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl ClearCellsRequest {
+    pub fn new() -> ClearCellsRequest {
+        ::std::default::Default::default()
+    }
+
+    // string tableId = 1;
+
+
+    pub fn get_tableId(&self) -> &str {
+        &self.tableId
+    }
+    pub fn clear_tableId(&mut self) {
+        self.tableId.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_tableId(&mut self, v: ::std::string::String) {
+        self.tableId = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_tableId(&mut self) -> &mut ::std::string::String {
+        &mut self.tableId
+    }
+
+    // Take field
+    pub fn take_tableId(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.tableId, ::std::string::String::new())
+    }
+
+    // .spreadsheet.Rect rect = 2;
+
+
+    pub fn get_rect(&self) -> &Rect {
+        self.rect.as_ref().unwrap_or_else(|| <Rect as ::protobuf::Message>::default_instance())
+    }
+    pub fn clear_rect(&mut self) {
+        self.rect.clear();
+    }
+
+    pub fn has_rect(&self) -> bool {
+        self.rect.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_rect(&mut self, v: Rect) {
+        self.rect = ::protobuf::SingularPtrField::some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_rect(&mut self) -> &mut Rect {
+        if self.rect.is_none() {
+            self.rect.set_default();
+        }
+        self.rect.as_mut().unwrap()
+    }
+
+    // Take field
+    pub fn take_rect(&mut self) -> Rect {
+        self.rect.take().unwrap_or_else(|| Rect::new())
+    }
+}
+
+impl ::protobuf::Message for ClearCellsRequest {
+    fn is_initialized(&self) -> bool {
+        for v in &self.rect {
+            if !v.is_initialized() {
+                return false;
+            }
+        };
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.tableId)?;
+                },
+                2 => {
+                    ::protobuf::rt::read_singular_message_into(wire_type, is, &mut self.rect)?;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if !self.tableId.is_empty() {
+            my_size += ::protobuf::rt::string_size(1, &self.tableId);
+        }
+        if let Some(ref v) = self.rect.as_ref() {
+            let len = v.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        if !self.tableId.is_empty() {
+            os.write_string(1, &self.tableId)?;
+        }
+        if let Some(ref v) = self.rect.as_ref() {
+            os.write_tag(2, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+            os.write_raw_varint32(v.get_cached_size())?;
+            v.write_to_with_cached_sizes(os)?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> ClearCellsRequest {
+        ClearCellsRequest::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            let mut fields = ::std::vec::Vec::new();
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "tableId",
+                |m: &ClearCellsRequest| { &m.tableId },
+                |m: &mut ClearCellsRequest| { &mut m.tableId },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_singular_ptr_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<Rect>>(
+                "rect",
+                |m: &ClearCellsRequest| { &m.rect },
+                |m: &mut ClearCellsRequest| { &mut m.rect },
+            ));
+            ::protobuf::reflect::MessageDescriptor::new_pb_name::<ClearCellsRequest>(
+                "ClearCellsRequest",
+                fields,
+                file_descriptor_proto()
+            )
+        })
+    }
+
+    fn default_instance() -> &'static ClearCellsRequest {
+        static instance: ::protobuf::rt::LazyV2<ClearCellsRequest> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(ClearCellsRequest::new)
+    }
+}
+
+impl ::protobuf::Clear for ClearCellsRequest {
+    fn clear(&mut self) {
+        self.tableId.clear();
+        self.rect.clear();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for ClearCellsRequest {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for ClearCellsRequest {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct ClearCellsResponse {
+    // message fields
+    pub cells: ::protobuf::RepeatedField<Cell>,
+    pub styles: ::std::collections::HashMap<u32, Style>,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a ClearCellsResponse {
+    fn default() -> &'a ClearCellsResponse {
+        <ClearCellsResponse as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl ClearCellsResponse {
+    pub fn new() -> ClearCellsResponse {
+        ::std::default::Default::default()
+    }
+
+    // repeated .spreadsheet.Cell cells = 1;
+
+
+    pub fn get_cells(&self) -> &[Cell] {
+        &self.cells
+    }
+    pub fn clear_cells(&mut self) {
+        self.cells.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_cells(&mut self, v: ::protobuf::RepeatedField<Cell>) {
+        self.cells = v;
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_cells(&mut self) -> &mut ::protobuf::RepeatedField<Cell> {
+        &mut self.cells
+    }
+
+    // Take field
+    pub fn take_cells(&mut self) -> ::protobuf::RepeatedField<Cell> {
+        ::std::mem::replace(&mut self.cells, ::protobuf::RepeatedField::new())
+    }
+
+    // repeated .spreadsheet.ClearCellsResponse.StylesEntry styles = 2;
+
+
+    pub fn get_styles(&self) -> &::std::collections::HashMap<u32, Style> {
+        &self.styles
+    }
+    pub fn clear_styles(&mut self) {
+        self.styles.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_styles(&mut self, v: ::std::collections::HashMap<u32, Style>) {
+        self.styles = v;
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_styles(&mut self) -> &mut ::std::collections::HashMap<u32, Style> {
+        &mut self.styles
+    }
+
+    // Take field
+    pub fn take_styles(&mut self) -> ::std::collections::HashMap<u32, Style> {
+        ::std::mem::replace(&mut self.styles, ::std::collections::HashMap::new())
+    }
+}
+
+impl ::protobuf::Message for ClearCellsResponse {
+    fn is_initialized(&self) -> bool {
+        for v in &self.cells {
+            if !v.is_initialized() {
+                return false;
+            }
+        };
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_repeated_message_into(wire_type, is, &mut self.cells)?;
+                },
+                2 => {
+                    ::protobuf::rt::read_map_into::<::protobuf::types::ProtobufTypeUint32, ::protobuf::types::ProtobufTypeMessage<Style>>(wire_type, is, &mut self.styles)?;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        for value in &self.cells {
+            let len = value.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        };
+        my_size += ::protobuf::rt::compute_map_size::<::protobuf::types::ProtobufTypeUint32, ::protobuf::types::ProtobufTypeMessage<Style>>(2, &self.styles);
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        for v in &self.cells {
+            os.write_tag(1, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+            os.write_raw_varint32(v.get_cached_size())?;
+            v.write_to_with_cached_sizes(os)?;
+        };
+        ::protobuf::rt::write_map_with_cached_sizes::<::protobuf::types::ProtobufTypeUint32, ::protobuf::types::ProtobufTypeMessage<Style>>(2, &self.styles, os)?;
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> ClearCellsResponse {
+        ClearCellsResponse::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            let mut fields = ::std::vec::Vec::new();
+            fields.push(::protobuf::reflect::accessor::make_repeated_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<Cell>>(
+                "cells",
+                |m: &ClearCellsResponse| { &m.cells },
+                |m: &mut ClearCellsResponse| { &mut m.cells },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_map_accessor::<_, ::protobuf::types::ProtobufTypeUint32, ::protobuf::types::ProtobufTypeMessage<Style>>(
+                "styles",
+                |m: &ClearCellsResponse| { &m.styles },
+                |m: &mut ClearCellsResponse| { &mut m.styles },
+            ));
+            ::protobuf::reflect::MessageDescriptor::new_pb_name::<ClearCellsResponse>(
+                "ClearCellsResponse",
+                fields,
+                file_descriptor_proto()
+            )
+        })
+    }
+
+    fn default_instance() -> &'static ClearCellsResponse {
+        static instance: ::protobuf::rt::LazyV2<ClearCellsResponse> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(ClearCellsResponse::new)
+    }
+}
+
+impl ::protobuf::Clear for ClearCellsResponse {
+    fn clear(&mut self) {
+        self.cells.clear();
+        self.styles.clear();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for ClearCellsResponse {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for ClearCellsResponse {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct InsertRowsRequest {
+    // message fields
+    pub tableId: ::std::string::String,
+    pub at: i32,
+    pub count: i32,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a InsertRowsRequest {
+    fn default() -> &'a InsertRowsRequest {
+        <InsertRowsRequest as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl InsertRowsRequest {
+    pub fn new() -> InsertRowsRequest {
+        ::std::default::Default::default()
+    }
+
+    // string tableId = 1;
+
+
+    pub fn get_tableId(&self) -> &str {
+        &self.tableId
+    }
+    pub fn clear_tableId(&mut self) {
+        self.tableId.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_tableId(&mut self, v: ::std::string::String) {
+        self.tableId = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_tableId(&mut self) -> &mut ::std::string::String {
+        &mut self.tableId
+    }
+
+    // Take field
+    pub fn take_tableId(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.tableId, ::std::string::String::new())
+    }
+
+    // int32 at = 2;
+
+
+    pub fn get_at(&self) -> i32 {
+        self.at
+    }
+    pub fn clear_at(&mut self) {
+        self.at = 0;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_at(&mut self, v: i32) {
+        self.at = v;
+    }
+
+    // int32 count = 3;
+
+
+    pub fn get_count(&self) -> i32 {
+        self.count
+    }
+    pub fn clear_count(&mut self) {
+        self.count = 0;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_count(&mut self, v: i32) {
+        self.count = v;
+    }
+}
+
+impl ::protobuf::Message for InsertRowsRequest {
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.tableId)?;
+                },
+                2 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_int32()?;
+                    self.at = tmp;
+                },
+                3 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_int32()?;
+                    self.count = tmp;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if !self.tableId.is_empty() {
+            my_size += ::protobuf::rt::string_size(1, &self.tableId);
+        }
+        if self.at != 0 {
+            my_size += ::protobuf::rt::value_size(2, self.at, ::protobuf::wire_format::WireTypeVarint);
+        }
+        if self.count != 0 {
+            my_size += ::protobuf::rt::value_size(3, self.count, ::protobuf::wire_format::WireTypeVarint);
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        if !self.tableId.is_empty() {
+            os.write_string(1, &self.tableId)?;
+        }
+        if self.at != 0 {
+            os.write_int32(2, self.at)?;
+        }
+        if self.count != 0 {
+            os.write_int32(3, self.count)?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> InsertRowsRequest {
+        InsertRowsRequest::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            let mut fields = ::std::vec::Vec::new();
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "tableId",
+                |m: &InsertRowsRequest| { &m.tableId },
+                |m: &mut InsertRowsRequest| { &mut m.tableId },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeInt32>(
+                "at",
+                |m: &InsertRowsRequest| { &m.at },
+                |m: &mut InsertRowsRequest| { &mut m.at },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeInt32>(
+                "count",
+                |m: &InsertRowsRequest| { &m.count },
+                |m: &mut InsertRowsRequest| { &mut m.count },
+            ));
+            ::protobuf::reflect::MessageDescriptor::new_pb_name::<InsertRowsRequest>(
+                "InsertRowsRequest",
+                fields,
+                file_descriptor_proto()
+            )
+        })
+    }
+
+    fn default_instance() -> &'static InsertRowsRequest {
+        static instance: ::protobuf::rt::LazyV2<InsertRowsRequest> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(InsertRowsRequest::new)
+    }
+}
+
+impl ::protobuf::Clear for InsertRowsRequest {
+    fn clear(&mut self) {
+        self.tableId.clear();
+        self.at = 0;
+        self.count = 0;
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for InsertRowsRequest {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for InsertRowsRequest {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct InsertRowsResponse {
+    // message fields
+    pub cells: ::protobuf::RepeatedField<Cell>,
+    pub styles: ::std::collections::HashMap<u32, Style>,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a InsertRowsResponse {
+    fn default() -> &'a InsertRowsResponse {
+        <InsertRowsResponse as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl InsertRowsResponse {
+    pub fn new() -> InsertRowsResponse {
+        ::std::default::Default::default()
+    }
+
+    // repeated .spreadsheet.Cell cells = 1;
+
+
+    pub fn get_cells(&self) -> &[Cell] {
+        &self.cells
+    }
+    pub fn clear_cells(&mut self) {
+        self.cells.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_cells(&mut self, v: ::protobuf::RepeatedField<Cell>) {
+        self.cells = v;
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_cells(&mut self) -> &mut ::protobuf::RepeatedField<Cell> {
+        &mut self.cells
+    }
+
+    // Take field
+    pub fn take_cells(&mut self) -> ::protobuf::RepeatedField<Cell> {
+        ::std::mem::replace(&mut self.cells, ::protobuf::RepeatedField::new())
+    }
+
+    // repeated .spreadsheet.InsertRowsResponse.StylesEntry styles = 2;
+
+
+    pub fn get_styles(&self) -> &::std::collections::HashMap<u32, Style> {
+        &self.styles
+    }
+    pub fn clear_styles(&mut self) {
+        self.styles.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_styles(&mut self, v: ::std::collections::HashMap<u32, Style>) {
+        self.styles = v;
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_styles(&mut self) -> &mut ::std::collections::HashMap<u32, Style> {
+        &mut self.styles
+    }
+
+    // Take field
+    pub fn take_styles(&mut self) -> ::std::collections::HashMap<u32, Style> {
+        ::std::mem::replace(&mut self.styles, ::std::collections::HashMap::new())
+    }
+}
+
+impl ::protobuf::Message for InsertRowsResponse {
+    fn is_initialized(&self) -> bool {
+        for v in &self.cells {
+            if !v.is_initialized() {
+                return false;
+            }
+        };
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_repeated_message_into(wire_type, is, &mut self.cells)?;
+                },
+                2 => {
+                    ::protobuf::rt::read_map_into::<::protobuf::types::ProtobufTypeUint32, ::protobuf::types::ProtobufTypeMessage<Style>>(wire_type, is, &mut self.styles)?;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        for value in &self.cells {
+            let len = value.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        };
+        my_size += ::protobuf::rt::compute_map_size::<::protobuf::types::ProtobufTypeUint32, ::protobuf::types::ProtobufTypeMessage<Style>>(2, &self.styles);
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        for v in &self.cells {
+            os.write_tag(1, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+            os.write_raw_varint32(v.get_cached_size())?;
+            v.write_to_with_cached_sizes(os)?;
+        };
+        ::protobuf::rt::write_map_with_cached_sizes::<::protobuf::types::ProtobufTypeUint32, ::protobuf::types::ProtobufTypeMessage<Style>>(2, &self.styles, os)?;
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> InsertRowsResponse {
+        InsertRowsResponse::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            let mut fields = ::std::vec::Vec::new();
+            fields.push(::protobuf::reflect::accessor::make_repeated_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<Cell>>(
+                "cells",
+                |m: &InsertRowsResponse| { &m.cells },
+                |m: &mut InsertRowsResponse| { &mut m.cells },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_map_accessor::<_, ::protobuf::types::ProtobufTypeUint32, ::protobuf::types::ProtobufTypeMessage<Style>>(
+                "styles",
+                |m: &InsertRowsResponse| { &m.styles },
+                |m: &mut InsertRowsResponse| { &mut m.styles },
+            ));
+            ::protobuf::reflect::MessageDescriptor::new_pb_name::<InsertRowsResponse>(
+                "InsertRowsResponse",
+                fields,
+                file_descriptor_proto()
+            )
+        })
+    }
+
+    fn default_instance() -> &'static InsertRowsResponse {
+        static instance: ::protobuf::rt::LazyV2<InsertRowsResponse> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(InsertRowsResponse::new)
+    }
+}
+
+impl ::protobuf::Clear for InsertRowsResponse {
+    fn clear(&mut self) {
+        self.cells.clear();
+        self.styles.clear();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for InsertRowsResponse {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for InsertRowsResponse {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct DeleteRowsRequest {
+    // message fields
+    pub tableId: ::std::string::String,
+    pub at: i32,
+    pub count: i32,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a DeleteRowsRequest {
+    fn default() -> &'a DeleteRowsRequest {
+        <DeleteRowsRequest as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl DeleteRowsRequest {
+    pub fn new() -> DeleteRowsRequest {
+        ::std::default::Default::default()
+    }
+
+    // string tableId = 1;
+
+
+    pub fn get_tableId(&self) -> &str {
+        &self.tableId
+    }
+    pub fn clear_tableId(&mut self) {
+        self.tableId.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_tableId(&mut self, v: ::std::string::String) {
+        self.tableId = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_tableId(&mut self) -> &mut ::std::string::String {
+        &mut self.tableId
+    }
+
+    // Take field
+    pub fn take_tableId(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.tableId, ::std::string::String::new())
+    }
+
+    // int32 at = 2;
+
+
+    pub fn get_at(&self) -> i32 {
+        self.at
+    }
+    pub fn clear_at(&mut self) {
+        self.at = 0;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_at(&mut self, v: i32) {
+        self.at = v;
+    }
+
+    // int32 count = 3;
+
+
+    pub fn get_count(&self) -> i32 {
+        self.count
+    }
+    pub fn clear_count(&mut self) {
+        self.count = 0;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_count(&mut self, v: i32) {
+        self.count = v;
+    }
+}
+
+impl ::protobuf::Message for DeleteRowsRequest {
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.tableId)?;
+                },
+                2 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_int32()?;
+                    self.at = tmp;
+                },
+                3 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_int32()?;
+                    self.count = tmp;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if !self.tableId.is_empty() {
+            my_size += ::protobuf::rt::string_size(1, &self.tableId);
+        }
+        if self.at != 0 {
+            my_size += ::protobuf::rt::value_size(2, self.at, ::protobuf::wire_format::WireTypeVarint);
+        }
+        if self.count != 0 {
+            my_size += ::protobuf::rt::value_size(3, self.count, ::protobuf::wire_format::WireTypeVarint);
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        if !self.tableId.is_empty() {
+            os.write_string(1, &self.tableId)?;
+        }
+        if self.at != 0 {
+            os.write_int32(2, self.at)?;
+        }
+        if self.count != 0 {
+            os.write_int32(3, self.count)?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> DeleteRowsRequest {
+        DeleteRowsRequest::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            let mut fields = ::std::vec::Vec::new();
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "tableId",
+                |m: &DeleteRowsRequest| { &m.tableId },
+                |m: &mut DeleteRowsRequest| { &mut m.tableId },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeInt32>(
+                "at",
+                |m: &DeleteRowsRequest| { &m.at },
+                |m: &mut DeleteRowsRequest| { &mut m.at },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeInt32>(
+                "count",
+                |m: &DeleteRowsRequest| { &m.count },
+                |m: &mut DeleteRowsRequest| { &mut m.count },
+            ));
+            ::protobuf::reflect::MessageDescriptor::new_pb_name::<DeleteRowsRequest>(
+                "DeleteRowsRequest",
+                fields,
+                file_descriptor_proto()
+            )
+        })
+    }
+
+    fn default_instance() -> &'static DeleteRowsRequest {
+        static instance: ::protobuf::rt::LazyV2<DeleteRowsRequest> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(DeleteRowsRequest::new)
+    }
+}
+
+impl ::protobuf::Clear for DeleteRowsRequest {
+    fn clear(&mut self) {
+        self.tableId.clear();
+        self.at = 0;
+        self.count = 0;
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for DeleteRowsRequest {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for DeleteRowsRequest {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct DeleteRowsResponse {
+    // message fields
+    pub cells: ::protobuf::RepeatedField<Cell>,
+    pub styles: ::std::collections::HashMap<u32, Style>,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a DeleteRowsResponse {
+    fn default() -> &'a DeleteRowsResponse {
+        <DeleteRowsResponse as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl DeleteRowsResponse {
+    pub fn new() -> DeleteRowsResponse {
+        ::std::default::Default::default()
+    }
+
+    // repeated .spreadsheet.Cell cells = 1;
+
+
+    pub fn get_cells(&self) -> &[Cell] {
+        &self.cells
+    }
+    pub fn clear_cells(&mut self) {
+        self.cells.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_cells(&mut self, v: ::protobuf::RepeatedField<Cell>) {
+        self.cells = v;
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_cells(&mut self) -> &mut ::protobuf::RepeatedField<Cell> {
+        &mut self.cells
+    }
+
+    // Take field
+    pub fn take_cells(&mut self) -> ::protobuf::RepeatedField<Cell> {
+        ::std::mem::replace(&mut self.cells, ::protobuf::RepeatedField::new())
+    }
+
+    // repeated .spreadsheet.DeleteRowsResponse.StylesEntry styles = 2;
+
+
+    pub fn get_styles(&self) -> &::std::collections::HashMap<u32, Style> {
+        &self.styles
+    }
+    pub fn clear_styles(&mut self) {
+        self.styles.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_styles(&mut self, v: ::std::collections::HashMap<u32, Style>) {
+        self.styles = v;
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_styles(&mut self) -> &mut ::std::collections::HashMap<u32, Style> {
+        &mut self.styles
+    }
+
+    // Take field
+    pub fn take_styles(&mut self) -> ::std::collections::HashMap<u32, Style> {
+        ::std::mem::replace(&mut self.styles, ::std::collections::HashMap::new())
+    }
+}
+
+impl ::protobuf::Message for DeleteRowsResponse {
+    fn is_initialized(&self) -> bool {
+        for v in &self.cells {
+            if !v.is_initialized() {
+                return false;
+            }
+        };
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_repeated_message_into(wire_type, is, &mut self.cells)?;
+                },
+                2 => {
+                    ::protobuf::rt::read_map_into::<::protobuf::types::ProtobufTypeUint32, ::protobuf::types::ProtobufTypeMessage<Style>>(wire_type, is, &mut self.styles)?;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        for value in &self.cells {
+            let len = value.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        };
+        my_size += ::protobuf::rt::compute_map_size::<::protobuf::types::ProtobufTypeUint32, ::protobuf::types::ProtobufTypeMessage<Style>>(2, &self.styles);
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        for v in &self.cells {
+            os.write_tag(1, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+            os.write_raw_varint32(v.get_cached_size())?;
+            v.write_to_with_cached_sizes(os)?;
+        };
+        ::protobuf::rt::write_map_with_cached_sizes::<::protobuf::types::ProtobufTypeUint32, ::protobuf::types::ProtobufTypeMessage<Style>>(2, &self.styles, os)?;
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> DeleteRowsResponse {
+        DeleteRowsResponse::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            let mut fields = ::std::vec::Vec::new();
+            fields.push(::protobuf::reflect::accessor::make_repeated_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<Cell>>(
+                "cells",
+                |m: &DeleteRowsResponse| { &m.cells },
+                |m: &mut DeleteRowsResponse| { &mut m.cells },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_map_accessor::<_, ::protobuf::types::ProtobufTypeUint32, ::protobuf::types::ProtobufTypeMessage<Style>>(
+                "styles",
+                |m: &DeleteRowsResponse| { &m.styles },
+                |m: &mut DeleteRowsResponse| { &mut m.styles },
+            ));
+            ::protobuf::reflect::MessageDescriptor::new_pb_name::<DeleteRowsResponse>(
+                "DeleteRowsResponse",
+                fields,
+                file_descriptor_proto()
+            )
+        })
+    }
+
+    fn default_instance() -> &'static DeleteRowsResponse {
+        static instance: ::protobuf::rt::LazyV2<DeleteRowsResponse> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(DeleteRowsResponse::new)
+    }
+}
+
+impl ::protobuf::Clear for DeleteRowsResponse {
+    fn clear(&mut self) {
+        self.cells.clear();
+        self.styles.clear();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for DeleteRowsResponse {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for DeleteRowsResponse {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct InsertColumnsRequest {
+    // message fields
+    pub tableId: ::std::string::String,
+    pub at: i32,
+    pub count: i32,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a InsertColumnsRequest {
+    fn default() -> &'a InsertColumnsRequest {
+        <InsertColumnsRequest as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl InsertColumnsRequest {
+    pub fn new() -> InsertColumnsRequest {
+        ::std::default::Default::default()
+    }
+
+    // string tableId = 1;
+
+
+    pub fn get_tableId(&self) -> &str {
+        &self.tableId
+    }
+    pub fn clear_tableId(&mut self) {
+        self.tableId.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_tableId(&mut self, v: ::std::string::String) {
+        self.tableId = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_tableId(&mut self) -> &mut ::std::string::String {
+        &mut self.tableId
+    }
+
+    // Take field
+    pub fn take_tableId(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.tableId, ::std::string::String::new())
+    }
+
+    // int32 at = 2;
+
+
+    pub fn get_at(&self) -> i32 {
+        self.at
+    }
+    pub fn clear_at(&mut self) {
+        self.at = 0;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_at(&mut self, v: i32) {
+        self.at = v;
+    }
+
+    // int32 count = 3;
+
+
+    pub fn get_count(&self) -> i32 {
+        self.count
+    }
+    pub fn clear_count(&mut self) {
+        self.count = 0;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_count(&mut self, v: i32) {
+        self.count = v;
+    }
+}
+
+impl ::protobuf::Message for InsertColumnsRequest {
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.tableId)?;
+                },
+                2 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_int32()?;
+                    self.at = tmp;
+                },
+                3 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_int32()?;
+                    self.count = tmp;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if !self.tableId.is_empty() {
+            my_size += ::protobuf::rt::string_size(1, &self.tableId);
+        }
+        if self.at != 0 {
+            my_size += ::protobuf::rt::value_size(2, self.at, ::protobuf::wire_format::WireTypeVarint);
+        }
+        if self.count != 0 {
+            my_size += ::protobuf::rt::value_size(3, self.count, ::protobuf::wire_format::WireTypeVarint);
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        if !self.tableId.is_empty() {
+            os.write_string(1, &self.tableId)?;
+        }
+        if self.at != 0 {
+            os.write_int32(2, self.at)?;
+        }
+        if self.count != 0 {
+            os.write_int32(3, self.count)?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> InsertColumnsRequest {
+        InsertColumnsRequest::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            let mut fields = ::std::vec::Vec::new();
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "tableId",
+                |m: &InsertColumnsRequest| { &m.tableId },
+                |m: &mut InsertColumnsRequest| { &mut m.tableId },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeInt32>(
+                "at",
+                |m: &InsertColumnsRequest| { &m.at },
+                |m: &mut InsertColumnsRequest| { &mut m.at },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeInt32>(
+                "count",
+                |m: &InsertColumnsRequest| { &m.count },
+                |m: &mut InsertColumnsRequest| { &mut m.count },
+            ));
+            ::protobuf::reflect::MessageDescriptor::new_pb_name::<InsertColumnsRequest>(
+                "InsertColumnsRequest",
+                fields,
+                file_descriptor_proto()
+            )
+        })
+    }
+
+    fn default_instance() -> &'static InsertColumnsRequest {
+        static instance: ::protobuf::rt::LazyV2<InsertColumnsRequest> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(InsertColumnsRequest::new)
+    }
+}
+
+impl ::protobuf::Clear for InsertColumnsRequest {
+    fn clear(&mut self) {
+        self.tableId.clear();
+        self.at = 0;
+        self.count = 0;
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for InsertColumnsRequest {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for InsertColumnsRequest {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct InsertColumnsResponse {
+    // message fields
+    pub cells: ::protobuf::RepeatedField<Cell>,
+    pub styles: ::std::collections::HashMap<u32, Style>,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a InsertColumnsResponse {
+    fn default() -> &'a InsertColumnsResponse {
+        <InsertColumnsResponse as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl InsertColumnsResponse {
+    pub fn new() -> InsertColumnsResponse {
+        ::std::default::Default::default()
+    }
+
+    // repeated .spreadsheet.Cell cells = 1;
+
+
+    pub fn get_cells(&self) -> &[Cell] {
+        &self.cells
+    }
+    pub fn clear_cells(&mut self) {
+        self.cells.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_cells(&mut self, v: ::protobuf::RepeatedField<Cell>) {
+        self.cells = v;
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_cells(&mut self) -> &mut ::protobuf::RepeatedField<Cell> {
+        &mut self.cells
+    }
+
+    // Take field
+    pub fn take_cells(&mut self) -> ::protobuf::RepeatedField<Cell> {
+        ::std::mem::replace(&mut self.cells, ::protobuf::RepeatedField::new())
+    }
+
+    // repeated .spreadsheet.InsertColumnsResponse.StylesEntry styles = 2;
+
+
+    pub fn get_styles(&self) -> &::std::collections::HashMap<u32, Style> {
+        &self.styles
+    }
+    pub fn clear_styles(&mut self) {
+        self.styles.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_styles(&mut self, v: ::std::collections::HashMap<u32, Style>) {
+        self.styles = v;
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_styles(&mut self) -> &mut ::std::collections::HashMap<u32, Style> {
+        &mut self.styles
+    }
+
+    // Take field
+    pub fn take_styles(&mut self) -> ::std::collections::HashMap<u32, Style> {
+        ::std::mem::replace(&mut self.styles, ::std::collections::HashMap::new())
+    }
+}
+
+impl ::protobuf::Message for InsertColumnsResponse {
+    fn is_initialized(&self) -> bool {
+        for v in &self.cells {
+            if !v.is_initialized() {
+                return false;
+            }
+        };
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_repeated_message_into(wire_type, is, &mut self.cells)?;
+                },
+                2 => {
+                    ::protobuf::rt::read_map_into::<::protobuf::types::ProtobufTypeUint32, ::protobuf::types::ProtobufTypeMessage<Style>>(wire_type, is, &mut self.styles)?;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        for value in &self.cells {
+            let len = value.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        };
+        my_size += ::protobuf::rt::compute_map_size::<::protobuf::types::ProtobufTypeUint32, ::protobuf::types::ProtobufTypeMessage<Style>>(2, &self.styles);
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        for v in &self.cells {
+            os.write_tag(1, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+            os.write_raw_varint32(v.get_cached_size())?;
+            v.write_to_with_cached_sizes(os)?;
+        };
+        ::protobuf::rt::write_map_with_cached_sizes::<::protobuf::types::ProtobufTypeUint32, ::protobuf::types::ProtobufTypeMessage<Style>>(2, &self.styles, os)?;
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> InsertColumnsResponse {
+        InsertColumnsResponse::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            let mut fields = ::std::vec::Vec::new();
+            fields.push(::protobuf::reflect::accessor::make_repeated_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<Cell>>(
+                "cells",
+                |m: &InsertColumnsResponse| { &m.cells },
+                |m: &mut InsertColumnsResponse| { &mut m.cells },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_map_accessor::<_, ::protobuf::types::ProtobufTypeUint32, ::protobuf::types::ProtobufTypeMessage<Style>>(
+                "styles",
+                |m: &InsertColumnsResponse| { &m.styles },
+                |m: &mut InsertColumnsResponse| { &mut m.styles },
+            ));
+            ::protobuf::reflect::MessageDescriptor::new_pb_name::<InsertColumnsResponse>(
+                "InsertColumnsResponse",
+                fields,
+                file_descriptor_proto()
+            )
+        })
+    }
+
+    fn default_instance() -> &'static InsertColumnsResponse {
+        static instance: ::protobuf::rt::LazyV2<InsertColumnsResponse> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(InsertColumnsResponse::new)
+    }
+}
+
+impl ::protobuf::Clear for InsertColumnsResponse {
+    fn clear(&mut self) {
+        self.cells.clear();
+        self.styles.clear();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for InsertColumnsResponse {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for InsertColumnsResponse {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct DeleteColumnsRequest {
+    // message fields
+    pub tableId: ::std::string::String,
+    pub at: i32,
+    pub count: i32,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a DeleteColumnsRequest {
+    fn default() -> &'a DeleteColumnsRequest {
+        <DeleteColumnsRequest as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl DeleteColumnsRequest {
+    pub fn new() -> DeleteColumnsRequest {
+        ::std::default::Default::default()
+    }
+
+    // string tableId = 1;
+
+
+    pub fn get_tableId(&self) -> &str {
+        &self.tableId
+    }
+    pub fn clear_tableId(&mut self) {
+        self.tableId.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_tableId(&mut self, v: ::std::string::String) {
+        self.tableId = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_tableId(&mut self) -> &mut ::std::string::String {
+        &mut self.tableId
+    }
+
+    // Take field
+    pub fn take_tableId(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.tableId, ::std::string::String::new())
+    }
+
+    // int32 at = 2;
+
+
+    pub fn get_at(&self) -> i32 {
+        self.at
+    }
+    pub fn clear_at(&mut self) {
+        self.at = 0;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_at(&mut self, v: i32) {
+        self.at = v;
+    }
+
+    // int32 count = 3;
+
+
+    pub fn get_count(&self) -> i32 {
+        self.count
+    }
+    pub fn clear_count(&mut self) {
+        self.count = 0;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_count(&mut self, v: i32) {
+        self.count = v;
+    }
+}
+
+impl ::protobuf::Message for DeleteColumnsRequest {
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.tableId)?;
+                },
+                2 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_int32()?;
+                    self.at = tmp;
+                },
+                3 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_int32()?;
+                    self.count = tmp;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if !self.tableId.is_empty() {
+            my_size += ::protobuf::rt::string_size(1, &self.tableId);
+        }
+        if self.at != 0 {
+            my_size += ::protobuf::rt::value_size(2, self.at, ::protobuf::wire_format::WireTypeVarint);
+        }
+        if self.count != 0 {
+            my_size += ::protobuf::rt::value_size(3, self.count, ::protobuf::wire_format::WireTypeVarint);
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        if !self.tableId.is_empty() {
+            os.write_string(1, &self.tableId)?;
+        }
+        if self.at != 0 {
+            os.write_int32(2, self.at)?;
+        }
+        if self.count != 0 {
+            os.write_int32(3, self.count)?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> DeleteColumnsRequest {
+        DeleteColumnsRequest::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            let mut fields = ::std::vec::Vec::new();
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "tableId",
+                |m: &DeleteColumnsRequest| { &m.tableId },
+                |m: &mut DeleteColumnsRequest| { &mut m.tableId },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeInt32>(
+                "at",
+                |m: &DeleteColumnsRequest| { &m.at },
+                |m: &mut DeleteColumnsRequest| { &mut m.at },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeInt32>(
+                "count",
+                |m: &DeleteColumnsRequest| { &m.count },
+                |m: &mut DeleteColumnsRequest| { &mut m.count },
+            ));
+            ::protobuf::reflect::MessageDescriptor::new_pb_name::<DeleteColumnsRequest>(
+                "DeleteColumnsRequest",
+                fields,
+                file_descriptor_proto()
+            )
+        })
+    }
+
+    fn default_instance() -> &'static DeleteColumnsRequest {
+        static instance: ::protobuf::rt::LazyV2<DeleteColumnsRequest> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(DeleteColumnsRequest::new)
+    }
+}
+
+impl ::protobuf::Clear for DeleteColumnsRequest {
+    fn clear(&mut self) {
+        self.tableId.clear();
+        self.at = 0;
+        self.count = 0;
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for DeleteColumnsRequest {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for DeleteColumnsRequest {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct DeleteColumnsResponse {
+    // message fields
+    pub cells: ::protobuf::RepeatedField<Cell>,
+    pub styles: ::std::collections::HashMap<u32, Style>,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a DeleteColumnsResponse {
+    fn default() -> &'a DeleteColumnsResponse {
+        <DeleteColumnsResponse as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl DeleteColumnsResponse {
+    pub fn new() -> DeleteColumnsResponse {
+        ::std::default::Default::default()
+    }
+
+    // repeated .spreadsheet.Cell cells = 1;
+
+
+    pub fn get_cells(&self) -> &[Cell] {
+        &self.cells
+    }
+    pub fn clear_cells(&mut self) {
+        self.cells.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_cells(&mut self, v: ::protobuf::RepeatedField<Cell>) {
+        self.cells = v;
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_cells(&mut self) -> &mut ::protobuf::RepeatedField<Cell> {
+        &mut self.cells
+    }
+
+    // Take field
+    pub fn take_cells(&mut self) -> ::protobuf::RepeatedField<Cell> {
+        ::std::mem::replace(&mut self.cells, ::protobuf::RepeatedField::new())
+    }
+
+    // repeated .spreadsheet.DeleteColumnsResponse.StylesEntry styles = 2;
+
+
+    pub fn get_styles(&self) -> &::std::collections::HashMap<u32, Style> {
+        &self.styles
+    }
+    pub fn clear_styles(&mut self) {
+        self.styles.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_styles(&mut self, v: ::std::collections::HashMap<u32, Style>) {
+        self.styles = v;
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_styles(&mut self) -> &mut ::std::collections::HashMap<u32, Style> {
+        &mut self.styles
+    }
+
+    // Take field
+    pub fn take_styles(&mut self) -> ::std::collections::HashMap<u32, Style> {
+        ::std::mem::replace(&mut self.styles, ::std::collections::HashMap::new())
+    }
+}
+
+impl ::protobuf::Message for DeleteColumnsResponse {
+    fn is_initialized(&self) -> bool {
+        for v in &self.cells {
+            if !v.is_initialized() {
+                return false;
+            }
+        };
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_repeated_message_into(wire_type, is, &mut self.cells)?;
+                },
+                2 => {
+                    ::protobuf::rt::read_map_into::<::protobuf::types::ProtobufTypeUint32, ::protobuf::types::ProtobufTypeMessage<Style>>(wire_type, is, &mut self.styles)?;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        for value in &self.cells {
+            let len = value.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        };
+        my_size += ::protobuf::rt::compute_map_size::<::protobuf::types::ProtobufTypeUint32, ::protobuf::types::ProtobufTypeMessage<Style>>(2, &self.styles);
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        for v in &self.cells {
+            os.write_tag(1, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+            os.write_raw_varint32(v.get_cached_size())?;
+            v.write_to_with_cached_sizes(os)?;
+        };
+        ::protobuf::rt::write_map_with_cached_sizes::<::protobuf::types::ProtobufTypeUint32, ::protobuf::types::ProtobufTypeMessage<Style>>(2, &self.styles, os)?;
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> DeleteColumnsResponse {
+        DeleteColumnsResponse::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            let mut fields = ::std::vec::Vec::new();
+            fields.push(::protobuf::reflect::accessor::make_repeated_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<Cell>>(
+                "cells",
+                |m: &DeleteColumnsResponse| { &m.cells },
+                |m: &mut DeleteColumnsResponse| { &mut m.cells },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_map_accessor::<_, ::protobuf::types::ProtobufTypeUint32, ::protobuf::types::ProtobufTypeMessage<Style>>(
+                "styles",
+                |m: &DeleteColumnsResponse| { &m.styles },
+                |m: &mut DeleteColumnsResponse| { &mut m.styles },
+            ));
+            ::protobuf::reflect::MessageDescriptor::new_pb_name::<DeleteColumnsResponse>(
+                "DeleteColumnsResponse",
+                fields,
+                file_descriptor_proto()
+            )
+        })
+    }
+
+    fn default_instance() -> &'static DeleteColumnsResponse {
+        static instance: ::protobuf::rt::LazyV2<DeleteColumnsResponse> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(DeleteColumnsResponse::new)
+    }
+}
+
+impl ::protobuf::Clear for DeleteColumnsResponse {
+    fn clear(&mut self) {
+        self.cells.clear();
+        self.styles.clear();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for DeleteColumnsResponse {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for DeleteColumnsResponse {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct MoveRangeRequest {
+    // message fields
+    pub tableId: ::std::string::String,
+    pub source: ::protobuf::SingularPtrField<Rect>,
+    pub row: i32,
+    pub col: i32,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a MoveRangeRequest {
+    fn default() -> &'a MoveRangeRequest {
+        <MoveRangeRequest as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl MoveRangeRequest {
+    pub fn new() -> MoveRangeRequest {
+        ::std::default::Default::default()
+    }
+
+    // string tableId = 1;
+
+
+    pub fn get_tableId(&self) -> &str {
+        &self.tableId
+    }
+    pub fn clear_tableId(&mut self) {
+        self.tableId.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_tableId(&mut self, v: ::std::string::String) {
+        self.tableId = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_tableId(&mut self) -> &mut ::std::string::String {
+        &mut self.tableId
+    }
+
+    // Take field
+    pub fn take_tableId(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.tableId, ::std::string::String::new())
+    }
+
+    // .spreadsheet.Rect source = 2;
+
+
+    pub fn get_source(&self) -> &Rect {
+        self.source.as_ref().unwrap_or_else(|| <Rect as ::protobuf::Message>::default_instance())
+    }
+    pub fn clear_source(&mut self) {
+        self.source.clear();
+    }
+
+    pub fn has_source(&self) -> bool {
+        self.source.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_source(&mut self, v: Rect) {
+        self.source = ::protobuf::SingularPtrField::some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_source(&mut self) -> &mut Rect {
+        if self.source.is_none() {
+            self.source.set_default();
+        }
+        self.source.as_mut().unwrap()
+    }
+
+    // Take field
+    pub fn take_source(&mut self) -> Rect {
+        self.source.take().unwrap_or_else(|| Rect::new())
+    }
+
+    // int32 row = 3;
+
+
+    pub fn get_row(&self) -> i32 {
+        self.row
+    }
+    pub fn clear_row(&mut self) {
+        self.row = 0;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_row(&mut self, v: i32) {
+        self.row = v;
+    }
+
+    // int32 col = 4;
+
+
+    pub fn get_col(&self) -> i32 {
+        self.col
+    }
+    pub fn clear_col(&mut self) {
+        self.col = 0;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_col(&mut self, v: i32) {
+        self.col = v;
+    }
+}
+
+impl ::protobuf::Message for MoveRangeRequest {
+    fn is_initialized(&self) -> bool {
+        for v in &self.source {
+            if !v.is_initialized() {
+                return false;
+            }
+        };
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.tableId)?;
+                },
+                2 => {
+                    ::protobuf::rt::read_singular_message_into(wire_type, is, &mut self.source)?;
+                },
+                3 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_int32()?;
+                    self.row = tmp;
+                },
+                4 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_int32()?;
+                    self.col = tmp;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if !self.tableId.is_empty() {
+            my_size += ::protobuf::rt::string_size(1, &self.tableId);
+        }
+        if let Some(ref v) = self.source.as_ref() {
+            let len = v.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        }
+        if self.row != 0 {
+            my_size += ::protobuf::rt::value_size(3, self.row, ::protobuf::wire_format::WireTypeVarint);
+        }
+        if self.col != 0 {
+            my_size += ::protobuf::rt::value_size(4, self.col, ::protobuf::wire_format::WireTypeVarint);
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        if !self.tableId.is_empty() {
+            os.write_string(1, &self.tableId)?;
+        }
+        if let Some(ref v) = self.source.as_ref() {
+            os.write_tag(2, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+            os.write_raw_varint32(v.get_cached_size())?;
+            v.write_to_with_cached_sizes(os)?;
+        }
+        if self.row != 0 {
+            os.write_int32(3, self.row)?;
+        }
+        if self.col != 0 {
+            os.write_int32(4, self.col)?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> MoveRangeRequest {
+        MoveRangeRequest::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            let mut fields = ::std::vec::Vec::new();
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "tableId",
+                |m: &MoveRangeRequest| { &m.tableId },
+                |m: &mut MoveRangeRequest| { &mut m.tableId },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_singular_ptr_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<Rect>>(
+                "source",
+                |m: &MoveRangeRequest| { &m.source },
+                |m: &mut MoveRangeRequest| { &mut m.source },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeInt32>(
+                "row",
+                |m: &MoveRangeRequest| { &m.row },
+                |m: &mut MoveRangeRequest| { &mut m.row },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeInt32>(
+                "col",
+                |m: &MoveRangeRequest| { &m.col },
+                |m: &mut MoveRangeRequest| { &mut m.col },
+            ));
+            ::protobuf::reflect::MessageDescriptor::new_pb_name::<MoveRangeRequest>(
+                "MoveRangeRequest",
+                fields,
+                file_descriptor_proto()
+            )
+        })
+    }
+
+    fn default_instance() -> &'static MoveRangeRequest {
+        static instance: ::protobuf::rt::LazyV2<MoveRangeRequest> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(MoveRangeRequest::new)
+    }
+}
+
+impl ::protobuf::Clear for MoveRangeRequest {
+    fn clear(&mut self) {
+        self.tableId.clear();
+        self.source.clear();
+        self.row = 0;
+        self.col = 0;
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for MoveRangeRequest {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for MoveRangeRequest {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct MoveRangeResponse {
+    // message fields
+    pub cells: ::protobuf::RepeatedField<Cell>,
+    pub styles: ::std::collections::HashMap<u32, Style>,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a MoveRangeResponse {
+    fn default() -> &'a MoveRangeResponse {
+        <MoveRangeResponse as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl MoveRangeResponse {
+    pub fn new() -> MoveRangeResponse {
+        ::std::default::Default::default()
+    }
+
+    // repeated .spreadsheet.Cell cells = 1;
+
+
+    pub fn get_cells(&self) -> &[Cell] {
+        &self.cells
+    }
+    pub fn clear_cells(&mut self) {
+        self.cells.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_cells(&mut self, v: ::protobuf::RepeatedField<Cell>) {
+        self.cells = v;
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_cells(&mut self) -> &mut ::protobuf::RepeatedField<Cell> {
+        &mut self.cells
+    }
+
+    // Take field
+    pub fn take_cells(&mut self) -> ::protobuf::RepeatedField<Cell> {
+        ::std::mem::replace(&mut self.cells, ::protobuf::RepeatedField::new())
+    }
+
+    // repeated .spreadsheet.MoveRangeResponse.StylesEntry styles = 2;
+
+
+    pub fn get_styles(&self) -> &::std::collections::HashMap<u32, Style> {
+        &self.styles
+    }
+    pub fn clear_styles(&mut self) {
+        self.styles.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_styles(&mut self, v: ::std::collections::HashMap<u32, Style>) {
+        self.styles = v;
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_styles(&mut self) -> &mut ::std::collections::HashMap<u32, Style> {
+        &mut self.styles
+    }
+
+    // Take field
+    pub fn take_styles(&mut self) -> ::std::collections::HashMap<u32, Style> {
+        ::std::mem::replace(&mut self.styles, ::std::collections::HashMap::new())
+    }
+}
+
+impl ::protobuf::Message for MoveRangeResponse {
+    fn is_initialized(&self) -> bool {
+        for v in &self.cells {
+            if !v.is_initialized() {
+                return false;
+            }
+        };
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_repeated_message_into(wire_type, is, &mut self.cells)?;
+                },
+                2 => {
+                    ::protobuf::rt::read_map_into::<::protobuf::types::ProtobufTypeUint32, ::protobuf::types::ProtobufTypeMessage<Style>>(wire_type, is, &mut self.styles)?;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        for value in &self.cells {
+            let len = value.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        };
+        my_size += ::protobuf::rt::compute_map_size::<::protobuf::types::ProtobufTypeUint32, ::protobuf::types::ProtobufTypeMessage<Style>>(2, &self.styles);
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        for v in &self.cells {
+            os.write_tag(1, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+            os.write_raw_varint32(v.get_cached_size())?;
+            v.write_to_with_cached_sizes(os)?;
+        };
+        ::protobuf::rt::write_map_with_cached_sizes::<::protobuf::types::ProtobufTypeUint32, ::protobuf::types::ProtobufTypeMessage<Style>>(2, &self.styles, os)?;
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> MoveRangeResponse {
+        MoveRangeResponse::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            let mut fields = ::std::vec::Vec::new();
+            fields.push(::protobuf::reflect::accessor::make_repeated_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<Cell>>(
+                "cells",
+                |m: &MoveRangeResponse| { &m.cells },
+                |m: &mut MoveRangeResponse| { &mut m.cells },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_map_accessor::<_, ::protobuf::types::ProtobufTypeUint32, ::protobuf::types::ProtobufTypeMessage<Style>>(
+                "styles",
+                |m: &MoveRangeResponse| { &m.styles },
+                |m: &mut MoveRangeResponse| { &mut m.styles },
+            ));
+            ::protobuf::reflect::MessageDescriptor::new_pb_name::<MoveRangeResponse>(
+                "MoveRangeResponse",
+                fields,
+                file_descriptor_proto()
+            )
+        })
+    }
+
+    fn default_instance() -> &'static MoveRangeResponse {
+        static instance: ::protobuf::rt::LazyV2<MoveRangeResponse> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(MoveRangeResponse::new)
+    }
+}
+
+impl ::protobuf::Clear for MoveRangeResponse {
+    fn clear(&mut self) {
+        self.cells.clear();
+        self.styles.clear();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for MoveRangeResponse {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for MoveRangeResponse {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct CopyRangeRequest {
+    // message fields
+    pub tableId: ::std::string::String,
+    pub source: ::protobuf::SingularPtrField<Rect>,
+    pub row: i32,
+    pub col: i32,
+    pub mode: CopyMode,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a CopyRangeRequest {
+    fn default() -> &'a CopyRangeRequest {
+        <CopyRangeRequest as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl CopyRangeRequest {
+    pub fn new() -> CopyRangeRequest {
+        ::std::default::Default::default()
+    }
+
+    // string tableId = 1;
+
+
+    pub fn get_tableId(&self) -> &str {
+        &self.tableId
+    }
+    pub fn clear_tableId(&mut self) {
+        self.tableId.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_tableId(&mut self, v: ::std::string::String) {
+        self.tableId = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_tableId(&mut self) -> &mut ::std::string::String {
+        &mut self.tableId
+    }
+
+    // Take field
+    pub fn take_tableId(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.tableId, ::std::string::String::new())
+    }
+
+    // .spreadsheet.Rect source = 2;
+
+
+    pub fn get_source(&self) -> &Rect {
+        self.source.as_ref().unwrap_or_else(|| <Rect as ::protobuf::Message>::default_instance())
+    }
+    pub fn clear_source(&mut self) {
+        self.source.clear();
+    }
+
+    pub fn has_source(&self) -> bool {
+        self.source.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_source(&mut self, v: Rect) {
+        self.source = ::protobuf::SingularPtrField::some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_source(&mut self) -> &mut Rect {
+        if self.source.is_none() {
+            self.source.set_default();
+        }
+        self.source.as_mut().unwrap()
+    }
+
+    // Take field
+    pub fn take_source(&mut self) -> Rect {
+        self.source.take().unwrap_or_else(|| Rect::new())
+    }
+
+    // int32 row = 3;
+
+
+    pub fn get_row(&self) -> i32 {
+        self.row
+    }
+    pub fn clear_row(&mut self) {
+        self.row = 0;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_row(&mut self, v: i32) {
+        self.row = v;
+    }
+
+    // int32 col = 4;
+
+
+    pub fn get_col(&self) -> i32 {
+        self.col
+    }
+    pub fn clear_col(&mut self) {
+        self.col = 0;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_col(&mut self, v: i32) {
+        self.col = v;
+    }
+
+    // .spreadsheet.CopyMode mode = 5;
+
+
+    pub fn get_mode(&self) -> CopyMode {
+        self.mode
+    }
+    pub fn clear_mode(&mut self) {
+        self.mode = CopyMode::COPY_ALL;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_mode(&mut self, v: CopyMode) {
+        self.mode = v;
+    }
+}
+
+impl ::protobuf::Message for CopyRangeRequest {
+    fn is_initialized(&self) -> bool {
+        for v in &self.source {
+            if !v.is_initialized() {
+                return false;
+            }
+        };
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.tableId)?;
+                },
+                2 => {
+                    ::protobuf::rt::read_singular_message_into(wire_type, is, &mut self.source)?;
+                },
+                3 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_int32()?;
+                    self.row = tmp;
+                },
+                4 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_int32()?;
+                    self.col = tmp;
+                },
+                5 => {
+                    ::protobuf::rt::read_proto3_enum_with_unknown_fields_into(wire_type, is, &mut self.mode, 5, &mut self.unknown_fields)?
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if !self.tableId.is_empty() {
+            my_size += ::protobuf::rt::string_size(1, &self.tableId);
+        }
+        if let Some(ref v) = self.source.as_ref() {
+            let len = v.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        }
+        if self.row != 0 {
+            my_size += ::protobuf::rt::value_size(3, self.row, ::protobuf::wire_format::WireTypeVarint);
+        }
+        if self.col != 0 {
+            my_size += ::protobuf::rt::value_size(4, self.col, ::protobuf::wire_format::WireTypeVarint);
+        }
+        if self.mode != CopyMode::COPY_ALL {
+            my_size += ::protobuf::rt::enum_size(5, self.mode);
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        if !self.tableId.is_empty() {
+            os.write_string(1, &self.tableId)?;
+        }
+        if let Some(ref v) = self.source.as_ref() {
+            os.write_tag(2, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+            os.write_raw_varint32(v.get_cached_size())?;
+            v.write_to_with_cached_sizes(os)?;
+        }
+        if self.row != 0 {
+            os.write_int32(3, self.row)?;
+        }
+        if self.col != 0 {
+            os.write_int32(4, self.col)?;
+        }
+        if self.mode != CopyMode::COPY_ALL {
+            os.write_enum(5, ::protobuf::ProtobufEnum::value(&self.mode))?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> CopyRangeRequest {
+        CopyRangeRequest::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            let mut fields = ::std::vec::Vec::new();
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "tableId",
+                |m: &CopyRangeRequest| { &m.tableId },
+                |m: &mut CopyRangeRequest| { &mut m.tableId },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_singular_ptr_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<Rect>>(
+                "source",
+                |m: &CopyRangeRequest| { &m.source },
+                |m: &mut CopyRangeRequest| { &mut m.source },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeInt32>(
+                "row",
+                |m: &CopyRangeRequest| { &m.row },
+                |m: &mut CopyRangeRequest| { &mut m.row },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeInt32>(
+                "col",
+                |m: &CopyRangeRequest| { &m.col },
+                |m: &mut CopyRangeRequest| { &mut m.col },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeEnum<CopyMode>>(
+                "mode",
+                |m: &CopyRangeRequest| { &m.mode },
+                |m: &mut CopyRangeRequest| { &mut m.mode },
+            ));
+            ::protobuf::reflect::MessageDescriptor::new_pb_name::<CopyRangeRequest>(
+                "CopyRangeRequest",
+                fields,
+                file_descriptor_proto()
+            )
+        })
+    }
+
+    fn default_instance() -> &'static CopyRangeRequest {
+        static instance: ::protobuf::rt::LazyV2<CopyRangeRequest> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(CopyRangeRequest::new)
+    }
+}
+
+impl ::protobuf::Clear for CopyRangeRequest {
+    fn clear(&mut self) {
+        self.tableId.clear();
+        self.source.clear();
+        self.row = 0;
+        self.col = 0;
+        self.mode = CopyMode::COPY_ALL;
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for CopyRangeRequest {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for CopyRangeRequest {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct CopyRangeResponse {
+    // message fields
+    pub cells: ::protobuf::RepeatedField<Cell>,
+    pub styles: ::std::collections::HashMap<u32, Style>,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a CopyRangeResponse {
+    fn default() -> &'a CopyRangeResponse {
+        <CopyRangeResponse as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl CopyRangeResponse {
+    pub fn new() -> CopyRangeResponse {
+        ::std::default::Default::default()
+    }
+
+    // repeated .spreadsheet.Cell cells = 1;
+
+
+    pub fn get_cells(&self) -> &[Cell] {
+        &self.cells
+    }
+    pub fn clear_cells(&mut self) {
+        self.cells.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_cells(&mut self, v: ::protobuf::RepeatedField<Cell>) {
+        self.cells = v;
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_cells(&mut self) -> &mut ::protobuf::RepeatedField<Cell> {
+        &mut self.cells
+    }
+
+    // Take field
+    pub fn take_cells(&mut self) -> ::protobuf::RepeatedField<Cell> {
+        ::std::mem::replace(&mut self.cells, ::protobuf::RepeatedField::new())
+    }
+
+    // repeated .spreadsheet.CopyRangeResponse.StylesEntry styles = 2;
+
+
+    pub fn get_styles(&self) -> &::std::collections::HashMap<u32, Style> {
+        &self.styles
+    }
+    pub fn clear_styles(&mut self) {
+        self.styles.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_styles(&mut self, v: ::std::collections::HashMap<u32, Style>) {
+        self.styles = v;
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_styles(&mut self) -> &mut ::std::collections::HashMap<u32, Style> {
+        &mut self.styles
+    }
+
+    // Take field
+    pub fn take_styles(&mut self) -> ::std::collections::HashMap<u32, Style> {
+        ::std::mem::replace(&mut self.styles, ::std::collections::HashMap::new())
+    }
+}
+
+impl ::protobuf::Message for CopyRangeResponse {
+    fn is_initialized(&self) -> bool {
+        for v in &self.cells {
+            if !v.is_initialized() {
+                return false;
+            }
+        };
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_repeated_message_into(wire_type, is, &mut self.cells)?;
+                },
+                2 => {
+                    ::protobuf::rt::read_map_into::<::protobuf::types::ProtobufTypeUint32, ::protobuf::types::ProtobufTypeMessage<Style>>(wire_type, is, &mut self.styles)?;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        for value in &self.cells {
+            let len = value.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        };
+        my_size += ::protobuf::rt::compute_map_size::<::protobuf::types::ProtobufTypeUint32, ::protobuf::types::ProtobufTypeMessage<Style>>(2, &self.styles);
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        for v in &self.cells {
+            os.write_tag(1, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+            os.write_raw_varint32(v.get_cached_size())?;
+            v.write_to_with_cached_sizes(os)?;
+        };
+        ::protobuf::rt::write_map_with_cached_sizes::<::protobuf::types::ProtobufTypeUint32, ::protobuf::types::ProtobufTypeMessage<Style>>(2, &self.styles, os)?;
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> CopyRangeResponse {
+        CopyRangeResponse::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            let mut fields = ::std::vec::Vec::new();
+            fields.push(::protobuf::reflect::accessor::make_repeated_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<Cell>>(
+                "cells",
+                |m: &CopyRangeResponse| { &m.cells },
+                |m: &mut CopyRangeResponse| { &mut m.cells },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_map_accessor::<_, ::protobuf::types::ProtobufTypeUint32, ::protobuf::types::ProtobufTypeMessage<Style>>(
+                "styles",
+                |m: &CopyRangeResponse| { &m.styles },
+                |m: &mut CopyRangeResponse| { &mut m.styles },
+            ));
+            ::protobuf::reflect::MessageDescriptor::new_pb_name::<CopyRangeResponse>(
+                "CopyRangeResponse",
+                fields,
+                file_descriptor_proto()
+            )
+        })
+    }
+
+    fn default_instance() -> &'static CopyRangeResponse {
+        static instance: ::protobuf::rt::LazyV2<CopyRangeResponse> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(CopyRangeResponse::new)
+    }
+}
+
+impl ::protobuf::Clear for CopyRangeResponse {
+    fn clear(&mut self) {
+        self.cells.clear();
+        self.styles.clear();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for CopyRangeResponse {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for CopyRangeResponse {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct FillRequest {
+    // message fields
+    pub tableId: ::std::string::String,
+    pub source: ::protobuf::SingularPtrField<Rect>,
+    pub target: ::protobuf::SingularPtrField<Rect>,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a FillRequest {
+    fn default() -> &'a FillRequest {
+        <FillRequest as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl FillRequest {
+    pub fn new() -> FillRequest {
+        ::std::default::Default::default()
+    }
+
+    // string tableId = 1;
+
+
+    pub fn get_tableId(&self) -> &str {
+        &self.tableId
+    }
+    pub fn clear_tableId(&mut self) {
+        self.tableId.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_tableId(&mut self, v: ::std::string::String) {
+        self.tableId = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_tableId(&mut self) -> &mut ::std::string::String {
+        &mut self.tableId
+    }
+
+    // Take field
+    pub fn take_tableId(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.tableId, ::std::string::String::new())
+    }
+
+    // .spreadsheet.Rect source = 2;
+
+
+    pub fn get_source(&self) -> &Rect {
+        self.source.as_ref().unwrap_or_else(|| <Rect as ::protobuf::Message>::default_instance())
+    }
+    pub fn clear_source(&mut self) {
+        self.source.clear();
+    }
+
+    pub fn has_source(&self) -> bool {
+        self.source.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_source(&mut self, v: Rect) {
+        self.source = ::protobuf::SingularPtrField::some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_source(&mut self) -> &mut Rect {
+        if self.source.is_none() {
+            self.source.set_default();
+        }
+        self.source.as_mut().unwrap()
+    }
+
+    // Take field
+    pub fn take_source(&mut self) -> Rect {
+        self.source.take().unwrap_or_else(|| Rect::new())
+    }
+
+    // .spreadsheet.Rect target = 3;
+
+
+    pub fn get_target(&self) -> &Rect {
+        self.target.as_ref().unwrap_or_else(|| <Rect as ::protobuf::Message>::default_instance())
+    }
+    pub fn clear_target(&mut self) {
+        self.target.clear();
+    }
+
+    pub fn has_target(&self) -> bool {
+        self.target.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_target(&mut self, v: Rect) {
+        self.target = ::protobuf::SingularPtrField::some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_target(&mut self) -> &mut Rect {
+        if self.target.is_none() {
+            self.target.set_default();
+        }
+        self.target.as_mut().unwrap()
+    }
+
+    // Take field
+    pub fn take_target(&mut self) -> Rect {
+        self.target.take().unwrap_or_else(|| Rect::new())
+    }
+}
+
+impl ::protobuf::Message for FillRequest {
+    fn is_initialized(&self) -> bool {
+        for v in &self.source {
+            if !v.is_initialized() {
+                return false;
+            }
+        };
+        for v in &self.target {
+            if !v.is_initialized() {
+                return false;
+            }
+        };
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.tableId)?;
+                },
+                2 => {
+                    ::protobuf::rt::read_singular_message_into(wire_type, is, &mut self.source)?;
+                },
+                3 => {
+                    ::protobuf::rt::read_singular_message_into(wire_type, is, &mut self.target)?;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if !self.tableId.is_empty() {
+            my_size += ::protobuf::rt::string_size(1, &self.tableId);
+        }
+        if let Some(ref v) = self.source.as_ref() {
+            let len = v.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        }
+        if let Some(ref v) = self.target.as_ref() {
+            let len = v.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        if !self.tableId.is_empty() {
+            os.write_string(1, &self.tableId)?;
+        }
+        if let Some(ref v) = self.source.as_ref() {
+            os.write_tag(2, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+            os.write_raw_varint32(v.get_cached_size())?;
+            v.write_to_with_cached_sizes(os)?;
+        }
+        if let Some(ref v) = self.target.as_ref() {
+            os.write_tag(3, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+            os.write_raw_varint32(v.get_cached_size())?;
+            v.write_to_with_cached_sizes(os)?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> FillRequest {
+        FillRequest::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            let mut fields = ::std::vec::Vec::new();
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "tableId",
+                |m: &FillRequest| { &m.tableId },
+                |m: &mut FillRequest| { &mut m.tableId },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_singular_ptr_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<Rect>>(
+                "source",
+                |m: &FillRequest| { &m.source },
+                |m: &mut FillRequest| { &mut m.source },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_singular_ptr_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<Rect>>(
+                "target",
+                |m: &FillRequest| { &m.target },
+                |m: &mut FillRequest| { &mut m.target },
+            ));
+            ::protobuf::reflect::MessageDescriptor::new_pb_name::<FillRequest>(
+                "FillRequest",
+                fields,
+                file_descriptor_proto()
+            )
+        })
+    }
+
+    fn default_instance() -> &'static FillRequest {
+        static instance: ::protobuf::rt::LazyV2<FillRequest> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(FillRequest::new)
+    }
+}
+
+impl ::protobuf::Clear for FillRequest {
+    fn clear(&mut self) {
+        self.tableId.clear();
+        self.source.clear();
+        self.target.clear();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for FillRequest {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for FillRequest {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct FillResponse {
+    // message fields
+    pub cells: ::protobuf::RepeatedField<Cell>,
+    pub styles: ::std::collections::HashMap<u32, Style>,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a FillResponse {
+    fn default() -> &'a FillResponse {
+        <FillResponse as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl FillResponse {
+    pub fn new() -> FillResponse {
+        ::std::default::Default::default()
+    }
+
+    // repeated .spreadsheet.Cell cells = 1;
+
+
+    pub fn get_cells(&self) -> &[Cell] {
+        &self.cells
+    }
+    pub fn clear_cells(&mut self) {
+        self.cells.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_cells(&mut self, v: ::protobuf::RepeatedField<Cell>) {
+        self.cells = v;
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_cells(&mut self) -> &mut ::protobuf::RepeatedField<Cell> {
+        &mut self.cells
+    }
+
+    // Take field
+    pub fn take_cells(&mut self) -> ::protobuf::RepeatedField<Cell> {
+        ::std::mem::replace(&mut self.cells, ::protobuf::RepeatedField::new())
+    }
+
+    // repeated .spreadsheet.FillResponse.StylesEntry styles = 2;
+
+
+    pub fn get_styles(&self) -> &::std::collections::HashMap<u32, Style> {
+        &self.styles
+    }
+    pub fn clear_styles(&mut self) {
+        self.styles.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_styles(&mut self, v: ::std::collections::HashMap<u32, Style>) {
+        self.styles = v;
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_styles(&mut self) -> &mut ::std::collections::HashMap<u32, Style> {
+        &mut self.styles
+    }
+
+    // Take field
+    pub fn take_styles(&mut self) -> ::std::collections::HashMap<u32, Style> {
+        ::std::mem::replace(&mut self.styles, ::std::collections::HashMap::new())
+    }
+}
+
+impl ::protobuf::Message for FillResponse {
+    fn is_initialized(&self) -> bool {
+        for v in &self.cells {
+            if !v.is_initialized() {
+                return false;
+            }
+        };
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_repeated_message_into(wire_type, is, &mut self.cells)?;
+                },
+                2 => {
+                    ::protobuf::rt::read_map_into::<::protobuf::types::ProtobufTypeUint32, ::protobuf::types::ProtobufTypeMessage<Style>>(wire_type, is, &mut self.styles)?;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        for value in &self.cells {
+            let len = value.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        };
+        my_size += ::protobuf::rt::compute_map_size::<::protobuf::types::ProtobufTypeUint32, ::protobuf::types::ProtobufTypeMessage<Style>>(2, &self.styles);
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        for v in &self.cells {
+            os.write_tag(1, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+            os.write_raw_varint32(v.get_cached_size())?;
+            v.write_to_with_cached_sizes(os)?;
+        };
+        ::protobuf::rt::write_map_with_cached_sizes::<::protobuf::types::ProtobufTypeUint32, ::protobuf::types::ProtobufTypeMessage<Style>>(2, &self.styles, os)?;
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> FillResponse {
+        FillResponse::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            let mut fields = ::std::vec::Vec::new();
+            fields.push(::protobuf::reflect::accessor::make_repeated_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<Cell>>(
+                "cells",
+                |m: &FillResponse| { &m.cells },
+                |m: &mut FillResponse| { &mut m.cells },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_map_accessor::<_, ::protobuf::types::ProtobufTypeUint32, ::protobuf::types::ProtobufTypeMessage<Style>>(
+                "styles",
+                |m: &FillResponse| { &m.styles },
+                |m: &mut FillResponse| { &mut m.styles },
+            ));
+            ::protobuf::reflect::MessageDescriptor::new_pb_name::<FillResponse>(
+                "FillResponse",
+                fields,
+                file_descriptor_proto()
+            )
+        })
+    }
+
+    fn default_instance() -> &'static FillResponse {
+        static instance: ::protobuf::rt::LazyV2<FillResponse> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(FillResponse::new)
+    }
+}
+
+impl ::protobuf::Clear for FillResponse {
+    fn clear(&mut self) {
+        self.cells.clear();
+        self.styles.clear();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for FillResponse {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for FillResponse {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct SetIterativeCalculationRequest {
+    // message fields
+    pub tableId: ::std::string::String,
+    pub enabled: bool,
+    pub maxIterations: u32,
+    pub maxChange: f64,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a SetIterativeCalculationRequest {
+    fn default() -> &'a SetIterativeCalculationRequest {
+        <SetIterativeCalculationRequest as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl SetIterativeCalculationRequest {
+    pub fn new() -> SetIterativeCalculationRequest {
+        ::std::default::Default::default()
+    }
+
+    // string tableId = 1;
+
+
+    pub fn get_tableId(&self) -> &str {
+        &self.tableId
+    }
+    pub fn clear_tableId(&mut self) {
+        self.tableId.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_tableId(&mut self, v: ::std::string::String) {
+        self.tableId = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_tableId(&mut self) -> &mut ::std::string::String {
+        &mut self.tableId
+    }
+
+    // Take field
+    pub fn take_tableId(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.tableId, ::std::string::String::new())
+    }
+
+    // bool enabled = 2;
+
+
+    pub fn get_enabled(&self) -> bool {
+        self.enabled
+    }
+    pub fn clear_enabled(&mut self) {
+        self.enabled = false;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_enabled(&mut self, v: bool) {
+        self.enabled = v;
+    }
+
+    // uint32 maxIterations = 3;
+
+
+    pub fn get_maxIterations(&self) -> u32 {
+        self.maxIterations
+    }
+    pub fn clear_maxIterations(&mut self) {
+        self.maxIterations = 0;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_maxIterations(&mut self, v: u32) {
+        self.maxIterations = v;
+    }
+
+    // double maxChange = 4;
+
+
+    pub fn get_maxChange(&self) -> f64 {
+        self.maxChange
+    }
+    pub fn clear_maxChange(&mut self) {
+        self.maxChange = 0.;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_maxChange(&mut self, v: f64) {
+        self.maxChange = v;
+    }
+}
+
+impl ::protobuf::Message for SetIterativeCalculationRequest {
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.tableId)?;
+                },
+                2 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_bool()?;
+                    self.enabled = tmp;
+                },
+                3 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_uint32()?;
+                    self.maxIterations = tmp;
+                },
+                4 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeFixed64 {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_double()?;
+                    self.maxChange = tmp;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if !self.tableId.is_empty() {
+            my_size += ::protobuf::rt::string_size(1, &self.tableId);
+        }
+        if self.enabled != false {
+            my_size += 2;
+        }
+        if self.maxIterations != 0 {
+            my_size += ::protobuf::rt::value_size(3, self.maxIterations, ::protobuf::wire_format::WireTypeVarint);
+        }
+        if self.maxChange != 0. {
+            my_size += 9;
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        if !self.tableId.is_empty() {
+            os.write_string(1, &self.tableId)?;
+        }
+        if self.enabled != false {
+            os.write_bool(2, self.enabled)?;
+        }
+        if self.maxIterations != 0 {
+            os.write_uint32(3, self.maxIterations)?;
+        }
+        if self.maxChange != 0. {
+            os.write_double(4, self.maxChange)?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> SetIterativeCalculationRequest {
+        SetIterativeCalculationRequest::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            let mut fields = ::std::vec::Vec::new();
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "tableId",
+                |m: &SetIterativeCalculationRequest| { &m.tableId },
+                |m: &mut SetIterativeCalculationRequest| { &mut m.tableId },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeBool>(
+                "enabled",
+                |m: &SetIterativeCalculationRequest| { &m.enabled },
+                |m: &mut SetIterativeCalculationRequest| { &mut m.enabled },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeUint32>(
+                "maxIterations",
+                |m: &SetIterativeCalculationRequest| { &m.maxIterations },
+                |m: &mut SetIterativeCalculationRequest| { &mut m.maxIterations },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeDouble>(
+                "maxChange",
+                |m: &SetIterativeCalculationRequest| { &m.maxChange },
+                |m: &mut SetIterativeCalculationRequest| { &mut m.maxChange },
+            ));
+            ::protobuf::reflect::MessageDescriptor::new_pb_name::<SetIterativeCalculationRequest>(
+                "SetIterativeCalculationRequest",
+                fields,
+                file_descriptor_proto()
+            )
+        })
+    }
+
+    fn default_instance() -> &'static SetIterativeCalculationRequest {
+        static instance: ::protobuf::rt::LazyV2<SetIterativeCalculationRequest> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(SetIterativeCalculationRequest::new)
+    }
+}
+
+impl ::protobuf::Clear for SetIterativeCalculationRequest {
+    fn clear(&mut self) {
+        self.tableId.clear();
+        self.enabled = false;
+        self.maxIterations = 0;
+        self.maxChange = 0.;
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for SetIterativeCalculationRequest {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for SetIterativeCalculationRequest {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct SetIterativeCalculationResponse {
+    // message fields
+    pub cells: ::protobuf::RepeatedField<Cell>,
+    pub styles: ::std::collections::HashMap<u32, Style>,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a SetIterativeCalculationResponse {
+    fn default() -> &'a SetIterativeCalculationResponse {
+        <SetIterativeCalculationResponse as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl SetIterativeCalculationResponse {
+    pub fn new() -> SetIterativeCalculationResponse {
+        ::std::default::Default::default()
+    }
+
+    // repeated .spreadsheet.Cell cells = 1;
+
+
+    pub fn get_cells(&self) -> &[Cell] {
+        &self.cells
+    }
+    pub fn clear_cells(&mut self) {
+        self.cells.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_cells(&mut self, v: ::protobuf::RepeatedField<Cell>) {
+        self.cells = v;
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_cells(&mut self) -> &mut ::protobuf::RepeatedField<Cell> {
+        &mut self.cells
+    }
+
+    // Take field
+    pub fn take_cells(&mut self) -> ::protobuf::RepeatedField<Cell> {
+        ::std::mem::replace(&mut self.cells, ::protobuf::RepeatedField::new())
+    }
+
+    // repeated .spreadsheet.SetIterativeCalculationResponse.StylesEntry styles = 2;
+
+
+    pub fn get_styles(&self) -> &::std::collections::HashMap<u32, Style> {
+        &self.styles
+    }
+    pub fn clear_styles(&mut self) {
+        self.styles.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_styles(&mut self, v: ::std::collections::HashMap<u32, Style>) {
+        self.styles = v;
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_styles(&mut self) -> &mut ::std::collections::HashMap<u32, Style> {
+        &mut self.styles
+    }
+
+    // Take field
+    pub fn take_styles(&mut self) -> ::std::collections::HashMap<u32, Style> {
+        ::std::mem::replace(&mut self.styles, ::std::collections::HashMap::new())
+    }
+}
+
+impl ::protobuf::Message for SetIterativeCalculationResponse {
+    fn is_initialized(&self) -> bool {
+        for v in &self.cells {
+            if !v.is_initialized() {
+                return false;
+            }
+        };
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_repeated_message_into(wire_type, is, &mut self.cells)?;
+                },
+                2 => {
+                    ::protobuf::rt::read_map_into::<::protobuf::types::ProtobufTypeUint32, ::protobuf::types::ProtobufTypeMessage<Style>>(wire_type, is, &mut self.styles)?;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        for value in &self.cells {
+            let len = value.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        };
+        my_size += ::protobuf::rt::compute_map_size::<::protobuf::types::ProtobufTypeUint32, ::protobuf::types::ProtobufTypeMessage<Style>>(2, &self.styles);
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        for v in &self.cells {
+            os.write_tag(1, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+            os.write_raw_varint32(v.get_cached_size())?;
+            v.write_to_with_cached_sizes(os)?;
+        };
+        ::protobuf::rt::write_map_with_cached_sizes::<::protobuf::types::ProtobufTypeUint32, ::protobuf::types::ProtobufTypeMessage<Style>>(2, &self.styles, os)?;
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> SetIterativeCalculationResponse {
+        SetIterativeCalculationResponse::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            let mut fields = ::std::vec::Vec::new();
+            fields.push(::protobuf::reflect::accessor::make_repeated_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<Cell>>(
+                "cells",
+                |m: &SetIterativeCalculationResponse| { &m.cells },
+                |m: &mut SetIterativeCalculationResponse| { &mut m.cells },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_map_accessor::<_, ::protobuf::types::ProtobufTypeUint32, ::protobuf::types::ProtobufTypeMessage<Style>>(
+                "styles",
+                |m: &SetIterativeCalculationResponse| { &m.styles },
+                |m: &mut SetIterativeCalculationResponse| { &mut m.styles },
+            ));
+            ::protobuf::reflect::MessageDescriptor::new_pb_name::<SetIterativeCalculationResponse>(
+                "SetIterativeCalculationResponse",
+                fields,
+                file_descriptor_proto()
+            )
+        })
+    }
+
+    fn default_instance() -> &'static SetIterativeCalculationResponse {
+        static instance: ::protobuf::rt::LazyV2<SetIterativeCalculationResponse> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(SetIterativeCalculationResponse::new)
+    }
+}
+
+impl ::protobuf::Clear for SetIterativeCalculationResponse {
+    fn clear(&mut self) {
+        self.cells.clear();
+        self.styles.clear();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for SetIterativeCalculationResponse {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for SetIterativeCalculationResponse {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct SetLocaleRequest {
+    // message fields
+    pub tableId: ::std::string::String,
+    pub locale: ::std::string::String,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a SetLocaleRequest {
+    fn default() -> &'a SetLocaleRequest {
+        <SetLocaleRequest as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl SetLocaleRequest {
+    pub fn new() -> SetLocaleRequest {
+        ::std::default::Default::default()
+    }
+
+    // string tableId = 1;
+
+
+    pub fn get_tableId(&self) -> &str {
+        &self.tableId
+    }
+    pub fn clear_tableId(&mut self) {
+        self.tableId.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_tableId(&mut self, v: ::std::string::String) {
+        self.tableId = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_tableId(&mut self) -> &mut ::std::string::String {
+        &mut self.tableId
+    }
+
+    // Take field
+    pub fn take_tableId(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.tableId, ::std::string::String::new())
+    }
+
+    // string locale = 2;
+
+
+    pub fn get_locale(&self) -> &str {
+        &self.locale
+    }
+    pub fn clear_locale(&mut self) {
+        self.locale.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_locale(&mut self, v: ::std::string::String) {
+        self.locale = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_locale(&mut self) -> &mut ::std::string::String {
+        &mut self.locale
+    }
+
+    // Take field
+    pub fn take_locale(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.locale, ::std::string::String::new())
+    }
+}
+
+impl ::protobuf::Message for SetLocaleRequest {
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.tableId)?;
+                },
+                2 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.locale)?;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if !self.tableId.is_empty() {
+            my_size += ::protobuf::rt::string_size(1, &self.tableId);
+        }
+        if !self.locale.is_empty() {
+            my_size += ::protobuf::rt::string_size(2, &self.locale);
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        if !self.tableId.is_empty() {
+            os.write_string(1, &self.tableId)?;
+        }
+        if !self.locale.is_empty() {
+            os.write_string(2, &self.locale)?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> SetLocaleRequest {
+        SetLocaleRequest::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            let mut fields = ::std::vec::Vec::new();
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "tableId",
+                |m: &SetLocaleRequest| { &m.tableId },
+                |m: &mut SetLocaleRequest| { &mut m.tableId },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "locale",
+                |m: &SetLocaleRequest| { &m.locale },
+                |m: &mut SetLocaleRequest| { &mut m.locale },
+            ));
+            ::protobuf::reflect::MessageDescriptor::new_pb_name::<SetLocaleRequest>(
+                "SetLocaleRequest",
+                fields,
+                file_descriptor_proto()
+            )
+        })
+    }
+
+    fn default_instance() -> &'static SetLocaleRequest {
+        static instance: ::protobuf::rt::LazyV2<SetLocaleRequest> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(SetLocaleRequest::new)
+    }
+}
+
+impl ::protobuf::Clear for SetLocaleRequest {
+    fn clear(&mut self) {
+        self.tableId.clear();
+        self.locale.clear();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for SetLocaleRequest {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for SetLocaleRequest {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct SetLocaleResponse {
+    // message fields
+    pub cells: ::protobuf::RepeatedField<Cell>,
+    pub styles: ::std::collections::HashMap<u32, Style>,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a SetLocaleResponse {
+    fn default() -> &'a SetLocaleResponse {
+        <SetLocaleResponse as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl SetLocaleResponse {
+    pub fn new() -> SetLocaleResponse {
+        ::std::default::Default::default()
+    }
+
+    // repeated .spreadsheet.Cell cells = 1;
+
+
+    pub fn get_cells(&self) -> &[Cell] {
+        &self.cells
+    }
+    pub fn clear_cells(&mut self) {
+        self.cells.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_cells(&mut self, v: ::protobuf::RepeatedField<Cell>) {
+        self.cells = v;
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_cells(&mut self) -> &mut ::protobuf::RepeatedField<Cell> {
+        &mut self.cells
+    }
+
+    // Take field
+    pub fn take_cells(&mut self) -> ::protobuf::RepeatedField<Cell> {
+        ::std::mem::replace(&mut self.cells, ::protobuf::RepeatedField::new())
+    }
+
+    // repeated .spreadsheet.SetLocaleResponse.StylesEntry styles = 2;
+
+
+    pub fn get_styles(&self) -> &::std::collections::HashMap<u32, Style> {
+        &self.styles
+    }
+    pub fn clear_styles(&mut self) {
+        self.styles.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_styles(&mut self, v: ::std::collections::HashMap<u32, Style>) {
+        self.styles = v;
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_styles(&mut self) -> &mut ::std::collections::HashMap<u32, Style> {
+        &mut self.styles
+    }
+
+    // Take field
+    pub fn take_styles(&mut self) -> ::std::collections::HashMap<u32, Style> {
+        ::std::mem::replace(&mut self.styles, ::std::collections::HashMap::new())
+    }
+}
+
+impl ::protobuf::Message for SetLocaleResponse {
+    fn is_initialized(&self) -> bool {
+        for v in &self.cells {
+            if !v.is_initialized() {
+                return false;
+            }
+        };
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_repeated_message_into(wire_type, is, &mut self.cells)?;
+                },
+                2 => {
+                    ::protobuf::rt::read_map_into::<::protobuf::types::ProtobufTypeUint32, ::protobuf::types::ProtobufTypeMessage<Style>>(wire_type, is, &mut self.styles)?;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        for value in &self.cells {
+            let len = value.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        };
+        my_size += ::protobuf::rt::compute_map_size::<::protobuf::types::ProtobufTypeUint32, ::protobuf::types::ProtobufTypeMessage<Style>>(2, &self.styles);
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        for v in &self.cells {
+            os.write_tag(1, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+            os.write_raw_varint32(v.get_cached_size())?;
+            v.write_to_with_cached_sizes(os)?;
+        };
+        ::protobuf::rt::write_map_with_cached_sizes::<::protobuf::types::ProtobufTypeUint32, ::protobuf::types::ProtobufTypeMessage<Style>>(2, &self.styles, os)?;
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> SetLocaleResponse {
+        SetLocaleResponse::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            let mut fields = ::std::vec::Vec::new();
+            fields.push(::protobuf::reflect::accessor::make_repeated_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<Cell>>(
+                "cells",
+                |m: &SetLocaleResponse| { &m.cells },
+                |m: &mut SetLocaleResponse| { &mut m.cells },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_map_accessor::<_, ::protobuf::types::ProtobufTypeUint32, ::protobuf::types::ProtobufTypeMessage<Style>>(
+                "styles",
+                |m: &SetLocaleResponse| { &m.styles },
+                |m: &mut SetLocaleResponse| { &mut m.styles },
+            ));
+            ::protobuf::reflect::MessageDescriptor::new_pb_name::<SetLocaleResponse>(
+                "SetLocaleResponse",
+                fields,
+                file_descriptor_proto()
+            )
+        })
+    }
+
+    fn default_instance() -> &'static SetLocaleResponse {
+        static instance: ::protobuf::rt::LazyV2<SetLocaleResponse> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(SetLocaleResponse::new)
+    }
+}
+
+impl ::protobuf::Clear for SetLocaleResponse {
+    fn clear(&mut self) {
+        self.cells.clear();
+        self.styles.clear();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for SetLocaleResponse {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for SetLocaleResponse {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct SetFormatRequest {
+    // message fields
+    pub tableId: ::std::string::String,
+    pub rect: ::protobuf::SingularPtrField<Rect>,
+    pub format: ::std::string::String,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a SetFormatRequest {
+    fn default() -> &'a SetFormatRequest {
+        <SetFormatRequest as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl SetFormatRequest {
+    pub fn new() -> SetFormatRequest {
+        ::std::default::Default::default()
+    }
+
+    // string tableId = 1;
+
+
+    pub fn get_tableId(&self) -> &str {
+        &self.tableId
+    }
+    pub fn clear_tableId(&mut self) {
+        self.tableId.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_tableId(&mut self, v: ::std::string::String) {
+        self.tableId = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_tableId(&mut self) -> &mut ::std::string::String {
+        &mut self.tableId
+    }
+
+    // Take field
+    pub fn take_tableId(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.tableId, ::std::string::String::new())
+    }
+
+    // .spreadsheet.Rect rect = 2;
+
+
+    pub fn get_rect(&self) -> &Rect {
+        self.rect.as_ref().unwrap_or_else(|| <Rect as ::protobuf::Message>::default_instance())
+    }
+    pub fn clear_rect(&mut self) {
+        self.rect.clear();
+    }
+
+    pub fn has_rect(&self) -> bool {
+        self.rect.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_rect(&mut self, v: Rect) {
+        self.rect = ::protobuf::SingularPtrField::some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_rect(&mut self) -> &mut Rect {
+        if self.rect.is_none() {
+            self.rect.set_default();
+        }
+        self.rect.as_mut().unwrap()
+    }
+
+    // Take field
+    pub fn take_rect(&mut self) -> Rect {
+        self.rect.take().unwrap_or_else(|| Rect::new())
+    }
+
+    // string format = 3;
+
+
+    pub fn get_format(&self) -> &str {
+        &self.format
+    }
+    pub fn clear_format(&mut self) {
+        self.format.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_format(&mut self, v: ::std::string::String) {
+        self.format = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_format(&mut self) -> &mut ::std::string::String {
+        &mut self.format
+    }
+
+    // Take field
+    pub fn take_format(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.format, ::std::string::String::new())
+    }
+}
+
+impl ::protobuf::Message for SetFormatRequest {
+    fn is_initialized(&self) -> bool {
+        for v in &self.rect {
+            if !v.is_initialized() {
+                return false;
+            }
+        };
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.tableId)?;
+                },
+                2 => {
+                    ::protobuf::rt::read_singular_message_into(wire_type, is, &mut self.rect)?;
+                },
+                3 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.format)?;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if !self.tableId.is_empty() {
+            my_size += ::protobuf::rt::string_size(1, &self.tableId);
+        }
+        if let Some(ref v) = self.rect.as_ref() {
+            let len = v.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        }
+        if !self.format.is_empty() {
+            my_size += ::protobuf::rt::string_size(3, &self.format);
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        if !self.tableId.is_empty() {
+            os.write_string(1, &self.tableId)?;
+        }
+        if let Some(ref v) = self.rect.as_ref() {
+            os.write_tag(2, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+            os.write_raw_varint32(v.get_cached_size())?;
+            v.write_to_with_cached_sizes(os)?;
+        }
+        if !self.format.is_empty() {
+            os.write_string(3, &self.format)?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> SetFormatRequest {
+        SetFormatRequest::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            let mut fields = ::std::vec::Vec::new();
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "tableId",
+                |m: &SetFormatRequest| { &m.tableId },
+                |m: &mut SetFormatRequest| { &mut m.tableId },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_singular_ptr_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<Rect>>(
+                "rect",
+                |m: &SetFormatRequest| { &m.rect },
+                |m: &mut SetFormatRequest| { &mut m.rect },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "format",
+                |m: &SetFormatRequest| { &m.format },
+                |m: &mut SetFormatRequest| { &mut m.format },
+            ));
+            ::protobuf::reflect::MessageDescriptor::new_pb_name::<SetFormatRequest>(
+                "SetFormatRequest",
+                fields,
+                file_descriptor_proto()
+            )
+        })
+    }
+
+    fn default_instance() -> &'static SetFormatRequest {
+        static instance: ::protobuf::rt::LazyV2<SetFormatRequest> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(SetFormatRequest::new)
+    }
+}
+
+impl ::protobuf::Clear for SetFormatRequest {
+    fn clear(&mut self) {
+        self.tableId.clear();
+        self.rect.clear();
+        self.format.clear();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for SetFormatRequest {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for SetFormatRequest {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct SetFormatResponse {
+    // message fields
+    pub cells: ::protobuf::RepeatedField<Cell>,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a SetFormatResponse {
+    fn default() -> &'a SetFormatResponse {
+        <SetFormatResponse as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl SetFormatResponse {
+    pub fn new() -> SetFormatResponse {
+        ::std::default::Default::default()
+    }
+
+    // repeated .spreadsheet.Cell cells = 1;
+
+
+    pub fn get_cells(&self) -> &[Cell] {
+        &self.cells
+    }
+    pub fn clear_cells(&mut self) {
+        self.cells.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_cells(&mut self, v: ::protobuf::RepeatedField<Cell>) {
+        self.cells = v;
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_cells(&mut self) -> &mut ::protobuf::RepeatedField<Cell> {
+        &mut self.cells
+    }
+
+    // Take field
+    pub fn take_cells(&mut self) -> ::protobuf::RepeatedField<Cell> {
+        ::std::mem::replace(&mut self.cells, ::protobuf::RepeatedField::new())
+    }
+}
+
+impl ::protobuf::Message for SetFormatResponse {
+    fn is_initialized(&self) -> bool {
+        for v in &self.cells {
+            if !v.is_initialized() {
+                return false;
+            }
+        };
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_repeated_message_into(wire_type, is, &mut self.cells)?;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        for value in &self.cells {
+            let len = value.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        };
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        for v in &self.cells {
+            os.write_tag(1, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+            os.write_raw_varint32(v.get_cached_size())?;
+            v.write_to_with_cached_sizes(os)?;
+        };
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> SetFormatResponse {
+        SetFormatResponse::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            let mut fields = ::std::vec::Vec::new();
+            fields.push(::protobuf::reflect::accessor::make_repeated_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<Cell>>(
+                "cells",
+                |m: &SetFormatResponse| { &m.cells },
+                |m: &mut SetFormatResponse| { &mut m.cells },
+            ));
+            ::protobuf::reflect::MessageDescriptor::new_pb_name::<SetFormatResponse>(
+                "SetFormatResponse",
+                fields,
+                file_descriptor_proto()
+            )
+        })
+    }
+
+    fn default_instance() -> &'static SetFormatResponse {
+        static instance: ::protobuf::rt::LazyV2<SetFormatResponse> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(SetFormatResponse::new)
+    }
+}
+
+impl ::protobuf::Clear for SetFormatResponse {
+    fn clear(&mut self) {
+        self.cells.clear();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for SetFormatResponse {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for SetFormatResponse {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct Style {
+    // message fields
+    pub bold: bool,
+    pub italic: bool,
+    pub font_size: u32,
+    pub text_color: ::std::string::String,
+    pub fill_color: ::std::string::String,
+    pub top_border: ::protobuf::SingularPtrField<Border>,
+    pub bottom_border: ::protobuf::SingularPtrField<Border>,
+    pub left_border: ::protobuf::SingularPtrField<Border>,
+    pub right_border: ::protobuf::SingularPtrField<Border>,
+    pub horizontal_alignment: HorizontalAlignment,
+    pub vertical_alignment: VerticalAlignment,
+    pub wrap: bool,
+    pub data_bar: ::protobuf::SingularPtrField<DataBar>,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a Style {
+    fn default() -> &'a Style {
+        <Style as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl Style {
+    pub fn new() -> Style {
+        ::std::default::Default::default()
+    }
+
+    // bool bold = 1;
+
+
+    pub fn get_bold(&self) -> bool {
+        self.bold
+    }
+    pub fn clear_bold(&mut self) {
+        self.bold = false;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_bold(&mut self, v: bool) {
+        self.bold = v;
+    }
+
+    // bool italic = 2;
+
+
+    pub fn get_italic(&self) -> bool {
+        self.italic
+    }
+    pub fn clear_italic(&mut self) {
+        self.italic = false;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_italic(&mut self, v: bool) {
+        self.italic = v;
+    }
+
+    // uint32 font_size = 3;
+
+
+    pub fn get_font_size(&self) -> u32 {
+        self.font_size
+    }
+    pub fn clear_font_size(&mut self) {
+        self.font_size = 0;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_font_size(&mut self, v: u32) {
+        self.font_size = v;
+    }
+
+    // string text_color = 4;
+
+
+    pub fn get_text_color(&self) -> &str {
+        &self.text_color
+    }
+    pub fn clear_text_color(&mut self) {
+        self.text_color.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_text_color(&mut self, v: ::std::string::String) {
+        self.text_color = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_text_color(&mut self) -> &mut ::std::string::String {
+        &mut self.text_color
+    }
+
+    // Take field
+    pub fn take_text_color(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.text_color, ::std::string::String::new())
+    }
+
+    // string fill_color = 5;
+
+
+    pub fn get_fill_color(&self) -> &str {
+        &self.fill_color
+    }
+    pub fn clear_fill_color(&mut self) {
+        self.fill_color.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_fill_color(&mut self, v: ::std::string::String) {
+        self.fill_color = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_fill_color(&mut self) -> &mut ::std::string::String {
+        &mut self.fill_color
+    }
+
+    // Take field
+    pub fn take_fill_color(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.fill_color, ::std::string::String::new())
+    }
+
+    // .spreadsheet.Border top_border = 6;
+
+
+    pub fn get_top_border(&self) -> &Border {
+        self.top_border.as_ref().unwrap_or_else(|| <Border as ::protobuf::Message>::default_instance())
+    }
+    pub fn clear_top_border(&mut self) {
+        self.top_border.clear();
+    }
+
+    pub fn has_top_border(&self) -> bool {
+        self.top_border.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_top_border(&mut self, v: Border) {
+        self.top_border = ::protobuf::SingularPtrField::some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_top_border(&mut self) -> &mut Border {
+        if self.top_border.is_none() {
+            self.top_border.set_default();
+        }
+        self.top_border.as_mut().unwrap()
+    }
+
+    // Take field
+    pub fn take_top_border(&mut self) -> Border {
+        self.top_border.take().unwrap_or_else(|| Border::new())
+    }
+
+    // .spreadsheet.Border bottom_border = 7;
+
+
+    pub fn get_bottom_border(&self) -> &Border {
+        self.bottom_border.as_ref().unwrap_or_else(|| <Border as ::protobuf::Message>::default_instance())
+    }
+    pub fn clear_bottom_border(&mut self) {
+        self.bottom_border.clear();
+    }
+
+    pub fn has_bottom_border(&self) -> bool {
+        self.bottom_border.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_bottom_border(&mut self, v: Border) {
+        self.bottom_border = ::protobuf::SingularPtrField::some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_bottom_border(&mut self) -> &mut Border {
+        if self.bottom_border.is_none() {
+            self.bottom_border.set_default();
+        }
+        self.bottom_border.as_mut().unwrap()
+    }
+
+    // Take field
+    pub fn take_bottom_border(&mut self) -> Border {
+        self.bottom_border.take().unwrap_or_else(|| Border::new())
+    }
+
+    // .spreadsheet.Border left_border = 8;
+
+
+    pub fn get_left_border(&self) -> &Border {
+        self.left_border.as_ref().unwrap_or_else(|| <Border as ::protobuf::Message>::default_instance())
+    }
+    pub fn clear_left_border(&mut self) {
+        self.left_border.clear();
+    }
+
+    pub fn has_left_border(&self) -> bool {
+        self.left_border.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_left_border(&mut self, v: Border) {
+        self.left_border = ::protobuf::SingularPtrField::some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_left_border(&mut self) -> &mut Border {
+        if self.left_border.is_none() {
+            self.left_border.set_default();
+        }
+        self.left_border.as_mut().unwrap()
+    }
+
+    // Take field
+    pub fn take_left_border(&mut self) -> Border {
+        self.left_border.take().unwrap_or_else(|| Border::new())
+    }
+
+    // .spreadsheet.Border right_border = 9;
+
+
+    pub fn get_right_border(&self) -> &Border {
+        self.right_border.as_ref().unwrap_or_else(|| <Border as ::protobuf::Message>::default_instance())
+    }
+    pub fn clear_right_border(&mut self) {
+        self.right_border.clear();
+    }
+
+    pub fn has_right_border(&self) -> bool {
+        self.right_border.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_right_border(&mut self, v: Border) {
+        self.right_border = ::protobuf::SingularPtrField::some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_right_border(&mut self) -> &mut Border {
+        if self.right_border.is_none() {
+            self.right_border.set_default();
+        }
+        self.right_border.as_mut().unwrap()
+    }
+
+    // Take field
+    pub fn take_right_border(&mut self) -> Border {
+        self.right_border.take().unwrap_or_else(|| Border::new())
+    }
+
+    // .spreadsheet.HorizontalAlignment horizontal_alignment = 10;
+
+
+    pub fn get_horizontal_alignment(&self) -> HorizontalAlignment {
+        self.horizontal_alignment
+    }
+    pub fn clear_horizontal_alignment(&mut self) {
+        self.horizontal_alignment = HorizontalAlignment::HORIZONTAL_GENERAL;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_horizontal_alignment(&mut self, v: HorizontalAlignment) {
+        self.horizontal_alignment = v;
+    }
+
+    // .spreadsheet.VerticalAlignment vertical_alignment = 11;
+
+
+    pub fn get_vertical_alignment(&self) -> VerticalAlignment {
+        self.vertical_alignment
+    }
+    pub fn clear_vertical_alignment(&mut self) {
+        self.vertical_alignment = VerticalAlignment::VERTICAL_BOTTOM;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_vertical_alignment(&mut self, v: VerticalAlignment) {
+        self.vertical_alignment = v;
+    }
+
+    // bool wrap = 12;
+
+
+    pub fn get_wrap(&self) -> bool {
+        self.wrap
+    }
+    pub fn clear_wrap(&mut self) {
+        self.wrap = false;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_wrap(&mut self, v: bool) {
+        self.wrap = v;
+    }
+
+    // .spreadsheet.DataBar data_bar = 13;
+
+
+    pub fn get_data_bar(&self) -> &DataBar {
+        self.data_bar.as_ref().unwrap_or_else(|| <DataBar as ::protobuf::Message>::default_instance())
+    }
+    pub fn clear_data_bar(&mut self) {
+        self.data_bar.clear();
+    }
+
+    pub fn has_data_bar(&self) -> bool {
+        self.data_bar.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_data_bar(&mut self, v: DataBar) {
+        self.data_bar = ::protobuf::SingularPtrField::some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_data_bar(&mut self) -> &mut DataBar {
+        if self.data_bar.is_none() {
+            self.data_bar.set_default();
+        }
+        self.data_bar.as_mut().unwrap()
+    }
+
+    // Take field
+    pub fn take_data_bar(&mut self) -> DataBar {
+        self.data_bar.take().unwrap_or_else(|| DataBar::new())
+    }
+}
+
+impl ::protobuf::Message for Style {
+    fn is_initialized(&self) -> bool {
+        for v in &self.top_border {
+            if !v.is_initialized() {
+                return false;
+            }
+        };
+        for v in &self.bottom_border {
+            if !v.is_initialized() {
+                return false;
+            }
+        };
+        for v in &self.left_border {
+            if !v.is_initialized() {
+                return false;
+            }
+        };
+        for v in &self.right_border {
+            if !v.is_initialized() {
+                return false;
+            }
+        };
+        for v in &self.data_bar {
+            if !v.is_initialized() {
+                return false;
+            }
+        };
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_bool()?;
+                    self.bold = tmp;
+                },
+                2 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_bool()?;
+                    self.italic = tmp;
+                },
+                3 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_uint32()?;
+                    self.font_size = tmp;
+                },
+                4 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.text_color)?;
+                },
+                5 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.fill_color)?;
+                },
+                6 => {
+                    ::protobuf::rt::read_singular_message_into(wire_type, is, &mut self.top_border)?;
+                },
+                7 => {
+                    ::protobuf::rt::read_singular_message_into(wire_type, is, &mut self.bottom_border)?;
+                },
+                8 => {
+                    ::protobuf::rt::read_singular_message_into(wire_type, is, &mut self.left_border)?;
+                },
+                9 => {
+                    ::protobuf::rt::read_singular_message_into(wire_type, is, &mut self.right_border)?;
+                },
+                10 => {
+                    ::protobuf::rt::read_proto3_enum_with_unknown_fields_into(wire_type, is, &mut self.horizontal_alignment, 10, &mut self.unknown_fields)?
+                },
+                11 => {
+                    ::protobuf::rt::read_proto3_enum_with_unknown_fields_into(wire_type, is, &mut self.vertical_alignment, 11, &mut self.unknown_fields)?
+                },
+                12 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_bool()?;
+                    self.wrap = tmp;
+                },
+                13 => {
+                    ::protobuf::rt::read_singular_message_into(wire_type, is, &mut self.data_bar)?;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if self.bold != false {
+            my_size += 2;
+        }
+        if self.italic != false {
+            my_size += 2;
+        }
+        if self.font_size != 0 {
+            my_size += ::protobuf::rt::value_size(3, self.font_size, ::protobuf::wire_format::WireTypeVarint);
+        }
+        if !self.text_color.is_empty() {
+            my_size += ::protobuf::rt::string_size(4, &self.text_color);
+        }
+        if !self.fill_color.is_empty() {
+            my_size += ::protobuf::rt::string_size(5, &self.fill_color);
+        }
+        if let Some(ref v) = self.top_border.as_ref() {
+            let len = v.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        }
+        if let Some(ref v) = self.bottom_border.as_ref() {
+            let len = v.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        }
+        if let Some(ref v) = self.left_border.as_ref() {
+            let len = v.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        }
+        if let Some(ref v) = self.right_border.as_ref() {
+            let len = v.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        }
+        if self.horizontal_alignment != HorizontalAlignment::HORIZONTAL_GENERAL {
+            my_size += ::protobuf::rt::enum_size(10, self.horizontal_alignment);
+        }
+        if self.vertical_alignment != VerticalAlignment::VERTICAL_BOTTOM {
+            my_size += ::protobuf::rt::enum_size(11, self.vertical_alignment);
+        }
+        if self.wrap != false {
+            my_size += 2;
+        }
+        if let Some(ref v) = self.data_bar.as_ref() {
+            let len = v.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        if self.bold != false {
+            os.write_bool(1, self.bold)?;
+        }
+        if self.italic != false {
+            os.write_bool(2, self.italic)?;
+        }
+        if self.font_size != 0 {
+            os.write_uint32(3, self.font_size)?;
+        }
+        if !self.text_color.is_empty() {
+            os.write_string(4, &self.text_color)?;
+        }
+        if !self.fill_color.is_empty() {
+            os.write_string(5, &self.fill_color)?;
+        }
+        if let Some(ref v) = self.top_border.as_ref() {
+            os.write_tag(6, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+            os.write_raw_varint32(v.get_cached_size())?;
+            v.write_to_with_cached_sizes(os)?;
+        }
+        if let Some(ref v) = self.bottom_border.as_ref() {
+            os.write_tag(7, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+            os.write_raw_varint32(v.get_cached_size())?;
+            v.write_to_with_cached_sizes(os)?;
+        }
+        if let Some(ref v) = self.left_border.as_ref() {
+            os.write_tag(8, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+            os.write_raw_varint32(v.get_cached_size())?;
+            v.write_to_with_cached_sizes(os)?;
+        }
+        if let Some(ref v) = self.right_border.as_ref() {
+            os.write_tag(9, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+            os.write_raw_varint32(v.get_cached_size())?;
+            v.write_to_with_cached_sizes(os)?;
+        }
+        if self.horizontal_alignment != HorizontalAlignment::HORIZONTAL_GENERAL {
+            os.write_enum(10, ::protobuf::ProtobufEnum::value(&self.horizontal_alignment))?;
+        }
+        if self.vertical_alignment != VerticalAlignment::VERTICAL_BOTTOM {
+            os.write_enum(11, ::protobuf::ProtobufEnum::value(&self.vertical_alignment))?;
+        }
+        if self.wrap != false {
+            os.write_bool(12, self.wrap)?;
+        }
+        if let Some(ref v) = self.data_bar.as_ref() {
+            os.write_tag(13, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+            os.write_raw_varint32(v.get_cached_size())?;
+            v.write_to_with_cached_sizes(os)?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> Style {
+        Style::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            let mut fields = ::std::vec::Vec::new();
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeBool>(
+                "bold",
+                |m: &Style| { &m.bold },
+                |m: &mut Style| { &mut m.bold },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeBool>(
+                "italic",
+                |m: &Style| { &m.italic },
+                |m: &mut Style| { &mut m.italic },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeUint32>(
+                "font_size",
+                |m: &Style| { &m.font_size },
+                |m: &mut Style| { &mut m.font_size },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "text_color",
+                |m: &Style| { &m.text_color },
+                |m: &mut Style| { &mut m.text_color },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "fill_color",
+                |m: &Style| { &m.fill_color },
+                |m: &mut Style| { &mut m.fill_color },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_singular_ptr_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<Border>>(
+                "top_border",
+                |m: &Style| { &m.top_border },
+                |m: &mut Style| { &mut m.top_border },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_singular_ptr_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<Border>>(
+                "bottom_border",
+                |m: &Style| { &m.bottom_border },
+                |m: &mut Style| { &mut m.bottom_border },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_singular_ptr_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<Border>>(
+                "left_border",
+                |m: &Style| { &m.left_border },
+                |m: &mut Style| { &mut m.left_border },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_singular_ptr_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<Border>>(
+                "right_border",
+                |m: &Style| { &m.right_border },
+                |m: &mut Style| { &mut m.right_border },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeEnum<HorizontalAlignment>>(
+                "horizontal_alignment",
+                |m: &Style| { &m.horizontal_alignment },
+                |m: &mut Style| { &mut m.horizontal_alignment },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeEnum<VerticalAlignment>>(
+                "vertical_alignment",
+                |m: &Style| { &m.vertical_alignment },
+                |m: &mut Style| { &mut m.vertical_alignment },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeBool>(
+                "wrap",
+                |m: &Style| { &m.wrap },
+                |m: &mut Style| { &mut m.wrap },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_singular_ptr_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<DataBar>>(
+                "data_bar",
+                |m: &Style| { &m.data_bar },
+                |m: &mut Style| { &mut m.data_bar },
+            ));
+            ::protobuf::reflect::MessageDescriptor::new_pb_name::<Style>(
+                "Style",
+                fields,
+                file_descriptor_proto()
+            )
+        })
+    }
+
+    fn default_instance() -> &'static Style {
+        static instance: ::protobuf::rt::LazyV2<Style> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(Style::new)
+    }
+}
+
+impl ::protobuf::Clear for Style {
+    fn clear(&mut self) {
+        self.bold = false;
+        self.italic = false;
+        self.font_size = 0;
+        self.text_color.clear();
+        self.fill_color.clear();
+        self.top_border.clear();
+        self.bottom_border.clear();
+        self.left_border.clear();
+        self.right_border.clear();
+        self.horizontal_alignment = HorizontalAlignment::HORIZONTAL_GENERAL;
+        self.vertical_alignment = VerticalAlignment::VERTICAL_BOTTOM;
+        self.wrap = false;
+        self.data_bar.clear();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for Style {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for Style {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct DataBar {
+    // message fields
+    pub color: ::std::string::String,
+    pub percent: u32,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a DataBar {
+    fn default() -> &'a DataBar {
+        <DataBar as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl DataBar {
+    pub fn new() -> DataBar {
+        ::std::default::Default::default()
+    }
+
+    // string color = 1;
+
+
+    pub fn get_color(&self) -> &str {
+        &self.color
+    }
+    pub fn clear_color(&mut self) {
+        self.color.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_color(&mut self, v: ::std::string::String) {
+        self.color = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_color(&mut self) -> &mut ::std::string::String {
+        &mut self.color
+    }
+
+    // Take field
+    pub fn take_color(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.color, ::std::string::String::new())
+    }
+
+    // uint32 percent = 2;
+
+
+    pub fn get_percent(&self) -> u32 {
+        self.percent
+    }
+    pub fn clear_percent(&mut self) {
+        self.percent = 0;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_percent(&mut self, v: u32) {
+        self.percent = v;
+    }
+}
+
+impl ::protobuf::Message for DataBar {
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.color)?;
+                },
+                2 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_uint32()?;
+                    self.percent = tmp;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if !self.color.is_empty() {
+            my_size += ::protobuf::rt::string_size(1, &self.color);
+        }
+        if self.percent != 0 {
+            my_size += ::protobuf::rt::value_size(2, self.percent, ::protobuf::wire_format::WireTypeVarint);
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        if !self.color.is_empty() {
+            os.write_string(1, &self.color)?;
+        }
+        if self.percent != 0 {
+            os.write_uint32(2, self.percent)?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> DataBar {
+        DataBar::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            let mut fields = ::std::vec::Vec::new();
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "color",
+                |m: &DataBar| { &m.color },
+                |m: &mut DataBar| { &mut m.color },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeUint32>(
+                "percent",
+                |m: &DataBar| { &m.percent },
+                |m: &mut DataBar| { &mut m.percent },
+            ));
+            ::protobuf::reflect::MessageDescriptor::new_pb_name::<DataBar>(
+                "DataBar",
+                fields,
+                file_descriptor_proto()
+            )
+        })
+    }
+
+    fn default_instance() -> &'static DataBar {
+        static instance: ::protobuf::rt::LazyV2<DataBar> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(DataBar::new)
+    }
+}
+
+impl ::protobuf::Clear for DataBar {
+    fn clear(&mut self) {
+        self.color.clear();
+        self.percent = 0;
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for DataBar {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for DataBar {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct Border {
+    // message fields
+    pub style: BorderStyle,
+    pub color: ::std::string::String,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a Border {
+    fn default() -> &'a Border {
+        <Border as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl Border {
+    pub fn new() -> Border {
+        ::std::default::Default::default()
+    }
+
+    // .spreadsheet.BorderStyle style = 1;
+
+
+    pub fn get_style(&self) -> BorderStyle {
+        self.style
+    }
+    pub fn clear_style(&mut self) {
+        self.style = BorderStyle::BORDER_NONE;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_style(&mut self, v: BorderStyle) {
+        self.style = v;
+    }
+
+    // string color = 2;
+
+
+    pub fn get_color(&self) -> &str {
+        &self.color
+    }
+    pub fn clear_color(&mut self) {
+        self.color.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_color(&mut self, v: ::std::string::String) {
+        self.color = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_color(&mut self) -> &mut ::std::string::String {
+        &mut self.color
+    }
+
+    // Take field
+    pub fn take_color(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.color, ::std::string::String::new())
+    }
+}
+
+impl ::protobuf::Message for Border {
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_proto3_enum_with_unknown_fields_into(wire_type, is, &mut self.style, 1, &mut self.unknown_fields)?
+                },
+                2 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.color)?;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if self.style != BorderStyle::BORDER_NONE {
+            my_size += ::protobuf::rt::enum_size(1, self.style);
+        }
+        if !self.color.is_empty() {
+            my_size += ::protobuf::rt::string_size(2, &self.color);
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        if self.style != BorderStyle::BORDER_NONE {
+            os.write_enum(1, ::protobuf::ProtobufEnum::value(&self.style))?;
+        }
+        if !self.color.is_empty() {
+            os.write_string(2, &self.color)?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> Border {
+        Border::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            let mut fields = ::std::vec::Vec::new();
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeEnum<BorderStyle>>(
+                "style",
+                |m: &Border| { &m.style },
+                |m: &mut Border| { &mut m.style },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "color",
+                |m: &Border| { &m.color },
+                |m: &mut Border| { &mut m.color },
+            ));
+            ::protobuf::reflect::MessageDescriptor::new_pb_name::<Border>(
+                "Border",
+                fields,
+                file_descriptor_proto()
+            )
+        })
+    }
+
+    fn default_instance() -> &'static Border {
+        static instance: ::protobuf::rt::LazyV2<Border> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(Border::new)
+    }
+}
+
+impl ::protobuf::Clear for Border {
+    fn clear(&mut self) {
+        self.style = BorderStyle::BORDER_NONE;
+        self.color.clear();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for Border {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for Border {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct SetStylesRequest {
+    // message fields
+    pub tableId: ::std::string::String,
+    pub rect: ::protobuf::SingularPtrField<Rect>,
+    pub style: ::protobuf::SingularPtrField<Style>,
+    pub fields: ::protobuf::RepeatedField<::std::string::String>,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a SetStylesRequest {
+    fn default() -> &'a SetStylesRequest {
+        <SetStylesRequest as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl SetStylesRequest {
+    pub fn new() -> SetStylesRequest {
+        ::std::default::Default::default()
+    }
+
+    // string tableId = 1;
+
+
+    pub fn get_tableId(&self) -> &str {
+        &self.tableId
+    }
+    pub fn clear_tableId(&mut self) {
+        self.tableId.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_tableId(&mut self, v: ::std::string::String) {
+        self.tableId = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_tableId(&mut self) -> &mut ::std::string::String {
+        &mut self.tableId
+    }
+
+    // Take field
+    pub fn take_tableId(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.tableId, ::std::string::String::new())
+    }
+
+    // .spreadsheet.Rect rect = 2;
+
+
+    pub fn get_rect(&self) -> &Rect {
+        self.rect.as_ref().unwrap_or_else(|| <Rect as ::protobuf::Message>::default_instance())
+    }
+    pub fn clear_rect(&mut self) {
+        self.rect.clear();
+    }
+
+    pub fn has_rect(&self) -> bool {
+        self.rect.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_rect(&mut self, v: Rect) {
+        self.rect = ::protobuf::SingularPtrField::some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_rect(&mut self) -> &mut Rect {
+        if self.rect.is_none() {
+            self.rect.set_default();
+        }
+        self.rect.as_mut().unwrap()
+    }
+
+    // Take field
+    pub fn take_rect(&mut self) -> Rect {
+        self.rect.take().unwrap_or_else(|| Rect::new())
+    }
+
+    // .spreadsheet.Style style = 3;
+
+
+    pub fn get_style(&self) -> &Style {
+        self.style.as_ref().unwrap_or_else(|| <Style as ::protobuf::Message>::default_instance())
+    }
+    pub fn clear_style(&mut self) {
+        self.style.clear();
+    }
+
+    pub fn has_style(&self) -> bool {
+        self.style.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_style(&mut self, v: Style) {
+        self.style = ::protobuf::SingularPtrField::some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_style(&mut self) -> &mut Style {
+        if self.style.is_none() {
+            self.style.set_default();
+        }
+        self.style.as_mut().unwrap()
+    }
+
+    // Take field
+    pub fn take_style(&mut self) -> Style {
+        self.style.take().unwrap_or_else(|| Style::new())
+    }
+
+    // repeated string fields = 4;
+
+
+    pub fn get_fields(&self) -> &[::std::string::String] {
+        &self.fields
+    }
+    pub fn clear_fields(&mut self) {
+        self.fields.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_fields(&mut self, v: ::protobuf::RepeatedField<::std::string::String>) {
+        self.fields = v;
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_fields(&mut self) -> &mut ::protobuf::RepeatedField<::std::string::String> {
+        &mut self.fields
+    }
+
+    // Take field
+    pub fn take_fields(&mut self) -> ::protobuf::RepeatedField<::std::string::String> {
+        ::std::mem::replace(&mut self.fields, ::protobuf::RepeatedField::new())
+    }
+}
+
+impl ::protobuf::Message for SetStylesRequest {
+    fn is_initialized(&self) -> bool {
+        for v in &self.rect {
+            if !v.is_initialized() {
+                return false;
+            }
+        };
+        for v in &self.style {
+            if !v.is_initialized() {
+                return false;
+            }
+        };
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.tableId)?;
+                },
+                2 => {
+                    ::protobuf::rt::read_singular_message_into(wire_type, is, &mut self.rect)?;
+                },
+                3 => {
+                    ::protobuf::rt::read_singular_message_into(wire_type, is, &mut self.style)?;
+                },
+                4 => {
+                    ::protobuf::rt::read_repeated_string_into(wire_type, is, &mut self.fields)?;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if !self.tableId.is_empty() {
+            my_size += ::protobuf::rt::string_size(1, &self.tableId);
+        }
+        if let Some(ref v) = self.rect.as_ref() {
+            let len = v.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        }
+        if let Some(ref v) = self.style.as_ref() {
+            let len = v.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        }
+        for value in &self.fields {
+            my_size += ::protobuf::rt::string_size(4, &value);
+        };
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        if !self.tableId.is_empty() {
+            os.write_string(1, &self.tableId)?;
+        }
+        if let Some(ref v) = self.rect.as_ref() {
+            os.write_tag(2, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+            os.write_raw_varint32(v.get_cached_size())?;
+            v.write_to_with_cached_sizes(os)?;
+        }
+        if let Some(ref v) = self.style.as_ref() {
+            os.write_tag(3, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+            os.write_raw_varint32(v.get_cached_size())?;
+            v.write_to_with_cached_sizes(os)?;
+        }
+        for v in &self.fields {
+            os.write_string(4, &v)?;
+        };
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> SetStylesRequest {
+        SetStylesRequest::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            let mut fields = ::std::vec::Vec::new();
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "tableId",
+                |m: &SetStylesRequest| { &m.tableId },
+                |m: &mut SetStylesRequest| { &mut m.tableId },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_singular_ptr_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<Rect>>(
+                "rect",
+                |m: &SetStylesRequest| { &m.rect },
+                |m: &mut SetStylesRequest| { &mut m.rect },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_singular_ptr_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<Style>>(
+                "style",
+                |m: &SetStylesRequest| { &m.style },
+                |m: &mut SetStylesRequest| { &mut m.style },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_repeated_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "fields",
+                |m: &SetStylesRequest| { &m.fields },
+                |m: &mut SetStylesRequest| { &mut m.fields },
+            ));
+            ::protobuf::reflect::MessageDescriptor::new_pb_name::<SetStylesRequest>(
+                "SetStylesRequest",
+                fields,
+                file_descriptor_proto()
+            )
+        })
+    }
+
+    fn default_instance() -> &'static SetStylesRequest {
+        static instance: ::protobuf::rt::LazyV2<SetStylesRequest> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(SetStylesRequest::new)
+    }
+}
+
+impl ::protobuf::Clear for SetStylesRequest {
+    fn clear(&mut self) {
+        self.tableId.clear();
+        self.rect.clear();
+        self.style.clear();
+        self.fields.clear();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for SetStylesRequest {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for SetStylesRequest {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct SetStylesResponse {
+    // message fields
+    pub cells: ::protobuf::RepeatedField<Cell>,
+    pub styles: ::std::collections::HashMap<u32, Style>,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a SetStylesResponse {
+    fn default() -> &'a SetStylesResponse {
+        <SetStylesResponse as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl SetStylesResponse {
+    pub fn new() -> SetStylesResponse {
+        ::std::default::Default::default()
+    }
+
+    // repeated .spreadsheet.Cell cells = 1;
+
+
+    pub fn get_cells(&self) -> &[Cell] {
+        &self.cells
+    }
+    pub fn clear_cells(&mut self) {
+        self.cells.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_cells(&mut self, v: ::protobuf::RepeatedField<Cell>) {
+        self.cells = v;
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_cells(&mut self) -> &mut ::protobuf::RepeatedField<Cell> {
+        &mut self.cells
+    }
+
+    // Take field
+    pub fn take_cells(&mut self) -> ::protobuf::RepeatedField<Cell> {
+        ::std::mem::replace(&mut self.cells, ::protobuf::RepeatedField::new())
+    }
+
+    // repeated .spreadsheet.SetStylesResponse.StylesEntry styles = 2;
+
+
+    pub fn get_styles(&self) -> &::std::collections::HashMap<u32, Style> {
+        &self.styles
+    }
+    pub fn clear_styles(&mut self) {
+        self.styles.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_styles(&mut self, v: ::std::collections::HashMap<u32, Style>) {
+        self.styles = v;
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_styles(&mut self) -> &mut ::std::collections::HashMap<u32, Style> {
+        &mut self.styles
+    }
+
+    // Take field
+    pub fn take_styles(&mut self) -> ::std::collections::HashMap<u32, Style> {
+        ::std::mem::replace(&mut self.styles, ::std::collections::HashMap::new())
+    }
+}
+
+impl ::protobuf::Message for SetStylesResponse {
+    fn is_initialized(&self) -> bool {
+        for v in &self.cells {
+            if !v.is_initialized() {
+                return false;
+            }
+        };
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_repeated_message_into(wire_type, is, &mut self.cells)?;
+                },
+                2 => {
+                    ::protobuf::rt::read_map_into::<::protobuf::types::ProtobufTypeUint32, ::protobuf::types::ProtobufTypeMessage<Style>>(wire_type, is, &mut self.styles)?;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        for value in &self.cells {
+            let len = value.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        };
+        my_size += ::protobuf::rt::compute_map_size::<::protobuf::types::ProtobufTypeUint32, ::protobuf::types::ProtobufTypeMessage<Style>>(2, &self.styles);
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        for v in &self.cells {
+            os.write_tag(1, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+            os.write_raw_varint32(v.get_cached_size())?;
+            v.write_to_with_cached_sizes(os)?;
+        };
+        ::protobuf::rt::write_map_with_cached_sizes::<::protobuf::types::ProtobufTypeUint32, ::protobuf::types::ProtobufTypeMessage<Style>>(2, &self.styles, os)?;
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> SetStylesResponse {
+        SetStylesResponse::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            let mut fields = ::std::vec::Vec::new();
+            fields.push(::protobuf::reflect::accessor::make_repeated_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<Cell>>(
+                "cells",
+                |m: &SetStylesResponse| { &m.cells },
+                |m: &mut SetStylesResponse| { &mut m.cells },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_map_accessor::<_, ::protobuf::types::ProtobufTypeUint32, ::protobuf::types::ProtobufTypeMessage<Style>>(
+                "styles",
+                |m: &SetStylesResponse| { &m.styles },
+                |m: &mut SetStylesResponse| { &mut m.styles },
+            ));
+            ::protobuf::reflect::MessageDescriptor::new_pb_name::<SetStylesResponse>(
+                "SetStylesResponse",
+                fields,
+                file_descriptor_proto()
+            )
+        })
+    }
+
+    fn default_instance() -> &'static SetStylesResponse {
+        static instance: ::protobuf::rt::LazyV2<SetStylesResponse> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(SetStylesResponse::new)
+    }
+}
+
+impl ::protobuf::Clear for SetStylesResponse {
+    fn clear(&mut self) {
+        self.cells.clear();
+        self.styles.clear();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for SetStylesResponse {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for SetStylesResponse {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct ConditionalFormat {
+    // message fields
+    pub range: ::protobuf::SingularPtrField<Rect>,
+    // message oneof groups
+    pub rule: ::std::option::Option<ConditionalFormat_oneof_rule>,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a ConditionalFormat {
+    fn default() -> &'a ConditionalFormat {
+        <ConditionalFormat as ::protobuf::Message>::default_instance()
+    }
+}
+
+#[derive(Clone,PartialEq,Debug)]
+pub enum ConditionalFormat_oneof_rule {
+    cell_value(CellValueRule),
+    formula(FormulaRule),
+    color_scale(ColorScaleRule),
+    data_bar(DataBarRule),
+    top_n(TopNRule),
+}
+
+impl ConditionalFormat {
+    pub fn new() -> ConditionalFormat {
+        ::std::default::Default::default()
+    }
+
+    // .spreadsheet.Rect range = 1;
+
+
+    pub fn get_range(&self) -> &Rect {
+        self.range.as_ref().unwrap_or_else(|| <Rect as ::protobuf::Message>::default_instance())
+    }
+    pub fn clear_range(&mut self) {
+        self.range.clear();
+    }
+
+    pub fn has_range(&self) -> bool {
+        self.range.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_range(&mut self, v: Rect) {
+        self.range = ::protobuf::SingularPtrField::some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_range(&mut self) -> &mut Rect {
+        if self.range.is_none() {
+            self.range.set_default();
+        }
+        self.range.as_mut().unwrap()
+    }
+
+    // Take field
+    pub fn take_range(&mut self) -> Rect {
+        self.range.take().unwrap_or_else(|| Rect::new())
+    }
+
+    // .spreadsheet.CellValueRule cell_value = 2;
+
+
+    pub fn get_cell_value(&self) -> &CellValueRule {
+        match self.rule {
+            ::std::option::Option::Some(ConditionalFormat_oneof_rule::cell_value(ref v)) => v,
+            _ => <CellValueRule as ::protobuf::Message>::default_instance(),
+        }
+    }
+    pub fn clear_cell_value(&mut self) {
+        self.rule = ::std::option::Option::None;
+    }
+
+    pub fn has_cell_value(&self) -> bool {
+        match self.rule {
+            ::std::option::Option::Some(ConditionalFormat_oneof_rule::cell_value(..)) => true,
+            _ => false,
+        }
+    }
+
+    // Param is passed by value, moved
+    pub fn set_cell_value(&mut self, v: CellValueRule) {
+        self.rule = ::std::option::Option::Some(ConditionalFormat_oneof_rule::cell_value(v))
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_cell_value(&mut self) -> &mut CellValueRule {
+        if let ::std::option::Option::Some(ConditionalFormat_oneof_rule::cell_value(_)) = self.rule {
+        } else {
+            self.rule = ::std::option::Option::Some(ConditionalFormat_oneof_rule::cell_value(CellValueRule::new()));
+        }
+        match self.rule {
+            ::std::option::Option::Some(ConditionalFormat_oneof_rule::cell_value(ref mut v)) => v,
+            _ => panic!(),
+        }
+    }
+
+    // Take field
+    pub fn take_cell_value(&mut self) -> CellValueRule {
+        if self.has_cell_value() {
+            match self.rule.take() {
+                ::std::option::Option::Some(ConditionalFormat_oneof_rule::cell_value(v)) => v,
+                _ => panic!(),
+            }
+        } else {
+            CellValueRule::new()
+        }
+    }
+
+    // .spreadsheet.FormulaRule formula = 3;
+
+
+    pub fn get_formula(&self) -> &FormulaRule {
+        match self.rule {
+            ::std::option::Option::Some(ConditionalFormat_oneof_rule::formula(ref v)) => v,
+            _ => <FormulaRule as ::protobuf::Message>::default_instance(),
+        }
+    }
+    pub fn clear_formula(&mut self) {
+        self.rule = ::std::option::Option::None;
+    }
+
+    pub fn has_formula(&self) -> bool {
+        match self.rule {
+            ::std::option::Option::Some(ConditionalFormat_oneof_rule::formula(..)) => true,
+            _ => false,
+        }
+    }
+
+    // Param is passed by value, moved
+    pub fn set_formula(&mut self, v: FormulaRule) {
+        self.rule = ::std::option::Option::Some(ConditionalFormat_oneof_rule::formula(v))
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_formula(&mut self) -> &mut FormulaRule {
+        if let ::std::option::Option::Some(ConditionalFormat_oneof_rule::formula(_)) = self.rule {
+        } else {
+            self.rule = ::std::option::Option::Some(ConditionalFormat_oneof_rule::formula(FormulaRule::new()));
+        }
+        match self.rule {
+            ::std::option::Option::Some(ConditionalFormat_oneof_rule::formula(ref mut v)) => v,
+            _ => panic!(),
+        }
+    }
+
+    // Take field
+    pub fn take_formula(&mut self) -> FormulaRule {
+        if self.has_formula() {
+            match self.rule.take() {
+                ::std::option::Option::Some(ConditionalFormat_oneof_rule::formula(v)) => v,
+                _ => panic!(),
+            }
+        } else {
+            FormulaRule::new()
+        }
+    }
+
+    // .spreadsheet.ColorScaleRule color_scale = 4;
+
+
+    pub fn get_color_scale(&self) -> &ColorScaleRule {
+        match self.rule {
+            ::std::option::Option::Some(ConditionalFormat_oneof_rule::color_scale(ref v)) => v,
+            _ => <ColorScaleRule as ::protobuf::Message>::default_instance(),
+        }
+    }
+    pub fn clear_color_scale(&mut self) {
+        self.rule = ::std::option::Option::None;
+    }
+
+    pub fn has_color_scale(&self) -> bool {
+        match self.rule {
+            ::std::option::Option::Some(ConditionalFormat_oneof_rule::color_scale(..)) => true,
+            _ => false,
+        }
+    }
+
+    // Param is passed by value, moved
+    pub fn set_color_scale(&mut self, v: ColorScaleRule) {
+        self.rule = ::std::option::Option::Some(ConditionalFormat_oneof_rule::color_scale(v))
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_color_scale(&mut self) -> &mut ColorScaleRule {
+        if let ::std::option::Option::Some(ConditionalFormat_oneof_rule::color_scale(_)) = self.rule {
+        } else {
+            self.rule = ::std::option::Option::Some(ConditionalFormat_oneof_rule::color_scale(ColorScaleRule::new()));
+        }
+        match self.rule {
+            ::std::option::Option::Some(ConditionalFormat_oneof_rule::color_scale(ref mut v)) => v,
+            _ => panic!(),
+        }
+    }
+
+    // Take field
+    pub fn take_color_scale(&mut self) -> ColorScaleRule {
+        if self.has_color_scale() {
+            match self.rule.take() {
+                ::std::option::Option::Some(ConditionalFormat_oneof_rule::color_scale(v)) => v,
+                _ => panic!(),
+            }
+        } else {
+            ColorScaleRule::new()
+        }
+    }
+
+    // .spreadsheet.DataBarRule data_bar = 5;
+
+
+    pub fn get_data_bar(&self) -> &DataBarRule {
+        match self.rule {
+            ::std::option::Option::Some(ConditionalFormat_oneof_rule::data_bar(ref v)) => v,
+            _ => <DataBarRule as ::protobuf::Message>::default_instance(),
+        }
+    }
+    pub fn clear_data_bar(&mut self) {
+        self.rule = ::std::option::Option::None;
+    }
+
+    pub fn has_data_bar(&self) -> bool {
+        match self.rule {
+            ::std::option::Option::Some(ConditionalFormat_oneof_rule::data_bar(..)) => true,
+            _ => false,
+        }
+    }
+
+    // Param is passed by value, moved
+    pub fn set_data_bar(&mut self, v: DataBarRule) {
+        self.rule = ::std::option::Option::Some(ConditionalFormat_oneof_rule::data_bar(v))
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_data_bar(&mut self) -> &mut DataBarRule {
+        if let ::std::option::Option::Some(ConditionalFormat_oneof_rule::data_bar(_)) = self.rule {
+        } else {
+            self.rule = ::std::option::Option::Some(ConditionalFormat_oneof_rule::data_bar(DataBarRule::new()));
+        }
+        match self.rule {
+            ::std::option::Option::Some(ConditionalFormat_oneof_rule::data_bar(ref mut v)) => v,
+            _ => panic!(),
+        }
+    }
+
+    // Take field
+    pub fn take_data_bar(&mut self) -> DataBarRule {
+        if self.has_data_bar() {
+            match self.rule.take() {
+                ::std::option::Option::Some(ConditionalFormat_oneof_rule::data_bar(v)) => v,
+                _ => panic!(),
+            }
+        } else {
+            DataBarRule::new()
+        }
+    }
+
+    // .spreadsheet.TopNRule top_n = 6;
+
+
+    pub fn get_top_n(&self) -> &TopNRule {
+        match self.rule {
+            ::std::option::Option::Some(ConditionalFormat_oneof_rule::top_n(ref v)) => v,
+            _ => <TopNRule as ::protobuf::Message>::default_instance(),
+        }
+    }
+    pub fn clear_top_n(&mut self) {
+        self.rule = ::std::option::Option::None;
+    }
+
+    pub fn has_top_n(&self) -> bool {
+        match self.rule {
+            ::std::option::Option::Some(ConditionalFormat_oneof_rule::top_n(..)) => true,
+            _ => false,
+        }
+    }
+
+    // Param is passed by value, moved
+    pub fn set_top_n(&mut self, v: TopNRule) {
+        self.rule = ::std::option::Option::Some(ConditionalFormat_oneof_rule::top_n(v))
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_top_n(&mut self) -> &mut TopNRule {
+        if let ::std::option::Option::Some(ConditionalFormat_oneof_rule::top_n(_)) = self.rule {
+        } else {
+            self.rule = ::std::option::Option::Some(ConditionalFormat_oneof_rule::top_n(TopNRule::new()));
+        }
+        match self.rule {
+            ::std::option::Option::Some(ConditionalFormat_oneof_rule::top_n(ref mut v)) => v,
+            _ => panic!(),
+        }
+    }
+
+    // Take field
+    pub fn take_top_n(&mut self) -> TopNRule {
+        if self.has_top_n() {
+            match self.rule.take() {
+                ::std::option::Option::Some(ConditionalFormat_oneof_rule::top_n(v)) => v,
+                _ => panic!(),
+            }
+        } else {
+            TopNRule::new()
+        }
+    }
+}
+
+impl ::protobuf::Message for ConditionalFormat {
+    fn is_initialized(&self) -> bool {
+        for v in &self.range {
+            if !v.is_initialized() {
+                return false;
+            }
+        };
+        if let Some(ConditionalFormat_oneof_rule::cell_value(ref v)) = self.rule {
+            if !v.is_initialized() {
+                return false;
+            }
+        }
+        if let Some(ConditionalFormat_oneof_rule::formula(ref v)) = self.rule {
+            if !v.is_initialized() {
+                return false;
+            }
+        }
+        if let Some(ConditionalFormat_oneof_rule::color_scale(ref v)) = self.rule {
+            if !v.is_initialized() {
+                return false;
+            }
+        }
+        if let Some(ConditionalFormat_oneof_rule::data_bar(ref v)) = self.rule {
+            if !v.is_initialized() {
+                return false;
+            }
+        }
+        if let Some(ConditionalFormat_oneof_rule::top_n(ref v)) = self.rule {
+            if !v.is_initialized() {
+                return false;
+            }
+        }
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_singular_message_into(wire_type, is, &mut self.range)?;
+                },
+                2 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeLengthDelimited {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    self.rule = ::std::option::Option::Some(ConditionalFormat_oneof_rule::cell_value(is.read_message()?));
+                },
+                3 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeLengthDelimited {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    self.rule = ::std::option::Option::Some(ConditionalFormat_oneof_rule::formula(is.read_message()?));
+                },
+                4 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeLengthDelimited {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    self.rule = ::std::option::Option::Some(ConditionalFormat_oneof_rule::color_scale(is.read_message()?));
+                },
+                5 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeLengthDelimited {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    self.rule = ::std::option::Option::Some(ConditionalFormat_oneof_rule::data_bar(is.read_message()?));
+                },
+                6 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeLengthDelimited {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    self.rule = ::std::option::Option::Some(ConditionalFormat_oneof_rule::top_n(is.read_message()?));
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if let Some(ref v) = self.range.as_ref() {
+            let len = v.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        }
+        if let ::std::option::Option::Some(ref v) = self.rule {
+            match v {
+                &ConditionalFormat_oneof_rule::cell_value(ref v) => {
+                    let len = v.compute_size();
+                    my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+                },
+                &ConditionalFormat_oneof_rule::formula(ref v) => {
+                    let len = v.compute_size();
+                    my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+                },
+                &ConditionalFormat_oneof_rule::color_scale(ref v) => {
+                    let len = v.compute_size();
+                    my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+                },
+                &ConditionalFormat_oneof_rule::data_bar(ref v) => {
+                    let len = v.compute_size();
+                    my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+                },
+                &ConditionalFormat_oneof_rule::top_n(ref v) => {
+                    let len = v.compute_size();
+                    my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+                },
+            };
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        if let Some(ref v) = self.range.as_ref() {
+            os.write_tag(1, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+            os.write_raw_varint32(v.get_cached_size())?;
+            v.write_to_with_cached_sizes(os)?;
+        }
+        if let ::std::option::Option::Some(ref v) = self.rule {
+            match v {
+                &ConditionalFormat_oneof_rule::cell_value(ref v) => {
+                    os.write_tag(2, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+                    os.write_raw_varint32(v.get_cached_size())?;
+                    v.write_to_with_cached_sizes(os)?;
+                },
+                &ConditionalFormat_oneof_rule::formula(ref v) => {
+                    os.write_tag(3, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+                    os.write_raw_varint32(v.get_cached_size())?;
+                    v.write_to_with_cached_sizes(os)?;
+                },
+                &ConditionalFormat_oneof_rule::color_scale(ref v) => {
+                    os.write_tag(4, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+                    os.write_raw_varint32(v.get_cached_size())?;
+                    v.write_to_with_cached_sizes(os)?;
+                },
+                &ConditionalFormat_oneof_rule::data_bar(ref v) => {
+                    os.write_tag(5, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+                    os.write_raw_varint32(v.get_cached_size())?;
+                    v.write_to_with_cached_sizes(os)?;
+                },
+                &ConditionalFormat_oneof_rule::top_n(ref v) => {
+                    os.write_tag(6, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+                    os.write_raw_varint32(v.get_cached_size())?;
+                    v.write_to_with_cached_sizes(os)?;
+                },
+            };
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> ConditionalFormat {
+        ConditionalFormat::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            let mut fields = ::std::vec::Vec::new();
+            fields.push(::protobuf::reflect::accessor::make_singular_ptr_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<Rect>>(
+                "range",
+                |m: &ConditionalFormat| { &m.range },
+                |m: &mut ConditionalFormat| { &mut m.range },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_singular_message_accessor::<_, CellValueRule>(
+                "cell_value",
+                ConditionalFormat::has_cell_value,
+                ConditionalFormat::get_cell_value,
+            ));
+            fields.push(::protobuf::reflect::accessor::make_singular_message_accessor::<_, FormulaRule>(
+                "formula",
+                ConditionalFormat::has_formula,
+                ConditionalFormat::get_formula,
+            ));
+            fields.push(::protobuf::reflect::accessor::make_singular_message_accessor::<_, ColorScaleRule>(
+                "color_scale",
+                ConditionalFormat::has_color_scale,
+                ConditionalFormat::get_color_scale,
+            ));
+            fields.push(::protobuf::reflect::accessor::make_singular_message_accessor::<_, DataBarRule>(
+                "data_bar",
+                ConditionalFormat::has_data_bar,
+                ConditionalFormat::get_data_bar,
+            ));
+            fields.push(::protobuf::reflect::accessor::make_singular_message_accessor::<_, TopNRule>(
+                "top_n",
+                ConditionalFormat::has_top_n,
+                ConditionalFormat::get_top_n,
+            ));
+            ::protobuf::reflect::MessageDescriptor::new_pb_name::<ConditionalFormat>(
+                "ConditionalFormat",
+                fields,
+                file_descriptor_proto()
+            )
+        })
+    }
+
+    fn default_instance() -> &'static ConditionalFormat {
+        static instance: ::protobuf::rt::LazyV2<ConditionalFormat> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(ConditionalFormat::new)
+    }
+}
+
+impl ::protobuf::Clear for ConditionalFormat {
+    fn clear(&mut self) {
+        self.range.clear();
+        self.rule = ::std::option::Option::None;
+        self.rule = ::std::option::Option::None;
+        self.rule = ::std::option::Option::None;
+        self.rule = ::std::option::Option::None;
+        self.rule = ::std::option::Option::None;
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for ConditionalFormat {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for ConditionalFormat {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct CellValueRule {
+    // message fields
+    pub comparison: Comparison,
+    pub values: ::protobuf::RepeatedField<::std::string::String>,
+    pub style: ::protobuf::SingularPtrField<Style>,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a CellValueRule {
+    fn default() -> &'a CellValueRule {
+        <CellValueRule as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl CellValueRule {
+    pub fn new() -> CellValueRule {
+        ::std::default::Default::default()
+    }
+
+    // .spreadsheet.Comparison comparison = 1;
+
+
+    pub fn get_comparison(&self) -> Comparison {
+        self.comparison
+    }
+    pub fn clear_comparison(&mut self) {
+        self.comparison = Comparison::COMPARISON_EQUAL;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_comparison(&mut self, v: Comparison) {
+        self.comparison = v;
+    }
+
+    // repeated string values = 2;
+
+
+    pub fn get_values(&self) -> &[::std::string::String] {
+        &self.values
+    }
+    pub fn clear_values(&mut self) {
+        self.values.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_values(&mut self, v: ::protobuf::RepeatedField<::std::string::String>) {
+        self.values = v;
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_values(&mut self) -> &mut ::protobuf::RepeatedField<::std::string::String> {
+        &mut self.values
+    }
+
+    // Take field
+    pub fn take_values(&mut self) -> ::protobuf::RepeatedField<::std::string::String> {
+        ::std::mem::replace(&mut self.values, ::protobuf::RepeatedField::new())
+    }
+
+    // .spreadsheet.Style style = 3;
+
+
+    pub fn get_style(&self) -> &Style {
+        self.style.as_ref().unwrap_or_else(|| <Style as ::protobuf::Message>::default_instance())
+    }
+    pub fn clear_style(&mut self) {
+        self.style.clear();
+    }
+
+    pub fn has_style(&self) -> bool {
+        self.style.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_style(&mut self, v: Style) {
+        self.style = ::protobuf::SingularPtrField::some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_style(&mut self) -> &mut Style {
+        if self.style.is_none() {
+            self.style.set_default();
+        }
+        self.style.as_mut().unwrap()
+    }
+
+    // Take field
+    pub fn take_style(&mut self) -> Style {
+        self.style.take().unwrap_or_else(|| Style::new())
+    }
+}
+
+impl ::protobuf::Message for CellValueRule {
+    fn is_initialized(&self) -> bool {
+        for v in &self.style {
+            if !v.is_initialized() {
+                return false;
+            }
+        };
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_proto3_enum_with_unknown_fields_into(wire_type, is, &mut self.comparison, 1, &mut self.unknown_fields)?
+                },
+                2 => {
+                    ::protobuf::rt::read_repeated_string_into(wire_type, is, &mut self.values)?;
+                },
+                3 => {
+                    ::protobuf::rt::read_singular_message_into(wire_type, is, &mut self.style)?;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if self.comparison != Comparison::COMPARISON_EQUAL {
+            my_size += ::protobuf::rt::enum_size(1, self.comparison);
+        }
+        for value in &self.values {
+            my_size += ::protobuf::rt::string_size(2, &value);
+        };
+        if let Some(ref v) = self.style.as_ref() {
+            let len = v.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        if self.comparison != Comparison::COMPARISON_EQUAL {
+            os.write_enum(1, ::protobuf::ProtobufEnum::value(&self.comparison))?;
+        }
+        for v in &self.values {
+            os.write_string(2, &v)?;
+        };
+        if let Some(ref v) = self.style.as_ref() {
+            os.write_tag(3, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+            os.write_raw_varint32(v.get_cached_size())?;
+            v.write_to_with_cached_sizes(os)?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> CellValueRule {
+        CellValueRule::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            let mut fields = ::std::vec::Vec::new();
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeEnum<Comparison>>(
+                "comparison",
+                |m: &CellValueRule| { &m.comparison },
+                |m: &mut CellValueRule| { &mut m.comparison },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_repeated_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "values",
+                |m: &CellValueRule| { &m.values },
+                |m: &mut CellValueRule| { &mut m.values },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_singular_ptr_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<Style>>(
+                "style",
+                |m: &CellValueRule| { &m.style },
+                |m: &mut CellValueRule| { &mut m.style },
+            ));
+            ::protobuf::reflect::MessageDescriptor::new_pb_name::<CellValueRule>(
+                "CellValueRule",
+                fields,
+                file_descriptor_proto()
+            )
+        })
+    }
+
+    fn default_instance() -> &'static CellValueRule {
+        static instance: ::protobuf::rt::LazyV2<CellValueRule> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(CellValueRule::new)
+    }
+}
+
+impl ::protobuf::Clear for CellValueRule {
+    fn clear(&mut self) {
+        self.comparison = Comparison::COMPARISON_EQUAL;
+        self.values.clear();
+        self.style.clear();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for CellValueRule {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for CellValueRule {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct FormulaRule {
+    // message fields
+    pub formula: ::std::string::String,
+    pub style: ::protobuf::SingularPtrField<Style>,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a FormulaRule {
+    fn default() -> &'a FormulaRule {
+        <FormulaRule as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl FormulaRule {
+    pub fn new() -> FormulaRule {
+        ::std::default::Default::default()
+    }
+
+    // string formula = 1;
+
+
+    pub fn get_formula(&self) -> &str {
+        &self.formula
+    }
+    pub fn clear_formula(&mut self) {
+        self.formula.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_formula(&mut self, v: ::std::string::String) {
+        self.formula = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_formula(&mut self) -> &mut ::std::string::String {
+        &mut self.formula
+    }
+
+    // Take field
+    pub fn take_formula(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.formula, ::std::string::String::new())
+    }
+
+    // .spreadsheet.Style style = 2;
+
+
+    pub fn get_style(&self) -> &Style {
+        self.style.as_ref().unwrap_or_else(|| <Style as ::protobuf::Message>::default_instance())
+    }
+    pub fn clear_style(&mut self) {
+        self.style.clear();
+    }
+
+    pub fn has_style(&self) -> bool {
+        self.style.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_style(&mut self, v: Style) {
+        self.style = ::protobuf::SingularPtrField::some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_style(&mut self) -> &mut Style {
+        if self.style.is_none() {
+            self.style.set_default();
+        }
+        self.style.as_mut().unwrap()
+    }
+
+    // Take field
+    pub fn take_style(&mut self) -> Style {
+        self.style.take().unwrap_or_else(|| Style::new())
+    }
+}
+
+impl ::protobuf::Message for FormulaRule {
+    fn is_initialized(&self) -> bool {
+        for v in &self.style {
+            if !v.is_initialized() {
+                return false;
+            }
+        };
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.formula)?;
+                },
+                2 => {
+                    ::protobuf::rt::read_singular_message_into(wire_type, is, &mut self.style)?;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if !self.formula.is_empty() {
+            my_size += ::protobuf::rt::string_size(1, &self.formula);
+        }
+        if let Some(ref v) = self.style.as_ref() {
+            let len = v.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        if !self.formula.is_empty() {
+            os.write_string(1, &self.formula)?;
+        }
+        if let Some(ref v) = self.style.as_ref() {
+            os.write_tag(2, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+            os.write_raw_varint32(v.get_cached_size())?;
+            v.write_to_with_cached_sizes(os)?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> FormulaRule {
+        FormulaRule::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            let mut fields = ::std::vec::Vec::new();
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "formula",
+                |m: &FormulaRule| { &m.formula },
+                |m: &mut FormulaRule| { &mut m.formula },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_singular_ptr_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<Style>>(
+                "style",
+                |m: &FormulaRule| { &m.style },
+                |m: &mut FormulaRule| { &mut m.style },
+            ));
+            ::protobuf::reflect::MessageDescriptor::new_pb_name::<FormulaRule>(
+                "FormulaRule",
+                fields,
+                file_descriptor_proto()
+            )
+        })
+    }
+
+    fn default_instance() -> &'static FormulaRule {
+        static instance: ::protobuf::rt::LazyV2<FormulaRule> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(FormulaRule::new)
+    }
+}
+
+impl ::protobuf::Clear for FormulaRule {
+    fn clear(&mut self) {
+        self.formula.clear();
+        self.style.clear();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for FormulaRule {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for FormulaRule {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct ColorScaleRule {
+    // message fields
+    pub min_color: ::std::string::String,
+    pub mid_color: ::std::string::String,
+    pub max_color: ::std::string::String,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a ColorScaleRule {
+    fn default() -> &'a ColorScaleRule {
+        <ColorScaleRule as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl ColorScaleRule {
+    pub fn new() -> ColorScaleRule {
+        ::std::default::Default::default()
+    }
+
+    // string min_color = 1;
+
+
+    pub fn get_min_color(&self) -> &str {
+        &self.min_color
+    }
+    pub fn clear_min_color(&mut self) {
+        self.min_color.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_min_color(&mut self, v: ::std::string::String) {
+        self.min_color = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_min_color(&mut self) -> &mut ::std::string::String {
+        &mut self.min_color
+    }
+
+    // Take field
+    pub fn take_min_color(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.min_color, ::std::string::String::new())
+    }
+
+    // string mid_color = 2;
+
+
+    pub fn get_mid_color(&self) -> &str {
+        &self.mid_color
+    }
+    pub fn clear_mid_color(&mut self) {
+        self.mid_color.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_mid_color(&mut self, v: ::std::string::String) {
+        self.mid_color = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_mid_color(&mut self) -> &mut ::std::string::String {
+        &mut self.mid_color
+    }
+
+    // Take field
+    pub fn take_mid_color(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.mid_color, ::std::string::String::new())
+    }
+
+    // string max_color = 3;
+
+
+    pub fn get_max_color(&self) -> &str {
+        &self.max_color
+    }
+    pub fn clear_max_color(&mut self) {
+        self.max_color.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_max_color(&mut self, v: ::std::string::String) {
+        self.max_color = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_max_color(&mut self) -> &mut ::std::string::String {
+        &mut self.max_color
+    }
+
+    // Take field
+    pub fn take_max_color(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.max_color, ::std::string::String::new())
+    }
+}
+
+impl ::protobuf::Message for ColorScaleRule {
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.min_color)?;
+                },
+                2 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.mid_color)?;
+                },
+                3 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.max_color)?;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if !self.min_color.is_empty() {
+            my_size += ::protobuf::rt::string_size(1, &self.min_color);
+        }
+        if !self.mid_color.is_empty() {
+            my_size += ::protobuf::rt::string_size(2, &self.mid_color);
+        }
+        if !self.max_color.is_empty() {
+            my_size += ::protobuf::rt::string_size(3, &self.max_color);
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        if !self.min_color.is_empty() {
+            os.write_string(1, &self.min_color)?;
+        }
+        if !self.mid_color.is_empty() {
+            os.write_string(2, &self.mid_color)?;
+        }
+        if !self.max_color.is_empty() {
+            os.write_string(3, &self.max_color)?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> ColorScaleRule {
+        ColorScaleRule::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            let mut fields = ::std::vec::Vec::new();
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "min_color",
+                |m: &ColorScaleRule| { &m.min_color },
+                |m: &mut ColorScaleRule| { &mut m.min_color },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "mid_color",
+                |m: &ColorScaleRule| { &m.mid_color },
+                |m: &mut ColorScaleRule| { &mut m.mid_color },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "max_color",
+                |m: &ColorScaleRule| { &m.max_color },
+                |m: &mut ColorScaleRule| { &mut m.max_color },
+            ));
+            ::protobuf::reflect::MessageDescriptor::new_pb_name::<ColorScaleRule>(
+                "ColorScaleRule",
+                fields,
+                file_descriptor_proto()
+            )
+        })
+    }
+
+    fn default_instance() -> &'static ColorScaleRule {
+        static instance: ::protobuf::rt::LazyV2<ColorScaleRule> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(ColorScaleRule::new)
+    }
+}
+
+impl ::protobuf::Clear for ColorScaleRule {
+    fn clear(&mut self) {
+        self.min_color.clear();
+        self.mid_color.clear();
+        self.max_color.clear();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for ColorScaleRule {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for ColorScaleRule {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct DataBarRule {
+    // message fields
+    pub color: ::std::string::String,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a DataBarRule {
+    fn default() -> &'a DataBarRule {
+        <DataBarRule as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl DataBarRule {
+    pub fn new() -> DataBarRule {
+        ::std::default::Default::default()
+    }
+
+    // string color = 1;
+
+
+    pub fn get_color(&self) -> &str {
+        &self.color
+    }
+    pub fn clear_color(&mut self) {
+        self.color.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_color(&mut self, v: ::std::string::String) {
+        self.color = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_color(&mut self) -> &mut ::std::string::String {
+        &mut self.color
+    }
+
+    // Take field
+    pub fn take_color(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.color, ::std::string::String::new())
+    }
+}
+
+impl ::protobuf::Message for DataBarRule {
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.color)?;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if !self.color.is_empty() {
+            my_size += ::protobuf::rt::string_size(1, &self.color);
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        if !self.color.is_empty() {
+            os.write_string(1, &self.color)?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> DataBarRule {
+        DataBarRule::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            let mut fields = ::std::vec::Vec::new();
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "color",
+                |m: &DataBarRule| { &m.color },
+                |m: &mut DataBarRule| { &mut m.color },
+            ));
+            ::protobuf::reflect::MessageDescriptor::new_pb_name::<DataBarRule>(
+                "DataBarRule",
+                fields,
+                file_descriptor_proto()
+            )
+        })
+    }
+
+    fn default_instance() -> &'static DataBarRule {
+        static instance: ::protobuf::rt::LazyV2<DataBarRule> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(DataBarRule::new)
+    }
+}
+
+impl ::protobuf::Clear for DataBarRule {
+    fn clear(&mut self) {
+        self.color.clear();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for DataBarRule {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for DataBarRule {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct TopNRule {
+    // message fields
+    pub n: u32,
+    pub bottom: bool,
+    pub percent: bool,
+    pub style: ::protobuf::SingularPtrField<Style>,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a TopNRule {
+    fn default() -> &'a TopNRule {
+        <TopNRule as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl TopNRule {
+    pub fn new() -> TopNRule {
+        ::std::default::Default::default()
+    }
+
+    // uint32 n = 1;
+
+
+    pub fn get_n(&self) -> u32 {
+        self.n
+    }
+    pub fn clear_n(&mut self) {
+        self.n = 0;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_n(&mut self, v: u32) {
+        self.n = v;
+    }
+
+    // bool bottom = 2;
+
+
+    pub fn get_bottom(&self) -> bool {
+        self.bottom
+    }
+    pub fn clear_bottom(&mut self) {
+        self.bottom = false;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_bottom(&mut self, v: bool) {
+        self.bottom = v;
+    }
+
+    // bool percent = 3;
+
+
+    pub fn get_percent(&self) -> bool {
+        self.percent
+    }
+    pub fn clear_percent(&mut self) {
+        self.percent = false;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_percent(&mut self, v: bool) {
+        self.percent = v;
+    }
+
+    // .spreadsheet.Style style = 4;
+
+
+    pub fn get_style(&self) -> &Style {
+        self.style.as_ref().unwrap_or_else(|| <Style as ::protobuf::Message>::default_instance())
+    }
+    pub fn clear_style(&mut self) {
+        self.style.clear();
+    }
+
+    pub fn has_style(&self) -> bool {
+        self.style.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_style(&mut self, v: Style) {
+        self.style = ::protobuf::SingularPtrField::some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_style(&mut self) -> &mut Style {
+        if self.style.is_none() {
+            self.style.set_default();
+        }
+        self.style.as_mut().unwrap()
+    }
+
+    // Take field
+    pub fn take_style(&mut self) -> Style {
+        self.style.take().unwrap_or_else(|| Style::new())
+    }
+}
+
+impl ::protobuf::Message for TopNRule {
+    fn is_initialized(&self) -> bool {
+        for v in &self.style {
+            if !v.is_initialized() {
+                return false;
+            }
+        };
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_uint32()?;
+                    self.n = tmp;
+                },
+                2 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_bool()?;
+                    self.bottom = tmp;
+                },
+                3 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_bool()?;
+                    self.percent = tmp;
+                },
+                4 => {
+                    ::protobuf::rt::read_singular_message_into(wire_type, is, &mut self.style)?;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if self.n != 0 {
+            my_size += ::protobuf::rt::value_size(1, self.n, ::protobuf::wire_format::WireTypeVarint);
+        }
+        if self.bottom != false {
+            my_size += 2;
+        }
+        if self.percent != false {
+            my_size += 2;
+        }
+        if let Some(ref v) = self.style.as_ref() {
+            let len = v.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        if self.n != 0 {
+            os.write_uint32(1, self.n)?;
+        }
+        if self.bottom != false {
+            os.write_bool(2, self.bottom)?;
+        }
+        if self.percent != false {
+            os.write_bool(3, self.percent)?;
+        }
+        if let Some(ref v) = self.style.as_ref() {
+            os.write_tag(4, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+            os.write_raw_varint32(v.get_cached_size())?;
+            v.write_to_with_cached_sizes(os)?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> TopNRule {
+        TopNRule::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            let mut fields = ::std::vec::Vec::new();
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeUint32>(
+                "n",
+                |m: &TopNRule| { &m.n },
+                |m: &mut TopNRule| { &mut m.n },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeBool>(
+                "bottom",
+                |m: &TopNRule| { &m.bottom },
+                |m: &mut TopNRule| { &mut m.bottom },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeBool>(
+                "percent",
+                |m: &TopNRule| { &m.percent },
+                |m: &mut TopNRule| { &mut m.percent },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_singular_ptr_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<Style>>(
+                "style",
+                |m: &TopNRule| { &m.style },
+                |m: &mut TopNRule| { &mut m.style },
+            ));
+            ::protobuf::reflect::MessageDescriptor::new_pb_name::<TopNRule>(
+                "TopNRule",
+                fields,
+                file_descriptor_proto()
+            )
+        })
+    }
+
+    fn default_instance() -> &'static TopNRule {
+        static instance: ::protobuf::rt::LazyV2<TopNRule> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(TopNRule::new)
+    }
+}
+
+impl ::protobuf::Clear for TopNRule {
+    fn clear(&mut self) {
+        self.n = 0;
+        self.bottom = false;
+        self.percent = false;
+        self.style.clear();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for TopNRule {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for TopNRule {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct SetConditionalFormatsRequest {
+    // message fields
+    pub tableId: ::std::string::String,
+    pub formats: ::protobuf::RepeatedField<ConditionalFormat>,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a SetConditionalFormatsRequest {
+    fn default() -> &'a SetConditionalFormatsRequest {
+        <SetConditionalFormatsRequest as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl SetConditionalFormatsRequest {
+    pub fn new() -> SetConditionalFormatsRequest {
+        ::std::default::Default::default()
+    }
+
+    // string tableId = 1;
+
+
+    pub fn get_tableId(&self) -> &str {
+        &self.tableId
+    }
+    pub fn clear_tableId(&mut self) {
+        self.tableId.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_tableId(&mut self, v: ::std::string::String) {
+        self.tableId = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_tableId(&mut self) -> &mut ::std::string::String {
+        &mut self.tableId
+    }
+
+    // Take field
+    pub fn take_tableId(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.tableId, ::std::string::String::new())
+    }
+
+    // repeated .spreadsheet.ConditionalFormat formats = 2;
+
+
+    pub fn get_formats(&self) -> &[ConditionalFormat] {
+        &self.formats
+    }
+    pub fn clear_formats(&mut self) {
+        self.formats.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_formats(&mut self, v: ::protobuf::RepeatedField<ConditionalFormat>) {
+        self.formats = v;
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_formats(&mut self) -> &mut ::protobuf::RepeatedField<ConditionalFormat> {
+        &mut self.formats
+    }
+
+    // Take field
+    pub fn take_formats(&mut self) -> ::protobuf::RepeatedField<ConditionalFormat> {
+        ::std::mem::replace(&mut self.formats, ::protobuf::RepeatedField::new())
+    }
+}
+
+impl ::protobuf::Message for SetConditionalFormatsRequest {
+    fn is_initialized(&self) -> bool {
+        for v in &self.formats {
+            if !v.is_initialized() {
+                return false;
+            }
+        };
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.tableId)?;
+                },
+                2 => {
+                    ::protobuf::rt::read_repeated_message_into(wire_type, is, &mut self.formats)?;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if !self.tableId.is_empty() {
+            my_size += ::protobuf::rt::string_size(1, &self.tableId);
+        }
+        for value in &self.formats {
+            let len = value.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        };
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        if !self.tableId.is_empty() {
+            os.write_string(1, &self.tableId)?;
+        }
+        for v in &self.formats {
+            os.write_tag(2, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+            os.write_raw_varint32(v.get_cached_size())?;
+            v.write_to_with_cached_sizes(os)?;
+        };
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> SetConditionalFormatsRequest {
+        SetConditionalFormatsRequest::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            let mut fields = ::std::vec::Vec::new();
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "tableId",
+                |m: &SetConditionalFormatsRequest| { &m.tableId },
+                |m: &mut SetConditionalFormatsRequest| { &mut m.tableId },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_repeated_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<ConditionalFormat>>(
+                "formats",
+                |m: &SetConditionalFormatsRequest| { &m.formats },
+                |m: &mut SetConditionalFormatsRequest| { &mut m.formats },
+            ));
+            ::protobuf::reflect::MessageDescriptor::new_pb_name::<SetConditionalFormatsRequest>(
+                "SetConditionalFormatsRequest",
+                fields,
+                file_descriptor_proto()
+            )
+        })
+    }
+
+    fn default_instance() -> &'static SetConditionalFormatsRequest {
+        static instance: ::protobuf::rt::LazyV2<SetConditionalFormatsRequest> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(SetConditionalFormatsRequest::new)
+    }
+}
+
+impl ::protobuf::Clear for SetConditionalFormatsRequest {
+    fn clear(&mut self) {
+        self.tableId.clear();
+        self.formats.clear();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for SetConditionalFormatsRequest {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for SetConditionalFormatsRequest {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct SetConditionalFormatsResponse {
+    // message fields
+    pub cells: ::protobuf::RepeatedField<Cell>,
+    pub styles: ::std::collections::HashMap<u32, Style>,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a SetConditionalFormatsResponse {
+    fn default() -> &'a SetConditionalFormatsResponse {
+        <SetConditionalFormatsResponse as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl SetConditionalFormatsResponse {
+    pub fn new() -> SetConditionalFormatsResponse {
+        ::std::default::Default::default()
+    }
+
+    // repeated .spreadsheet.Cell cells = 1;
+
+
+    pub fn get_cells(&self) -> &[Cell] {
+        &self.cells
+    }
+    pub fn clear_cells(&mut self) {
+        self.cells.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_cells(&mut self, v: ::protobuf::RepeatedField<Cell>) {
+        self.cells = v;
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_cells(&mut self) -> &mut ::protobuf::RepeatedField<Cell> {
+        &mut self.cells
+    }
+
+    // Take field
+    pub fn take_cells(&mut self) -> ::protobuf::RepeatedField<Cell> {
+        ::std::mem::replace(&mut self.cells, ::protobuf::RepeatedField::new())
+    }
+
+    // repeated .spreadsheet.SetConditionalFormatsResponse.StylesEntry styles = 2;
+
+
+    pub fn get_styles(&self) -> &::std::collections::HashMap<u32, Style> {
+        &self.styles
+    }
+    pub fn clear_styles(&mut self) {
+        self.styles.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_styles(&mut self, v: ::std::collections::HashMap<u32, Style>) {
+        self.styles = v;
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_styles(&mut self) -> &mut ::std::collections::HashMap<u32, Style> {
+        &mut self.styles
+    }
+
+    // Take field
+    pub fn take_styles(&mut self) -> ::std::collections::HashMap<u32, Style> {
+        ::std::mem::replace(&mut self.styles, ::std::collections::HashMap::new())
+    }
+}
+
+impl ::protobuf::Message for SetConditionalFormatsResponse {
+    fn is_initialized(&self) -> bool {
+        for v in &self.cells {
+            if !v.is_initialized() {
+                return false;
+            }
+        };
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_repeated_message_into(wire_type, is, &mut self.cells)?;
+                },
+                2 => {
+                    ::protobuf::rt::read_map_into::<::protobuf::types::ProtobufTypeUint32, ::protobuf::types::ProtobufTypeMessage<Style>>(wire_type, is, &mut self.styles)?;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        for value in &self.cells {
+            let len = value.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        };
+        my_size += ::protobuf::rt::compute_map_size::<::protobuf::types::ProtobufTypeUint32, ::protobuf::types::ProtobufTypeMessage<Style>>(2, &self.styles);
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        for v in &self.cells {
+            os.write_tag(1, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+            os.write_raw_varint32(v.get_cached_size())?;
+            v.write_to_with_cached_sizes(os)?;
+        };
+        ::protobuf::rt::write_map_with_cached_sizes::<::protobuf::types::ProtobufTypeUint32, ::protobuf::types::ProtobufTypeMessage<Style>>(2, &self.styles, os)?;
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> SetConditionalFormatsResponse {
+        SetConditionalFormatsResponse::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            let mut fields = ::std::vec::Vec::new();
+            fields.push(::protobuf::reflect::accessor::make_repeated_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<Cell>>(
+                "cells",
+                |m: &SetConditionalFormatsResponse| { &m.cells },
+                |m: &mut SetConditionalFormatsResponse| { &mut m.cells },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_map_accessor::<_, ::protobuf::types::ProtobufTypeUint32, ::protobuf::types::ProtobufTypeMessage<Style>>(
+                "styles",
+                |m: &SetConditionalFormatsResponse| { &m.styles },
+                |m: &mut SetConditionalFormatsResponse| { &mut m.styles },
+            ));
+            ::protobuf::reflect::MessageDescriptor::new_pb_name::<SetConditionalFormatsResponse>(
+                "SetConditionalFormatsResponse",
+                fields,
+                file_descriptor_proto()
+            )
+        })
+    }
+
+    fn default_instance() -> &'static SetConditionalFormatsResponse {
+        static instance: ::protobuf::rt::LazyV2<SetConditionalFormatsResponse> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(SetConditionalFormatsResponse::new)
+    }
+}
+
+impl ::protobuf::Clear for SetConditionalFormatsResponse {
+    fn clear(&mut self) {
+        self.cells.clear();
+        self.styles.clear();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for SetConditionalFormatsResponse {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for SetConditionalFormatsResponse {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct GetConditionalFormatsRequest {
+    // message fields
+    pub tableId: ::std::string::String,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a GetConditionalFormatsRequest {
+    fn default() -> &'a GetConditionalFormatsRequest {
+        <GetConditionalFormatsRequest as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl GetConditionalFormatsRequest {
+    pub fn new() -> GetConditionalFormatsRequest {
+        ::std::default::Default::default()
+    }
+
+    // string tableId = 1;
+
+
+    pub fn get_tableId(&self) -> &str {
+        &self.tableId
+    }
+    pub fn clear_tableId(&mut self) {
+        self.tableId.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_tableId(&mut self, v: ::std::string::String) {
+        self.tableId = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_tableId(&mut self) -> &mut ::std::string::String {
+        &mut self.tableId
+    }
+
+    // Take field
+    pub fn take_tableId(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.tableId, ::std::string::String::new())
+    }
+}
+
+impl ::protobuf::Message for GetConditionalFormatsRequest {
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.tableId)?;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if !self.tableId.is_empty() {
+            my_size += ::protobuf::rt::string_size(1, &self.tableId);
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        if !self.tableId.is_empty() {
+            os.write_string(1, &self.tableId)?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> GetConditionalFormatsRequest {
+        GetConditionalFormatsRequest::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            let mut fields = ::std::vec::Vec::new();
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "tableId",
+                |m: &GetConditionalFormatsRequest| { &m.tableId },
+                |m: &mut GetConditionalFormatsRequest| { &mut m.tableId },
+            ));
+            ::protobuf::reflect::MessageDescriptor::new_pb_name::<GetConditionalFormatsRequest>(
+                "GetConditionalFormatsRequest",
+                fields,
+                file_descriptor_proto()
+            )
+        })
+    }
+
+    fn default_instance() -> &'static GetConditionalFormatsRequest {
+        static instance: ::protobuf::rt::LazyV2<GetConditionalFormatsRequest> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(GetConditionalFormatsRequest::new)
+    }
+}
+
+impl ::protobuf::Clear for GetConditionalFormatsRequest {
+    fn clear(&mut self) {
+        self.tableId.clear();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for GetConditionalFormatsRequest {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for GetConditionalFormatsRequest {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct GetConditionalFormatsResponse {
+    // message fields
+    pub formats: ::protobuf::RepeatedField<ConditionalFormat>,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a GetConditionalFormatsResponse {
+    fn default() -> &'a GetConditionalFormatsResponse {
+        <GetConditionalFormatsResponse as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl GetConditionalFormatsResponse {
+    pub fn new() -> GetConditionalFormatsResponse {
+        ::std::default::Default::default()
+    }
+
+    // repeated .spreadsheet.ConditionalFormat formats = 1;
+
+
+    pub fn get_formats(&self) -> &[ConditionalFormat] {
+        &self.formats
+    }
+    pub fn clear_formats(&mut self) {
+        self.formats.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_formats(&mut self, v: ::protobuf::RepeatedField<ConditionalFormat>) {
+        self.formats = v;
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_formats(&mut self) -> &mut ::protobuf::RepeatedField<ConditionalFormat> {
+        &mut self.formats
+    }
+
+    // Take field
+    pub fn take_formats(&mut self) -> ::protobuf::RepeatedField<ConditionalFormat> {
+        ::std::mem::replace(&mut self.formats, ::protobuf::RepeatedField::new())
+    }
+}
+
+impl ::protobuf::Message for GetConditionalFormatsResponse {
+    fn is_initialized(&self) -> bool {
+        for v in &self.formats {
+            if !v.is_initialized() {
+                return false;
+            }
+        };
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_repeated_message_into(wire_type, is, &mut self.formats)?;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        for value in &self.formats {
+            let len = value.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        };
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        for v in &self.formats {
+            os.write_tag(1, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+            os.write_raw_varint32(v.get_cached_size())?;
+            v.write_to_with_cached_sizes(os)?;
+        };
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> GetConditionalFormatsResponse {
+        GetConditionalFormatsResponse::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            let mut fields = ::std::vec::Vec::new();
+            fields.push(::protobuf::reflect::accessor::make_repeated_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<ConditionalFormat>>(
+                "formats",
+                |m: &GetConditionalFormatsResponse| { &m.formats },
+                |m: &mut GetConditionalFormatsResponse| { &mut m.formats },
+            ));
+            ::protobuf::reflect::MessageDescriptor::new_pb_name::<GetConditionalFormatsResponse>(
+                "GetConditionalFormatsResponse",
+                fields,
+                file_descriptor_proto()
+            )
+        })
+    }
+
+    fn default_instance() -> &'static GetConditionalFormatsResponse {
+        static instance: ::protobuf::rt::LazyV2<GetConditionalFormatsResponse> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(GetConditionalFormatsResponse::new)
+    }
+}
+
+impl ::protobuf::Clear for GetConditionalFormatsResponse {
+    fn clear(&mut self) {
+        self.formats.clear();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for GetConditionalFormatsResponse {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for GetConditionalFormatsResponse {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct ValidationRule {
+    // message fields
+    pub range: ::protobuf::SingularPtrField<Rect>,
+    pub mode: ValidationMode,
+    pub message: ::std::string::String,
+    // message oneof groups
+    pub criteria: ::std::option::Option<ValidationRule_oneof_criteria>,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a ValidationRule {
+    fn default() -> &'a ValidationRule {
+        <ValidationRule as ::protobuf::Message>::default_instance()
+    }
+}
+
+#[derive(Clone,PartialEq,Debug)]
+pub enum ValidationRule_oneof_criteria {
+    list(ListCriteria),
+    list_range(Rect),
+    number(ComparisonCriteria),
+    date(ComparisonCriteria),
+    text_length(ComparisonCriteria),
+    formula(::std::string::String),
+}
+
+impl ValidationRule {
+    pub fn new() -> ValidationRule {
+        ::std::default::Default::default()
+    }
+
+    // .spreadsheet.Rect range = 1;
+
+
+    pub fn get_range(&self) -> &Rect {
+        self.range.as_ref().unwrap_or_else(|| <Rect as ::protobuf::Message>::default_instance())
+    }
+    pub fn clear_range(&mut self) {
+        self.range.clear();
+    }
+
+    pub fn has_range(&self) -> bool {
+        self.range.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_range(&mut self, v: Rect) {
+        self.range = ::protobuf::SingularPtrField::some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_range(&mut self) -> &mut Rect {
+        if self.range.is_none() {
+            self.range.set_default();
+        }
+        self.range.as_mut().unwrap()
+    }
+
+    // Take field
+    pub fn take_range(&mut self) -> Rect {
+        self.range.take().unwrap_or_else(|| Rect::new())
+    }
+
+    // .spreadsheet.ListCriteria list = 2;
+
+
+    pub fn get_list(&self) -> &ListCriteria {
+        match self.criteria {
+            ::std::option::Option::Some(ValidationRule_oneof_criteria::list(ref v)) => v,
+            _ => <ListCriteria as ::protobuf::Message>::default_instance(),
+        }
+    }
+    pub fn clear_list(&mut self) {
+        self.criteria = ::std::option::Option::None;
+    }
+
+    pub fn has_list(&self) -> bool {
+        match self.criteria {
+            ::std::option::Option::Some(ValidationRule_oneof_criteria::list(..)) => true,
+            _ => false,
+        }
+    }
+
+    // Param is passed by value, moved
+    pub fn set_list(&mut self, v: ListCriteria) {
+        self.criteria = ::std::option::Option::Some(ValidationRule_oneof_criteria::list(v))
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_list(&mut self) -> &mut ListCriteria {
+        if let ::std::option::Option::Some(ValidationRule_oneof_criteria::list(_)) = self.criteria {
+        } else {
+            self.criteria = ::std::option::Option::Some(ValidationRule_oneof_criteria::list(ListCriteria::new()));
+        }
+        match self.criteria {
+            ::std::option::Option::Some(ValidationRule_oneof_criteria::list(ref mut v)) => v,
+            _ => panic!(),
+        }
+    }
+
+    // Take field
+    pub fn take_list(&mut self) -> ListCriteria {
+        if self.has_list() {
+            match self.criteria.take() {
+                ::std::option::Option::Some(ValidationRule_oneof_criteria::list(v)) => v,
+                _ => panic!(),
+            }
+        } else {
+            ListCriteria::new()
+        }
+    }
+
+    // .spreadsheet.Rect list_range = 3;
+
+
+    pub fn get_list_range(&self) -> &Rect {
+        match self.criteria {
+            ::std::option::Option::Some(ValidationRule_oneof_criteria::list_range(ref v)) => v,
+            _ => <Rect as ::protobuf::Message>::default_instance(),
+        }
+    }
+    pub fn clear_list_range(&mut self) {
+        self.criteria = ::std::option::Option::None;
+    }
+
+    pub fn has_list_range(&self) -> bool {
+        match self.criteria {
+            ::std::option::Option::Some(ValidationRule_oneof_criteria::list_range(..)) => true,
+            _ => false,
+        }
+    }
+
+    // Param is passed by value, moved
+    pub fn set_list_range(&mut self, v: Rect) {
+        self.criteria = ::std::option::Option::Some(ValidationRule_oneof_criteria::list_range(v))
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_list_range(&mut self) -> &mut Rect {
+        if let ::std::option::Option::Some(ValidationRule_oneof_criteria::list_range(_)) = self.criteria {
+        } else {
+            self.criteria = ::std::option::Option::Some(ValidationRule_oneof_criteria::list_range(Rect::new()));
+        }
+        match self.criteria {
+            ::std::option::Option::Some(ValidationRule_oneof_criteria::list_range(ref mut v)) => v,
+            _ => panic!(),
+        }
+    }
+
+    // Take field
+    pub fn take_list_range(&mut self) -> Rect {
+        if self.has_list_range() {
+            match self.criteria.take() {
+                ::std::option::Option::Some(ValidationRule_oneof_criteria::list_range(v)) => v,
+                _ => panic!(),
+            }
+        } else {
+            Rect::new()
+        }
+    }
+
+    // .spreadsheet.ComparisonCriteria number = 4;
+
+
+    pub fn get_number(&self) -> &ComparisonCriteria {
+        match self.criteria {
+            ::std::option::Option::Some(ValidationRule_oneof_criteria::number(ref v)) => v,
+            _ => <ComparisonCriteria as ::protobuf::Message>::default_instance(),
+        }
+    }
+    pub fn clear_number(&mut self) {
+        self.criteria = ::std::option::Option::None;
+    }
+
+    pub fn has_number(&self) -> bool {
+        match self.criteria {
+            ::std::option::Option::Some(ValidationRule_oneof_criteria::number(..)) => true,
+            _ => false,
+        }
+    }
+
+    // Param is passed by value, moved
+    pub fn set_number(&mut self, v: ComparisonCriteria) {
+        self.criteria = ::std::option::Option::Some(ValidationRule_oneof_criteria::number(v))
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_number(&mut self) -> &mut ComparisonCriteria {
+        if let ::std::option::Option::Some(ValidationRule_oneof_criteria::number(_)) = self.criteria {
+        } else {
+            self.criteria = ::std::option::Option::Some(ValidationRule_oneof_criteria::number(ComparisonCriteria::new()));
+        }
+        match self.criteria {
+            ::std::option::Option::Some(ValidationRule_oneof_criteria::number(ref mut v)) => v,
+            _ => panic!(),
+        }
+    }
+
+    // Take field
+    pub fn take_number(&mut self) -> ComparisonCriteria {
+        if self.has_number() {
+            match self.criteria.take() {
+                ::std::option::Option::Some(ValidationRule_oneof_criteria::number(v)) => v,
+                _ => panic!(),
+            }
+        } else {
+            ComparisonCriteria::new()
+        }
+    }
+
+    // .spreadsheet.ComparisonCriteria date = 5;
+
+
+    pub fn get_date(&self) -> &ComparisonCriteria {
+        match self.criteria {
+            ::std::option::Option::Some(ValidationRule_oneof_criteria::date(ref v)) => v,
+            _ => <ComparisonCriteria as ::protobuf::Message>::default_instance(),
+        }
+    }
+    pub fn clear_date(&mut self) {
+        self.criteria = ::std::option::Option::None;
+    }
+
+    pub fn has_date(&self) -> bool {
+        match self.criteria {
+            ::std::option::Option::Some(ValidationRule_oneof_criteria::date(..)) => true,
+            _ => false,
+        }
+    }
+
+    // Param is passed by value, moved
+    pub fn set_date(&mut self, v: ComparisonCriteria) {
+        self.criteria = ::std::option::Option::Some(ValidationRule_oneof_criteria::date(v))
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_date(&mut self) -> &mut ComparisonCriteria {
+        if let ::std::option::Option::Some(ValidationRule_oneof_criteria::date(_)) = self.criteria {
+        } else {
+            self.criteria = ::std::option::Option::Some(ValidationRule_oneof_criteria::date(ComparisonCriteria::new()));
+        }
+        match self.criteria {
+            ::std::option::Option::Some(ValidationRule_oneof_criteria::date(ref mut v)) => v,
+            _ => panic!(),
+        }
+    }
+
+    // Take field
+    pub fn take_date(&mut self) -> ComparisonCriteria {
+        if self.has_date() {
+            match self.criteria.take() {
+                ::std::option::Option::Some(ValidationRule_oneof_criteria::date(v)) => v,
+                _ => panic!(),
+            }
+        } else {
+            ComparisonCriteria::new()
+        }
+    }
+
+    // .spreadsheet.ComparisonCriteria text_length = 6;
+
+
+    pub fn get_text_length(&self) -> &ComparisonCriteria {
+        match self.criteria {
+            ::std::option::Option::Some(ValidationRule_oneof_criteria::text_length(ref v)) => v,
+            _ => <ComparisonCriteria as ::protobuf::Message>::default_instance(),
+        }
+    }
+    pub fn clear_text_length(&mut self) {
+        self.criteria = ::std::option::Option::None;
+    }
+
+    pub fn has_text_length(&self) -> bool {
+        match self.criteria {
+            ::std::option::Option::Some(ValidationRule_oneof_criteria::text_length(..)) => true,
+            _ => false,
+        }
+    }
+
+    // Param is passed by value, moved
+    pub fn set_text_length(&mut self, v: ComparisonCriteria) {
+        self.criteria = ::std::option::Option::Some(ValidationRule_oneof_criteria::text_length(v))
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_text_length(&mut self) -> &mut ComparisonCriteria {
+        if let ::std::option::Option::Some(ValidationRule_oneof_criteria::text_length(_)) = self.criteria {
+        } else {
+            self.criteria = ::std::option::Option::Some(ValidationRule_oneof_criteria::text_length(ComparisonCriteria::new()));
+        }
+        match self.criteria {
+            ::std::option::Option::Some(ValidationRule_oneof_criteria::text_length(ref mut v)) => v,
+            _ => panic!(),
+        }
+    }
+
+    // Take field
+    pub fn take_text_length(&mut self) -> ComparisonCriteria {
+        if self.has_text_length() {
+            match self.criteria.take() {
+                ::std::option::Option::Some(ValidationRule_oneof_criteria::text_length(v)) => v,
+                _ => panic!(),
+            }
+        } else {
+            ComparisonCriteria::new()
+        }
+    }
+
+    // string formula = 7;
+
+
+    pub fn get_formula(&self) -> &str {
+        match self.criteria {
+            ::std::option::Option::Some(ValidationRule_oneof_criteria::formula(ref v)) => v,
+            _ => "",
+        }
+    }
+    pub fn clear_formula(&mut self) {
+        self.criteria = ::std::option::Option::None;
+    }
+
+    pub fn has_formula(&self) -> bool {
+        match self.criteria {
+            ::std::option::Option::Some(ValidationRule_oneof_criteria::formula(..)) => true,
+            _ => false,
+        }
+    }
+
+    // Param is passed by value, moved
+    pub fn set_formula(&mut self, v: ::std::string::String) {
+        self.criteria = ::std::option::Option::Some(ValidationRule_oneof_criteria::formula(v))
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_formula(&mut self) -> &mut ::std::string::String {
+        if let ::std::option::Option::Some(ValidationRule_oneof_criteria::formula(_)) = self.criteria {
+        } else {
+            self.criteria = ::std::option::Option::Some(ValidationRule_oneof_criteria::formula(::std::string::String::new()));
+        }
+        match self.criteria {
+            ::std::option::Option::Some(ValidationRule_oneof_criteria::formula(ref mut v)) => v,
+            _ => panic!(),
+        }
+    }
+
+    // Take field
+    pub fn take_formula(&mut self) -> ::std::string::String {
+        if self.has_formula() {
+            match self.criteria.take() {
+                ::std::option::Option::Some(ValidationRule_oneof_criteria::formula(v)) => v,
+                _ => panic!(),
+            }
+        } else {
+            ::std::string::String::new()
+        }
+    }
+
+    // .spreadsheet.ValidationMode mode = 8;
+
+
+    pub fn get_mode(&self) -> ValidationMode {
+        self.mode
+    }
+    pub fn clear_mode(&mut self) {
+        self.mode = ValidationMode::VALIDATION_REJECT;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_mode(&mut self, v: ValidationMode) {
+        self.mode = v;
+    }
+
+    // string message = 9;
+
+
+    pub fn get_message(&self) -> &str {
+        &self.message
+    }
+    pub fn clear_message(&mut self) {
+        self.message.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_message(&mut self, v: ::std::string::String) {
+        self.message = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_message(&mut self) -> &mut ::std::string::String {
+        &mut self.message
+    }
+
+    // Take field
+    pub fn take_message(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.message, ::std::string::String::new())
+    }
+}
+
+impl ::protobuf::Message for ValidationRule {
+    fn is_initialized(&self) -> bool {
+        for v in &self.range {
+            if !v.is_initialized() {
+                return false;
+            }
+        };
+        if let Some(ValidationRule_oneof_criteria::list(ref v)) = self.criteria {
+            if !v.is_initialized() {
+                return false;
+            }
+        }
+        if let Some(ValidationRule_oneof_criteria::list_range(ref v)) = self.criteria {
+            if !v.is_initialized() {
+                return false;
+            }
+        }
+        if let Some(ValidationRule_oneof_criteria::number(ref v)) = self.criteria {
+            if !v.is_initialized() {
+                return false;
+            }
+        }
+        if let Some(ValidationRule_oneof_criteria::date(ref v)) = self.criteria {
+            if !v.is_initialized() {
+                return false;
+            }
+        }
+        if let Some(ValidationRule_oneof_criteria::text_length(ref v)) = self.criteria {
+            if !v.is_initialized() {
+                return false;
+            }
+        }
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_singular_message_into(wire_type, is, &mut self.range)?;
+                },
+                2 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeLengthDelimited {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    self.criteria = ::std::option::Option::Some(ValidationRule_oneof_criteria::list(is.read_message()?));
+                },
+                3 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeLengthDelimited {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    self.criteria = ::std::option::Option::Some(ValidationRule_oneof_criteria::list_range(is.read_message()?));
+                },
+                4 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeLengthDelimited {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    self.criteria = ::std::option::Option::Some(ValidationRule_oneof_criteria::number(is.read_message()?));
+                },
+                5 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeLengthDelimited {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    self.criteria = ::std::option::Option::Some(ValidationRule_oneof_criteria::date(is.read_message()?));
+                },
+                6 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeLengthDelimited {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    self.criteria = ::std::option::Option::Some(ValidationRule_oneof_criteria::text_length(is.read_message()?));
+                },
+                7 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeLengthDelimited {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    self.criteria = ::std::option::Option::Some(ValidationRule_oneof_criteria::formula(is.read_string()?));
+                },
+                8 => {
+                    ::protobuf::rt::read_proto3_enum_with_unknown_fields_into(wire_type, is, &mut self.mode, 8, &mut self.unknown_fields)?
+                },
+                9 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.message)?;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if let Some(ref v) = self.range.as_ref() {
+            let len = v.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        }
+        if self.mode != ValidationMode::VALIDATION_REJECT {
+            my_size += ::protobuf::rt::enum_size(8, self.mode);
+        }
+        if !self.message.is_empty() {
+            my_size += ::protobuf::rt::string_size(9, &self.message);
+        }
+        if let ::std::option::Option::Some(ref v) = self.criteria {
+            match v {
+                &ValidationRule_oneof_criteria::list(ref v) => {
+                    let len = v.compute_size();
+                    my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+                },
+                &ValidationRule_oneof_criteria::list_range(ref v) => {
+                    let len = v.compute_size();
+                    my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+                },
+                &ValidationRule_oneof_criteria::number(ref v) => {
+                    let len = v.compute_size();
+                    my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+                },
+                &ValidationRule_oneof_criteria::date(ref v) => {
+                    let len = v.compute_size();
+                    my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+                },
+                &ValidationRule_oneof_criteria::text_length(ref v) => {
+                    let len = v.compute_size();
+                    my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+                },
+                &ValidationRule_oneof_criteria::formula(ref v) => {
+                    my_size += ::protobuf::rt::string_size(7, &v);
+                },
+            };
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        if let Some(ref v) = self.range.as_ref() {
+            os.write_tag(1, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+            os.write_raw_varint32(v.get_cached_size())?;
+            v.write_to_with_cached_sizes(os)?;
+        }
+        if self.mode != ValidationMode::VALIDATION_REJECT {
+            os.write_enum(8, ::protobuf::ProtobufEnum::value(&self.mode))?;
+        }
+        if !self.message.is_empty() {
+            os.write_string(9, &self.message)?;
+        }
+        if let ::std::option::Option::Some(ref v) = self.criteria {
+            match v {
+                &ValidationRule_oneof_criteria::list(ref v) => {
+                    os.write_tag(2, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+                    os.write_raw_varint32(v.get_cached_size())?;
+                    v.write_to_with_cached_sizes(os)?;
+                },
+                &ValidationRule_oneof_criteria::list_range(ref v) => {
+                    os.write_tag(3, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+                    os.write_raw_varint32(v.get_cached_size())?;
+                    v.write_to_with_cached_sizes(os)?;
+                },
+                &ValidationRule_oneof_criteria::number(ref v) => {
+                    os.write_tag(4, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+                    os.write_raw_varint32(v.get_cached_size())?;
+                    v.write_to_with_cached_sizes(os)?;
+                },
+                &ValidationRule_oneof_criteria::date(ref v) => {
+                    os.write_tag(5, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+                    os.write_raw_varint32(v.get_cached_size())?;
+                    v.write_to_with_cached_sizes(os)?;
+                },
+                &ValidationRule_oneof_criteria::text_length(ref v) => {
+                    os.write_tag(6, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+                    os.write_raw_varint32(v.get_cached_size())?;
+                    v.write_to_with_cached_sizes(os)?;
+                },
+                &ValidationRule_oneof_criteria::formula(ref v) => {
+                    os.write_string(7, v)?;
+                },
+            };
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> ValidationRule {
+        ValidationRule::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            let mut fields = ::std::vec::Vec::new();
+            fields.push(::protobuf::reflect::accessor::make_singular_ptr_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<Rect>>(
+                "range",
+                |m: &ValidationRule| { &m.range },
+                |m: &mut ValidationRule| { &mut m.range },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_singular_message_accessor::<_, ListCriteria>(
+                "list",
+                ValidationRule::has_list,
+                ValidationRule::get_list,
+            ));
+            fields.push(::protobuf::reflect::accessor::make_singular_message_accessor::<_, Rect>(
+                "list_range",
+                ValidationRule::has_list_range,
+                ValidationRule::get_list_range,
+            ));
+            fields.push(::protobuf::reflect::accessor::make_singular_message_accessor::<_, ComparisonCriteria>(
+                "number",
+                ValidationRule::has_number,
+                ValidationRule::get_number,
+            ));
+            fields.push(::protobuf::reflect::accessor::make_singular_message_accessor::<_, ComparisonCriteria>(
+                "date",
+                ValidationRule::has_date,
+                ValidationRule::get_date,
+            ));
+            fields.push(::protobuf::reflect::accessor::make_singular_message_accessor::<_, ComparisonCriteria>(
+                "text_length",
+                ValidationRule::has_text_length,
+                ValidationRule::get_text_length,
+            ));
+            fields.push(::protobuf::reflect::accessor::make_singular_string_accessor::<_>(
+                "formula",
+                ValidationRule::has_formula,
+                ValidationRule::get_formula,
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeEnum<ValidationMode>>(
+                "mode",
+                |m: &ValidationRule| { &m.mode },
+                |m: &mut ValidationRule| { &mut m.mode },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "message",
+                |m: &ValidationRule| { &m.message },
+                |m: &mut ValidationRule| { &mut m.message },
+            ));
+            ::protobuf::reflect::MessageDescriptor::new_pb_name::<ValidationRule>(
+                "ValidationRule",
+                fields,
+                file_descriptor_proto()
+            )
+        })
+    }
+
+    fn default_instance() -> &'static ValidationRule {
+        static instance: ::protobuf::rt::LazyV2<ValidationRule> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(ValidationRule::new)
+    }
+}
+
+impl ::protobuf::Clear for ValidationRule {
+    fn clear(&mut self) {
+        self.range.clear();
+        self.criteria = ::std::option::Option::None;
+        self.criteria = ::std::option::Option::None;
+        self.criteria = ::std::option::Option::None;
+        self.criteria = ::std::option::Option::None;
+        self.criteria = ::std::option::Option::None;
+        self.criteria = ::std::option::Option::None;
+        self.mode = ValidationMode::VALIDATION_REJECT;
+        self.message.clear();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for ValidationRule {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for ValidationRule {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct ListCriteria {
+    // message fields
+    pub values: ::protobuf::RepeatedField<::std::string::String>,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a ListCriteria {
+    fn default() -> &'a ListCriteria {
+        <ListCriteria as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl ListCriteria {
+    pub fn new() -> ListCriteria {
+        ::std::default::Default::default()
+    }
+
+    // repeated string values = 1;
+
+
+    pub fn get_values(&self) -> &[::std::string::String] {
+        &self.values
+    }
+    pub fn clear_values(&mut self) {
+        self.values.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_values(&mut self, v: ::protobuf::RepeatedField<::std::string::String>) {
+        self.values = v;
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_values(&mut self) -> &mut ::protobuf::RepeatedField<::std::string::String> {
+        &mut self.values
+    }
+
+    // Take field
+    pub fn take_values(&mut self) -> ::protobuf::RepeatedField<::std::string::String> {
+        ::std::mem::replace(&mut self.values, ::protobuf::RepeatedField::new())
+    }
+}
+
+impl ::protobuf::Message for ListCriteria {
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_repeated_string_into(wire_type, is, &mut self.values)?;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        for value in &self.values {
+            my_size += ::protobuf::rt::string_size(1, &value);
+        };
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        for v in &self.values {
+            os.write_string(1, &v)?;
+        };
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> ListCriteria {
+        ListCriteria::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            let mut fields = ::std::vec::Vec::new();
+            fields.push(::protobuf::reflect::accessor::make_repeated_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "values",
+                |m: &ListCriteria| { &m.values },
+                |m: &mut ListCriteria| { &mut m.values },
+            ));
+            ::protobuf::reflect::MessageDescriptor::new_pb_name::<ListCriteria>(
+                "ListCriteria",
+                fields,
+                file_descriptor_proto()
+            )
+        })
+    }
+
+    fn default_instance() -> &'static ListCriteria {
+        static instance: ::protobuf::rt::LazyV2<ListCriteria> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(ListCriteria::new)
+    }
+}
+
+impl ::protobuf::Clear for ListCriteria {
+    fn clear(&mut self) {
+        self.values.clear();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for ListCriteria {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for ListCriteria {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct ComparisonCriteria {
+    // message fields
+    pub comparison: Comparison,
+    pub values: ::protobuf::RepeatedField<::std::string::String>,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a ComparisonCriteria {
+    fn default() -> &'a ComparisonCriteria {
+        <ComparisonCriteria as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl ComparisonCriteria {
+    pub fn new() -> ComparisonCriteria {
+        ::std::default::Default::default()
+    }
+
+    // .spreadsheet.Comparison comparison = 1;
+
+
+    pub fn get_comparison(&self) -> Comparison {
+        self.comparison
+    }
+    pub fn clear_comparison(&mut self) {
+        self.comparison = Comparison::COMPARISON_EQUAL;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_comparison(&mut self, v: Comparison) {
+        self.comparison = v;
+    }
+
+    // repeated string values = 2;
+
+
+    pub fn get_values(&self) -> &[::std::string::String] {
+        &self.values
+    }
+    pub fn clear_values(&mut self) {
+        self.values.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_values(&mut self, v: ::protobuf::RepeatedField<::std::string::String>) {
+        self.values = v;
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_values(&mut self) -> &mut ::protobuf::RepeatedField<::std::string::String> {
+        &mut self.values
+    }
+
+    // Take field
+    pub fn take_values(&mut self) -> ::protobuf::RepeatedField<::std::string::String> {
+        ::std::mem::replace(&mut self.values, ::protobuf::RepeatedField::new())
+    }
+}
+
+impl ::protobuf::Message for ComparisonCriteria {
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_proto3_enum_with_unknown_fields_into(wire_type, is, &mut self.comparison, 1, &mut self.unknown_fields)?
+                },
+                2 => {
+                    ::protobuf::rt::read_repeated_string_into(wire_type, is, &mut self.values)?;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if self.comparison != Comparison::COMPARISON_EQUAL {
+            my_size += ::protobuf::rt::enum_size(1, self.comparison);
+        }
+        for value in &self.values {
+            my_size += ::protobuf::rt::string_size(2, &value);
+        };
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        if self.comparison != Comparison::COMPARISON_EQUAL {
+            os.write_enum(1, ::protobuf::ProtobufEnum::value(&self.comparison))?;
+        }
+        for v in &self.values {
+            os.write_string(2, &v)?;
+        };
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> ComparisonCriteria {
+        ComparisonCriteria::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            let mut fields = ::std::vec::Vec::new();
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeEnum<Comparison>>(
+                "comparison",
+                |m: &ComparisonCriteria| { &m.comparison },
+                |m: &mut ComparisonCriteria| { &mut m.comparison },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_repeated_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "values",
+                |m: &ComparisonCriteria| { &m.values },
+                |m: &mut ComparisonCriteria| { &mut m.values },
+            ));
+            ::protobuf::reflect::MessageDescriptor::new_pb_name::<ComparisonCriteria>(
+                "ComparisonCriteria",
+                fields,
+                file_descriptor_proto()
+            )
+        })
+    }
+
+    fn default_instance() -> &'static ComparisonCriteria {
+        static instance: ::protobuf::rt::LazyV2<ComparisonCriteria> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(ComparisonCriteria::new)
+    }
+}
+
+impl ::protobuf::Clear for ComparisonCriteria {
+    fn clear(&mut self) {
+        self.comparison = Comparison::COMPARISON_EQUAL;
+        self.values.clear();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for ComparisonCriteria {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for ComparisonCriteria {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct ValidationError {
+    // message fields
+    pub row: i32,
+    pub col: i32,
+    pub message: ::std::string::String,
+    pub rejected: bool,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a ValidationError {
+    fn default() -> &'a ValidationError {
+        <ValidationError as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl ValidationError {
+    pub fn new() -> ValidationError {
+        ::std::default::Default::default()
+    }
+
+    // int32 row = 1;
+
+
+    pub fn get_row(&self) -> i32 {
+        self.row
+    }
+    pub fn clear_row(&mut self) {
+        self.row = 0;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_row(&mut self, v: i32) {
+        self.row = v;
+    }
+
+    // int32 col = 2;
+
+
+    pub fn get_col(&self) -> i32 {
+        self.col
+    }
+    pub fn clear_col(&mut self) {
+        self.col = 0;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_col(&mut self, v: i32) {
+        self.col = v;
+    }
+
+    // string message = 3;
+
+
+    pub fn get_message(&self) -> &str {
+        &self.message
+    }
+    pub fn clear_message(&mut self) {
+        self.message.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_message(&mut self, v: ::std::string::String) {
+        self.message = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_message(&mut self) -> &mut ::std::string::String {
+        &mut self.message
+    }
+
+    // Take field
+    pub fn take_message(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.message, ::std::string::String::new())
+    }
+
+    // bool rejected = 4;
+
+
+    pub fn get_rejected(&self) -> bool {
+        self.rejected
+    }
+    pub fn clear_rejected(&mut self) {
+        self.rejected = false;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_rejected(&mut self, v: bool) {
+        self.rejected = v;
+    }
+}
+
+impl ::protobuf::Message for ValidationError {
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_int32()?;
+                    self.row = tmp;
+                },
+                2 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_int32()?;
+                    self.col = tmp;
+                },
+                3 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.message)?;
+                },
+                4 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_bool()?;
+                    self.rejected = tmp;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if self.row != 0 {
+            my_size += ::protobuf::rt::value_size(1, self.row, ::protobuf::wire_format::WireTypeVarint);
+        }
+        if self.col != 0 {
+            my_size += ::protobuf::rt::value_size(2, self.col, ::protobuf::wire_format::WireTypeVarint);
+        }
+        if !self.message.is_empty() {
+            my_size += ::protobuf::rt::string_size(3, &self.message);
+        }
+        if self.rejected != false {
+            my_size += 2;
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        if self.row != 0 {
+            os.write_int32(1, self.row)?;
+        }
+        if self.col != 0 {
+            os.write_int32(2, self.col)?;
+        }
+        if !self.message.is_empty() {
+            os.write_string(3, &self.message)?;
+        }
+        if self.rejected != false {
+            os.write_bool(4, self.rejected)?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> ValidationError {
+        ValidationError::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            let mut fields = ::std::vec::Vec::new();
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeInt32>(
+                "row",
+                |m: &ValidationError| { &m.row },
+                |m: &mut ValidationError| { &mut m.row },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeInt32>(
+                "col",
+                |m: &ValidationError| { &m.col },
+                |m: &mut ValidationError| { &mut m.col },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "message",
+                |m: &ValidationError| { &m.message },
+                |m: &mut ValidationError| { &mut m.message },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeBool>(
+                "rejected",
+                |m: &ValidationError| { &m.rejected },
+                |m: &mut ValidationError| { &mut m.rejected },
+            ));
+            ::protobuf::reflect::MessageDescriptor::new_pb_name::<ValidationError>(
+                "ValidationError",
+                fields,
+                file_descriptor_proto()
+            )
+        })
+    }
+
+    fn default_instance() -> &'static ValidationError {
+        static instance: ::protobuf::rt::LazyV2<ValidationError> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(ValidationError::new)
+    }
+}
+
+impl ::protobuf::Clear for ValidationError {
+    fn clear(&mut self) {
+        self.row = 0;
+        self.col = 0;
+        self.message.clear();
+        self.rejected = false;
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for ValidationError {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for ValidationError {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct SetValidationRulesRequest {
+    // message fields
+    pub tableId: ::std::string::String,
+    pub rules: ::protobuf::RepeatedField<ValidationRule>,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a SetValidationRulesRequest {
+    fn default() -> &'a SetValidationRulesRequest {
+        <SetValidationRulesRequest as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl SetValidationRulesRequest {
+    pub fn new() -> SetValidationRulesRequest {
+        ::std::default::Default::default()
+    }
+
+    // string tableId = 1;
+
+
+    pub fn get_tableId(&self) -> &str {
+        &self.tableId
+    }
+    pub fn clear_tableId(&mut self) {
+        self.tableId.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_tableId(&mut self, v: ::std::string::String) {
+        self.tableId = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_tableId(&mut self) -> &mut ::std::string::String {
+        &mut self.tableId
+    }
+
+    // Take field
+    pub fn take_tableId(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.tableId, ::std::string::String::new())
+    }
+
+    // repeated .spreadsheet.ValidationRule rules = 2;
+
+
+    pub fn get_rules(&self) -> &[ValidationRule] {
+        &self.rules
+    }
+    pub fn clear_rules(&mut self) {
+        self.rules.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_rules(&mut self, v: ::protobuf::RepeatedField<ValidationRule>) {
+        self.rules = v;
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_rules(&mut self) -> &mut ::protobuf::RepeatedField<ValidationRule> {
+        &mut self.rules
+    }
+
+    // Take field
+    pub fn take_rules(&mut self) -> ::protobuf::RepeatedField<ValidationRule> {
+        ::std::mem::replace(&mut self.rules, ::protobuf::RepeatedField::new())
+    }
+}
+
+impl ::protobuf::Message for SetValidationRulesRequest {
+    fn is_initialized(&self) -> bool {
+        for v in &self.rules {
+            if !v.is_initialized() {
+                return false;
+            }
+        };
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.tableId)?;
+                },
+                2 => {
+                    ::protobuf::rt::read_repeated_message_into(wire_type, is, &mut self.rules)?;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if !self.tableId.is_empty() {
+            my_size += ::protobuf::rt::string_size(1, &self.tableId);
+        }
+        for value in &self.rules {
+            let len = value.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        };
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        if !self.tableId.is_empty() {
+            os.write_string(1, &self.tableId)?;
+        }
+        for v in &self.rules {
+            os.write_tag(2, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+            os.write_raw_varint32(v.get_cached_size())?;
+            v.write_to_with_cached_sizes(os)?;
+        };
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> SetValidationRulesRequest {
+        SetValidationRulesRequest::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            let mut fields = ::std::vec::Vec::new();
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "tableId",
+                |m: &SetValidationRulesRequest| { &m.tableId },
+                |m: &mut SetValidationRulesRequest| { &mut m.tableId },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_repeated_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<ValidationRule>>(
+                "rules",
+                |m: &SetValidationRulesRequest| { &m.rules },
+                |m: &mut SetValidationRulesRequest| { &mut m.rules },
+            ));
+            ::protobuf::reflect::MessageDescriptor::new_pb_name::<SetValidationRulesRequest>(
+                "SetValidationRulesRequest",
+                fields,
+                file_descriptor_proto()
+            )
+        })
+    }
+
+    fn default_instance() -> &'static SetValidationRulesRequest {
+        static instance: ::protobuf::rt::LazyV2<SetValidationRulesRequest> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(SetValidationRulesRequest::new)
+    }
+}
+
+impl ::protobuf::Clear for SetValidationRulesRequest {
+    fn clear(&mut self) {
+        self.tableId.clear();
+        self.rules.clear();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for SetValidationRulesRequest {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for SetValidationRulesRequest {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct SetValidationRulesResponse {
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a SetValidationRulesResponse {
+    fn default() -> &'a SetValidationRulesResponse {
+        <SetValidationRulesResponse as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl SetValidationRulesResponse {
+    pub fn new() -> SetValidationRulesResponse {
+        ::std::default::Default::default()
+    }
+}
+
+impl ::protobuf::Message for SetValidationRulesResponse {
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> SetValidationRulesResponse {
+        SetValidationRulesResponse::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            let fields = ::std::vec::Vec::new();
+            ::protobuf::reflect::MessageDescriptor::new_pb_name::<SetValidationRulesResponse>(
+                "SetValidationRulesResponse",
+                fields,
+                file_descriptor_proto()
+            )
+        })
+    }
+
+    fn default_instance() -> &'static SetValidationRulesResponse {
+        static instance: ::protobuf::rt::LazyV2<SetValidationRulesResponse> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(SetValidationRulesResponse::new)
+    }
+}
+
+impl ::protobuf::Clear for SetValidationRulesResponse {
+    fn clear(&mut self) {
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for SetValidationRulesResponse {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for SetValidationRulesResponse {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct GetValidationRulesRequest {
+    // message fields
+    pub tableId: ::std::string::String,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a GetValidationRulesRequest {
+    fn default() -> &'a GetValidationRulesRequest {
+        <GetValidationRulesRequest as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl GetValidationRulesRequest {
+    pub fn new() -> GetValidationRulesRequest {
+        ::std::default::Default::default()
+    }
+
+    // string tableId = 1;
+
+
+    pub fn get_tableId(&self) -> &str {
+        &self.tableId
+    }
+    pub fn clear_tableId(&mut self) {
+        self.tableId.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_tableId(&mut self, v: ::std::string::String) {
+        self.tableId = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_tableId(&mut self) -> &mut ::std::string::String {
+        &mut self.tableId
+    }
+
+    // Take field
+    pub fn take_tableId(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.tableId, ::std::string::String::new())
+    }
+}
+
+impl ::protobuf::Message for GetValidationRulesRequest {
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.tableId)?;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if !self.tableId.is_empty() {
+            my_size += ::protobuf::rt::string_size(1, &self.tableId);
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        if !self.tableId.is_empty() {
+            os.write_string(1, &self.tableId)?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> GetValidationRulesRequest {
+        GetValidationRulesRequest::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            let mut fields = ::std::vec::Vec::new();
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "tableId",
+                |m: &GetValidationRulesRequest| { &m.tableId },
+                |m: &mut GetValidationRulesRequest| { &mut m.tableId },
+            ));
+            ::protobuf::reflect::MessageDescriptor::new_pb_name::<GetValidationRulesRequest>(
+                "GetValidationRulesRequest",
+                fields,
+                file_descriptor_proto()
+            )
+        })
+    }
+
+    fn default_instance() -> &'static GetValidationRulesRequest {
+        static instance: ::protobuf::rt::LazyV2<GetValidationRulesRequest> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(GetValidationRulesRequest::new)
+    }
+}
+
+impl ::protobuf::Clear for GetValidationRulesRequest {
+    fn clear(&mut self) {
+        self.tableId.clear();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for GetValidationRulesRequest {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for GetValidationRulesRequest {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct GetValidationRulesResponse {
+    // message fields
+    pub rules: ::protobuf::RepeatedField<ValidationRule>,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a GetValidationRulesResponse {
+    fn default() -> &'a GetValidationRulesResponse {
+        <GetValidationRulesResponse as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl GetValidationRulesResponse {
+    pub fn new() -> GetValidationRulesResponse {
+        ::std::default::Default::default()
+    }
+
+    // repeated .spreadsheet.ValidationRule rules = 1;
+
+
+    pub fn get_rules(&self) -> &[ValidationRule] {
+        &self.rules
+    }
+    pub fn clear_rules(&mut self) {
+        self.rules.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_rules(&mut self, v: ::protobuf::RepeatedField<ValidationRule>) {
+        self.rules = v;
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_rules(&mut self) -> &mut ::protobuf::RepeatedField<ValidationRule> {
+        &mut self.rules
+    }
+
+    // Take field
+    pub fn take_rules(&mut self) -> ::protobuf::RepeatedField<ValidationRule> {
+        ::std::mem::replace(&mut self.rules, ::protobuf::RepeatedField::new())
+    }
+}
+
+impl ::protobuf::Message for GetValidationRulesResponse {
+    fn is_initialized(&self) -> bool {
+        for v in &self.rules {
+            if !v.is_initialized() {
+                return false;
+            }
+        };
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_repeated_message_into(wire_type, is, &mut self.rules)?;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        for value in &self.rules {
+            let len = value.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        };
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        for v in &self.rules {
+            os.write_tag(1, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+            os.write_raw_varint32(v.get_cached_size())?;
+            v.write_to_with_cached_sizes(os)?;
+        };
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> GetValidationRulesResponse {
+        GetValidationRulesResponse::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            let mut fields = ::std::vec::Vec::new();
+            fields.push(::protobuf::reflect::accessor::make_repeated_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<ValidationRule>>(
+                "rules",
+                |m: &GetValidationRulesResponse| { &m.rules },
+                |m: &mut GetValidationRulesResponse| { &mut m.rules },
+            ));
+            ::protobuf::reflect::MessageDescriptor::new_pb_name::<GetValidationRulesResponse>(
+                "GetValidationRulesResponse",
+                fields,
+                file_descriptor_proto()
+            )
+        })
+    }
+
+    fn default_instance() -> &'static GetValidationRulesResponse {
+        static instance: ::protobuf::rt::LazyV2<GetValidationRulesResponse> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(GetValidationRulesResponse::new)
+    }
+}
+
+impl ::protobuf::Clear for GetValidationRulesResponse {
+    fn clear(&mut self) {
+        self.rules.clear();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for GetValidationRulesResponse {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for GetValidationRulesResponse {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct GetListValuesRequest {
+    // message fields
+    pub tableId: ::std::string::String,
+    pub row: i32,
+    pub col: i32,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a GetListValuesRequest {
+    fn default() -> &'a GetListValuesRequest {
+        <GetListValuesRequest as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl GetListValuesRequest {
+    pub fn new() -> GetListValuesRequest {
+        ::std::default::Default::default()
+    }
+
+    // string tableId = 1;
+
+
+    pub fn get_tableId(&self) -> &str {
+        &self.tableId
+    }
+    pub fn clear_tableId(&mut self) {
+        self.tableId.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_tableId(&mut self, v: ::std::string::String) {
+        self.tableId = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_tableId(&mut self) -> &mut ::std::string::String {
+        &mut self.tableId
+    }
+
+    // Take field
+    pub fn take_tableId(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.tableId, ::std::string::String::new())
+    }
+
+    // int32 row = 2;
+
+
+    pub fn get_row(&self) -> i32 {
+        self.row
+    }
+    pub fn clear_row(&mut self) {
+        self.row = 0;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_row(&mut self, v: i32) {
+        self.row = v;
+    }
+
+    // int32 col = 3;
+
+
+    pub fn get_col(&self) -> i32 {
+        self.col
+    }
+    pub fn clear_col(&mut self) {
+        self.col = 0;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_col(&mut self, v: i32) {
+        self.col = v;
+    }
+}
+
+impl ::protobuf::Message for GetListValuesRequest {
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.tableId)?;
+                },
+                2 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_int32()?;
+                    self.row = tmp;
+                },
+                3 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_int32()?;
+                    self.col = tmp;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if !self.tableId.is_empty() {
+            my_size += ::protobuf::rt::string_size(1, &self.tableId);
+        }
+        if self.row != 0 {
+            my_size += ::protobuf::rt::value_size(2, self.row, ::protobuf::wire_format::WireTypeVarint);
+        }
+        if self.col != 0 {
+            my_size += ::protobuf::rt::value_size(3, self.col, ::protobuf::wire_format::WireTypeVarint);
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        if !self.tableId.is_empty() {
+            os.write_string(1, &self.tableId)?;
+        }
+        if self.row != 0 {
+            os.write_int32(2, self.row)?;
+        }
+        if self.col != 0 {
+            os.write_int32(3, self.col)?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> GetListValuesRequest {
+        GetListValuesRequest::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            let mut fields = ::std::vec::Vec::new();
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "tableId",
+                |m: &GetListValuesRequest| { &m.tableId },
+                |m: &mut GetListValuesRequest| { &mut m.tableId },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeInt32>(
+                "row",
+                |m: &GetListValuesRequest| { &m.row },
+                |m: &mut GetListValuesRequest| { &mut m.row },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeInt32>(
+                "col",
+                |m: &GetListValuesRequest| { &m.col },
+                |m: &mut GetListValuesRequest| { &mut m.col },
+            ));
+            ::protobuf::reflect::MessageDescriptor::new_pb_name::<GetListValuesRequest>(
+                "GetListValuesRequest",
+                fields,
+                file_descriptor_proto()
+            )
+        })
+    }
+
+    fn default_instance() -> &'static GetListValuesRequest {
+        static instance: ::protobuf::rt::LazyV2<GetListValuesRequest> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(GetListValuesRequest::new)
+    }
+}
+
+impl ::protobuf::Clear for GetListValuesRequest {
+    fn clear(&mut self) {
+        self.tableId.clear();
+        self.row = 0;
+        self.col = 0;
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for GetListValuesRequest {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for GetListValuesRequest {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct GetListValuesResponse {
+    // message fields
+    pub values: ::protobuf::RepeatedField<::std::string::String>,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a GetListValuesResponse {
+    fn default() -> &'a GetListValuesResponse {
+        <GetListValuesResponse as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl GetListValuesResponse {
+    pub fn new() -> GetListValuesResponse {
+        ::std::default::Default::default()
+    }
+
+    // repeated string values = 1;
+
+
+    pub fn get_values(&self) -> &[::std::string::String] {
+        &self.values
+    }
+    pub fn clear_values(&mut self) {
+        self.values.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_values(&mut self, v: ::protobuf::RepeatedField<::std::string::String>) {
+        self.values = v;
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_values(&mut self) -> &mut ::protobuf::RepeatedField<::std::string::String> {
+        &mut self.values
+    }
+
+    // Take field
+    pub fn take_values(&mut self) -> ::protobuf::RepeatedField<::std::string::String> {
+        ::std::mem::replace(&mut self.values, ::protobuf::RepeatedField::new())
+    }
+}
+
+impl ::protobuf::Message for GetListValuesResponse {
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_repeated_string_into(wire_type, is, &mut self.values)?;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        for value in &self.values {
+            my_size += ::protobuf::rt::string_size(1, &value);
+        };
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        for v in &self.values {
+            os.write_string(1, &v)?;
+        };
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> GetListValuesResponse {
+        GetListValuesResponse::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            let mut fields = ::std::vec::Vec::new();
+            fields.push(::protobuf::reflect::accessor::make_repeated_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "values",
+                |m: &GetListValuesResponse| { &m.values },
+                |m: &mut GetListValuesResponse| { &mut m.values },
+            ));
+            ::protobuf::reflect::MessageDescriptor::new_pb_name::<GetListValuesResponse>(
+                "GetListValuesResponse",
+                fields,
+                file_descriptor_proto()
+            )
+        })
+    }
+
+    fn default_instance() -> &'static GetListValuesResponse {
+        static instance: ::protobuf::rt::LazyV2<GetListValuesResponse> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(GetListValuesResponse::new)
+    }
+}
+
+impl ::protobuf::Clear for GetListValuesResponse {
+    fn clear(&mut self) {
+        self.values.clear();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for GetListValuesResponse {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for GetListValuesResponse {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct TracedRange {
+    // message fields
+    pub tableId: ::std::string::String,
+    pub rect: ::protobuf::SingularPtrField<Rect>,
+    pub depth: u32,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a TracedRange {
+    fn default() -> &'a TracedRange {
+        <TracedRange as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl TracedRange {
+    pub fn new() -> TracedRange {
+        ::std::default::Default::default()
+    }
+
+    // string tableId = 1;
+
+
+    pub fn get_tableId(&self) -> &str {
+        &self.tableId
+    }
+    pub fn clear_tableId(&mut self) {
+        self.tableId.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_tableId(&mut self, v: ::std::string::String) {
+        self.tableId = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_tableId(&mut self) -> &mut ::std::string::String {
+        &mut self.tableId
+    }
+
+    // Take field
+    pub fn take_tableId(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.tableId, ::std::string::String::new())
+    }
+
+    // .spreadsheet.Rect rect = 2;
+
+
+    pub fn get_rect(&self) -> &Rect {
+        self.rect.as_ref().unwrap_or_else(|| <Rect as ::protobuf::Message>::default_instance())
+    }
+    pub fn clear_rect(&mut self) {
+        self.rect.clear();
+    }
+
+    pub fn has_rect(&self) -> bool {
+        self.rect.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_rect(&mut self, v: Rect) {
+        self.rect = ::protobuf::SingularPtrField::some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_rect(&mut self) -> &mut Rect {
+        if self.rect.is_none() {
+            self.rect.set_default();
+        }
+        self.rect.as_mut().unwrap()
+    }
+
+    // Take field
+    pub fn take_rect(&mut self) -> Rect {
+        self.rect.take().unwrap_or_else(|| Rect::new())
+    }
+
+    // uint32 depth = 3;
+
+
+    pub fn get_depth(&self) -> u32 {
+        self.depth
+    }
+    pub fn clear_depth(&mut self) {
+        self.depth = 0;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_depth(&mut self, v: u32) {
+        self.depth = v;
+    }
+}
+
+impl ::protobuf::Message for TracedRange {
+    fn is_initialized(&self) -> bool {
+        for v in &self.rect {
+            if !v.is_initialized() {
+                return false;
+            }
+        };
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.tableId)?;
+                },
+                2 => {
+                    ::protobuf::rt::read_singular_message_into(wire_type, is, &mut self.rect)?;
+                },
+                3 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_uint32()?;
+                    self.depth = tmp;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if !self.tableId.is_empty() {
+            my_size += ::protobuf::rt::string_size(1, &self.tableId);
+        }
+        if let Some(ref v) = self.rect.as_ref() {
+            let len = v.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        }
+        if self.depth != 0 {
+            my_size += ::protobuf::rt::value_size(3, self.depth, ::protobuf::wire_format::WireTypeVarint);
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        if !self.tableId.is_empty() {
+            os.write_string(1, &self.tableId)?;
+        }
+        if let Some(ref v) = self.rect.as_ref() {
+            os.write_tag(2, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+            os.write_raw_varint32(v.get_cached_size())?;
+            v.write_to_with_cached_sizes(os)?;
+        }
+        if self.depth != 0 {
+            os.write_uint32(3, self.depth)?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> TracedRange {
+        TracedRange::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            let mut fields = ::std::vec::Vec::new();
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "tableId",
+                |m: &TracedRange| { &m.tableId },
+                |m: &mut TracedRange| { &mut m.tableId },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_singular_ptr_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<Rect>>(
+                "rect",
+                |m: &TracedRange| { &m.rect },
+                |m: &mut TracedRange| { &mut m.rect },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeUint32>(
+                "depth",
+                |m: &TracedRange| { &m.depth },
+                |m: &mut TracedRange| { &mut m.depth },
+            ));
+            ::protobuf::reflect::MessageDescriptor::new_pb_name::<TracedRange>(
+                "TracedRange",
+                fields,
+                file_descriptor_proto()
+            )
+        })
+    }
+
+    fn default_instance() -> &'static TracedRange {
+        static instance: ::protobuf::rt::LazyV2<TracedRange> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(TracedRange::new)
+    }
+}
+
+impl ::protobuf::Clear for TracedRange {
+    fn clear(&mut self) {
+        self.tableId.clear();
+        self.rect.clear();
+        self.depth = 0;
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for TracedRange {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for TracedRange {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct GetPrecedentsRequest {
+    // message fields
+    pub tableId: ::std::string::String,
+    pub row: i32,
+    pub col: i32,
+    pub maxDepth: u32,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a GetPrecedentsRequest {
+    fn default() -> &'a GetPrecedentsRequest {
+        <GetPrecedentsRequest as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl GetPrecedentsRequest {
+    pub fn new() -> GetPrecedentsRequest {
+        ::std::default::Default::default()
+    }
+
+    // string tableId = 1;
+
+
+    pub fn get_tableId(&self) -> &str {
+        &self.tableId
+    }
+    pub fn clear_tableId(&mut self) {
+        self.tableId.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_tableId(&mut self, v: ::std::string::String) {
+        self.tableId = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_tableId(&mut self) -> &mut ::std::string::String {
+        &mut self.tableId
+    }
+
+    // Take field
+    pub fn take_tableId(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.tableId, ::std::string::String::new())
+    }
+
+    // int32 row = 2;
+
+
+    pub fn get_row(&self) -> i32 {
+        self.row
+    }
+    pub fn clear_row(&mut self) {
+        self.row = 0;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_row(&mut self, v: i32) {
+        self.row = v;
+    }
+
+    // int32 col = 3;
+
+
+    pub fn get_col(&self) -> i32 {
+        self.col
+    }
+    pub fn clear_col(&mut self) {
+        self.col = 0;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_col(&mut self, v: i32) {
+        self.col = v;
+    }
+
+    // uint32 maxDepth = 4;
+
+
+    pub fn get_maxDepth(&self) -> u32 {
+        self.maxDepth
+    }
+    pub fn clear_maxDepth(&mut self) {
+        self.maxDepth = 0;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_maxDepth(&mut self, v: u32) {
+        self.maxDepth = v;
+    }
+}
+
+impl ::protobuf::Message for GetPrecedentsRequest {
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.tableId)?;
+                },
+                2 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_int32()?;
+                    self.row = tmp;
+                },
+                3 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_int32()?;
+                    self.col = tmp;
+                },
+                4 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_uint32()?;
+                    self.maxDepth = tmp;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if !self.tableId.is_empty() {
+            my_size += ::protobuf::rt::string_size(1, &self.tableId);
+        }
+        if self.row != 0 {
+            my_size += ::protobuf::rt::value_size(2, self.row, ::protobuf::wire_format::WireTypeVarint);
+        }
+        if self.col != 0 {
+            my_size += ::protobuf::rt::value_size(3, self.col, ::protobuf::wire_format::WireTypeVarint);
+        }
+        if self.maxDepth != 0 {
+            my_size += ::protobuf::rt::value_size(4, self.maxDepth, ::protobuf::wire_format::WireTypeVarint);
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        if !self.tableId.is_empty() {
+            os.write_string(1, &self.tableId)?;
+        }
+        if self.row != 0 {
+            os.write_int32(2, self.row)?;
+        }
+        if self.col != 0 {
+            os.write_int32(3, self.col)?;
+        }
+        if self.maxDepth != 0 {
+            os.write_uint32(4, self.maxDepth)?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> GetPrecedentsRequest {
+        GetPrecedentsRequest::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            let mut fields = ::std::vec::Vec::new();
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "tableId",
+                |m: &GetPrecedentsRequest| { &m.tableId },
+                |m: &mut GetPrecedentsRequest| { &mut m.tableId },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeInt32>(
+                "row",
+                |m: &GetPrecedentsRequest| { &m.row },
+                |m: &mut GetPrecedentsRequest| { &mut m.row },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeInt32>(
+                "col",
+                |m: &GetPrecedentsRequest| { &m.col },
+                |m: &mut GetPrecedentsRequest| { &mut m.col },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeUint32>(
+                "maxDepth",
+                |m: &GetPrecedentsRequest| { &m.maxDepth },
+                |m: &mut GetPrecedentsRequest| { &mut m.maxDepth },
+            ));
+            ::protobuf::reflect::MessageDescriptor::new_pb_name::<GetPrecedentsRequest>(
+                "GetPrecedentsRequest",
+                fields,
+                file_descriptor_proto()
+            )
+        })
+    }
+
+    fn default_instance() -> &'static GetPrecedentsRequest {
+        static instance: ::protobuf::rt::LazyV2<GetPrecedentsRequest> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(GetPrecedentsRequest::new)
+    }
+}
+
+impl ::protobuf::Clear for GetPrecedentsRequest {
+    fn clear(&mut self) {
+        self.tableId.clear();
+        self.row = 0;
+        self.col = 0;
+        self.maxDepth = 0;
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for GetPrecedentsRequest {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for GetPrecedentsRequest {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct GetPrecedentsResponse {
+    // message fields
+    pub ranges: ::protobuf::RepeatedField<TracedRange>,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a GetPrecedentsResponse {
+    fn default() -> &'a GetPrecedentsResponse {
+        <GetPrecedentsResponse as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl GetPrecedentsResponse {
+    pub fn new() -> GetPrecedentsResponse {
+        ::std::default::Default::default()
+    }
+
+    // repeated .spreadsheet.TracedRange ranges = 1;
+
+
+    pub fn get_ranges(&self) -> &[TracedRange] {
+        &self.ranges
+    }
+    pub fn clear_ranges(&mut self) {
+        self.ranges.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_ranges(&mut self, v: ::protobuf::RepeatedField<TracedRange>) {
+        self.ranges = v;
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_ranges(&mut self) -> &mut ::protobuf::RepeatedField<TracedRange> {
+        &mut self.ranges
+    }
+
+    // Take field
+    pub fn take_ranges(&mut self) -> ::protobuf::RepeatedField<TracedRange> {
+        ::std::mem::replace(&mut self.ranges, ::protobuf::RepeatedField::new())
+    }
+}
+
+impl ::protobuf::Message for GetPrecedentsResponse {
+    fn is_initialized(&self) -> bool {
+        for v in &self.ranges {
+            if !v.is_initialized() {
+                return false;
+            }
+        };
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_repeated_message_into(wire_type, is, &mut self.ranges)?;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        for value in &self.ranges {
+            let len = value.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        };
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        for v in &self.ranges {
+            os.write_tag(1, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+            os.write_raw_varint32(v.get_cached_size())?;
+            v.write_to_with_cached_sizes(os)?;
+        };
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> GetPrecedentsResponse {
+        GetPrecedentsResponse::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            let mut fields = ::std::vec::Vec::new();
+            fields.push(::protobuf::reflect::accessor::make_repeated_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<TracedRange>>(
+                "ranges",
+                |m: &GetPrecedentsResponse| { &m.ranges },
+                |m: &mut GetPrecedentsResponse| { &mut m.ranges },
+            ));
+            ::protobuf::reflect::MessageDescriptor::new_pb_name::<GetPrecedentsResponse>(
+                "GetPrecedentsResponse",
+                fields,
+                file_descriptor_proto()
+            )
+        })
+    }
+
+    fn default_instance() -> &'static GetPrecedentsResponse {
+        static instance: ::protobuf::rt::LazyV2<GetPrecedentsResponse> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(GetPrecedentsResponse::new)
+    }
+}
+
+impl ::protobuf::Clear for GetPrecedentsResponse {
+    fn clear(&mut self) {
+        self.ranges.clear();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for GetPrecedentsResponse {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for GetPrecedentsResponse {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct GetDependentsRequest {
+    // message fields
+    pub tableId: ::std::string::String,
+    pub row: i32,
+    pub col: i32,
+    pub maxDepth: u32,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a GetDependentsRequest {
+    fn default() -> &'a GetDependentsRequest {
+        <GetDependentsRequest as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl GetDependentsRequest {
+    pub fn new() -> GetDependentsRequest {
+        ::std::default::Default::default()
+    }
+
+    // string tableId = 1;
+
+
+    pub fn get_tableId(&self) -> &str {
+        &self.tableId
+    }
+    pub fn clear_tableId(&mut self) {
+        self.tableId.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_tableId(&mut self, v: ::std::string::String) {
+        self.tableId = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_tableId(&mut self) -> &mut ::std::string::String {
+        &mut self.tableId
+    }
+
+    // Take field
+    pub fn take_tableId(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.tableId, ::std::string::String::new())
+    }
+
+    // int32 row = 2;
+
+
+    pub fn get_row(&self) -> i32 {
+        self.row
+    }
+    pub fn clear_row(&mut self) {
+        self.row = 0;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_row(&mut self, v: i32) {
+        self.row = v;
+    }
+
+    // int32 col = 3;
+
+
+    pub fn get_col(&self) -> i32 {
+        self.col
+    }
+    pub fn clear_col(&mut self) {
+        self.col = 0;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_col(&mut self, v: i32) {
+        self.col = v;
+    }
+
+    // uint32 maxDepth = 4;
+
+
+    pub fn get_maxDepth(&self) -> u32 {
+        self.maxDepth
+    }
+    pub fn clear_maxDepth(&mut self) {
+        self.maxDepth = 0;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_maxDepth(&mut self, v: u32) {
+        self.maxDepth = v;
+    }
+}
+
+impl ::protobuf::Message for GetDependentsRequest {
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.tableId)?;
+                },
+                2 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_int32()?;
+                    self.row = tmp;
+                },
+                3 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_int32()?;
+                    self.col = tmp;
+                },
+                4 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_uint32()?;
+                    self.maxDepth = tmp;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if !self.tableId.is_empty() {
+            my_size += ::protobuf::rt::string_size(1, &self.tableId);
+        }
+        if self.row != 0 {
+            my_size += ::protobuf::rt::value_size(2, self.row, ::protobuf::wire_format::WireTypeVarint);
+        }
+        if self.col != 0 {
+            my_size += ::protobuf::rt::value_size(3, self.col, ::protobuf::wire_format::WireTypeVarint);
+        }
+        if self.maxDepth != 0 {
+            my_size += ::protobuf::rt::value_size(4, self.maxDepth, ::protobuf::wire_format::WireTypeVarint);
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        if !self.tableId.is_empty() {
+            os.write_string(1, &self.tableId)?;
+        }
+        if self.row != 0 {
+            os.write_int32(2, self.row)?;
+        }
+        if self.col != 0 {
+            os.write_int32(3, self.col)?;
+        }
+        if self.maxDepth != 0 {
+            os.write_uint32(4, self.maxDepth)?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> GetDependentsRequest {
+        GetDependentsRequest::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            let mut fields = ::std::vec::Vec::new();
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "tableId",
+                |m: &GetDependentsRequest| { &m.tableId },
+                |m: &mut GetDependentsRequest| { &mut m.tableId },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeInt32>(
+                "row",
+                |m: &GetDependentsRequest| { &m.row },
+                |m: &mut GetDependentsRequest| { &mut m.row },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeInt32>(
+                "col",
+                |m: &GetDependentsRequest| { &m.col },
+                |m: &mut GetDependentsRequest| { &mut m.col },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeUint32>(
+                "maxDepth",
+                |m: &GetDependentsRequest| { &m.maxDepth },
+                |m: &mut GetDependentsRequest| { &mut m.maxDepth },
+            ));
+            ::protobuf::reflect::MessageDescriptor::new_pb_name::<GetDependentsRequest>(
+                "GetDependentsRequest",
+                fields,
+                file_descriptor_proto()
+            )
+        })
+    }
+
+    fn default_instance() -> &'static GetDependentsRequest {
+        static instance: ::protobuf::rt::LazyV2<GetDependentsRequest> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(GetDependentsRequest::new)
+    }
+}
+
+impl ::protobuf::Clear for GetDependentsRequest {
+    fn clear(&mut self) {
+        self.tableId.clear();
+        self.row = 0;
+        self.col = 0;
+        self.maxDepth = 0;
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for GetDependentsRequest {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for GetDependentsRequest {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct GetDependentsResponse {
+    // message fields
+    pub ranges: ::protobuf::RepeatedField<TracedRange>,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a GetDependentsResponse {
+    fn default() -> &'a GetDependentsResponse {
+        <GetDependentsResponse as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl GetDependentsResponse {
+    pub fn new() -> GetDependentsResponse {
+        ::std::default::Default::default()
+    }
+
+    // repeated .spreadsheet.TracedRange ranges = 1;
+
+
+    pub fn get_ranges(&self) -> &[TracedRange] {
+        &self.ranges
+    }
+    pub fn clear_ranges(&mut self) {
+        self.ranges.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_ranges(&mut self, v: ::protobuf::RepeatedField<TracedRange>) {
+        self.ranges = v;
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_ranges(&mut self) -> &mut ::protobuf::RepeatedField<TracedRange> {
+        &mut self.ranges
+    }
+
+    // Take field
+    pub fn take_ranges(&mut self) -> ::protobuf::RepeatedField<TracedRange> {
+        ::std::mem::replace(&mut self.ranges, ::protobuf::RepeatedField::new())
+    }
+}
+
+impl ::protobuf::Message for GetDependentsResponse {
+    fn is_initialized(&self) -> bool {
+        for v in &self.ranges {
+            if !v.is_initialized() {
+                return false;
+            }
+        };
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_repeated_message_into(wire_type, is, &mut self.ranges)?;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        for value in &self.ranges {
+            let len = value.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        };
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        for v in &self.ranges {
+            os.write_tag(1, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+            os.write_raw_varint32(v.get_cached_size())?;
+            v.write_to_with_cached_sizes(os)?;
+        };
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> GetDependentsResponse {
+        GetDependentsResponse::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            let mut fields = ::std::vec::Vec::new();
+            fields.push(::protobuf::reflect::accessor::make_repeated_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<TracedRange>>(
+                "ranges",
+                |m: &GetDependentsResponse| { &m.ranges },
+                |m: &mut GetDependentsResponse| { &mut m.ranges },
+            ));
+            ::protobuf::reflect::MessageDescriptor::new_pb_name::<GetDependentsResponse>(
+                "GetDependentsResponse",
+                fields,
+                file_descriptor_proto()
+            )
+        })
+    }
+
+    fn default_instance() -> &'static GetDependentsResponse {
+        static instance: ::protobuf::rt::LazyV2<GetDependentsResponse> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(GetDependentsResponse::new)
+    }
+}
+
+impl ::protobuf::Clear for GetDependentsResponse {
+    fn clear(&mut self) {
+        self.ranges.clear();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for GetDependentsResponse {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for GetDependentsResponse {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct CellRef {
+    // message fields
+    pub tableId: ::std::string::String,
+    pub row: i32,
+    pub col: i32,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a CellRef {
+    fn default() -> &'a CellRef {
+        <CellRef as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl CellRef {
+    pub fn new() -> CellRef {
+        ::std::default::Default::default()
+    }
+
+    // string tableId = 1;
+
+
+    pub fn get_tableId(&self) -> &str {
+        &self.tableId
+    }
+    pub fn clear_tableId(&mut self) {
+        self.tableId.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_tableId(&mut self, v: ::std::string::String) {
+        self.tableId = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_tableId(&mut self) -> &mut ::std::string::String {
+        &mut self.tableId
+    }
+
+    // Take field
+    pub fn take_tableId(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.tableId, ::std::string::String::new())
+    }
+
+    // int32 row = 2;
+
+
+    pub fn get_row(&self) -> i32 {
+        self.row
+    }
+    pub fn clear_row(&mut self) {
+        self.row = 0;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_row(&mut self, v: i32) {
+        self.row = v;
+    }
+
+    // int32 col = 3;
+
+
+    pub fn get_col(&self) -> i32 {
+        self.col
+    }
+    pub fn clear_col(&mut self) {
+        self.col = 0;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_col(&mut self, v: i32) {
+        self.col = v;
+    }
+}
+
+impl ::protobuf::Message for CellRef {
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.tableId)?;
+                },
+                2 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_int32()?;
+                    self.row = tmp;
+                },
+                3 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_int32()?;
+                    self.col = tmp;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if !self.tableId.is_empty() {
+            my_size += ::protobuf::rt::string_size(1, &self.tableId);
+        }
+        if self.row != 0 {
+            my_size += ::protobuf::rt::value_size(2, self.row, ::protobuf::wire_format::WireTypeVarint);
+        }
+        if self.col != 0 {
+            my_size += ::protobuf::rt::value_size(3, self.col, ::protobuf::wire_format::WireTypeVarint);
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        if !self.tableId.is_empty() {
+            os.write_string(1, &self.tableId)?;
+        }
+        if self.row != 0 {
+            os.write_int32(2, self.row)?;
+        }
+        if self.col != 0 {
+            os.write_int32(3, self.col)?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> CellRef {
+        CellRef::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            let mut fields = ::std::vec::Vec::new();
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "tableId",
+                |m: &CellRef| { &m.tableId },
+                |m: &mut CellRef| { &mut m.tableId },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeInt32>(
+                "row",
+                |m: &CellRef| { &m.row },
+                |m: &mut CellRef| { &mut m.row },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeInt32>(
+                "col",
+                |m: &CellRef| { &m.col },
+                |m: &mut CellRef| { &mut m.col },
+            ));
+            ::protobuf::reflect::MessageDescriptor::new_pb_name::<CellRef>(
+                "CellRef",
+                fields,
+                file_descriptor_proto()
+            )
+        })
+    }
+
+    fn default_instance() -> &'static CellRef {
+        static instance: ::protobuf::rt::LazyV2<CellRef> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(CellRef::new)
+    }
+}
+
+impl ::protobuf::Clear for CellRef {
+    fn clear(&mut self) {
+        self.tableId.clear();
+        self.row = 0;
+        self.col = 0;
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for CellRef {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for CellRef {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct Cycle {
+    // message fields
+    pub path: ::protobuf::RepeatedField<CellRef>,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a Cycle {
+    fn default() -> &'a Cycle {
+        <Cycle as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl Cycle {
+    pub fn new() -> Cycle {
+        ::std::default::Default::default()
+    }
+
+    // repeated .spreadsheet.CellRef path = 1;
+
+
+    pub fn get_path(&self) -> &[CellRef] {
+        &self.path
+    }
+    pub fn clear_path(&mut self) {
+        self.path.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_path(&mut self, v: ::protobuf::RepeatedField<CellRef>) {
+        self.path = v;
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_path(&mut self) -> &mut ::protobuf::RepeatedField<CellRef> {
+        &mut self.path
+    }
+
+    // Take field
+    pub fn take_path(&mut self) -> ::protobuf::RepeatedField<CellRef> {
+        ::std::mem::replace(&mut self.path, ::protobuf::RepeatedField::new())
+    }
+}
+
+impl ::protobuf::Message for Cycle {
+    fn is_initialized(&self) -> bool {
+        for v in &self.path {
+            if !v.is_initialized() {
+                return false;
+            }
+        };
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_repeated_message_into(wire_type, is, &mut self.path)?;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        for value in &self.path {
+            let len = value.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        };
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        for v in &self.path {
+            os.write_tag(1, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+            os.write_raw_varint32(v.get_cached_size())?;
+            v.write_to_with_cached_sizes(os)?;
+        };
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> Cycle {
+        Cycle::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            let mut fields = ::std::vec::Vec::new();
+            fields.push(::protobuf::reflect::accessor::make_repeated_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<CellRef>>(
+                "path",
+                |m: &Cycle| { &m.path },
+                |m: &mut Cycle| { &mut m.path },
+            ));
+            ::protobuf::reflect::MessageDescriptor::new_pb_name::<Cycle>(
+                "Cycle",
+                fields,
+                file_descriptor_proto()
+            )
+        })
+    }
+
+    fn default_instance() -> &'static Cycle {
+        static instance: ::protobuf::rt::LazyV2<Cycle> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(Cycle::new)
+    }
+}
+
+impl ::protobuf::Clear for Cycle {
+    fn clear(&mut self) {
+        self.path.clear();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for Cycle {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for Cycle {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct GetCyclesRequest {
+    // message fields
+    pub tableId: ::std::string::String,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a GetCyclesRequest {
+    fn default() -> &'a GetCyclesRequest {
+        <GetCyclesRequest as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl GetCyclesRequest {
+    pub fn new() -> GetCyclesRequest {
+        ::std::default::Default::default()
+    }
+
+    // string tableId = 1;
+
+
+    pub fn get_tableId(&self) -> &str {
+        &self.tableId
+    }
+    pub fn clear_tableId(&mut self) {
+        self.tableId.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_tableId(&mut self, v: ::std::string::String) {
+        self.tableId = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_tableId(&mut self) -> &mut ::std::string::String {
+        &mut self.tableId
+    }
+
+    // Take field
+    pub fn take_tableId(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.tableId, ::std::string::String::new())
+    }
+}
+
+impl ::protobuf::Message for GetCyclesRequest {
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.tableId)?;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if !self.tableId.is_empty() {
+            my_size += ::protobuf::rt::string_size(1, &self.tableId);
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        if !self.tableId.is_empty() {
+            os.write_string(1, &self.tableId)?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> GetCyclesRequest {
+        GetCyclesRequest::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            let mut fields = ::std::vec::Vec::new();
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "tableId",
+                |m: &GetCyclesRequest| { &m.tableId },
+                |m: &mut GetCyclesRequest| { &mut m.tableId },
+            ));
+            ::protobuf::reflect::MessageDescriptor::new_pb_name::<GetCyclesRequest>(
+                "GetCyclesRequest",
+                fields,
+                file_descriptor_proto()
+            )
+        })
+    }
+
+    fn default_instance() -> &'static GetCyclesRequest {
+        static instance: ::protobuf::rt::LazyV2<GetCyclesRequest> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(GetCyclesRequest::new)
+    }
+}
+
+impl ::protobuf::Clear for GetCyclesRequest {
+    fn clear(&mut self) {
+        self.tableId.clear();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for GetCyclesRequest {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for GetCyclesRequest {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct GetCyclesResponse {
+    // message fields
+    pub cycles: ::protobuf::RepeatedField<Cycle>,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a GetCyclesResponse {
+    fn default() -> &'a GetCyclesResponse {
+        <GetCyclesResponse as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl GetCyclesResponse {
+    pub fn new() -> GetCyclesResponse {
+        ::std::default::Default::default()
+    }
+
+    // repeated .spreadsheet.Cycle cycles = 1;
+
+
+    pub fn get_cycles(&self) -> &[Cycle] {
+        &self.cycles
+    }
+    pub fn clear_cycles(&mut self) {
+        self.cycles.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_cycles(&mut self, v: ::protobuf::RepeatedField<Cycle>) {
+        self.cycles = v;
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_cycles(&mut self) -> &mut ::protobuf::RepeatedField<Cycle> {
+        &mut self.cycles
+    }
+
+    // Take field
+    pub fn take_cycles(&mut self) -> ::protobuf::RepeatedField<Cycle> {
+        ::std::mem::replace(&mut self.cycles, ::protobuf::RepeatedField::new())
+    }
+}
+
+impl ::protobuf::Message for GetCyclesResponse {
+    fn is_initialized(&self) -> bool {
+        for v in &self.cycles {
+            if !v.is_initialized() {
+                return false;
+            }
+        };
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_repeated_message_into(wire_type, is, &mut self.cycles)?;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        for value in &self.cycles {
+            let len = value.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        };
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        for v in &self.cycles {
+            os.write_tag(1, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+            os.write_raw_varint32(v.get_cached_size())?;
+            v.write_to_with_cached_sizes(os)?;
+        };
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> GetCyclesResponse {
+        GetCyclesResponse::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            let mut fields = ::std::vec::Vec::new();
+            fields.push(::protobuf::reflect::accessor::make_repeated_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<Cycle>>(
+                "cycles",
+                |m: &GetCyclesResponse| { &m.cycles },
+                |m: &mut GetCyclesResponse| { &mut m.cycles },
+            ));
+            ::protobuf::reflect::MessageDescriptor::new_pb_name::<GetCyclesResponse>(
+                "GetCyclesResponse",
+                fields,
+                file_descriptor_proto()
+            )
+        })
+    }
+
+    fn default_instance() -> &'static GetCyclesResponse {
+        static instance: ::protobuf::rt::LazyV2<GetCyclesResponse> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(GetCyclesResponse::new)
+    }
+}
+
+impl ::protobuf::Clear for GetCyclesResponse {
+    fn clear(&mut self) {
+        self.cycles.clear();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for GetCyclesResponse {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for GetCyclesResponse {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct SetCalculationModeRequest {
+    // message fields
+    pub tableId: ::std::string::String,
+    pub mode: CalculationMode,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a SetCalculationModeRequest {
+    fn default() -> &'a SetCalculationModeRequest {
+        <SetCalculationModeRequest as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl SetCalculationModeRequest {
+    pub fn new() -> SetCalculationModeRequest {
+        ::std::default::Default::default()
+    }
+
+    // string tableId = 1;
+
+
+    pub fn get_tableId(&self) -> &str {
+        &self.tableId
+    }
+    pub fn clear_tableId(&mut self) {
+        self.tableId.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_tableId(&mut self, v: ::std::string::String) {
+        self.tableId = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_tableId(&mut self) -> &mut ::std::string::String {
+        &mut self.tableId
+    }
+
+    // Take field
+    pub fn take_tableId(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.tableId, ::std::string::String::new())
+    }
+
+    // .spreadsheet.CalculationMode mode = 2;
+
+
+    pub fn get_mode(&self) -> CalculationMode {
+        self.mode
+    }
+    pub fn clear_mode(&mut self) {
+        self.mode = CalculationMode::CALCULATION_AUTOMATIC;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_mode(&mut self, v: CalculationMode) {
+        self.mode = v;
+    }
+}
+
+impl ::protobuf::Message for SetCalculationModeRequest {
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.tableId)?;
+                },
+                2 => {
+                    ::protobuf::rt::read_proto3_enum_with_unknown_fields_into(wire_type, is, &mut self.mode, 2, &mut self.unknown_fields)?
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if !self.tableId.is_empty() {
+            my_size += ::protobuf::rt::string_size(1, &self.tableId);
+        }
+        if self.mode != CalculationMode::CALCULATION_AUTOMATIC {
+            my_size += ::protobuf::rt::enum_size(2, self.mode);
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        if !self.tableId.is_empty() {
+            os.write_string(1, &self.tableId)?;
+        }
+        if self.mode != CalculationMode::CALCULATION_AUTOMATIC {
+            os.write_enum(2, ::protobuf::ProtobufEnum::value(&self.mode))?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> SetCalculationModeRequest {
+        SetCalculationModeRequest::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            let mut fields = ::std::vec::Vec::new();
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "tableId",
+                |m: &SetCalculationModeRequest| { &m.tableId },
+                |m: &mut SetCalculationModeRequest| { &mut m.tableId },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeEnum<CalculationMode>>(
+                "mode",
+                |m: &SetCalculationModeRequest| { &m.mode },
+                |m: &mut SetCalculationModeRequest| { &mut m.mode },
+            ));
+            ::protobuf::reflect::MessageDescriptor::new_pb_name::<SetCalculationModeRequest>(
+                "SetCalculationModeRequest",
+                fields,
+                file_descriptor_proto()
+            )
+        })
+    }
+
+    fn default_instance() -> &'static SetCalculationModeRequest {
+        static instance: ::protobuf::rt::LazyV2<SetCalculationModeRequest> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(SetCalculationModeRequest::new)
+    }
+}
+
+impl ::protobuf::Clear for SetCalculationModeRequest {
+    fn clear(&mut self) {
+        self.tableId.clear();
+        self.mode = CalculationMode::CALCULATION_AUTOMATIC;
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for SetCalculationModeRequest {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for SetCalculationModeRequest {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct SetCalculationModeResponse {
+    // message fields
+    pub cells: ::protobuf::RepeatedField<Cell>,
+    pub styles: ::std::collections::HashMap<u32, Style>,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a SetCalculationModeResponse {
+    fn default() -> &'a SetCalculationModeResponse {
+        <SetCalculationModeResponse as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl SetCalculationModeResponse {
+    pub fn new() -> SetCalculationModeResponse {
+        ::std::default::Default::default()
+    }
+
+    // repeated .spreadsheet.Cell cells = 1;
+
+
+    pub fn get_cells(&self) -> &[Cell] {
+        &self.cells
+    }
+    pub fn clear_cells(&mut self) {
+        self.cells.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_cells(&mut self, v: ::protobuf::RepeatedField<Cell>) {
+        self.cells = v;
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_cells(&mut self) -> &mut ::protobuf::RepeatedField<Cell> {
+        &mut self.cells
+    }
+
+    // Take field
+    pub fn take_cells(&mut self) -> ::protobuf::RepeatedField<Cell> {
+        ::std::mem::replace(&mut self.cells, ::protobuf::RepeatedField::new())
+    }
+
+    // repeated .spreadsheet.SetCalculationModeResponse.StylesEntry styles = 2;
+
+
+    pub fn get_styles(&self) -> &::std::collections::HashMap<u32, Style> {
+        &self.styles
+    }
+    pub fn clear_styles(&mut self) {
+        self.styles.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_styles(&mut self, v: ::std::collections::HashMap<u32, Style>) {
+        self.styles = v;
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_styles(&mut self) -> &mut ::std::collections::HashMap<u32, Style> {
+        &mut self.styles
+    }
+
+    // Take field
+    pub fn take_styles(&mut self) -> ::std::collections::HashMap<u32, Style> {
+        ::std::mem::replace(&mut self.styles, ::std::collections::HashMap::new())
+    }
+}
+
+impl ::protobuf::Message for SetCalculationModeResponse {
+    fn is_initialized(&self) -> bool {
+        for v in &self.cells {
+            if !v.is_initialized() {
+                return false;
+            }
+        };
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_repeated_message_into(wire_type, is, &mut self.cells)?;
+                },
+                2 => {
+                    ::protobuf::rt::read_map_into::<::protobuf::types::ProtobufTypeUint32, ::protobuf::types::ProtobufTypeMessage<Style>>(wire_type, is, &mut self.styles)?;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        for value in &self.cells {
+            let len = value.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        };
+        my_size += ::protobuf::rt::compute_map_size::<::protobuf::types::ProtobufTypeUint32, ::protobuf::types::ProtobufTypeMessage<Style>>(2, &self.styles);
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        for v in &self.cells {
+            os.write_tag(1, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+            os.write_raw_varint32(v.get_cached_size())?;
+            v.write_to_with_cached_sizes(os)?;
+        };
+        ::protobuf::rt::write_map_with_cached_sizes::<::protobuf::types::ProtobufTypeUint32, ::protobuf::types::ProtobufTypeMessage<Style>>(2, &self.styles, os)?;
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> SetCalculationModeResponse {
+        SetCalculationModeResponse::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            let mut fields = ::std::vec::Vec::new();
+            fields.push(::protobuf::reflect::accessor::make_repeated_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<Cell>>(
+                "cells",
+                |m: &SetCalculationModeResponse| { &m.cells },
+                |m: &mut SetCalculationModeResponse| { &mut m.cells },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_map_accessor::<_, ::protobuf::types::ProtobufTypeUint32, ::protobuf::types::ProtobufTypeMessage<Style>>(
+                "styles",
+                |m: &SetCalculationModeResponse| { &m.styles },
+                |m: &mut SetCalculationModeResponse| { &mut m.styles },
+            ));
+            ::protobuf::reflect::MessageDescriptor::new_pb_name::<SetCalculationModeResponse>(
+                "SetCalculationModeResponse",
+                fields,
+                file_descriptor_proto()
+            )
+        })
+    }
+
+    fn default_instance() -> &'static SetCalculationModeResponse {
+        static instance: ::protobuf::rt::LazyV2<SetCalculationModeResponse> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(SetCalculationModeResponse::new)
+    }
+}
+
+impl ::protobuf::Clear for SetCalculationModeResponse {
+    fn clear(&mut self) {
+        self.cells.clear();
+        self.styles.clear();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for SetCalculationModeResponse {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for SetCalculationModeResponse {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct ExportGraphRequest {
+    // message fields
+    pub tableId: ::std::string::String,
+    pub format: GraphFormat,
+    pub from: ::protobuf::SingularPtrField<CellRef>,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a ExportGraphRequest {
+    fn default() -> &'a ExportGraphRequest {
+        <ExportGraphRequest as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl ExportGraphRequest {
+    pub fn new() -> ExportGraphRequest {
+        ::std::default::Default::default()
+    }
+
+    // string tableId = 1;
+
+
+    pub fn get_tableId(&self) -> &str {
+        &self.tableId
+    }
+    pub fn clear_tableId(&mut self) {
+        self.tableId.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_tableId(&mut self, v: ::std::string::String) {
+        self.tableId = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_tableId(&mut self) -> &mut ::std::string::String {
+        &mut self.tableId
+    }
+
+    // Take field
+    pub fn take_tableId(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.tableId, ::std::string::String::new())
+    }
+
+    // .spreadsheet.GraphFormat format = 2;
+
+
+    pub fn get_format(&self) -> GraphFormat {
+        self.format
+    }
+    pub fn clear_format(&mut self) {
+        self.format = GraphFormat::GRAPH_DOT;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_format(&mut self, v: GraphFormat) {
+        self.format = v;
+    }
+
+    // .spreadsheet.CellRef from = 3;
+
+
+    pub fn get_from(&self) -> &CellRef {
+        self.from.as_ref().unwrap_or_else(|| <CellRef as ::protobuf::Message>::default_instance())
+    }
+    pub fn clear_from(&mut self) {
+        self.from.clear();
+    }
+
+    pub fn has_from(&self) -> bool {
+        self.from.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_from(&mut self, v: CellRef) {
+        self.from = ::protobuf::SingularPtrField::some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_from(&mut self) -> &mut CellRef {
+        if self.from.is_none() {
+            self.from.set_default();
+        }
+        self.from.as_mut().unwrap()
+    }
+
+    // Take field
+    pub fn take_from(&mut self) -> CellRef {
+        self.from.take().unwrap_or_else(|| CellRef::new())
+    }
+}
+
+impl ::protobuf::Message for ExportGraphRequest {
+    fn is_initialized(&self) -> bool {
+        for v in &self.from {
+            if !v.is_initialized() {
+                return false;
+            }
+        };
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.tableId)?;
+                },
+                2 => {
+                    ::protobuf::rt::read_proto3_enum_with_unknown_fields_into(wire_type, is, &mut self.format, 2, &mut self.unknown_fields)?
+                },
+                3 => {
+                    ::protobuf::rt::read_singular_message_into(wire_type, is, &mut self.from)?;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if !self.tableId.is_empty() {
+            my_size += ::protobuf::rt::string_size(1, &self.tableId);
+        }
+        if self.format != GraphFormat::GRAPH_DOT {
+            my_size += ::protobuf::rt::enum_size(2, self.format);
+        }
+        if let Some(ref v) = self.from.as_ref() {
+            let len = v.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        if !self.tableId.is_empty() {
+            os.write_string(1, &self.tableId)?;
+        }
+        if self.format != GraphFormat::GRAPH_DOT {
+            os.write_enum(2, ::protobuf::ProtobufEnum::value(&self.format))?;
+        }
+        if let Some(ref v) = self.from.as_ref() {
+            os.write_tag(3, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+            os.write_raw_varint32(v.get_cached_size())?;
+            v.write_to_with_cached_sizes(os)?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> ExportGraphRequest {
+        ExportGraphRequest::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            let mut fields = ::std::vec::Vec::new();
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "tableId",
+                |m: &ExportGraphRequest| { &m.tableId },
+                |m: &mut ExportGraphRequest| { &mut m.tableId },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeEnum<GraphFormat>>(
+                "format",
+                |m: &ExportGraphRequest| { &m.format },
+                |m: &mut ExportGraphRequest| { &mut m.format },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_singular_ptr_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<CellRef>>(
+                "from",
+                |m: &ExportGraphRequest| { &m.from },
+                |m: &mut ExportGraphRequest| { &mut m.from },
+            ));
+            ::protobuf::reflect::MessageDescriptor::new_pb_name::<ExportGraphRequest>(
+                "ExportGraphRequest",
+                fields,
+                file_descriptor_proto()
+            )
+        })
+    }
+
+    fn default_instance() -> &'static ExportGraphRequest {
+        static instance: ::protobuf::rt::LazyV2<ExportGraphRequest> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(ExportGraphRequest::new)
+    }
+}
+
+impl ::protobuf::Clear for ExportGraphRequest {
+    fn clear(&mut self) {
+        self.tableId.clear();
+        self.format = GraphFormat::GRAPH_DOT;
+        self.from.clear();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for ExportGraphRequest {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for ExportGraphRequest {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct ExportGraphResponse {
+    // message fields
+    pub graph: ::std::string::String,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a ExportGraphResponse {
+    fn default() -> &'a ExportGraphResponse {
+        <ExportGraphResponse as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl ExportGraphResponse {
+    pub fn new() -> ExportGraphResponse {
+        ::std::default::Default::default()
+    }
+
+    // string graph = 1;
+
+
+    pub fn get_graph(&self) -> &str {
+        &self.graph
+    }
+    pub fn clear_graph(&mut self) {
+        self.graph.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_graph(&mut self, v: ::std::string::String) {
+        self.graph = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_graph(&mut self) -> &mut ::std::string::String {
+        &mut self.graph
+    }
+
+    // Take field
+    pub fn take_graph(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.graph, ::std::string::String::new())
+    }
+}
+
+impl ::protobuf::Message for ExportGraphResponse {
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.graph)?;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if !self.graph.is_empty() {
+            my_size += ::protobuf::rt::string_size(1, &self.graph);
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        if !self.graph.is_empty() {
+            os.write_string(1, &self.graph)?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> ExportGraphResponse {
+        ExportGraphResponse::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            let mut fields = ::std::vec::Vec::new();
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "graph",
+                |m: &ExportGraphResponse| { &m.graph },
+                |m: &mut ExportGraphResponse| { &mut m.graph },
+            ));
+            ::protobuf::reflect::MessageDescriptor::new_pb_name::<ExportGraphResponse>(
+                "ExportGraphResponse",
+                fields,
+                file_descriptor_proto()
+            )
+        })
+    }
+
+    fn default_instance() -> &'static ExportGraphResponse {
+        static instance: ::protobuf::rt::LazyV2<ExportGraphResponse> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(ExportGraphResponse::new)
+    }
+}
+
+impl ::protobuf::Clear for ExportGraphResponse {
+    fn clear(&mut self) {
+        self.graph.clear();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for ExportGraphResponse {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for ExportGraphResponse {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+#[derive(Clone,PartialEq,Eq,Debug,Hash)]
+pub enum CopyMode {
+    COPY_ALL = 0,
+    COPY_VALUES = 1,
+    COPY_FORMULAS = 2,
+    COPY_FORMATS = 3,
+    COPY_TRANSPOSE = 4,
+}
+
+impl ::protobuf::ProtobufEnum for CopyMode {
+    fn value(&self) -> i32 {
+        *self as i32
+    }
+
+    fn from_i32(value: i32) -> ::std::option::Option<CopyMode> {
+        match value {
+            0 => ::std::option::Option::Some(CopyMode::COPY_ALL),
+            1 => ::std::option::Option::Some(CopyMode::COPY_VALUES),
+            2 => ::std::option::Option::Some(CopyMode::COPY_FORMULAS),
+            3 => ::std::option::Option::Some(CopyMode::COPY_FORMATS),
+            4 => ::std::option::Option::Some(CopyMode::COPY_TRANSPOSE),
+            _ => ::std::option::Option::None
+        }
+    }
+
+    fn values() -> &'static [Self] {
+        static values: &'static [CopyMode] = &[
+            CopyMode::COPY_ALL,
+            CopyMode::COPY_VALUES,
+            CopyMode::COPY_FORMULAS,
+            CopyMode::COPY_FORMATS,
+            CopyMode::COPY_TRANSPOSE,
+        ];
+        values
+    }
+
+    fn enum_descriptor_static() -> &'static ::protobuf::reflect::EnumDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::EnumDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            ::protobuf::reflect::EnumDescriptor::new_pb_name::<CopyMode>("CopyMode", file_descriptor_proto())
+        })
+    }
+}
+
+impl ::std::marker::Copy for CopyMode {
+}
+
+impl ::std::default::Default for CopyMode {
+    fn default() -> Self {
+        CopyMode::COPY_ALL
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for CopyMode {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Enum(::protobuf::ProtobufEnum::descriptor(self))
+    }
+}
+
+#[derive(Clone,PartialEq,Eq,Debug,Hash)]
+pub enum BorderStyle {
+    BORDER_NONE = 0,
+    BORDER_THIN = 1,
+    BORDER_MEDIUM = 2,
+    BORDER_THICK = 3,
+    BORDER_DASHED = 4,
+    BORDER_DOTTED = 5,
+    BORDER_DOUBLE = 6,
+}
+
+impl ::protobuf::ProtobufEnum for BorderStyle {
+    fn value(&self) -> i32 {
+        *self as i32
+    }
+
+    fn from_i32(value: i32) -> ::std::option::Option<BorderStyle> {
+        match value {
+            0 => ::std::option::Option::Some(BorderStyle::BORDER_NONE),
+            1 => ::std::option::Option::Some(BorderStyle::BORDER_THIN),
+            2 => ::std::option::Option::Some(BorderStyle::BORDER_MEDIUM),
+            3 => ::std::option::Option::Some(BorderStyle::BORDER_THICK),
+            4 => ::std::option::Option::Some(BorderStyle::BORDER_DASHED),
+            5 => ::std::option::Option::Some(BorderStyle::BORDER_DOTTED),
+            6 => ::std::option::Option::Some(BorderStyle::BORDER_DOUBLE),
+            _ => ::std::option::Option::None
+        }
+    }
+
+    fn values() -> &'static [Self] {
+        static values: &'static [BorderStyle] = &[
+            BorderStyle::BORDER_NONE,
+            BorderStyle::BORDER_THIN,
+            BorderStyle::BORDER_MEDIUM,
+            BorderStyle::BORDER_THICK,
+            BorderStyle::BORDER_DASHED,
+            BorderStyle::BORDER_DOTTED,
+            BorderStyle::BORDER_DOUBLE,
+        ];
+        values
+    }
+
+    fn enum_descriptor_static() -> &'static ::protobuf::reflect::EnumDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::EnumDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            ::protobuf::reflect::EnumDescriptor::new_pb_name::<BorderStyle>("BorderStyle", file_descriptor_proto())
+        })
+    }
+}
+
+impl ::std::marker::Copy for BorderStyle {
+}
+
+impl ::std::default::Default for BorderStyle {
+    fn default() -> Self {
+        BorderStyle::BORDER_NONE
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for BorderStyle {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Enum(::protobuf::ProtobufEnum::descriptor(self))
+    }
+}
+
+#[derive(Clone,PartialEq,Eq,Debug,Hash)]
+pub enum HorizontalAlignment {
+    HORIZONTAL_GENERAL = 0,
+    HORIZONTAL_LEFT = 1,
+    HORIZONTAL_CENTER = 2,
+    HORIZONTAL_RIGHT = 3,
+}
+
+impl ::protobuf::ProtobufEnum for HorizontalAlignment {
+    fn value(&self) -> i32 {
+        *self as i32
+    }
+
+    fn from_i32(value: i32) -> ::std::option::Option<HorizontalAlignment> {
+        match value {
+            0 => ::std::option::Option::Some(HorizontalAlignment::HORIZONTAL_GENERAL),
+            1 => ::std::option::Option::Some(HorizontalAlignment::HORIZONTAL_LEFT),
+            2 => ::std::option::Option::Some(HorizontalAlignment::HORIZONTAL_CENTER),
+            3 => ::std::option::Option::Some(HorizontalAlignment::HORIZONTAL_RIGHT),
+            _ => ::std::option::Option::None
+        }
+    }
+
+    fn values() -> &'static [Self] {
+        static values: &'static [HorizontalAlignment] = &[
+            HorizontalAlignment::HORIZONTAL_GENERAL,
+            HorizontalAlignment::HORIZONTAL_LEFT,
+            HorizontalAlignment::HORIZONTAL_CENTER,
+            HorizontalAlignment::HORIZONTAL_RIGHT,
+        ];
+        values
+    }
+
+    fn enum_descriptor_static() -> &'static ::protobuf::reflect::EnumDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::EnumDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            ::protobuf::reflect::EnumDescriptor::new_pb_name::<HorizontalAlignment>("HorizontalAlignment", file_descriptor_proto())
+        })
+    }
+}
+
+impl ::std::marker::Copy for HorizontalAlignment {
+}
+
+impl ::std::default::Default for HorizontalAlignment {
+    fn default() -> Self {
+        HorizontalAlignment::HORIZONTAL_GENERAL
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for HorizontalAlignment {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Enum(::protobuf::ProtobufEnum::descriptor(self))
+    }
+}
+
+#[derive(Clone,PartialEq,Eq,Debug,Hash)]
+pub enum VerticalAlignment {
+    VERTICAL_BOTTOM = 0,
+    VERTICAL_MIDDLE = 1,
+    VERTICAL_TOP = 2,
+}
+
+impl ::protobuf::ProtobufEnum for VerticalAlignment {
+    fn value(&self) -> i32 {
+        *self as i32
+    }
+
+    fn from_i32(value: i32) -> ::std::option::Option<VerticalAlignment> {
+        match value {
+            0 => ::std::option::Option::Some(VerticalAlignment::VERTICAL_BOTTOM),
+            1 => ::std::option::Option::Some(VerticalAlignment::VERTICAL_MIDDLE),
+            2 => ::std::option::Option::Some(VerticalAlignment::VERTICAL_TOP),
+            _ => ::std::option::Option::None
+        }
+    }
+
+    fn values() -> &'static [Self] {
+        static values: &'static [VerticalAlignment] = &[
+            VerticalAlignment::VERTICAL_BOTTOM,
+            VerticalAlignment::VERTICAL_MIDDLE,
+            VerticalAlignment::VERTICAL_TOP,
+        ];
+        values
+    }
+
+    fn enum_descriptor_static() -> &'static ::protobuf::reflect::EnumDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::EnumDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            ::protobuf::reflect::EnumDescriptor::new_pb_name::<VerticalAlignment>("VerticalAlignment", file_descriptor_proto())
+        })
+    }
+}
+
+impl ::std::marker::Copy for VerticalAlignment {
+}
+
+impl ::std::default::Default for VerticalAlignment {
+    fn default() -> Self {
+        VerticalAlignment::VERTICAL_BOTTOM
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for VerticalAlignment {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Enum(::protobuf::ProtobufEnum::descriptor(self))
+    }
+}
+
+#[derive(Clone,PartialEq,Eq,Debug,Hash)]
+pub enum Comparison {
+    COMPARISON_EQUAL = 0,
+    COMPARISON_NOT_EQUAL = 1,
+    COMPARISON_GREATER = 2,
+    COMPARISON_GREATER_OR_EQUAL = 3,
+    COMPARISON_LESS = 4,
+    COMPARISON_LESS_OR_EQUAL = 5,
+    COMPARISON_BETWEEN = 6,
+    COMPARISON_NOT_BETWEEN = 7,
+}
+
+impl ::protobuf::ProtobufEnum for Comparison {
+    fn value(&self) -> i32 {
+        *self as i32
+    }
+
+    fn from_i32(value: i32) -> ::std::option::Option<Comparison> {
+        match value {
+            0 => ::std::option::Option::Some(Comparison::COMPARISON_EQUAL),
+            1 => ::std::option::Option::Some(Comparison::COMPARISON_NOT_EQUAL),
+            2 => ::std::option::Option::Some(Comparison::COMPARISON_GREATER),
+            3 => ::std::option::Option::Some(Comparison::COMPARISON_GREATER_OR_EQUAL),
+            4 => ::std::option::Option::Some(Comparison::COMPARISON_LESS),
+            5 => ::std::option::Option::Some(Comparison::COMPARISON_LESS_OR_EQUAL),
+            6 => ::std::option::Option::Some(Comparison::COMPARISON_BETWEEN),
+            7 => ::std::option::Option::Some(Comparison::COMPARISON_NOT_BETWEEN),
+            _ => ::std::option::Option::None
+        }
+    }
+
+    fn values() -> &'static [Self] {
+        static values: &'static [Comparison] = &[
+            Comparison::COMPARISON_EQUAL,
+            Comparison::COMPARISON_NOT_EQUAL,
+            Comparison::COMPARISON_GREATER,
+            Comparison::COMPARISON_GREATER_OR_EQUAL,
+            Comparison::COMPARISON_LESS,
+            Comparison::COMPARISON_LESS_OR_EQUAL,
+            Comparison::COMPARISON_BETWEEN,
+            Comparison::COMPARISON_NOT_BETWEEN,
+        ];
+        values
+    }
+
+    fn enum_descriptor_static() -> &'static ::protobuf::reflect::EnumDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::EnumDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            ::protobuf::reflect::EnumDescriptor::new_pb_name::<Comparison>("Comparison", file_descriptor_proto())
+        })
+    }
+}
+
+impl ::std::marker::Copy for Comparison {
+}
+
+impl ::std::default::Default for Comparison {
+    fn default() -> Self {
+        Comparison::COMPARISON_EQUAL
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for Comparison {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Enum(::protobuf::ProtobufEnum::descriptor(self))
+    }
+}
+
+#[derive(Clone,PartialEq,Eq,Debug,Hash)]
+pub enum ValidationMode {
+    VALIDATION_REJECT = 0,
+    VALIDATION_WARN = 1,
+}
+
+impl ::protobuf::ProtobufEnum for ValidationMode {
+    fn value(&self) -> i32 {
+        *self as i32
+    }
+
+    fn from_i32(value: i32) -> ::std::option::Option<ValidationMode> {
+        match value {
+            0 => ::std::option::Option::Some(ValidationMode::VALIDATION_REJECT),
+            1 => ::std::option::Option::Some(ValidationMode::VALIDATION_WARN),
+            _ => ::std::option::Option::None
+        }
+    }
+
+    fn values() -> &'static [Self] {
+        static values: &'static [ValidationMode] = &[
+            ValidationMode::VALIDATION_REJECT,
+            ValidationMode::VALIDATION_WARN,
+        ];
+        values
+    }
+
+    fn enum_descriptor_static() -> &'static ::protobuf::reflect::EnumDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::EnumDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            ::protobuf::reflect::EnumDescriptor::new_pb_name::<ValidationMode>("ValidationMode", file_descriptor_proto())
+        })
+    }
+}
+
+impl ::std::marker::Copy for ValidationMode {
+}
+
+impl ::std::default::Default for ValidationMode {
+    fn default() -> Self {
+        ValidationMode::VALIDATION_REJECT
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for ValidationMode {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Enum(::protobuf::ProtobufEnum::descriptor(self))
+    }
+}
+
+#[derive(Clone,PartialEq,Eq,Debug,Hash)]
+pub enum CalculationMode {
+    CALCULATION_AUTOMATIC = 0,
+    CALCULATION_MANUAL = 1,
+    CALCULATION_ON_DEMAND = 2,
+}
+
+impl ::protobuf::ProtobufEnum for CalculationMode {
+    fn value(&self) -> i32 {
+        *self as i32
+    }
+
+    fn from_i32(value: i32) -> ::std::option::Option<CalculationMode> {
+        match value {
+            0 => ::std::option::Option::Some(CalculationMode::CALCULATION_AUTOMATIC),
+            1 => ::std::option::Option::Some(CalculationMode::CALCULATION_MANUAL),
+            2 => ::std::option::Option::Some(CalculationMode::CALCULATION_ON_DEMAND),
+            _ => ::std::option::Option::None
+        }
+    }
+
+    fn values() -> &'static [Self] {
+        static values: &'static [CalculationMode] = &[
+            CalculationMode::CALCULATION_AUTOMATIC,
+            CalculationMode::CALCULATION_MANUAL,
+            CalculationMode::CALCULATION_ON_DEMAND,
+        ];
+        values
+    }
+
+    fn enum_descriptor_static() -> &'static ::protobuf::reflect::EnumDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::EnumDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            ::protobuf::reflect::EnumDescriptor::new_pb_name::<CalculationMode>("CalculationMode", file_descriptor_proto())
+        })
+    }
+}
+
+impl ::std::marker::Copy for CalculationMode {
+}
+
+impl ::std::default::Default for CalculationMode {
+    fn default() -> Self {
+        CalculationMode::CALCULATION_AUTOMATIC
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for CalculationMode {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Enum(::protobuf::ProtobufEnum::descriptor(self))
+    }
+}
+
+#[derive(Clone,PartialEq,Eq,Debug,Hash)]
+pub enum GraphFormat {
+    GRAPH_DOT = 0,
+    GRAPH_JSON = 1,
+}
+
+impl ::protobuf::ProtobufEnum for GraphFormat {
+    fn value(&self) -> i32 {
+        *self as i32
+    }
+
+    fn from_i32(value: i32) -> ::std::option::Option<GraphFormat> {
+        match value {
+            0 => ::std::option::Option::Some(GraphFormat::GRAPH_DOT),
+            1 => ::std::option::Option::Some(GraphFormat::GRAPH_JSON),
+            _ => ::std::option::Option::None
+        }
+    }
+
+    fn values() -> &'static [Self] {
+        static values: &'static [GraphFormat] = &[
+            GraphFormat::GRAPH_DOT,
+            GraphFormat::GRAPH_JSON,
+        ];
+        values
+    }
+
+    fn enum_descriptor_static() -> &'static ::protobuf::reflect::EnumDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::EnumDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            ::protobuf::reflect::EnumDescriptor::new_pb_name::<GraphFormat>("GraphFormat", file_descriptor_proto())
+        })
+    }
+}
+
+impl ::std::marker::Copy for GraphFormat {
+}
+
+impl ::std::default::Default for GraphFormat {
+    fn default() -> Self {
+        GraphFormat::GRAPH_DOT
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for GraphFormat {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Enum(::protobuf::ProtobufEnum::descriptor(self))
+    }
+}
+
 static file_descriptor_proto_data: &'static [u8] = b"\
     \n\tapi.proto\x12\x0bspreadsheet\"v\n\x04Rect\x12\x1b\n\tstart_row\x18\
     \x01\x20\x01(\x05R\x08startRow\x12\x1b\n\tstart_col\x18\x02\x20\x01(\x05\
     R\x08startCol\x12\x19\n\x08stop_row\x18\x03\x20\x01(\x05R\x07stopRow\x12\
     \x19\n\x08stop_col\x18\x04\x20\x01(\x05R\x07stopCol\"F\n\nInsertCell\x12\
     \x10\n\x03row\x18\x01\x20\x01(\x05R\x03row\x12\x10\n\x03col\x18\x02\x20\
-    \x01(\x05R\x03col\x12\x14\n\x05value\x18\x03\x20\x01(\tR\x05value\"e\n\
-    \x04Cell\x12\x10\n\x03row\x18\x01\x20\x01(\x05R\x03row\x12\x10\n\x03col\
-    \x18\x02\x20\x01(\x05R\x03col\x12\x14\n\x05value\x18\x03\x20\x01(\tR\x05\
-    value\x12#\n\rdisplay_value\x18\x04\x20\x01(\tR\x0cdisplayValue\"]\n\x12\
-    InsertCellsRequest\x12\x18\n\x07tableId\x18\x01\x20\x01(\tR\x07tableId\
-    \x12-\n\x05cells\x18\x02\x20\x03(\x0b2\x17.spreadsheet.InsertCellR\x05ce\
-    lls\">\n\x13InsertCellsResponse\x12'\n\x05cells\x18\x01\x20\x03(\x0b2\
-    \x11.spreadsheet.CellR\x05cells\"R\n\x0fGetCellsRequest\x12\x18\n\x07tab\
-    leId\x18\x01\x20\x01(\tR\x07tableId\x12%\n\x04rect\x18\x02\x20\x01(\x0b2\
-    \x11.spreadsheet.RectR\x04rect\";\n\x10GetCellsResponse\x12'\n\x05cells\
-    \x18\x01\x20\x03(\x0b2\x11.spreadsheet.CellR\x05cells2\xab\x01\n\x0eSpre\
-    adsheetAPI\x12P\n\x0bInsertCells\x12\x1f.spreadsheet.InsertCellsRequest\
-    \x1a\x20.spreadsheet.InsertCellsResponse\x12G\n\x08GetCells\x12\x1c.spre\
-    adsheet.GetCellsRequest\x1a\x1d.spreadsheet.GetCellsResponseb\x06proto3\
+    \x01(\x05R\x03col\x12\x14\n\x05value\x18\x03\x20\x01(\tR\x05value\"\xf8\
+    \x02\n\x04Cell\x12\x10\n\x03row\x18\x01\x20\x01(\x05R\x03row\x12\x10\n\
+    \x03col\x18\x02\x20\x01(\x05R\x03col\x12\x14\n\x05value\x18\x03\x20\x01(\
+    \tR\x05value\x12#\n\rdisplay_value\x18\x04\x20\x01(\tR\x0cdisplayValue\
+    \x12/\n\x05empty\x18\x05\x20\x01(\x0b2\x17.spreadsheet.EmptyValueH\0R\
+    \x05empty\x12\x18\n\x06number\x18\x06\x20\x01(\x01H\0R\x06number\x12\x14\
+    \n\x04text\x18\x07\x20\x01(\tH\0R\x04text\x12\x14\n\x04bool\x18\x08\x20\
+    \x01(\x08H\0R\x04bool\x12\x16\n\x05error\x18\t\x20\x01(\tH\0R\x05error\
+    \x12\x14\n\x04date\x18\n\x20\x01(\x01H\0R\x04date\x12\x19\n\x08style_id\
+    \x18\x0b\x20\x01(\rR\x07styleId\x12,\n\x12effective_style_id\x18\x0c\x20\
+    \x01(\rR\x10effectiveStyleId\x12\x14\n\x05stale\x18\r\x20\x01(\x08R\x05s\
+    taleB\r\n\x0btyped_value\"\x0c\n\nEmptyValue\"]\n\x12InsertCellsRequest\
+    \x12\x18\n\x07tableId\x18\x01\x20\x01(\tR\x07tableId\x12-\n\x05cells\x18\
+    \x02\x20\x03(\x0b2\x17.spreadsheet.InsertCellR\x05cells\"\x89\x02\n\x13I\
+    nsertCellsResponse\x12'\n\x05cells\x18\x01\x20\x03(\x0b2\x11.spreadsheet\
+    .CellR\x05cells\x12D\n\x06styles\x18\x02\x20\x03(\x0b2,.spreadsheet.Inse\
+    rtCellsResponse.StylesEntryR\x06styles\x124\n\x06errors\x18\x03\x20\x03(\
+    \x0b2\x1c.spreadsheet.ValidationErrorR\x06errors\x1aM\n\x0bStylesEntry\
+    \x12\x10\n\x03key\x18\x01\x20\x01(\rR\x03key\x12(\n\x05value\x18\x02\x20\
+    \x01(\x0b2\x12.spreadsheet.StyleR\x05value:\x028\x01\"R\n\x0fGetCellsReq\
+    uest\x12\x18\n\x07tableId\x18\x01\x20\x01(\tR\x07tableId\x12%\n\x04rect\
+    \x18\x02\x20\x01(\x0b2\x11.spreadsheet.RectR\x04rect\"\xcd\x01\n\x10GetC\
+    ellsResponse\x12'\n\x05cells\x18\x01\x20\x03(\x0b2\x11.spreadsheet.CellR\
+    \x05cells\x12A\n\x06styles\x18\x02\x20\x03(\x0b2).spreadsheet.GetCellsRe\
+    sponse.StylesEntryR\x06styles\x1aM\n\x0bStylesEntry\x12\x10\n\x03key\x18\
+    \x01\x20\x01(\rR\x03key\x12(\n\x05value\x18\x02\x20\x01(\x0b2\x12.spread\
+    sheet.StyleR\x05value:\x028\x01\"U\n\x12RecalculateRequest\x12\x18\n\x07\
+    tableId\x18\x01\x20\x01(\tR\x07tableId\x12%\n\x04rect\x18\x02\x20\x01(\
+    \x0b2\x11.spreadsheet.RectR\x04rect\"\xd3\x01\n\x13RecalculateResponse\
+    \x12'\n\x05cells\x18\x01\x20\x03(\x0b2\x11.spreadsheet.CellR\x05cells\
+    \x12D\n\x06styles\x18\x02\x20\x03(\x0b2,.spreadsheet.RecalculateResponse\
+    .StylesEntryR\x06styles\x1aM\n\x0bStylesEntry\x12\x10\n\x03key\x18\x01\
+    \x20\x01(\rR\x03key\x12(\n\x05value\x18\x02\x20\x01(\x0b2\x12.spreadshee\
+    t.StyleR\x05value:\x028\x01\"T\n\x11ClearCellsRequest\x12\x18\n\x07table\
+    Id\x18\x01\x20\x01(\tR\x07tableId\x12%\n\x04rect\x18\x02\x20\x01(\x0b2\
+    \x11.spreadsheet.RectR\x04rect\"\xd1\x01\n\x12ClearCellsResponse\x12'\n\
+    \x05cells\x18\x01\x20\x03(\x0b2\x11.spreadsheet.CellR\x05cells\x12C\n\
+    \x06styles\x18\x02\x20\x03(\x0b2+.spreadsheet.ClearCellsResponse.StylesE\
+    ntryR\x06styles\x1aM\n\x0bStylesEntry\x12\x10\n\x03key\x18\x01\x20\x01(\
+    \rR\x03key\x12(\n\x05value\x18\x02\x20\x01(\x0b2\x12.spreadsheet.StyleR\
+    \x05value:\x028\x01\"S\n\x11InsertRowsRequest\x12\x18\n\x07tableId\x18\
+    \x01\x20\x01(\tR\x07tableId\x12\x0e\n\x02at\x18\x02\x20\x01(\x05R\x02at\
+    \x12\x14\n\x05count\x18\x03\x20\x01(\x05R\x05count\"\xd1\x01\n\x12Insert\
+    RowsResponse\x12'\n\x05cells\x18\x01\x20\x03(\x0b2\x11.spreadsheet.CellR\
+    \x05cells\x12C\n\x06styles\x18\x02\x20\x03(\x0b2+.spreadsheet.InsertRows\
+    Response.StylesEntryR\x06styles\x1aM\n\x0bStylesEntry\x12\x10\n\x03key\
+    \x18\x01\x20\x01(\rR\x03key\x12(\n\x05value\x18\x02\x20\x01(\x0b2\x12.sp\
+    readsheet.StyleR\x05value:\x028\x01\"S\n\x11DeleteRowsRequest\x12\x18\n\
+    \x07tableId\x18\x01\x20\x01(\tR\x07tableId\x12\x0e\n\x02at\x18\x02\x20\
+    \x01(\x05R\x02at\x12\x14\n\x05count\x18\x03\x20\x01(\x05R\x05count\"\xd1\
+    \x01\n\x12DeleteRowsResponse\x12'\n\x05cells\x18\x01\x20\x03(\x0b2\x11.s\
+    preadsheet.CellR\x05cells\x12C\n\x06styles\x18\x02\x20\x03(\x0b2+.spread\
+    sheet.DeleteRowsResponse.StylesEntryR\x06styles\x1aM\n\x0bStylesEntry\
+    \x12\x10\n\x03key\x18\x01\x20\x01(\rR\x03key\x12(\n\x05value\x18\x02\x20\
+    \x01(\x0b2\x12.spreadsheet.StyleR\x05value:\x028\x01\"V\n\x14InsertColum\
+    nsRequest\x12\x18\n\x07tableId\x18\x01\x20\x01(\tR\x07tableId\x12\x0e\n\
+    \x02at\x18\x02\x20\x01(\x05R\x02at\x12\x14\n\x05count\x18\x03\x20\x01(\
+    \x05R\x05count\"\xd7\x01\n\x15InsertColumnsResponse\x12'\n\x05cells\x18\
+    \x01\x20\x03(\x0b2\x11.spreadsheet.CellR\x05cells\x12F\n\x06styles\x18\
+    \x02\x20\x03(\x0b2..spreadsheet.InsertColumnsResponse.StylesEntryR\x06st\
+    yles\x1aM\n\x0bStylesEntry\x12\x10\n\x03key\x18\x01\x20\x01(\rR\x03key\
+    \x12(\n\x05value\x18\x02\x20\x01(\x0b2\x12.spreadsheet.StyleR\x05value:\
+    \x028\x01\"V\n\x14DeleteColumnsRequest\x12\x18\n\x07tableId\x18\x01\x20\
+    \x01(\tR\x07tableId\x12\x0e\n\x02at\x18\x02\x20\x01(\x05R\x02at\x12\x14\
+    \n\x05count\x18\x03\x20\x01(\x05R\x05count\"\xd7\x01\n\x15DeleteColumnsR\
+    esponse\x12'\n\x05cells\x18\x01\x20\x03(\x0b2\x11.spreadsheet.CellR\x05c\
+    ells\x12F\n\x06styles\x18\x02\x20\x03(\x0b2..spreadsheet.DeleteColumnsRe\
+    sponse.StylesEntryR\x06styles\x1aM\n\x0bStylesEntry\x12\x10\n\x03key\x18\
+    \x01\x20\x01(\rR\x03key\x12(\n\x05value\x18\x02\x20\x01(\x0b2\x12.spread\
+    sheet.StyleR\x05value:\x028\x01\"{\n\x10MoveRangeRequest\x12\x18\n\x07ta\
+    bleId\x18\x01\x20\x01(\tR\x07tableId\x12)\n\x06source\x18\x02\x20\x01(\
+    \x0b2\x11.spreadsheet.RectR\x06source\x12\x10\n\x03row\x18\x03\x20\x01(\
+    \x05R\x03row\x12\x10\n\x03col\x18\x04\x20\x01(\x05R\x03col\"\xcf\x01\n\
+    \x11MoveRangeResponse\x12'\n\x05cells\x18\x01\x20\x03(\x0b2\x11.spreadsh\
+    eet.CellR\x05cells\x12B\n\x06styles\x18\x02\x20\x03(\x0b2*.spreadsheet.M\
+    oveRangeResponse.StylesEntryR\x06styles\x1aM\n\x0bStylesEntry\x12\x10\n\
+    \x03key\x18\x01\x20\x01(\rR\x03key\x12(\n\x05value\x18\x02\x20\x01(\x0b2\
+    \x12.spreadsheet.StyleR\x05value:\x028\x01\"\xa6\x01\n\x10CopyRangeReque\
+    st\x12\x18\n\x07tableId\x18\x01\x20\x01(\tR\x07tableId\x12)\n\x06source\
+    \x18\x02\x20\x01(\x0b2\x11.spreadsheet.RectR\x06source\x12\x10\n\x03row\
+    \x18\x03\x20\x01(\x05R\x03row\x12\x10\n\x03col\x18\x04\x20\x01(\x05R\x03\
+    col\x12)\n\x04mode\x18\x05\x20\x01(\x0e2\x15.spreadsheet.CopyModeR\x04mo\
+    de\"\xcf\x01\n\x11CopyRangeResponse\x12'\n\x05cells\x18\x01\x20\x03(\x0b\
+    2\x11.spreadsheet.CellR\x05cells\x12B\n\x06styles\x18\x02\x20\x03(\x0b2*\
+    .spreadsheet.CopyRangeResponse.StylesEntryR\x06styles\x1aM\n\x0bStylesEn\
+    try\x12\x10\n\x03key\x18\x01\x20\x01(\rR\x03key\x12(\n\x05value\x18\x02\
+    \x20\x01(\x0b2\x12.spreadsheet.StyleR\x05value:\x028\x01\"}\n\x0bFillReq\
+    uest\x12\x18\n\x07tableId\x18\x01\x20\x01(\tR\x07tableId\x12)\n\x06sourc\
+    e\x18\x02\x20\x01(\x0b2\x11.spreadsheet.RectR\x06source\x12)\n\x06target\
+    \x18\x03\x20\x01(\x0b2\x11.spreadsheet.RectR\x06target\"\xc5\x01\n\x0cFi\
+    llResponse\x12'\n\x05cells\x18\x01\x20\x03(\x0b2\x11.spreadsheet.CellR\
+    \x05cells\x12=\n\x06styles\x18\x02\x20\x03(\x0b2%.spreadsheet.FillRespon\
+    se.StylesEntryR\x06styles\x1aM\n\x0bStylesEntry\x12\x10\n\x03key\x18\x01\
+    \x20\x01(\rR\x03key\x12(\n\x05value\x18\x02\x20\x01(\x0b2\x12.spreadshee\
+    t.StyleR\x05value:\x028\x01\"\x98\x01\n\x1eSetIterativeCalculationReques\
+    t\x12\x18\n\x07tableId\x18\x01\x20\x01(\tR\x07tableId\x12\x18\n\x07enabl\
+    ed\x18\x02\x20\x01(\x08R\x07enabled\x12$\n\rmaxIterations\x18\x03\x20\
+    \x01(\rR\rmaxIterations\x12\x1c\n\tmaxChange\x18\x04\x20\x01(\x01R\tmaxC\
+    hange\"\xeb\x01\n\x1fSetIterativeCalculationResponse\x12'\n\x05cells\x18\
+    \x01\x20\x03(\x0b2\x11.spreadsheet.CellR\x05cells\x12P\n\x06styles\x18\
+    \x02\x20\x03(\x0b28.spreadsheet.SetIterativeCalculationResponse.StylesEn\
+    tryR\x06styles\x1aM\n\x0bStylesEntry\x12\x10\n\x03key\x18\x01\x20\x01(\r\
+    R\x03key\x12(\n\x05value\x18\x02\x20\x01(\x0b2\x12.spreadsheet.StyleR\
+    \x05value:\x028\x01\"D\n\x10SetLocaleRequest\x12\x18\n\x07tableId\x18\
+    \x01\x20\x01(\tR\x07tableId\x12\x16\n\x06locale\x18\x02\x20\x01(\tR\x06l\
+    ocale\"\xcf\x01\n\x11SetLocaleResponse\x12'\n\x05cells\x18\x01\x20\x03(\
+    \x0b2\x11.spreadsheet.CellR\x05cells\x12B\n\x06styles\x18\x02\x20\x03(\
+    \x0b2*.spreadsheet.SetLocaleResponse.StylesEntryR\x06styles\x1aM\n\x0bSt\
+    ylesEntry\x12\x10\n\x03key\x18\x01\x20\x01(\rR\x03key\x12(\n\x05value\
+    \x18\x02\x20\x01(\x0b2\x12.spreadsheet.StyleR\x05value:\x028\x01\"k\n\
+    \x10SetFormatRequest\x12\x18\n\x07tableId\x18\x01\x20\x01(\tR\x07tableId\
+    \x12%\n\x04rect\x18\x02\x20\x01(\x0b2\x11.spreadsheet.RectR\x04rect\x12\
+    \x16\n\x06format\x18\x03\x20\x01(\tR\x06format\"<\n\x11SetFormatResponse\
+    \x12'\n\x05cells\x18\x01\x20\x03(\x0b2\x11.spreadsheet.CellR\x05cells\"\
+    \xd3\x04\n\x05Style\x12\x12\n\x04bold\x18\x01\x20\x01(\x08R\x04bold\x12\
+    \x16\n\x06italic\x18\x02\x20\x01(\x08R\x06italic\x12\x1b\n\tfont_size\
+    \x18\x03\x20\x01(\rR\x08fontSize\x12\x1d\n\ntext_color\x18\x04\x20\x01(\
+    \tR\ttextColor\x12\x1d\n\nfill_color\x18\x05\x20\x01(\tR\tfillColor\x122\
+    \n\ntop_border\x18\x06\x20\x01(\x0b2\x13.spreadsheet.BorderR\ttopBorder\
+    \x128\n\rbottom_border\x18\x07\x20\x01(\x0b2\x13.spreadsheet.BorderR\x0c\
+    bottomBorder\x124\n\x0bleft_border\x18\x08\x20\x01(\x0b2\x13.spreadsheet\
+    .BorderR\nleftBorder\x126\n\x0cright_border\x18\t\x20\x01(\x0b2\x13.spre\
+    adsheet.BorderR\x0brightBorder\x12S\n\x14horizontal_alignment\x18\n\x20\
+    \x01(\x0e2\x20.spreadsheet.HorizontalAlignmentR\x13horizontalAlignment\
+    \x12M\n\x12vertical_alignment\x18\x0b\x20\x01(\x0e2\x1e.spreadsheet.Vert\
+    icalAlignmentR\x11verticalAlignment\x12\x12\n\x04wrap\x18\x0c\x20\x01(\
+    \x08R\x04wrap\x12/\n\x08data_bar\x18\r\x20\x01(\x0b2\x14.spreadsheet.Dat\
+    aBarR\x07dataBar\"9\n\x07DataBar\x12\x14\n\x05color\x18\x01\x20\x01(\tR\
+    \x05color\x12\x18\n\x07percent\x18\x02\x20\x01(\rR\x07percent\"N\n\x06Bo\
+    rder\x12.\n\x05style\x18\x01\x20\x01(\x0e2\x18.spreadsheet.BorderStyleR\
+    \x05style\x12\x14\n\x05color\x18\x02\x20\x01(\tR\x05color\"\x95\x01\n\
+    \x10SetStylesRequest\x12\x18\n\x07tableId\x18\x01\x20\x01(\tR\x07tableId\
+    \x12%\n\x04rect\x18\x02\x20\x01(\x0b2\x11.spreadsheet.RectR\x04rect\x12(\
+    \n\x05style\x18\x03\x20\x01(\x0b2\x12.spreadsheet.StyleR\x05style\x12\
+    \x16\n\x06fields\x18\x04\x20\x03(\tR\x06fields\"\xcf\x01\n\x11SetStylesR\
+    esponse\x12'\n\x05cells\x18\x01\x20\x03(\x0b2\x11.spreadsheet.CellR\x05c\
+    ells\x12B\n\x06styles\x18\x02\x20\x03(\x0b2*.spreadsheet.SetStylesRespon\
+    se.StylesEntryR\x06styles\x1aM\n\x0bStylesEntry\x12\x10\n\x03key\x18\x01\
+    \x20\x01(\rR\x03key\x12(\n\x05value\x18\x02\x20\x01(\x0b2\x12.spreadshee\
+    t.StyleR\x05value:\x028\x01\"\xdc\x02\n\x11ConditionalFormat\x12'\n\x05r\
+    ange\x18\x01\x20\x01(\x0b2\x11.spreadsheet.RectR\x05range\x12;\n\ncell_v\
+    alue\x18\x02\x20\x01(\x0b2\x1a.spreadsheet.CellValueRuleH\0R\tcellValue\
+    \x124\n\x07formula\x18\x03\x20\x01(\x0b2\x18.spreadsheet.FormulaRuleH\0R\
+    \x07formula\x12>\n\x0bcolor_scale\x18\x04\x20\x01(\x0b2\x1b.spreadsheet.\
+    ColorScaleRuleH\0R\ncolorScale\x125\n\x08data_bar\x18\x05\x20\x01(\x0b2\
+    \x18.spreadsheet.DataBarRuleH\0R\x07dataBar\x12,\n\x05top_n\x18\x06\x20\
+    \x01(\x0b2\x15.spreadsheet.TopNRuleH\0R\x04topNB\x06\n\x04rule\"\x8a\x01\
+    \n\rCellValueRule\x127\n\ncomparison\x18\x01\x20\x01(\x0e2\x17.spreadshe\
+    et.ComparisonR\ncomparison\x12\x16\n\x06values\x18\x02\x20\x03(\tR\x06va\
+    lues\x12(\n\x05style\x18\x03\x20\x01(\x0b2\x12.spreadsheet.StyleR\x05sty\
+    le\"Q\n\x0bFormulaRule\x12\x18\n\x07formula\x18\x01\x20\x01(\tR\x07formu\
+    la\x12(\n\x05style\x18\x02\x20\x01(\x0b2\x12.spreadsheet.StyleR\x05style\
+    \"g\n\x0eColorScaleRule\x12\x1b\n\tmin_color\x18\x01\x20\x01(\tR\x08minC\
+    olor\x12\x1b\n\tmid_color\x18\x02\x20\x01(\tR\x08midColor\x12\x1b\n\tmax\
+    _color\x18\x03\x20\x01(\tR\x08maxColor\"#\n\x0bDataBarRule\x12\x14\n\x05\
+    color\x18\x01\x20\x01(\tR\x05color\"t\n\x08TopNRule\x12\x0c\n\x01n\x18\
+    \x01\x20\x01(\rR\x01n\x12\x16\n\x06bottom\x18\x02\x20\x01(\x08R\x06botto\
+    m\x12\x18\n\x07percent\x18\x03\x20\x01(\x08R\x07percent\x12(\n\x05style\
+    \x18\x04\x20\x01(\x0b2\x12.spreadsheet.StyleR\x05style\"r\n\x1cSetCondit\
+    ionalFormatsRequest\x12\x18\n\x07tableId\x18\x01\x20\x01(\tR\x07tableId\
+    \x128\n\x07formats\x18\x02\x20\x03(\x0b2\x1e.spreadsheet.ConditionalForm\
+    atR\x07formats\"\xe7\x01\n\x1dSetConditionalFormatsResponse\x12'\n\x05ce\
+    lls\x18\x01\x20\x03(\x0b2\x11.spreadsheet.CellR\x05cells\x12N\n\x06style\
+    s\x18\x02\x20\x03(\x0b26.spreadsheet.SetConditionalFormatsResponse.Style\
+    sEntryR\x06styles\x1aM\n\x0bStylesEntry\x12\x10\n\x03key\x18\x01\x20\x01\
+    (\rR\x03key\x12(\n\x05value\x18\x02\x20\x01(\x0b2\x12.spreadsheet.StyleR\
+    \x05value:\x028\x01\"8\n\x1cGetConditionalFormatsRequest\x12\x18\n\x07ta\
+    bleId\x18\x01\x20\x01(\tR\x07tableId\"Y\n\x1dGetConditionalFormatsRespon\
+    se\x128\n\x07formats\x18\x01\x20\x03(\x0b2\x1e.spreadsheet.ConditionalFo\
+    rmatR\x07formats\"\xc7\x03\n\x0eValidationRule\x12'\n\x05range\x18\x01\
+    \x20\x01(\x0b2\x11.spreadsheet.RectR\x05range\x12/\n\x04list\x18\x02\x20\
+    \x01(\x0b2\x19.spreadsheet.ListCriteriaH\0R\x04list\x122\n\nlist_range\
+    \x18\x03\x20\x01(\x0b2\x11.spreadsheet.RectH\0R\tlistRange\x129\n\x06num\
+    ber\x18\x04\x20\x01(\x0b2\x1f.spreadsheet.ComparisonCriteriaH\0R\x06numb\
+    er\x125\n\x04date\x18\x05\x20\x01(\x0b2\x1f.spreadsheet.ComparisonCriter\
+    iaH\0R\x04date\x12B\n\x0btext_length\x18\x06\x20\x01(\x0b2\x1f.spreadshe\
+    et.ComparisonCriteriaH\0R\ntextLength\x12\x1a\n\x07formula\x18\x07\x20\
+    \x01(\tH\0R\x07formula\x12/\n\x04mode\x18\x08\x20\x01(\x0e2\x1b.spreadsh\
+    eet.ValidationModeR\x04mode\x12\x18\n\x07message\x18\t\x20\x01(\tR\x07me\
+    ssageB\n\n\x08criteria\"&\n\x0cListCriteria\x12\x16\n\x06values\x18\x01\
+    \x20\x03(\tR\x06values\"e\n\x12ComparisonCriteria\x127\n\ncomparison\x18\
+    \x01\x20\x01(\x0e2\x17.spreadsheet.ComparisonR\ncomparison\x12\x16\n\x06\
+    values\x18\x02\x20\x03(\tR\x06values\"k\n\x0fValidationError\x12\x10\n\
+    \x03row\x18\x01\x20\x01(\x05R\x03row\x12\x10\n\x03col\x18\x02\x20\x01(\
+    \x05R\x03col\x12\x18\n\x07message\x18\x03\x20\x01(\tR\x07message\x12\x1a\
+    \n\x08rejected\x18\x04\x20\x01(\x08R\x08rejected\"h\n\x19SetValidationRu\
+    lesRequest\x12\x18\n\x07tableId\x18\x01\x20\x01(\tR\x07tableId\x121\n\
+    \x05rules\x18\x02\x20\x03(\x0b2\x1b.spreadsheet.ValidationRuleR\x05rules\
+    \"\x1c\n\x1aSetValidationRulesResponse\"5\n\x19GetValidationRulesRequest\
+    \x12\x18\n\x07tableId\x18\x01\x20\x01(\tR\x07tableId\"O\n\x1aGetValidati\
+    onRulesResponse\x121\n\x05rules\x18\x01\x20\x03(\x0b2\x1b.spreadsheet.Va\
+    lidationRuleR\x05rules\"T\n\x14GetListValuesRequest\x12\x18\n\x07tableId\
+    \x18\x01\x20\x01(\tR\x07tableId\x12\x10\n\x03row\x18\x02\x20\x01(\x05R\
+    \x03row\x12\x10\n\x03col\x18\x03\x20\x01(\x05R\x03col\"/\n\x15GetListVal\
+    uesResponse\x12\x16\n\x06values\x18\x01\x20\x03(\tR\x06values\"d\n\x0bTr\
+    acedRange\x12\x18\n\x07tableId\x18\x01\x20\x01(\tR\x07tableId\x12%\n\x04\
+    rect\x18\x02\x20\x01(\x0b2\x11.spreadsheet.RectR\x04rect\x12\x14\n\x05de\
+    pth\x18\x03\x20\x01(\rR\x05depth\"p\n\x14GetPrecedentsRequest\x12\x18\n\
+    \x07tableId\x18\x01\x20\x01(\tR\x07tableId\x12\x10\n\x03row\x18\x02\x20\
+    \x01(\x05R\x03row\x12\x10\n\x03col\x18\x03\x20\x01(\x05R\x03col\x12\x1a\
+    \n\x08maxDepth\x18\x04\x20\x01(\rR\x08maxDepth\"I\n\x15GetPrecedentsResp\
+    onse\x120\n\x06ranges\x18\x01\x20\x03(\x0b2\x18.spreadsheet.TracedRangeR\
+    \x06ranges\"p\n\x14GetDependentsRequest\x12\x18\n\x07tableId\x18\x01\x20\
+    \x01(\tR\x07tableId\x12\x10\n\x03row\x18\x02\x20\x01(\x05R\x03row\x12\
+    \x10\n\x03col\x18\x03\x20\x01(\x05R\x03col\x12\x1a\n\x08maxDepth\x18\x04\
+    \x20\x01(\rR\x08maxDepth\"I\n\x15GetDependentsResponse\x120\n\x06ranges\
+    \x18\x01\x20\x03(\x0b2\x18.spreadsheet.TracedRangeR\x06ranges\"G\n\x07Ce\
+    llRef\x12\x18\n\x07tableId\x18\x01\x20\x01(\tR\x07tableId\x12\x10\n\x03r\
+    ow\x18\x02\x20\x01(\x05R\x03row\x12\x10\n\x03col\x18\x03\x20\x01(\x05R\
+    \x03col\"1\n\x05Cycle\x12(\n\x04path\x18\x01\x20\x03(\x0b2\x14.spreadshe\
+    et.CellRefR\x04path\",\n\x10GetCyclesRequest\x12\x18\n\x07tableId\x18\
+    \x01\x20\x01(\tR\x07tableId\"?\n\x11GetCyclesResponse\x12*\n\x06cycles\
+    \x18\x01\x20\x03(\x0b2\x12.spreadsheet.CycleR\x06cycles\"g\n\x19SetCalcu\
+    lationModeRequest\x12\x18\n\x07tableId\x18\x01\x20\x01(\tR\x07tableId\
+    \x120\n\x04mode\x18\x02\x20\x01(\x0e2\x1c.spreadsheet.CalculationModeR\
+    \x04mode\"\xe1\x01\n\x1aSetCalculationModeResponse\x12'\n\x05cells\x18\
+    \x01\x20\x03(\x0b2\x11.spreadsheet.CellR\x05cells\x12K\n\x06styles\x18\
+    \x02\x20\x03(\x0b23.spreadsheet.SetCalculationModeResponse.StylesEntryR\
+    \x06styles\x1aM\n\x0bStylesEntry\x12\x10\n\x03key\x18\x01\x20\x01(\rR\
+    \x03key\x12(\n\x05value\x18\x02\x20\x01(\x0b2\x12.spreadsheet.StyleR\x05\
+    value:\x028\x01\"\x8a\x01\n\x12ExportGraphRequest\x12\x18\n\x07tableId\
+    \x18\x01\x20\x01(\tR\x07tableId\x120\n\x06format\x18\x02\x20\x01(\x0e2\
+    \x18.spreadsheet.GraphFormatR\x06format\x12(\n\x04from\x18\x03\x20\x01(\
+    \x0b2\x14.spreadsheet.CellRefR\x04from\"+\n\x13ExportGraphResponse\x12\
+    \x14\n\x05graph\x18\x01\x20\x01(\tR\x05graph*b\n\x08CopyMode\x12\x0c\n\
+    \x08COPY_ALL\x10\0\x12\x0f\n\x0bCOPY_VALUES\x10\x01\x12\x11\n\rCOPY_FORM\
+    ULAS\x10\x02\x12\x10\n\x0cCOPY_FORMATS\x10\x03\x12\x12\n\x0eCOPY_TRANSPO\
+    SE\x10\x04*\x8d\x01\n\x0bBorderStyle\x12\x0f\n\x0bBORDER_NONE\x10\0\x12\
+    \x0f\n\x0bBORDER_THIN\x10\x01\x12\x11\n\rBORDER_MEDIUM\x10\x02\x12\x10\n\
+    \x0cBORDER_THICK\x10\x03\x12\x11\n\rBORDER_DASHED\x10\x04\x12\x11\n\rBOR\
+    DER_DOTTED\x10\x05\x12\x11\n\rBORDER_DOUBLE\x10\x06*o\n\x13HorizontalAli\
+    gnment\x12\x16\n\x12HORIZONTAL_GENERAL\x10\0\x12\x13\n\x0fHORIZONTAL_LEF\
+    T\x10\x01\x12\x15\n\x11HORIZONTAL_CENTER\x10\x02\x12\x14\n\x10HORIZONTAL\
+    _RIGHT\x10\x03*O\n\x11VerticalAlignment\x12\x13\n\x0fVERTICAL_BOTTOM\x10\
+    \0\x12\x13\n\x0fVERTICAL_MIDDLE\x10\x01\x12\x10\n\x0cVERTICAL_TOP\x10\
+    \x02*\xdc\x01\n\nComparison\x12\x14\n\x10COMPARISON_EQUAL\x10\0\x12\x18\
+    \n\x14COMPARISON_NOT_EQUAL\x10\x01\x12\x16\n\x12COMPARISON_GREATER\x10\
+    \x02\x12\x1f\n\x1bCOMPARISON_GREATER_OR_EQUAL\x10\x03\x12\x13\n\x0fCOMPA\
+    RISON_LESS\x10\x04\x12\x1c\n\x18COMPARISON_LESS_OR_EQUAL\x10\x05\x12\x16\
+    \n\x12COMPARISON_BETWEEN\x10\x06\x12\x1a\n\x16COMPARISON_NOT_BETWEEN\x10\
+    \x07*<\n\x0eValidationMode\x12\x15\n\x11VALIDATION_REJECT\x10\0\x12\x13\
+    \n\x0fVALIDATION_WARN\x10\x01*_\n\x0fCalculationMode\x12\x19\n\x15CALCUL\
+    ATION_AUTOMATIC\x10\0\x12\x16\n\x12CALCULATION_MANUAL\x10\x01\x12\x19\n\
+    \x15CALCULATION_ON_DEMAND\x10\x02*,\n\x0bGraphFormat\x12\r\n\tGRAPH_DOT\
+    \x10\0\x12\x0e\n\nGRAPH_JSON\x10\x012\x84\x11\n\x0eSpreadsheetAPI\x12P\n\
+    \x0bInsertCells\x12\x1f.spreadsheet.InsertCellsRequest\x1a\x20.spreadshe\
+    et.InsertCellsResponse\x12G\n\x08GetCells\x12\x1c.spreadsheet.GetCellsRe\
+    quest\x1a\x1d.spreadsheet.GetCellsResponse\x12P\n\x0bRecalculate\x12\x1f\
+    .spreadsheet.RecalculateRequest\x1a\x20.spreadsheet.RecalculateResponse\
+    \x12M\n\nClearCells\x12\x1e.spreadsheet.ClearCellsRequest\x1a\x1f.spread\
+    sheet.ClearCellsResponse\x12J\n\tSetLocale\x12\x1d.spreadsheet.SetLocale\
+    Request\x1a\x1e.spreadsheet.SetLocaleResponse\x12J\n\tSetFormat\x12\x1d.\
+    spreadsheet.SetFormatRequest\x1a\x1e.spreadsheet.SetFormatResponse\x12J\
+    \n\tSetStyles\x12\x1d.spreadsheet.SetStylesRequest\x1a\x1e.spreadsheet.S\
+    etStylesResponse\x12n\n\x15SetConditionalFormats\x12).spreadsheet.SetCon\
+    ditionalFormatsRequest\x1a*.spreadsheet.SetConditionalFormatsResponse\
+    \x12n\n\x15GetConditionalFormats\x12).spreadsheet.GetConditionalFormatsR\
+    equest\x1a*.spreadsheet.GetConditionalFormatsResponse\x12e\n\x12SetValid\
+    ationRules\x12&.spreadsheet.SetValidationRulesRequest\x1a'.spreadsheet.S\
+    etValidationRulesResponse\x12e\n\x12GetValidationRules\x12&.spreadsheet.\
+    GetValidationRulesRequest\x1a'.spreadsheet.GetValidationRulesResponse\
+    \x12V\n\rGetListValues\x12!.spreadsheet.GetListValuesRequest\x1a\".sprea\
+    dsheet.GetListValuesResponse\x12M\n\nInsertRows\x12\x1e.spreadsheet.Inse\
+    rtRowsRequest\x1a\x1f.spreadsheet.InsertRowsResponse\x12M\n\nDeleteRows\
+    \x12\x1e.spreadsheet.DeleteRowsRequest\x1a\x1f.spreadsheet.DeleteRowsRes\
+    ponse\x12V\n\rInsertColumns\x12!.spreadsheet.InsertColumnsRequest\x1a\".\
+    spreadsheet.InsertColumnsResponse\x12V\n\rDeleteColumns\x12!.spreadsheet\
+    .DeleteColumnsRequest\x1a\".spreadsheet.DeleteColumnsResponse\x12J\n\tMo\
+    veRange\x12\x1d.spreadsheet.MoveRangeRequest\x1a\x1e.spreadsheet.MoveRan\
+    geResponse\x12J\n\tCopyRange\x12\x1d.spreadsheet.CopyRangeRequest\x1a\
+    \x1e.spreadsheet.CopyRangeResponse\x12;\n\x04Fill\x12\x18.spreadsheet.Fi\
+    llRequest\x1a\x19.spreadsheet.FillResponse\x12t\n\x17SetIterativeCalcula\
+    tion\x12+.spreadsheet.SetIterativeCalculationRequest\x1a,.spreadsheet.Se\
+    tIterativeCalculationResponse\x12V\n\rGetPrecedents\x12!.spreadsheet.Get\
+    PrecedentsRequest\x1a\".spreadsheet.GetPrecedentsResponse\x12V\n\rGetDep\
+    endents\x12!.spreadsheet.GetDependentsRequest\x1a\".spreadsheet.GetDepen\
+    dentsResponse\x12J\n\tGetCycles\x12\x1d.spreadsheet.GetCyclesRequest\x1a\
+    \x1e.spreadsheet.GetCyclesResponse\x12e\n\x12SetCalculationMode\x12&.spr\
+    eadsheet.SetCalculationModeRequest\x1a'.spreadsheet.SetCalculationModeRe\
+    sponse\x12P\n\x0bExportGraph\x12\x1f.spreadsheet.ExportGraphRequest\x1a\
+    \x20.spreadsheet.ExportGraphResponseb\x06proto3\
 ";
 
 static file_descriptor_proto_lazy: ::protobuf::rt::LazyV2<::protobuf::descriptor::FileDescriptorProto> = ::protobuf::rt::LazyV2::INIT;
@@ -30,6 +30,167 @@ const METHOD_SPREADSHEET_API_GET_CELLS: ::grpcio::Method<super::api::GetCellsReq
     resp_mar: ::grpcio::Marshaller { ser: ::grpcio::pb_ser, de: ::grpcio::pb_de },
 };
 
+const METHOD_SPREADSHEET_API_RECALCULATE: ::grpcio::Method<super::api::RecalculateRequest, super::api::RecalculateResponse> = ::grpcio::Method {
+    ty: ::grpcio::MethodType::Unary,
+    name: "/spreadsheet.SpreadsheetAPI/Recalculate",
+    req_mar: ::grpcio::Marshaller { ser: ::grpcio::pb_ser, de: ::grpcio::pb_de },
+    resp_mar: ::grpcio::Marshaller { ser: ::grpcio::pb_ser, de: ::grpcio::pb_de },
+};
+
+const METHOD_SPREADSHEET_API_SET_LOCALE: ::grpcio::Method<super::api::SetLocaleRequest, super::api::SetLocaleResponse> = ::grpcio::Method {
+    ty: ::grpcio::MethodType::Unary,
+    name: "/spreadsheet.SpreadsheetAPI/SetLocale",
+    req_mar: ::grpcio::Marshaller { ser: ::grpcio::pb_ser, de: ::grpcio::pb_de },
+    resp_mar: ::grpcio::Marshaller { ser: ::grpcio::pb_ser, de: ::grpcio::pb_de },
+};
+
+const METHOD_SPREADSHEET_API_SET_FORMAT: ::grpcio::Method<super::api::SetFormatRequest, super::api::SetFormatResponse> = ::grpcio::Method {
+    ty: ::grpcio::MethodType::Unary,
+    name: "/spreadsheet.SpreadsheetAPI/SetFormat",
+    req_mar: ::grpcio::Marshaller { ser: ::grpcio::pb_ser, de: ::grpcio::pb_de },
+    resp_mar: ::grpcio::Marshaller { ser: ::grpcio::pb_ser, de: ::grpcio::pb_de },
+};
+
+const METHOD_SPREADSHEET_API_SET_STYLES: ::grpcio::Method<super::api::SetStylesRequest, super::api::SetStylesResponse> = ::grpcio::Method {
+    ty: ::grpcio::MethodType::Unary,
+    name: "/spreadsheet.SpreadsheetAPI/SetStyles",
+    req_mar: ::grpcio::Marshaller { ser: ::grpcio::pb_ser, de: ::grpcio::pb_de },
+    resp_mar: ::grpcio::Marshaller { ser: ::grpcio::pb_ser, de: ::grpcio::pb_de },
+};
+
+const METHOD_SPREADSHEET_API_SET_CONDITIONAL_FORMATS: ::grpcio::Method<super::api::SetConditionalFormatsRequest, super::api::SetConditionalFormatsResponse> = ::grpcio::Method {
+    ty: ::grpcio::MethodType::Unary,
+    name: "/spreadsheet.SpreadsheetAPI/SetConditionalFormats",
+    req_mar: ::grpcio::Marshaller { ser: ::grpcio::pb_ser, de: ::grpcio::pb_de },
+    resp_mar: ::grpcio::Marshaller { ser: ::grpcio::pb_ser, de: ::grpcio::pb_de },
+};
+
+const METHOD_SPREADSHEET_API_GET_CONDITIONAL_FORMATS: ::grpcio::Method<super::api::GetConditionalFormatsRequest, super::api::GetConditionalFormatsResponse> = ::grpcio::Method {
+    ty: ::grpcio::MethodType::Unary,
+    name: "/spreadsheet.SpreadsheetAPI/GetConditionalFormats",
+    req_mar: ::grpcio::Marshaller { ser: ::grpcio::pb_ser, de: ::grpcio::pb_de },
+    resp_mar: ::grpcio::Marshaller { ser: ::grpcio::pb_ser, de: ::grpcio::pb_de },
+};
+
+const METHOD_SPREADSHEET_API_SET_VALIDATION_RULES: ::grpcio::Method<super::api::SetValidationRulesRequest, super::api::SetValidationRulesResponse> = ::grpcio::Method {
+    ty: ::grpcio::MethodType::Unary,
+    name: "/spreadsheet.SpreadsheetAPI/SetValidationRules",
+    req_mar: ::grpcio::Marshaller { ser: ::grpcio::pb_ser, de: ::grpcio::pb_de },
+    resp_mar: ::grpcio::Marshaller { ser: ::grpcio::pb_ser, de: ::grpcio::pb_de },
+};
+
+const METHOD_SPREADSHEET_API_GET_VALIDATION_RULES: ::grpcio::Method<super::api::GetValidationRulesRequest, super::api::GetValidationRulesResponse> = ::grpcio::Method {
+    ty: ::grpcio::MethodType::Unary,
+    name: "/spreadsheet.SpreadsheetAPI/GetValidationRules",
+    req_mar: ::grpcio::Marshaller { ser: ::grpcio::pb_ser, de: ::grpcio::pb_de },
+    resp_mar: ::grpcio::Marshaller { ser: ::grpcio::pb_ser, de: ::grpcio::pb_de },
+};
+
+const METHOD_SPREADSHEET_API_GET_LIST_VALUES: ::grpcio::Method<super::api::GetListValuesRequest, super::api::GetListValuesResponse> = ::grpcio::Method {
+    ty: ::grpcio::MethodType::Unary,
+    name: "/spreadsheet.SpreadsheetAPI/GetListValues",
+    req_mar: ::grpcio::Marshaller { ser: ::grpcio::pb_ser, de: ::grpcio::pb_de },
+    resp_mar: ::grpcio::Marshaller { ser: ::grpcio::pb_ser, de: ::grpcio::pb_de },
+};
+
+const METHOD_SPREADSHEET_API_CLEAR_CELLS: ::grpcio::Method<super::api::ClearCellsRequest, super::api::ClearCellsResponse> = ::grpcio::Method {
+    ty: ::grpcio::MethodType::Unary,
+    name: "/spreadsheet.SpreadsheetAPI/ClearCells",
+    req_mar: ::grpcio::Marshaller { ser: ::grpcio::pb_ser, de: ::grpcio::pb_de },
+    resp_mar: ::grpcio::Marshaller { ser: ::grpcio::pb_ser, de: ::grpcio::pb_de },
+};
+
+const METHOD_SPREADSHEET_API_INSERT_ROWS: ::grpcio::Method<super::api::InsertRowsRequest, super::api::InsertRowsResponse> = ::grpcio::Method {
+    ty: ::grpcio::MethodType::Unary,
+    name: "/spreadsheet.SpreadsheetAPI/InsertRows",
+    req_mar: ::grpcio::Marshaller { ser: ::grpcio::pb_ser, de: ::grpcio::pb_de },
+    resp_mar: ::grpcio::Marshaller { ser: ::grpcio::pb_ser, de: ::grpcio::pb_de },
+};
+
+const METHOD_SPREADSHEET_API_DELETE_ROWS: ::grpcio::Method<super::api::DeleteRowsRequest, super::api::DeleteRowsResponse> = ::grpcio::Method {
+    ty: ::grpcio::MethodType::Unary,
+    name: "/spreadsheet.SpreadsheetAPI/DeleteRows",
+    req_mar: ::grpcio::Marshaller { ser: ::grpcio::pb_ser, de: ::grpcio::pb_de },
+    resp_mar: ::grpcio::Marshaller { ser: ::grpcio::pb_ser, de: ::grpcio::pb_de },
+};
+
+const METHOD_SPREADSHEET_API_INSERT_COLUMNS: ::grpcio::Method<super::api::InsertColumnsRequest, super::api::InsertColumnsResponse> = ::grpcio::Method {
+    ty: ::grpcio::MethodType::Unary,
+    name: "/spreadsheet.SpreadsheetAPI/InsertColumns",
+    req_mar: ::grpcio::Marshaller { ser: ::grpcio::pb_ser, de: ::grpcio::pb_de },
+    resp_mar: ::grpcio::Marshaller { ser: ::grpcio::pb_ser, de: ::grpcio::pb_de },
+};
+
+const METHOD_SPREADSHEET_API_DELETE_COLUMNS: ::grpcio::Method<super::api::DeleteColumnsRequest, super::api::DeleteColumnsResponse> = ::grpcio::Method {
+    ty: ::grpcio::MethodType::Unary,
+    name: "/spreadsheet.SpreadsheetAPI/DeleteColumns",
+    req_mar: ::grpcio::Marshaller { ser: ::grpcio::pb_ser, de: ::grpcio::pb_de },
+    resp_mar: ::grpcio::Marshaller { ser: ::grpcio::pb_ser, de: ::grpcio::pb_de },
+};
+
+const METHOD_SPREADSHEET_API_MOVE_RANGE: ::grpcio::Method<super::api::MoveRangeRequest, super::api::MoveRangeResponse> = ::grpcio::Method {
+    ty: ::grpcio::MethodType::Unary,
+    name: "/spreadsheet.SpreadsheetAPI/MoveRange",
+    req_mar: ::grpcio::Marshaller { ser: ::grpcio::pb_ser, de: ::grpcio::pb_de },
+    resp_mar: ::grpcio::Marshaller { ser: ::grpcio::pb_ser, de: ::grpcio::pb_de },
+};
+
+const METHOD_SPREADSHEET_API_COPY_RANGE: ::grpcio::Method<super::api::CopyRangeRequest, super::api::CopyRangeResponse> = ::grpcio::Method {
+    ty: ::grpcio::MethodType::Unary,
+    name: "/spreadsheet.SpreadsheetAPI/CopyRange",
+    req_mar: ::grpcio::Marshaller { ser: ::grpcio::pb_ser, de: ::grpcio::pb_de },
+    resp_mar: ::grpcio::Marshaller { ser: ::grpcio::pb_ser, de: ::grpcio::pb_de },
+};
+
+const METHOD_SPREADSHEET_API_FILL: ::grpcio::Method<super::api::FillRequest, super::api::FillResponse> = ::grpcio::Method {
+    ty: ::grpcio::MethodType::Unary,
+    name: "/spreadsheet.SpreadsheetAPI/Fill",
+    req_mar: ::grpcio::Marshaller { ser: ::grpcio::pb_ser, de: ::grpcio::pb_de },
+    resp_mar: ::grpcio::Marshaller { ser: ::grpcio::pb_ser, de: ::grpcio::pb_de },
+};
+
+const METHOD_SPREADSHEET_API_SET_ITERATIVE_CALCULATION: ::grpcio::Method<super::api::SetIterativeCalculationRequest, super::api::SetIterativeCalculationResponse> = ::grpcio::Method {
+    ty: ::grpcio::MethodType::Unary,
+    name: "/spreadsheet.SpreadsheetAPI/SetIterativeCalculation",
+    req_mar: ::grpcio::Marshaller { ser: ::grpcio::pb_ser, de: ::grpcio::pb_de },
+    resp_mar: ::grpcio::Marshaller { ser: ::grpcio::pb_ser, de: ::grpcio::pb_de },
+};
+
+const METHOD_SPREADSHEET_API_GET_PRECEDENTS: ::grpcio::Method<super::api::GetPrecedentsRequest, super::api::GetPrecedentsResponse> = ::grpcio::Method {
+    ty: ::grpcio::MethodType::Unary,
+    name: "/spreadsheet.SpreadsheetAPI/GetPrecedents",
+    req_mar: ::grpcio::Marshaller { ser: ::grpcio::pb_ser, de: ::grpcio::pb_de },
+    resp_mar: ::grpcio::Marshaller { ser: ::grpcio::pb_ser, de: ::grpcio::pb_de },
+};
+
+const METHOD_SPREADSHEET_API_GET_DEPENDENTS: ::grpcio::Method<super::api::GetDependentsRequest, super::api::GetDependentsResponse> = ::grpcio::Method {
+    ty: ::grpcio::MethodType::Unary,
+    name: "/spreadsheet.SpreadsheetAPI/GetDependents",
+    req_mar: ::grpcio::Marshaller { ser: ::grpcio::pb_ser, de: ::grpcio::pb_de },
+    resp_mar: ::grpcio::Marshaller { ser: ::grpcio::pb_ser, de: ::grpcio::pb_de },
+};
+
+const METHOD_SPREADSHEET_API_GET_CYCLES: ::grpcio::Method<super::api::GetCyclesRequest, super::api::GetCyclesResponse> = ::grpcio::Method {
+    ty: ::grpcio::MethodType::Unary,
+    name: "/spreadsheet.SpreadsheetAPI/GetCycles",
+    req_mar: ::grpcio::Marshaller { ser: ::grpcio::pb_ser, de: ::grpcio::pb_de },
+    resp_mar: ::grpcio::Marshaller { ser: ::grpcio::pb_ser, de: ::grpcio::pb_de },
+};
+
+const METHOD_SPREADSHEET_API_SET_CALCULATION_MODE: ::grpcio::Method<super::api::SetCalculationModeRequest, super::api::SetCalculationModeResponse> = ::grpcio::Method {
+    ty: ::grpcio::MethodType::Unary,
+    name: "/spreadsheet.SpreadsheetAPI/SetCalculationMode",
+    req_mar: ::grpcio::Marshaller { ser: ::grpcio::pb_ser, de: ::grpcio::pb_de },
+    resp_mar: ::grpcio::Marshaller { ser: ::grpcio::pb_ser, de: ::grpcio::pb_de },
+};
+
+const METHOD_SPREADSHEET_API_EXPORT_GRAPH: ::grpcio::Method<super::api::ExportGraphRequest, super::api::ExportGraphResponse> = ::grpcio::Method {
+    ty: ::grpcio::MethodType::Unary,
+    name: "/spreadsheet.SpreadsheetAPI/ExportGraph",
+    req_mar: ::grpcio::Marshaller { ser: ::grpcio::pb_ser, de: ::grpcio::pb_de },
+    resp_mar: ::grpcio::Marshaller { ser: ::grpcio::pb_ser, de: ::grpcio::pb_de },
+};
+
 #[derive(Clone)]
 pub struct SpreadsheetApiClient {
     client: ::grpcio::Client,
@@ -73,6 +234,374 @@ impl SpreadsheetApiClient {
     pub fn get_cells_async(&self, req: &super::api::GetCellsRequest) -> ::grpcio::Result<::grpcio::ClientUnaryReceiver<super::api::GetCellsResponse>> {
         self.get_cells_async_opt(req, ::grpcio::CallOption::default())
     }
+
+    pub fn recalculate_opt(&self, req: &super::api::RecalculateRequest, opt: ::grpcio::CallOption) -> ::grpcio::Result<super::api::RecalculateResponse> {
+        self.client.unary_call(&METHOD_SPREADSHEET_API_RECALCULATE, req, opt)
+    }
+
+    pub fn recalculate(&self, req: &super::api::RecalculateRequest) -> ::grpcio::Result<super::api::RecalculateResponse> {
+        self.recalculate_opt(req, ::grpcio::CallOption::default())
+    }
+
+    pub fn recalculate_async_opt(&self, req: &super::api::RecalculateRequest, opt: ::grpcio::CallOption) -> ::grpcio::Result<::grpcio::ClientUnaryReceiver<super::api::RecalculateResponse>> {
+        self.client.unary_call_async(&METHOD_SPREADSHEET_API_RECALCULATE, req, opt)
+    }
+
+    pub fn recalculate_async(&self, req: &super::api::RecalculateRequest) -> ::grpcio::Result<::grpcio::ClientUnaryReceiver<super::api::RecalculateResponse>> {
+        self.recalculate_async_opt(req, ::grpcio::CallOption::default())
+    }
+
+    pub fn set_locale_opt(&self, req: &super::api::SetLocaleRequest, opt: ::grpcio::CallOption) -> ::grpcio::Result<super::api::SetLocaleResponse> {
+        self.client.unary_call(&METHOD_SPREADSHEET_API_SET_LOCALE, req, opt)
+    }
+
+    pub fn set_locale(&self, req: &super::api::SetLocaleRequest) -> ::grpcio::Result<super::api::SetLocaleResponse> {
+        self.set_locale_opt(req, ::grpcio::CallOption::default())
+    }
+
+    pub fn set_locale_async_opt(&self, req: &super::api::SetLocaleRequest, opt: ::grpcio::CallOption) -> ::grpcio::Result<::grpcio::ClientUnaryReceiver<super::api::SetLocaleResponse>> {
+        self.client.unary_call_async(&METHOD_SPREADSHEET_API_SET_LOCALE, req, opt)
+    }
+
+    pub fn set_locale_async(&self, req: &super::api::SetLocaleRequest) -> ::grpcio::Result<::grpcio::ClientUnaryReceiver<super::api::SetLocaleResponse>> {
+        self.set_locale_async_opt(req, ::grpcio::CallOption::default())
+    }
+
+    pub fn set_format_opt(&self, req: &super::api::SetFormatRequest, opt: ::grpcio::CallOption) -> ::grpcio::Result<super::api::SetFormatResponse> {
+        self.client.unary_call(&METHOD_SPREADSHEET_API_SET_FORMAT, req, opt)
+    }
+
+    pub fn set_format(&self, req: &super::api::SetFormatRequest) -> ::grpcio::Result<super::api::SetFormatResponse> {
+        self.set_format_opt(req, ::grpcio::CallOption::default())
+    }
+
+    pub fn set_format_async_opt(&self, req: &super::api::SetFormatRequest, opt: ::grpcio::CallOption) -> ::grpcio::Result<::grpcio::ClientUnaryReceiver<super::api::SetFormatResponse>> {
+        self.client.unary_call_async(&METHOD_SPREADSHEET_API_SET_FORMAT, req, opt)
+    }
+
+    pub fn set_format_async(&self, req: &super::api::SetFormatRequest) -> ::grpcio::Result<::grpcio::ClientUnaryReceiver<super::api::SetFormatResponse>> {
+        self.set_format_async_opt(req, ::grpcio::CallOption::default())
+    }
+
+    pub fn set_styles_opt(&self, req: &super::api::SetStylesRequest, opt: ::grpcio::CallOption) -> ::grpcio::Result<super::api::SetStylesResponse> {
+        self.client.unary_call(&METHOD_SPREADSHEET_API_SET_STYLES, req, opt)
+    }
+
+    pub fn set_styles(&self, req: &super::api::SetStylesRequest) -> ::grpcio::Result<super::api::SetStylesResponse> {
+        self.set_styles_opt(req, ::grpcio::CallOption::default())
+    }
+
+    pub fn set_styles_async_opt(&self, req: &super::api::SetStylesRequest, opt: ::grpcio::CallOption) -> ::grpcio::Result<::grpcio::ClientUnaryReceiver<super::api::SetStylesResponse>> {
+        self.client.unary_call_async(&METHOD_SPREADSHEET_API_SET_STYLES, req, opt)
+    }
+
+    pub fn set_styles_async(&self, req: &super::api::SetStylesRequest) -> ::grpcio::Result<::grpcio::ClientUnaryReceiver<super::api::SetStylesResponse>> {
+        self.set_styles_async_opt(req, ::grpcio::CallOption::default())
+    }
+
+    pub fn set_conditional_formats_opt(&self, req: &super::api::SetConditionalFormatsRequest, opt: ::grpcio::CallOption) -> ::grpcio::Result<super::api::SetConditionalFormatsResponse> {
+        self.client.unary_call(&METHOD_SPREADSHEET_API_SET_CONDITIONAL_FORMATS, req, opt)
+    }
+
+    pub fn set_conditional_formats(&self, req: &super::api::SetConditionalFormatsRequest) -> ::grpcio::Result<super::api::SetConditionalFormatsResponse> {
+        self.set_conditional_formats_opt(req, ::grpcio::CallOption::default())
+    }
+
+    pub fn set_conditional_formats_async_opt(&self, req: &super::api::SetConditionalFormatsRequest, opt: ::grpcio::CallOption) -> ::grpcio::Result<::grpcio::ClientUnaryReceiver<super::api::SetConditionalFormatsResponse>> {
+        self.client.unary_call_async(&METHOD_SPREADSHEET_API_SET_CONDITIONAL_FORMATS, req, opt)
+    }
+
+    pub fn set_conditional_formats_async(&self, req: &super::api::SetConditionalFormatsRequest) -> ::grpcio::Result<::grpcio::ClientUnaryReceiver<super::api::SetConditionalFormatsResponse>> {
+        self.set_conditional_formats_async_opt(req, ::grpcio::CallOption::default())
+    }
+
+    pub fn get_conditional_formats_opt(&self, req: &super::api::GetConditionalFormatsRequest, opt: ::grpcio::CallOption) -> ::grpcio::Result<super::api::GetConditionalFormatsResponse> {
+        self.client.unary_call(&METHOD_SPREADSHEET_API_GET_CONDITIONAL_FORMATS, req, opt)
+    }
+
+    pub fn get_conditional_formats(&self, req: &super::api::GetConditionalFormatsRequest) -> ::grpcio::Result<super::api::GetConditionalFormatsResponse> {
+        self.get_conditional_formats_opt(req, ::grpcio::CallOption::default())
+    }
+
+    pub fn get_conditional_formats_async_opt(&self, req: &super::api::GetConditionalFormatsRequest, opt: ::grpcio::CallOption) -> ::grpcio::Result<::grpcio::ClientUnaryReceiver<super::api::GetConditionalFormatsResponse>> {
+        self.client.unary_call_async(&METHOD_SPREADSHEET_API_GET_CONDITIONAL_FORMATS, req, opt)
+    }
+
+    pub fn get_conditional_formats_async(&self, req: &super::api::GetConditionalFormatsRequest) -> ::grpcio::Result<::grpcio::ClientUnaryReceiver<super::api::GetConditionalFormatsResponse>> {
+        self.get_conditional_formats_async_opt(req, ::grpcio::CallOption::default())
+    }
+
+    pub fn set_validation_rules_opt(&self, req: &super::api::SetValidationRulesRequest, opt: ::grpcio::CallOption) -> ::grpcio::Result<super::api::SetValidationRulesResponse> {
+        self.client.unary_call(&METHOD_SPREADSHEET_API_SET_VALIDATION_RULES, req, opt)
+    }
+
+    pub fn set_validation_rules(&self, req: &super::api::SetValidationRulesRequest) -> ::grpcio::Result<super::api::SetValidationRulesResponse> {
+        self.set_validation_rules_opt(req, ::grpcio::CallOption::default())
+    }
+
+    pub fn set_validation_rules_async_opt(&self, req: &super::api::SetValidationRulesRequest, opt: ::grpcio::CallOption) -> ::grpcio::Result<::grpcio::ClientUnaryReceiver<super::api::SetValidationRulesResponse>> {
+        self.client.unary_call_async(&METHOD_SPREADSHEET_API_SET_VALIDATION_RULES, req, opt)
+    }
+
+    pub fn set_validation_rules_async(&self, req: &super::api::SetValidationRulesRequest) -> ::grpcio::Result<::grpcio::ClientUnaryReceiver<super::api::SetValidationRulesResponse>> {
+        self.set_validation_rules_async_opt(req, ::grpcio::CallOption::default())
+    }
+
+    pub fn get_validation_rules_opt(&self, req: &super::api::GetValidationRulesRequest, opt: ::grpcio::CallOption) -> ::grpcio::Result<super::api::GetValidationRulesResponse> {
+        self.client.unary_call(&METHOD_SPREADSHEET_API_GET_VALIDATION_RULES, req, opt)
+    }
+
+    pub fn get_validation_rules(&self, req: &super::api::GetValidationRulesRequest) -> ::grpcio::Result<super::api::GetValidationRulesResponse> {
+        self.get_validation_rules_opt(req, ::grpcio::CallOption::default())
+    }
+
+    pub fn get_validation_rules_async_opt(&self, req: &super::api::GetValidationRulesRequest, opt: ::grpcio::CallOption) -> ::grpcio::Result<::grpcio::ClientUnaryReceiver<super::api::GetValidationRulesResponse>> {
+        self.client.unary_call_async(&METHOD_SPREADSHEET_API_GET_VALIDATION_RULES, req, opt)
+    }
+
+    pub fn get_validation_rules_async(&self, req: &super::api::GetValidationRulesRequest) -> ::grpcio::Result<::grpcio::ClientUnaryReceiver<super::api::GetValidationRulesResponse>> {
+        self.get_validation_rules_async_opt(req, ::grpcio::CallOption::default())
+    }
+
+    pub fn get_list_values_opt(&self, req: &super::api::GetListValuesRequest, opt: ::grpcio::CallOption) -> ::grpcio::Result<super::api::GetListValuesResponse> {
+        self.client.unary_call(&METHOD_SPREADSHEET_API_GET_LIST_VALUES, req, opt)
+    }
+
+    pub fn get_list_values(&self, req: &super::api::GetListValuesRequest) -> ::grpcio::Result<super::api::GetListValuesResponse> {
+        self.get_list_values_opt(req, ::grpcio::CallOption::default())
+    }
+
+    pub fn get_list_values_async_opt(&self, req: &super::api::GetListValuesRequest, opt: ::grpcio::CallOption) -> ::grpcio::Result<::grpcio::ClientUnaryReceiver<super::api::GetListValuesResponse>> {
+        self.client.unary_call_async(&METHOD_SPREADSHEET_API_GET_LIST_VALUES, req, opt)
+    }
+
+    pub fn get_list_values_async(&self, req: &super::api::GetListValuesRequest) -> ::grpcio::Result<::grpcio::ClientUnaryReceiver<super::api::GetListValuesResponse>> {
+        self.get_list_values_async_opt(req, ::grpcio::CallOption::default())
+    }
+
+    pub fn clear_cells_opt(&self, req: &super::api::ClearCellsRequest, opt: ::grpcio::CallOption) -> ::grpcio::Result<super::api::ClearCellsResponse> {
+        self.client.unary_call(&METHOD_SPREADSHEET_API_CLEAR_CELLS, req, opt)
+    }
+
+    pub fn clear_cells(&self, req: &super::api::ClearCellsRequest) -> ::grpcio::Result<super::api::ClearCellsResponse> {
+        self.clear_cells_opt(req, ::grpcio::CallOption::default())
+    }
+
+    pub fn clear_cells_async_opt(&self, req: &super::api::ClearCellsRequest, opt: ::grpcio::CallOption) -> ::grpcio::Result<::grpcio::ClientUnaryReceiver<super::api::ClearCellsResponse>> {
+        self.client.unary_call_async(&METHOD_SPREADSHEET_API_CLEAR_CELLS, req, opt)
+    }
+
+    pub fn clear_cells_async(&self, req: &super::api::ClearCellsRequest) -> ::grpcio::Result<::grpcio::ClientUnaryReceiver<super::api::ClearCellsResponse>> {
+        self.clear_cells_async_opt(req, ::grpcio::CallOption::default())
+    }
+
+    pub fn insert_rows_opt(&self, req: &super::api::InsertRowsRequest, opt: ::grpcio::CallOption) -> ::grpcio::Result<super::api::InsertRowsResponse> {
+        self.client.unary_call(&METHOD_SPREADSHEET_API_INSERT_ROWS, req, opt)
+    }
+
+    pub fn insert_rows(&self, req: &super::api::InsertRowsRequest) -> ::grpcio::Result<super::api::InsertRowsResponse> {
+        self.insert_rows_opt(req, ::grpcio::CallOption::default())
+    }
+
+    pub fn insert_rows_async_opt(&self, req: &super::api::InsertRowsRequest, opt: ::grpcio::CallOption) -> ::grpcio::Result<::grpcio::ClientUnaryReceiver<super::api::InsertRowsResponse>> {
+        self.client.unary_call_async(&METHOD_SPREADSHEET_API_INSERT_ROWS, req, opt)
+    }
+
+    pub fn insert_rows_async(&self, req: &super::api::InsertRowsRequest) -> ::grpcio::Result<::grpcio::ClientUnaryReceiver<super::api::InsertRowsResponse>> {
+        self.insert_rows_async_opt(req, ::grpcio::CallOption::default())
+    }
+
+    pub fn delete_rows_opt(&self, req: &super::api::DeleteRowsRequest, opt: ::grpcio::CallOption) -> ::grpcio::Result<super::api::DeleteRowsResponse> {
+        self.client.unary_call(&METHOD_SPREADSHEET_API_DELETE_ROWS, req, opt)
+    }
+
+    pub fn delete_rows(&self, req: &super::api::DeleteRowsRequest) -> ::grpcio::Result<super::api::DeleteRowsResponse> {
+        self.delete_rows_opt(req, ::grpcio::CallOption::default())
+    }
+
+    pub fn delete_rows_async_opt(&self, req: &super::api::DeleteRowsRequest, opt: ::grpcio::CallOption) -> ::grpcio::Result<::grpcio::ClientUnaryReceiver<super::api::DeleteRowsResponse>> {
+        self.client.unary_call_async(&METHOD_SPREADSHEET_API_DELETE_ROWS, req, opt)
+    }
+
+    pub fn delete_rows_async(&self, req: &super::api::DeleteRowsRequest) -> ::grpcio::Result<::grpcio::ClientUnaryReceiver<super::api::DeleteRowsResponse>> {
+        self.delete_rows_async_opt(req, ::grpcio::CallOption::default())
+    }
+
+    pub fn insert_columns_opt(&self, req: &super::api::InsertColumnsRequest, opt: ::grpcio::CallOption) -> ::grpcio::Result<super::api::InsertColumnsResponse> {
+        self.client.unary_call(&METHOD_SPREADSHEET_API_INSERT_COLUMNS, req, opt)
+    }
+
+    pub fn insert_columns(&self, req: &super::api::InsertColumnsRequest) -> ::grpcio::Result<super::api::InsertColumnsResponse> {
+        self.insert_columns_opt(req, ::grpcio::CallOption::default())
+    }
+
+    pub fn insert_columns_async_opt(&self, req: &super::api::InsertColumnsRequest, opt: ::grpcio::CallOption) -> ::grpcio::Result<::grpcio::ClientUnaryReceiver<super::api::InsertColumnsResponse>> {
+        self.client.unary_call_async(&METHOD_SPREADSHEET_API_INSERT_COLUMNS, req, opt)
+    }
+
+    pub fn insert_columns_async(&self, req: &super::api::InsertColumnsRequest) -> ::grpcio::Result<::grpcio::ClientUnaryReceiver<super::api::InsertColumnsResponse>> {
+        self.insert_columns_async_opt(req, ::grpcio::CallOption::default())
+    }
+
+    pub fn delete_columns_opt(&self, req: &super::api::DeleteColumnsRequest, opt: ::grpcio::CallOption) -> ::grpcio::Result<super::api::DeleteColumnsResponse> {
+        self.client.unary_call(&METHOD_SPREADSHEET_API_DELETE_COLUMNS, req, opt)
+    }
+
+    pub fn delete_columns(&self, req: &super::api::DeleteColumnsRequest) -> ::grpcio::Result<super::api::DeleteColumnsResponse> {
+        self.delete_columns_opt(req, ::grpcio::CallOption::default())
+    }
+
+    pub fn delete_columns_async_opt(&self, req: &super::api::DeleteColumnsRequest, opt: ::grpcio::CallOption) -> ::grpcio::Result<::grpcio::ClientUnaryReceiver<super::api::DeleteColumnsResponse>> {
+        self.client.unary_call_async(&METHOD_SPREADSHEET_API_DELETE_COLUMNS, req, opt)
+    }
+
+    pub fn delete_columns_async(&self, req: &super::api::DeleteColumnsRequest) -> ::grpcio::Result<::grpcio::ClientUnaryReceiver<super::api::DeleteColumnsResponse>> {
+        self.delete_columns_async_opt(req, ::grpcio::CallOption::default())
+    }
+
+    pub fn move_range_opt(&self, req: &super::api::MoveRangeRequest, opt: ::grpcio::CallOption) -> ::grpcio::Result<super::api::MoveRangeResponse> {
+        self.client.unary_call(&METHOD_SPREADSHEET_API_MOVE_RANGE, req, opt)
+    }
+
+    pub fn move_range(&self, req: &super::api::MoveRangeRequest) -> ::grpcio::Result<super::api::MoveRangeResponse> {
+        self.move_range_opt(req, ::grpcio::CallOption::default())
+    }
+
+    pub fn move_range_async_opt(&self, req: &super::api::MoveRangeRequest, opt: ::grpcio::CallOption) -> ::grpcio::Result<::grpcio::ClientUnaryReceiver<super::api::MoveRangeResponse>> {
+        self.client.unary_call_async(&METHOD_SPREADSHEET_API_MOVE_RANGE, req, opt)
+    }
+
+    pub fn move_range_async(&self, req: &super::api::MoveRangeRequest) -> ::grpcio::Result<::grpcio::ClientUnaryReceiver<super::api::MoveRangeResponse>> {
+        self.move_range_async_opt(req, ::grpcio::CallOption::default())
+    }
+
+    pub fn copy_range_opt(&self, req: &super::api::CopyRangeRequest, opt: ::grpcio::CallOption) -> ::grpcio::Result<super::api::CopyRangeResponse> {
+        self.client.unary_call(&METHOD_SPREADSHEET_API_COPY_RANGE, req, opt)
+    }
+
+    pub fn copy_range(&self, req: &super::api::CopyRangeRequest) -> ::grpcio::Result<super::api::CopyRangeResponse> {
+        self.copy_range_opt(req, ::grpcio::CallOption::default())
+    }
+
+    pub fn copy_range_async_opt(&self, req: &super::api::CopyRangeRequest, opt: ::grpcio::CallOption) -> ::grpcio::Result<::grpcio::ClientUnaryReceiver<super::api::CopyRangeResponse>> {
+        self.client.unary_call_async(&METHOD_SPREADSHEET_API_COPY_RANGE, req, opt)
+    }
+
+    pub fn copy_range_async(&self, req: &super::api::CopyRangeRequest) -> ::grpcio::Result<::grpcio::ClientUnaryReceiver<super::api::CopyRangeResponse>> {
+        self.copy_range_async_opt(req, ::grpcio::CallOption::default())
+    }
+
+    pub fn fill_opt(&self, req: &super::api::FillRequest, opt: ::grpcio::CallOption) -> ::grpcio::Result<super::api::FillResponse> {
+        self.client.unary_call(&METHOD_SPREADSHEET_API_FILL, req, opt)
+    }
+
+    pub fn fill(&self, req: &super::api::FillRequest) -> ::grpcio::Result<super::api::FillResponse> {
+        self.fill_opt(req, ::grpcio::CallOption::default())
+    }
+
+    pub fn fill_async_opt(&self, req: &super::api::FillRequest, opt: ::grpcio::CallOption) -> ::grpcio::Result<::grpcio::ClientUnaryReceiver<super::api::FillResponse>> {
+        self.client.unary_call_async(&METHOD_SPREADSHEET_API_FILL, req, opt)
+    }
+
+    pub fn fill_async(&self, req: &super::api::FillRequest) -> ::grpcio::Result<::grpcio::ClientUnaryReceiver<super::api::FillResponse>> {
+        self.fill_async_opt(req, ::grpcio::CallOption::default())
+    }
+
+    pub fn set_iterative_calculation_opt(&self, req: &super::api::SetIterativeCalculationRequest, opt: ::grpcio::CallOption) -> ::grpcio::Result<super::api::SetIterativeCalculationResponse> {
+        self.client.unary_call(&METHOD_SPREADSHEET_API_SET_ITERATIVE_CALCULATION, req, opt)
+    }
+
+    pub fn set_iterative_calculation(&self, req: &super::api::SetIterativeCalculationRequest) -> ::grpcio::Result<super::api::SetIterativeCalculationResponse> {
+        self.set_iterative_calculation_opt(req, ::grpcio::CallOption::default())
+    }
+
+    pub fn set_iterative_calculation_async_opt(&self, req: &super::api::SetIterativeCalculationRequest, opt: ::grpcio::CallOption) -> ::grpcio::Result<::grpcio::ClientUnaryReceiver<super::api::SetIterativeCalculationResponse>> {
+        self.client.unary_call_async(&METHOD_SPREADSHEET_API_SET_ITERATIVE_CALCULATION, req, opt)
+    }
+
+    pub fn set_iterative_calculation_async(&self, req: &super::api::SetIterativeCalculationRequest) -> ::grpcio::Result<::grpcio::ClientUnaryReceiver<super::api::SetIterativeCalculationResponse>> {
+        self.set_iterative_calculation_async_opt(req, ::grpcio::CallOption::default())
+    }
+
+    pub fn get_precedents_opt(&self, req: &super::api::GetPrecedentsRequest, opt: ::grpcio::CallOption) -> ::grpcio::Result<super::api::GetPrecedentsResponse> {
+        self.client.unary_call(&METHOD_SPREADSHEET_API_GET_PRECEDENTS, req, opt)
+    }
+
+    pub fn get_precedents(&self, req: &super::api::GetPrecedentsRequest) -> ::grpcio::Result<super::api::GetPrecedentsResponse> {
+        self.get_precedents_opt(req, ::grpcio::CallOption::default())
+    }
+
+    pub fn get_precedents_async_opt(&self, req: &super::api::GetPrecedentsRequest, opt: ::grpcio::CallOption) -> ::grpcio::Result<::grpcio::ClientUnaryReceiver<super::api::GetPrecedentsResponse>> {
+        self.client.unary_call_async(&METHOD_SPREADSHEET_API_GET_PRECEDENTS, req, opt)
+    }
+
+    pub fn get_precedents_async(&self, req: &super::api::GetPrecedentsRequest) -> ::grpcio::Result<::grpcio::ClientUnaryReceiver<super::api::GetPrecedentsResponse>> {
+        self.get_precedents_async_opt(req, ::grpcio::CallOption::default())
+    }
+
+    pub fn get_dependents_opt(&self, req: &super::api::GetDependentsRequest, opt: ::grpcio::CallOption) -> ::grpcio::Result<super::api::GetDependentsResponse> {
+        self.client.unary_call(&METHOD_SPREADSHEET_API_GET_DEPENDENTS, req, opt)
+    }
+
+    pub fn get_dependents(&self, req: &super::api::GetDependentsRequest) -> ::grpcio::Result<super::api::GetDependentsResponse> {
+        self.get_dependents_opt(req, ::grpcio::CallOption::default())
+    }
+
+    pub fn get_dependents_async_opt(&self, req: &super::api::GetDependentsRequest, opt: ::grpcio::CallOption) -> ::grpcio::Result<::grpcio::ClientUnaryReceiver<super::api::GetDependentsResponse>> {
+        self.client.unary_call_async(&METHOD_SPREADSHEET_API_GET_DEPENDENTS, req, opt)
+    }
+
+    pub fn get_dependents_async(&self, req: &super::api::GetDependentsRequest) -> ::grpcio::Result<::grpcio::ClientUnaryReceiver<super::api::GetDependentsResponse>> {
+        self.get_dependents_async_opt(req, ::grpcio::CallOption::default())
+    }
+
+    pub fn get_cycles_opt(&self, req: &super::api::GetCyclesRequest, opt: ::grpcio::CallOption) -> ::grpcio::Result<super::api::GetCyclesResponse> {
+        self.client.unary_call(&METHOD_SPREADSHEET_API_GET_CYCLES, req, opt)
+    }
+
+    pub fn get_cycles(&self, req: &super::api::GetCyclesRequest) -> ::grpcio::Result<super::api::GetCyclesResponse> {
+        self.get_cycles_opt(req, ::grpcio::CallOption::default())
+    }
+
+    pub fn get_cycles_async_opt(&self, req: &super::api::GetCyclesRequest, opt: ::grpcio::CallOption) -> ::grpcio::Result<::grpcio::ClientUnaryReceiver<super::api::GetCyclesResponse>> {
+        self.client.unary_call_async(&METHOD_SPREADSHEET_API_GET_CYCLES, req, opt)
+    }
+
+    pub fn get_cycles_async(&self, req: &super::api::GetCyclesRequest) -> ::grpcio::Result<::grpcio::ClientUnaryReceiver<super::api::GetCyclesResponse>> {
+        self.get_cycles_async_opt(req, ::grpcio::CallOption::default())
+    }
+
+    pub fn set_calculation_mode_opt(&self, req: &super::api::SetCalculationModeRequest, opt: ::grpcio::CallOption) -> ::grpcio::Result<super::api::SetCalculationModeResponse> {
+        self.client.unary_call(&METHOD_SPREADSHEET_API_SET_CALCULATION_MODE, req, opt)
+    }
+
+    pub fn set_calculation_mode(&self, req: &super::api::SetCalculationModeRequest) -> ::grpcio::Result<super::api::SetCalculationModeResponse> {
+        self.set_calculation_mode_opt(req, ::grpcio::CallOption::default())
+    }
+
+    pub fn set_calculation_mode_async_opt(&self, req: &super::api::SetCalculationModeRequest, opt: ::grpcio::CallOption) -> ::grpcio::Result<::grpcio::ClientUnaryReceiver<super::api::SetCalculationModeResponse>> {
+        self.client.unary_call_async(&METHOD_SPREADSHEET_API_SET_CALCULATION_MODE, req, opt)
+    }
+
+    pub fn set_calculation_mode_async(&self, req: &super::api::SetCalculationModeRequest) -> ::grpcio::Result<::grpcio::ClientUnaryReceiver<super::api::SetCalculationModeResponse>> {
+        self.set_calculation_mode_async_opt(req, ::grpcio::CallOption::default())
+    }
+
+    pub fn export_graph_opt(&self, req: &super::api::ExportGraphRequest, opt: ::grpcio::CallOption) -> ::grpcio::Result<super::api::ExportGraphResponse> {
+        self.client.unary_call(&METHOD_SPREADSHEET_API_EXPORT_GRAPH, req, opt)
+    }
+
+    pub fn export_graph(&self, req: &super::api::ExportGraphRequest) -> ::grpcio::Result<super::api::ExportGraphResponse> {
+        self.export_graph_opt(req, ::grpcio::CallOption::default())
+    }
+
+    pub fn export_graph_async_opt(&self, req: &super::api::ExportGraphRequest, opt: ::grpcio::CallOption) -> ::grpcio::Result<::grpcio::ClientUnaryReceiver<super::api::ExportGraphResponse>> {
+        self.client.unary_call_async(&METHOD_SPREADSHEET_API_EXPORT_GRAPH, req, opt)
+    }
+
+    pub fn export_graph_async(&self, req: &super::api::ExportGraphRequest) -> ::grpcio::Result<::grpcio::ClientUnaryReceiver<super::api::ExportGraphResponse>> {
+        self.export_graph_async_opt(req, ::grpcio::CallOption::default())
+    }
     pub fn spawn<F>(&self, f: F) where F: ::futures::Future<Output = ()> + Send + 'static {
         self.client.spawn(f)
     }
@@ -81,6 +610,29 @@ impl SpreadsheetApiClient {
 pub trait SpreadsheetApi {
     fn insert_cells(&mut self, ctx: ::grpcio::RpcContext, req: super::api::InsertCellsRequest, sink: ::grpcio::UnarySink<super::api::InsertCellsResponse>);
     fn get_cells(&mut self, ctx: ::grpcio::RpcContext, req: super::api::GetCellsRequest, sink: ::grpcio::UnarySink<super::api::GetCellsResponse>);
+    fn recalculate(&mut self, ctx: ::grpcio::RpcContext, req: super::api::RecalculateRequest, sink: ::grpcio::UnarySink<super::api::RecalculateResponse>);
+    fn set_locale(&mut self, ctx: ::grpcio::RpcContext, req: super::api::SetLocaleRequest, sink: ::grpcio::UnarySink<super::api::SetLocaleResponse>);
+    fn set_format(&mut self, ctx: ::grpcio::RpcContext, req: super::api::SetFormatRequest, sink: ::grpcio::UnarySink<super::api::SetFormatResponse>);
+    fn set_styles(&mut self, ctx: ::grpcio::RpcContext, req: super::api::SetStylesRequest, sink: ::grpcio::UnarySink<super::api::SetStylesResponse>);
+    fn set_conditional_formats(&mut self, ctx: ::grpcio::RpcContext, req: super::api::SetConditionalFormatsRequest, sink: ::grpcio::UnarySink<super::api::SetConditionalFormatsResponse>);
+    fn get_conditional_formats(&mut self, ctx: ::grpcio::RpcContext, req: super::api::GetConditionalFormatsRequest, sink: ::grpcio::UnarySink<super::api::GetConditionalFormatsResponse>);
+    fn set_validation_rules(&mut self, ctx: ::grpcio::RpcContext, req: super::api::SetValidationRulesRequest, sink: ::grpcio::UnarySink<super::api::SetValidationRulesResponse>);
+    fn get_validation_rules(&mut self, ctx: ::grpcio::RpcContext, req: super::api::GetValidationRulesRequest, sink: ::grpcio::UnarySink<super::api::GetValidationRulesResponse>);
+    fn get_list_values(&mut self, ctx: ::grpcio::RpcContext, req: super::api::GetListValuesRequest, sink: ::grpcio::UnarySink<super::api::GetListValuesResponse>);
+    fn clear_cells(&mut self, ctx: ::grpcio::RpcContext, req: super::api::ClearCellsRequest, sink: ::grpcio::UnarySink<super::api::ClearCellsResponse>);
+    fn insert_rows(&mut self, ctx: ::grpcio::RpcContext, req: super::api::InsertRowsRequest, sink: ::grpcio::UnarySink<super::api::InsertRowsResponse>);
+    fn delete_rows(&mut self, ctx: ::grpcio::RpcContext, req: super::api::DeleteRowsRequest, sink: ::grpcio::UnarySink<super::api::DeleteRowsResponse>);
+    fn insert_columns(&mut self, ctx: ::grpcio::RpcContext, req: super::api::InsertColumnsRequest, sink: ::grpcio::UnarySink<super::api::InsertColumnsResponse>);
+    fn delete_columns(&mut self, ctx: ::grpcio::RpcContext, req: super::api::DeleteColumnsRequest, sink: ::grpcio::UnarySink<super::api::DeleteColumnsResponse>);
+    fn move_range(&mut self, ctx: ::grpcio::RpcContext, req: super::api::MoveRangeRequest, sink: ::grpcio::UnarySink<super::api::MoveRangeResponse>);
+    fn copy_range(&mut self, ctx: ::grpcio::RpcContext, req: super::api::CopyRangeRequest, sink: ::grpcio::UnarySink<super::api::CopyRangeResponse>);
+    fn fill(&mut self, ctx: ::grpcio::RpcContext, req: super::api::FillRequest, sink: ::grpcio::UnarySink<super::api::FillResponse>);
+    fn set_iterative_calculation(&mut self, ctx: ::grpcio::RpcContext, req: super::api::SetIterativeCalculationRequest, sink: ::grpcio::UnarySink<super::api::SetIterativeCalculationResponse>);
+    fn get_precedents(&mut self, ctx: ::grpcio::RpcContext, req: super::api::GetPrecedentsRequest, sink: ::grpcio::UnarySink<super::api::GetPrecedentsResponse>);
+    fn get_dependents(&mut self, ctx: ::grpcio::RpcContext, req: super::api::GetDependentsRequest, sink: ::grpcio::UnarySink<super::api::GetDependentsResponse>);
+    fn get_cycles(&mut self, ctx: ::grpcio::RpcContext, req: super::api::GetCyclesRequest, sink: ::grpcio::UnarySink<super::api::GetCyclesResponse>);
+    fn set_calculation_mode(&mut self, ctx: ::grpcio::RpcContext, req: super::api::SetCalculationModeRequest, sink: ::grpcio::UnarySink<super::api::SetCalculationModeResponse>);
+    fn export_graph(&mut self, ctx: ::grpcio::RpcContext, req: super::api::ExportGraphRequest, sink: ::grpcio::UnarySink<super::api::ExportGraphResponse>);
 }
 
 pub fn create_spreadsheet_api<S: SpreadsheetApi + Send + Clone + 'static>(s: S) -> ::grpcio::Service {
@@ -89,9 +641,101 @@ pub fn create_spreadsheet_api<S: SpreadsheetApi + Send + Clone + 'static>(s: S)
     builder = builder.add_unary_handler(&METHOD_SPREADSHEET_API_INSERT_CELLS, move |ctx, req, resp| {
         instance.insert_cells(ctx, req, resp)
     });
-    let mut instance = s;
+    let mut instance = s.clone();
     builder = builder.add_unary_handler(&METHOD_SPREADSHEET_API_GET_CELLS, move |ctx, req, resp| {
         instance.get_cells(ctx, req, resp)
     });
+    let mut instance = s.clone();
+    builder = builder.add_unary_handler(&METHOD_SPREADSHEET_API_RECALCULATE, move |ctx, req, resp| {
+        instance.recalculate(ctx, req, resp)
+    });
+    let mut instance = s.clone();
+    builder = builder.add_unary_handler(&METHOD_SPREADSHEET_API_SET_LOCALE, move |ctx, req, resp| {
+        instance.set_locale(ctx, req, resp)
+    });
+    let mut instance = s.clone();
+    builder = builder.add_unary_handler(&METHOD_SPREADSHEET_API_SET_FORMAT, move |ctx, req, resp| {
+        instance.set_format(ctx, req, resp)
+    });
+    let mut instance = s.clone();
+    builder = builder.add_unary_handler(&METHOD_SPREADSHEET_API_SET_STYLES, move |ctx, req, resp| {
+        instance.set_styles(ctx, req, resp)
+    });
+    let mut instance = s.clone();
+    builder = builder.add_unary_handler(&METHOD_SPREADSHEET_API_SET_CONDITIONAL_FORMATS, move |ctx, req, resp| {
+        instance.set_conditional_formats(ctx, req, resp)
+    });
+    let mut instance = s.clone();
+    builder = builder.add_unary_handler(&METHOD_SPREADSHEET_API_GET_CONDITIONAL_FORMATS, move |ctx, req, resp| {
+        instance.get_conditional_formats(ctx, req, resp)
+    });
+    let mut instance = s.clone();
+    builder = builder.add_unary_handler(&METHOD_SPREADSHEET_API_SET_VALIDATION_RULES, move |ctx, req, resp| {
+        instance.set_validation_rules(ctx, req, resp)
+    });
+    let mut instance = s.clone();
+    builder = builder.add_unary_handler(&METHOD_SPREADSHEET_API_GET_VALIDATION_RULES, move |ctx, req, resp| {
+        instance.get_validation_rules(ctx, req, resp)
+    });
+    let mut instance = s.clone();
+    builder = builder.add_unary_handler(&METHOD_SPREADSHEET_API_GET_LIST_VALUES, move |ctx, req, resp| {
+        instance.get_list_values(ctx, req, resp)
+    });
+    let mut instance = s.clone();
+    builder = builder.add_unary_handler(&METHOD_SPREADSHEET_API_CLEAR_CELLS, move |ctx, req, resp| {
+        instance.clear_cells(ctx, req, resp)
+    });
+    let mut instance = s.clone();
+    builder = builder.add_unary_handler(&METHOD_SPREADSHEET_API_INSERT_ROWS, move |ctx, req, resp| {
+        instance.insert_rows(ctx, req, resp)
+    });
+    let mut instance = s.clone();
+    builder = builder.add_unary_handler(&METHOD_SPREADSHEET_API_DELETE_ROWS, move |ctx, req, resp| {
+        instance.delete_rows(ctx, req, resp)
+    });
+    let mut instance = s.clone();
+    builder = builder.add_unary_handler(&METHOD_SPREADSHEET_API_INSERT_COLUMNS, move |ctx, req, resp| {
+        instance.insert_columns(ctx, req, resp)
+    });
+    let mut instance = s.clone();
+    builder = builder.add_unary_handler(&METHOD_SPREADSHEET_API_DELETE_COLUMNS, move |ctx, req, resp| {
+        instance.delete_columns(ctx, req, resp)
+    });
+    let mut instance = s.clone();
+    builder = builder.add_unary_handler(&METHOD_SPREADSHEET_API_MOVE_RANGE, move |ctx, req, resp| {
+        instance.move_range(ctx, req, resp)
+    });
+    let mut instance = s.clone();
+    builder = builder.add_unary_handler(&METHOD_SPREADSHEET_API_COPY_RANGE, move |ctx, req, resp| {
+        instance.copy_range(ctx, req, resp)
+    });
+    let mut instance = s.clone();
+    builder = builder.add_unary_handler(&METHOD_SPREADSHEET_API_FILL, move |ctx, req, resp| {
+        instance.fill(ctx, req, resp)
+    });
+    let mut instance = s.clone();
+    builder = builder.add_unary_handler(&METHOD_SPREADSHEET_API_SET_ITERATIVE_CALCULATION, move |ctx, req, resp| {
+        instance.set_iterative_calculation(ctx, req, resp)
+    });
+    let mut instance = s.clone();
+    builder = builder.add_unary_handler(&METHOD_SPREADSHEET_API_GET_PRECEDENTS, move |ctx, req, resp| {
+        instance.get_precedents(ctx, req, resp)
+    });
+    let mut instance = s.clone();
+    builder = builder.add_unary_handler(&METHOD_SPREADSHEET_API_GET_DEPENDENTS, move |ctx, req, resp| {
+        instance.get_dependents(ctx, req, resp)
+    });
+    let mut instance = s.clone();
+    builder = builder.add_unary_handler(&METHOD_SPREADSHEET_API_GET_CYCLES, move |ctx, req, resp| {
+        instance.get_cycles(ctx, req, resp)
+    });
+    let mut instance = s.clone();
+    builder = builder.add_unary_handler(&METHOD_SPREADSHEET_API_SET_CALCULATION_MODE, move |ctx, req, resp| {
+        instance.set_calculation_mode(ctx, req, resp)
+    });
+    let mut instance = s;
+    builder = builder.add_unary_handler(&METHOD_SPREADSHEET_API_EXPORT_GRAPH, move |ctx, req, resp| {
+        instance.export_graph(ctx, req, resp)
+    });
     builder.build()
 }
@@ -3,12 +3,14 @@ use spreadsheet_service::{models, parser, service};
 use futures::channel::oneshot;
 use futures::executor::block_on;
 use futures::prelude::*;
-use grpcio::{ChannelBuilder, Environment, ResourceQuota, RpcContext, ServerBuilder, UnarySink};
-use service::CellsService;
+use grpcio::{
+    ChannelBuilder, Environment, ResourceQuota, RpcContext, RpcStatus, RpcStatusCode,
+    ServerBuilder, UnarySink,
+};
 use std::collections::HashMap;
 use std::io::Read;
 use std::sync::{Arc, RwLock};
-use std::{io, thread};
+use std::{env, io, thread};
 
 #[path = "proto/grpc/api.rs"]
 mod api;
@@ -17,7 +19,7 @@ mod api_grpc;
 
 #[derive(Clone)]
 struct SpreadsheetService {
-    cells_service: Arc<RwLock<HashMap<String, service::MemoryCellsService>>>,
+    cells_service: Arc<RwLock<service::Workbook>>,
 }
 
 impl SpreadsheetService {
@@ -25,8 +27,7 @@ impl SpreadsheetService {
         self.cells_service
             .write()
             .unwrap()
-            .entry(table_id.to_owned())
-            .or_insert(service::MemoryCellsService::new(50, 26));
+            .create_table_if_not_exists(table_id);
     }
 }
 
@@ -35,22 +36,27 @@ impl SpreadsheetService {
         &mut self,
         req: api::InsertCellsRequest,
     ) -> Result<api::InsertCellsResponse, ()> {
-        let insert_res: Result<Vec<models::Cell>, parser::Error>;
+        let insert_res: Result<(Vec<models::Cell>, Vec<models::ValidationError>), parser::Error>;
+        let mut styles = HashMap::new();
         {
             let cells = insert_cells_to_models(req.get_cells());
-            self.create_table_if_not_exists(req.get_tableId());
 
             let cs = &mut self.cells_service.write().unwrap();
-            let service = cs.get_mut(req.get_tableId()).unwrap();
-
-            insert_res = service.insert_cells(&cells);
+            insert_res = cs.insert_cells(req.get_tableId(), &cells);
+            if let Ok((inserted_cells, _)) = &insert_res {
+                styles = cell_styles_to_api(cs, req.get_tableId(), inserted_cells);
+            }
         }
         let mut resp = api::InsertCellsResponse::default();
         match insert_res {
-            Ok(inserted_cells) => {
+            Ok((inserted_cells, errors)) => {
                 resp.set_cells(protobuf::RepeatedField::from_vec(model_cells_to_api(
                     inserted_cells,
                 )));
+                resp.set_styles(styles);
+                resp.set_errors(protobuf::RepeatedField::from_vec(
+                    model_validation_errors_to_api(errors),
+                ));
                 Ok(resp)
             }
             Err(e) => {
@@ -61,16 +67,464 @@ impl SpreadsheetService {
     }
 
     fn get_cells(&mut self, req: api::GetCellsRequest) -> Result<api::GetCellsResponse, ()> {
+        let rect = api_rect_to_model(req.get_rect());
+        let table_id = req.get_tableId();
+        // Cells are read under the read lock unless stale cells of tables calculated on
+        // demand have to be recomputed first
+        let read = {
+            let cs = &self.cells_service.read().unwrap();
+            match cs.has_table(table_id) && !cs.needs_recalc(table_id, &rect) {
+                true => {
+                    let cells = cs.get_cells(table_id, rect.clone());
+                    let styles = cell_styles_to_api(cs, table_id, &cells);
+                    Some((cells, styles))
+                }
+                false => None,
+            }
+        };
+        let (cells, styles) = match read {
+            Some(read) => read,
+            None => {
+                self.create_table_if_not_exists(table_id);
+                let cs = &mut self.cells_service.write().unwrap();
+                let cells = cs.read_cells(table_id, rect);
+                let styles = cell_styles_to_api(cs, table_id, &cells);
+                (cells, styles)
+            }
+        };
+        let mut resp = api::GetCellsResponse::default();
+        resp.set_cells(protobuf::RepeatedField::from_vec(model_cells_to_api(cells)));
+        resp.set_styles(styles);
+        Ok(resp)
+    }
+
+    fn recalculate(
+        &mut self,
+        req: api::RecalculateRequest,
+    ) -> Result<api::RecalculateResponse, ()> {
+        let cells: Vec<models::Cell>;
+        let styles: HashMap<u32, api::Style>;
+        {
+            let cs = &mut self.cells_service.write().unwrap();
+            cells = match req.has_rect() {
+                true => cs.recalculate_range(req.get_tableId(), api_rect_to_model(req.get_rect())),
+                false => cs.recalculate(req.get_tableId()),
+            };
+            styles = cell_styles_to_api(cs, req.get_tableId(), &cells);
+        }
+        let mut resp = api::RecalculateResponse::default();
+        resp.set_cells(protobuf::RepeatedField::from_vec(model_cells_to_api(cells)));
+        resp.set_styles(styles);
+        Ok(resp)
+    }
+
+    fn clear_cells(&mut self, req: api::ClearCellsRequest) -> Result<api::ClearCellsResponse, ()> {
         let rect = api_rect_to_model(req.get_rect());
         let cells: Vec<models::Cell>;
+        let styles: HashMap<u32, api::Style>;
+        {
+            let cs = &mut self.cells_service.write().unwrap();
+            cells = cs.clear_cells(req.get_tableId(), rect);
+            styles = cell_styles_to_api(cs, req.get_tableId(), &cells);
+        }
+        let mut resp = api::ClearCellsResponse::default();
+        resp.set_cells(protobuf::RepeatedField::from_vec(model_cells_to_api(cells)));
+        resp.set_styles(styles);
+        Ok(resp)
+    }
+
+    fn set_locale(&mut self, req: api::SetLocaleRequest) -> Result<api::SetLocaleResponse, String> {
+        let locale = match parser::Locale::from_name(req.get_locale()) {
+            Some(l) => l,
+            None => return Err(format!("unknown locale: {}", req.get_locale())),
+        };
+        let cells: Vec<models::Cell>;
+        let styles: HashMap<u32, api::Style>;
+        {
+            let cs = &mut self.cells_service.write().unwrap();
+            cells = cs.set_locale(req.get_tableId(), locale);
+            styles = cell_styles_to_api(cs, req.get_tableId(), &cells);
+        }
+        let mut resp = api::SetLocaleResponse::default();
+        resp.set_cells(protobuf::RepeatedField::from_vec(model_cells_to_api(cells)));
+        resp.set_styles(styles);
+        Ok(resp)
+    }
+
+    fn set_styles(&mut self, req: api::SetStylesRequest) -> Result<api::SetStylesResponse, String> {
+        let rect = api_rect_to_model(req.get_rect());
+        let style = api_style_to_model(req.get_style());
+        let cells: Vec<models::Cell>;
+        let styles: HashMap<u32, api::Style>;
+        {
+            let cs = &mut self.cells_service.write().unwrap();
+            cells = cs
+                .set_styles(req.get_tableId(), rect, &style, req.get_fields())
+                .map_err(|e| e.to_string())?;
+            styles = cell_styles_to_api(cs, req.get_tableId(), &cells);
+        }
+        let mut resp = api::SetStylesResponse::default();
+        resp.set_cells(protobuf::RepeatedField::from_vec(model_cells_to_api(cells)));
+        resp.set_styles(styles);
+        Ok(resp)
+    }
+
+    fn set_format(&mut self, req: api::SetFormatRequest) -> Result<api::SetFormatResponse, String> {
+        let rect = api_rect_to_model(req.get_rect());
+        let cells: Vec<models::Cell>;
+        {
+            let cs = &mut self.cells_service.write().unwrap();
+            cells = cs
+                .set_format(req.get_tableId(), rect, req.get_format())
+                .map_err(|e| e.to_string())?;
+        }
+        let mut resp = api::SetFormatResponse::default();
+        resp.set_cells(protobuf::RepeatedField::from_vec(model_cells_to_api(cells)));
+        Ok(resp)
+    }
+
+    fn set_conditional_formats(
+        &mut self,
+        req: api::SetConditionalFormatsRequest,
+    ) -> Result<api::SetConditionalFormatsResponse, String> {
+        let formats = req
+            .get_formats()
+            .iter()
+            .map(api_conditional_format_to_model)
+            .collect::<Result<Vec<models::ConditionalFormat>, String>>()?;
+        let cells: Vec<models::Cell>;
+        let styles: HashMap<u32, api::Style>;
+        {
+            let cs = &mut self.cells_service.write().unwrap();
+            cells = cs
+                .set_conditional_formats(req.get_tableId(), formats)
+                .map_err(|e| e.to_string())?;
+            styles = cell_styles_to_api(cs, req.get_tableId(), &cells);
+        }
+        let mut resp = api::SetConditionalFormatsResponse::default();
+        resp.set_cells(protobuf::RepeatedField::from_vec(model_cells_to_api(cells)));
+        resp.set_styles(styles);
+        Ok(resp)
+    }
+
+    fn get_conditional_formats(
+        &mut self,
+        req: api::GetConditionalFormatsRequest,
+    ) -> Result<api::GetConditionalFormatsResponse, ()> {
+        let formats: Vec<models::ConditionalFormat>;
         {
-            self.create_table_if_not_exists(req.get_tableId());
             let cs = &mut self.cells_service.read().unwrap();
-            let service = cs.get(req.get_tableId()).unwrap();
-            cells = service.get_cells(rect);
+            formats = cs.conditional_formats(req.get_tableId());
         }
-        let mut resp = api::GetCellsResponse::default();
+        let mut resp = api::GetConditionalFormatsResponse::default();
+        resp.set_formats(
+            formats
+                .iter()
+                .map(model_conditional_format_to_api)
+                .collect(),
+        );
+        Ok(resp)
+    }
+
+    fn set_validation_rules(
+        &mut self,
+        req: api::SetValidationRulesRequest,
+    ) -> Result<api::SetValidationRulesResponse, String> {
+        let rules = req
+            .get_rules()
+            .iter()
+            .map(api_validation_rule_to_model)
+            .collect::<Result<Vec<models::ValidationRule>, String>>()?;
+        {
+            let cs = &mut self.cells_service.write().unwrap();
+            cs.set_validation_rules(req.get_tableId(), rules)
+                .map_err(|e| e.to_string())?;
+        }
+        Ok(api::SetValidationRulesResponse::default())
+    }
+
+    fn get_validation_rules(
+        &mut self,
+        req: api::GetValidationRulesRequest,
+    ) -> Result<api::GetValidationRulesResponse, ()> {
+        let rules: Vec<models::ValidationRule>;
+        {
+            let cs = &mut self.cells_service.read().unwrap();
+            rules = cs.validation_rules(req.get_tableId());
+        }
+        let mut resp = api::GetValidationRulesResponse::default();
+        resp.set_rules(rules.iter().map(model_validation_rule_to_api).collect());
+        Ok(resp)
+    }
+
+    fn get_list_values(
+        &mut self,
+        req: api::GetListValuesRequest,
+    ) -> Result<api::GetListValuesResponse, ()> {
+        let values: Option<Vec<String>>;
+        {
+            let cs = &mut self.cells_service.read().unwrap();
+            values = cs.list_values(req.get_tableId(), req.row, req.col);
+        }
+        let mut resp = api::GetListValuesResponse::default();
+        resp.set_values(protobuf::RepeatedField::from_vec(
+            values.unwrap_or_default(),
+        ));
+        Ok(resp)
+    }
+
+    fn get_precedents(
+        &mut self,
+        req: api::GetPrecedentsRequest,
+    ) -> Result<api::GetPrecedentsResponse, ()> {
+        let ranges: Vec<models::TracedRange>;
+        {
+            let cs = &mut self.cells_service.read().unwrap();
+            ranges = cs.trace_precedents(req.get_tableId(), req.row, req.col, req.maxDepth);
+        }
+        let mut resp = api::GetPrecedentsResponse::default();
+        resp.set_ranges(protobuf::RepeatedField::from_vec(
+            model_traced_ranges_to_api(ranges),
+        ));
+        Ok(resp)
+    }
+
+    fn get_dependents(
+        &mut self,
+        req: api::GetDependentsRequest,
+    ) -> Result<api::GetDependentsResponse, ()> {
+        let ranges: Vec<models::TracedRange>;
+        {
+            let cs = &mut self.cells_service.read().unwrap();
+            ranges = cs.trace_dependents(req.get_tableId(), req.row, req.col, req.maxDepth);
+        }
+        let mut resp = api::GetDependentsResponse::default();
+        resp.set_ranges(protobuf::RepeatedField::from_vec(
+            model_traced_ranges_to_api(ranges),
+        ));
+        Ok(resp)
+    }
+
+    fn get_cycles(&mut self, req: api::GetCyclesRequest) -> Result<api::GetCyclesResponse, ()> {
+        let cycles: Vec<Vec<models::TableCellLocation>>;
+        {
+            let cs = &mut self.cells_service.read().unwrap();
+            cycles = cs.cycles(req.get_tableId());
+        }
+        let mut resp = api::GetCyclesResponse::default();
+        resp.set_cycles(protobuf::RepeatedField::from_vec(
+            cycles.into_iter().map(model_cycle_to_api).collect(),
+        ));
+        Ok(resp)
+    }
+
+    fn export_graph(
+        &mut self,
+        req: api::ExportGraphRequest,
+    ) -> Result<api::ExportGraphResponse, ()> {
+        let from = match req.has_from() {
+            true => Some(models::TableCellLocation {
+                table: match req.get_from().get_tableId() {
+                    "" => req.get_tableId().to_owned(),
+                    table => table.to_owned(),
+                },
+                loc: models::CellLocation {
+                    row: req.get_from().get_row(),
+                    col: req.get_from().get_col(),
+                },
+            }),
+            false => None,
+        };
+        let format = match req.get_format() {
+            api::GraphFormat::GRAPH_DOT => models::GraphFormat::Dot,
+            api::GraphFormat::GRAPH_JSON => models::GraphFormat::Json,
+        };
+        let graph: models::DependencyGraph;
+        {
+            let cs = &mut self.cells_service.read().unwrap();
+            graph = cs.export_graph(req.get_tableId(), from);
+        }
+        let mut resp = api::ExportGraphResponse::default();
+        resp.set_graph(graph.write(format));
+        Ok(resp)
+    }
+
+    fn insert_rows(
+        &mut self,
+        req: api::InsertRowsRequest,
+    ) -> Result<api::InsertRowsResponse, String> {
+        let cells: Vec<models::Cell>;
+        let styles: HashMap<u32, api::Style>;
+        {
+            let cs = &mut self.cells_service.write().unwrap();
+            cells = cs
+                .insert_rows(req.get_tableId(), req.get_at(), req.get_count())
+                .map_err(|e| e.to_string())?;
+            styles = cell_styles_to_api(cs, req.get_tableId(), &cells);
+        }
+        let mut resp = api::InsertRowsResponse::default();
+        resp.set_cells(protobuf::RepeatedField::from_vec(model_cells_to_api(cells)));
+        resp.set_styles(styles);
+        Ok(resp)
+    }
+
+    fn delete_rows(
+        &mut self,
+        req: api::DeleteRowsRequest,
+    ) -> Result<api::DeleteRowsResponse, String> {
+        let cells: Vec<models::Cell>;
+        let styles: HashMap<u32, api::Style>;
+        {
+            let cs = &mut self.cells_service.write().unwrap();
+            cells = cs
+                .delete_rows(req.get_tableId(), req.get_at(), req.get_count())
+                .map_err(|e| e.to_string())?;
+            styles = cell_styles_to_api(cs, req.get_tableId(), &cells);
+        }
+        let mut resp = api::DeleteRowsResponse::default();
+        resp.set_cells(protobuf::RepeatedField::from_vec(model_cells_to_api(cells)));
+        resp.set_styles(styles);
+        Ok(resp)
+    }
+
+    fn insert_columns(
+        &mut self,
+        req: api::InsertColumnsRequest,
+    ) -> Result<api::InsertColumnsResponse, String> {
+        let cells: Vec<models::Cell>;
+        let styles: HashMap<u32, api::Style>;
+        {
+            let cs = &mut self.cells_service.write().unwrap();
+            cells = cs
+                .insert_cols(req.get_tableId(), req.get_at(), req.get_count())
+                .map_err(|e| e.to_string())?;
+            styles = cell_styles_to_api(cs, req.get_tableId(), &cells);
+        }
+        let mut resp = api::InsertColumnsResponse::default();
+        resp.set_cells(protobuf::RepeatedField::from_vec(model_cells_to_api(cells)));
+        resp.set_styles(styles);
+        Ok(resp)
+    }
+
+    fn delete_columns(
+        &mut self,
+        req: api::DeleteColumnsRequest,
+    ) -> Result<api::DeleteColumnsResponse, String> {
+        let cells: Vec<models::Cell>;
+        let styles: HashMap<u32, api::Style>;
+        {
+            let cs = &mut self.cells_service.write().unwrap();
+            cells = cs
+                .delete_cols(req.get_tableId(), req.get_at(), req.get_count())
+                .map_err(|e| e.to_string())?;
+            styles = cell_styles_to_api(cs, req.get_tableId(), &cells);
+        }
+        let mut resp = api::DeleteColumnsResponse::default();
+        resp.set_cells(protobuf::RepeatedField::from_vec(model_cells_to_api(cells)));
+        resp.set_styles(styles);
+        Ok(resp)
+    }
+
+    fn move_range(&mut self, req: api::MoveRangeRequest) -> Result<api::MoveRangeResponse, String> {
+        let source = api_rect_to_model(req.get_source());
+        let cells: Vec<models::Cell>;
+        let styles: HashMap<u32, api::Style>;
+        {
+            let cs = &mut self.cells_service.write().unwrap();
+            cells = cs
+                .move_range(req.get_tableId(), source, req.get_row(), req.get_col())
+                .map_err(|e| e.to_string())?;
+            styles = cell_styles_to_api(cs, req.get_tableId(), &cells);
+        }
+        let mut resp = api::MoveRangeResponse::default();
+        resp.set_cells(protobuf::RepeatedField::from_vec(model_cells_to_api(cells)));
+        resp.set_styles(styles);
+        Ok(resp)
+    }
+
+    fn copy_range(&mut self, req: api::CopyRangeRequest) -> Result<api::CopyRangeResponse, String> {
+        let source = api_rect_to_model(req.get_source());
+        let mode = api_copy_mode_to_model(req.get_mode());
+        let cells: Vec<models::Cell>;
+        let styles: HashMap<u32, api::Style>;
+        {
+            let cs = &mut self.cells_service.write().unwrap();
+            cells = cs
+                .copy_range(
+                    req.get_tableId(),
+                    source,
+                    req.get_row(),
+                    req.get_col(),
+                    mode,
+                )
+                .map_err(|e| e.to_string())?;
+            styles = cell_styles_to_api(cs, req.get_tableId(), &cells);
+        }
+        let mut resp = api::CopyRangeResponse::default();
+        resp.set_cells(protobuf::RepeatedField::from_vec(model_cells_to_api(cells)));
+        resp.set_styles(styles);
+        Ok(resp)
+    }
+
+    fn fill(&mut self, req: api::FillRequest) -> Result<api::FillResponse, String> {
+        let source = api_rect_to_model(req.get_source());
+        let target = api_rect_to_model(req.get_target());
+        let cells: Vec<models::Cell>;
+        let styles: HashMap<u32, api::Style>;
+        {
+            let cs = &mut self.cells_service.write().unwrap();
+            cells = cs
+                .fill(req.get_tableId(), source, target)
+                .map_err(|e| e.to_string())?;
+            styles = cell_styles_to_api(cs, req.get_tableId(), &cells);
+        }
+        let mut resp = api::FillResponse::default();
+        resp.set_cells(protobuf::RepeatedField::from_vec(model_cells_to_api(cells)));
+        resp.set_styles(styles);
+        Ok(resp)
+    }
+
+    fn set_iterative_calculation(
+        &mut self,
+        req: api::SetIterativeCalculationRequest,
+    ) -> Result<api::SetIterativeCalculationResponse, String> {
+        let settings = match req.get_enabled() {
+            true => Some(models::IterativeCalculation {
+                max_iterations: req.get_maxIterations(),
+                max_change: req.get_maxChange(),
+            }),
+            false => None,
+        };
+        let cells: Vec<models::Cell>;
+        let styles: HashMap<u32, api::Style>;
+        {
+            let cs = &mut self.cells_service.write().unwrap();
+            cells = cs
+                .set_iterative_calculation(req.get_tableId(), settings)
+                .map_err(|e| e.to_string())?;
+            styles = cell_styles_to_api(cs, req.get_tableId(), &cells);
+        }
+        let mut resp = api::SetIterativeCalculationResponse::default();
         resp.set_cells(protobuf::RepeatedField::from_vec(model_cells_to_api(cells)));
+        resp.set_styles(styles);
+        Ok(resp)
+    }
+
+    fn set_calculation_mode(
+        &mut self,
+        req: api::SetCalculationModeRequest,
+    ) -> Result<api::SetCalculationModeResponse, ()> {
+        let mode = api_calculation_mode_to_model(req.get_mode());
+        let cells: Vec<models::Cell>;
+        let styles: HashMap<u32, api::Style>;
+        {
+            let cs = &mut self.cells_service.write().unwrap();
+            cells = cs.set_calculation_mode(req.get_tableId(), mode);
+            styles = cell_styles_to_api(cs, req.get_tableId(), &cells);
+        }
+        let mut resp = api::SetCalculationModeResponse::default();
+        resp.set_cells(protobuf::RepeatedField::from_vec(model_cells_to_api(cells)));
+        resp.set_styles(styles);
         Ok(resp)
     }
 }
@@ -103,11 +557,367 @@ impl api_grpc::SpreadsheetApi for SpreadsheetService {
             .map(|_| ());
         ctx.spawn(f);
     }
+
+    fn recalculate(
+        &mut self,
+        ctx: RpcContext<'_>,
+        req: api::RecalculateRequest,
+        sink: UnarySink<api::RecalculateResponse>,
+    ) {
+        let resp = SpreadsheetService::recalculate(self, req).unwrap();
+        let f = sink
+            .success(resp)
+            .map_err(move |e| println!("failed to reply: {:?}", e))
+            .map(|_| ());
+        ctx.spawn(f);
+    }
+
+    fn clear_cells(
+        &mut self,
+        ctx: RpcContext<'_>,
+        req: api::ClearCellsRequest,
+        sink: UnarySink<api::ClearCellsResponse>,
+    ) {
+        let resp = SpreadsheetService::clear_cells(self, req).unwrap();
+        let f = sink
+            .success(resp)
+            .map_err(move |e| println!("failed to reply: {:?}", e))
+            .map(|_| ());
+        ctx.spawn(f);
+    }
+
+    fn set_locale(
+        &mut self,
+        ctx: RpcContext<'_>,
+        req: api::SetLocaleRequest,
+        sink: UnarySink<api::SetLocaleResponse>,
+    ) {
+        let f = match SpreadsheetService::set_locale(self, req) {
+            Ok(resp) => sink.success(resp),
+            Err(e) => sink.fail(RpcStatus::with_message(RpcStatusCode::INVALID_ARGUMENT, e)),
+        };
+        let f = f
+            .map_err(move |e| println!("failed to reply: {:?}", e))
+            .map(|_| ());
+        ctx.spawn(f);
+    }
+
+    fn set_styles(
+        &mut self,
+        ctx: RpcContext<'_>,
+        req: api::SetStylesRequest,
+        sink: UnarySink<api::SetStylesResponse>,
+    ) {
+        let f = match SpreadsheetService::set_styles(self, req) {
+            Ok(resp) => sink.success(resp),
+            Err(e) => sink.fail(RpcStatus::with_message(RpcStatusCode::INVALID_ARGUMENT, e)),
+        };
+        let f = f
+            .map_err(move |e| println!("failed to reply: {:?}", e))
+            .map(|_| ());
+        ctx.spawn(f);
+    }
+
+    fn set_format(
+        &mut self,
+        ctx: RpcContext<'_>,
+        req: api::SetFormatRequest,
+        sink: UnarySink<api::SetFormatResponse>,
+    ) {
+        let f = match SpreadsheetService::set_format(self, req) {
+            Ok(resp) => sink.success(resp),
+            Err(e) => sink.fail(RpcStatus::with_message(RpcStatusCode::INVALID_ARGUMENT, e)),
+        };
+        let f = f
+            .map_err(move |e| println!("failed to reply: {:?}", e))
+            .map(|_| ());
+        ctx.spawn(f);
+    }
+
+    fn set_conditional_formats(
+        &mut self,
+        ctx: RpcContext<'_>,
+        req: api::SetConditionalFormatsRequest,
+        sink: UnarySink<api::SetConditionalFormatsResponse>,
+    ) {
+        let f = match SpreadsheetService::set_conditional_formats(self, req) {
+            Ok(resp) => sink.success(resp),
+            Err(e) => sink.fail(RpcStatus::with_message(RpcStatusCode::INVALID_ARGUMENT, e)),
+        };
+        let f = f
+            .map_err(move |e| println!("failed to reply: {:?}", e))
+            .map(|_| ());
+        ctx.spawn(f);
+    }
+
+    fn get_conditional_formats(
+        &mut self,
+        ctx: RpcContext<'_>,
+        req: api::GetConditionalFormatsRequest,
+        sink: UnarySink<api::GetConditionalFormatsResponse>,
+    ) {
+        let resp = SpreadsheetService::get_conditional_formats(self, req).unwrap();
+        let f = sink
+            .success(resp)
+            .map_err(move |e| println!("failed to reply: {:?}", e))
+            .map(|_| ());
+        ctx.spawn(f);
+    }
+
+    fn set_validation_rules(
+        &mut self,
+        ctx: RpcContext<'_>,
+        req: api::SetValidationRulesRequest,
+        sink: UnarySink<api::SetValidationRulesResponse>,
+    ) {
+        let f = match SpreadsheetService::set_validation_rules(self, req) {
+            Ok(resp) => sink.success(resp),
+            Err(e) => sink.fail(RpcStatus::with_message(RpcStatusCode::INVALID_ARGUMENT, e)),
+        };
+        let f = f
+            .map_err(move |e| println!("failed to reply: {:?}", e))
+            .map(|_| ());
+        ctx.spawn(f);
+    }
+
+    fn get_validation_rules(
+        &mut self,
+        ctx: RpcContext<'_>,
+        req: api::GetValidationRulesRequest,
+        sink: UnarySink<api::GetValidationRulesResponse>,
+    ) {
+        let resp = SpreadsheetService::get_validation_rules(self, req).unwrap();
+        let f = sink
+            .success(resp)
+            .map_err(move |e| println!("failed to reply: {:?}", e))
+            .map(|_| ());
+        ctx.spawn(f);
+    }
+
+    fn get_list_values(
+        &mut self,
+        ctx: RpcContext<'_>,
+        req: api::GetListValuesRequest,
+        sink: UnarySink<api::GetListValuesResponse>,
+    ) {
+        let resp = SpreadsheetService::get_list_values(self, req).unwrap();
+        let f = sink
+            .success(resp)
+            .map_err(move |e| println!("failed to reply: {:?}", e))
+            .map(|_| ());
+        ctx.spawn(f);
+    }
+
+    fn get_precedents(
+        &mut self,
+        ctx: RpcContext<'_>,
+        req: api::GetPrecedentsRequest,
+        sink: UnarySink<api::GetPrecedentsResponse>,
+    ) {
+        let resp = SpreadsheetService::get_precedents(self, req).unwrap();
+        let f = sink
+            .success(resp)
+            .map_err(move |e| println!("failed to reply: {:?}", e))
+            .map(|_| ());
+        ctx.spawn(f);
+    }
+
+    fn get_dependents(
+        &mut self,
+        ctx: RpcContext<'_>,
+        req: api::GetDependentsRequest,
+        sink: UnarySink<api::GetDependentsResponse>,
+    ) {
+        let resp = SpreadsheetService::get_dependents(self, req).unwrap();
+        let f = sink
+            .success(resp)
+            .map_err(move |e| println!("failed to reply: {:?}", e))
+            .map(|_| ());
+        ctx.spawn(f);
+    }
+
+    fn get_cycles(
+        &mut self,
+        ctx: RpcContext<'_>,
+        req: api::GetCyclesRequest,
+        sink: UnarySink<api::GetCyclesResponse>,
+    ) {
+        let resp = SpreadsheetService::get_cycles(self, req).unwrap();
+        let f = sink
+            .success(resp)
+            .map_err(move |e| println!("failed to reply: {:?}", e))
+            .map(|_| ());
+        ctx.spawn(f);
+    }
+
+    fn export_graph(
+        &mut self,
+        ctx: RpcContext<'_>,
+        req: api::ExportGraphRequest,
+        sink: UnarySink<api::ExportGraphResponse>,
+    ) {
+        let resp = SpreadsheetService::export_graph(self, req).unwrap();
+        let f = sink
+            .success(resp)
+            .map_err(move |e| println!("failed to reply: {:?}", e))
+            .map(|_| ());
+        ctx.spawn(f);
+    }
+
+    fn insert_rows(
+        &mut self,
+        ctx: RpcContext<'_>,
+        req: api::InsertRowsRequest,
+        sink: UnarySink<api::InsertRowsResponse>,
+    ) {
+        let f = match SpreadsheetService::insert_rows(self, req) {
+            Ok(resp) => sink.success(resp),
+            Err(e) => sink.fail(RpcStatus::with_message(RpcStatusCode::INVALID_ARGUMENT, e)),
+        };
+        let f = f
+            .map_err(move |e| println!("failed to reply: {:?}", e))
+            .map(|_| ());
+        ctx.spawn(f);
+    }
+
+    fn delete_rows(
+        &mut self,
+        ctx: RpcContext<'_>,
+        req: api::DeleteRowsRequest,
+        sink: UnarySink<api::DeleteRowsResponse>,
+    ) {
+        let f = match SpreadsheetService::delete_rows(self, req) {
+            Ok(resp) => sink.success(resp),
+            Err(e) => sink.fail(RpcStatus::with_message(RpcStatusCode::INVALID_ARGUMENT, e)),
+        };
+        let f = f
+            .map_err(move |e| println!("failed to reply: {:?}", e))
+            .map(|_| ());
+        ctx.spawn(f);
+    }
+
+    fn insert_columns(
+        &mut self,
+        ctx: RpcContext<'_>,
+        req: api::InsertColumnsRequest,
+        sink: UnarySink<api::InsertColumnsResponse>,
+    ) {
+        let f = match SpreadsheetService::insert_columns(self, req) {
+            Ok(resp) => sink.success(resp),
+            Err(e) => sink.fail(RpcStatus::with_message(RpcStatusCode::INVALID_ARGUMENT, e)),
+        };
+        let f = f
+            .map_err(move |e| println!("failed to reply: {:?}", e))
+            .map(|_| ());
+        ctx.spawn(f);
+    }
+
+    fn delete_columns(
+        &mut self,
+        ctx: RpcContext<'_>,
+        req: api::DeleteColumnsRequest,
+        sink: UnarySink<api::DeleteColumnsResponse>,
+    ) {
+        let f = match SpreadsheetService::delete_columns(self, req) {
+            Ok(resp) => sink.success(resp),
+            Err(e) => sink.fail(RpcStatus::with_message(RpcStatusCode::INVALID_ARGUMENT, e)),
+        };
+        let f = f
+            .map_err(move |e| println!("failed to reply: {:?}", e))
+            .map(|_| ());
+        ctx.spawn(f);
+    }
+
+    fn move_range(
+        &mut self,
+        ctx: RpcContext<'_>,
+        req: api::MoveRangeRequest,
+        sink: UnarySink<api::MoveRangeResponse>,
+    ) {
+        let f = match SpreadsheetService::move_range(self, req) {
+            Ok(resp) => sink.success(resp),
+            Err(e) => sink.fail(RpcStatus::with_message(RpcStatusCode::INVALID_ARGUMENT, e)),
+        };
+        let f = f
+            .map_err(move |e| println!("failed to reply: {:?}", e))
+            .map(|_| ());
+        ctx.spawn(f);
+    }
+
+    fn copy_range(
+        &mut self,
+        ctx: RpcContext<'_>,
+        req: api::CopyRangeRequest,
+        sink: UnarySink<api::CopyRangeResponse>,
+    ) {
+        let f = match SpreadsheetService::copy_range(self, req) {
+            Ok(resp) => sink.success(resp),
+            Err(e) => sink.fail(RpcStatus::with_message(RpcStatusCode::INVALID_ARGUMENT, e)),
+        };
+        let f = f
+            .map_err(move |e| println!("failed to reply: {:?}", e))
+            .map(|_| ());
+        ctx.spawn(f);
+    }
+
+    fn fill(
+        &mut self,
+        ctx: RpcContext<'_>,
+        req: api::FillRequest,
+        sink: UnarySink<api::FillResponse>,
+    ) {
+        let f = match SpreadsheetService::fill(self, req) {
+            Ok(resp) => sink.success(resp),
+            Err(e) => sink.fail(RpcStatus::with_message(RpcStatusCode::INVALID_ARGUMENT, e)),
+        };
+        let f = f
+            .map_err(move |e| println!("failed to reply: {:?}", e))
+            .map(|_| ());
+        ctx.spawn(f);
+    }
+
+    fn set_iterative_calculation(
+        &mut self,
+        ctx: RpcContext<'_>,
+        req: api::SetIterativeCalculationRequest,
+        sink: UnarySink<api::SetIterativeCalculationResponse>,
+    ) {
+        let f = match SpreadsheetService::set_iterative_calculation(self, req) {
+            Ok(resp) => sink.success(resp),
+            Err(e) => sink.fail(RpcStatus::with_message(RpcStatusCode::INVALID_ARGUMENT, e)),
+        };
+        let f = f
+            .map_err(move |e| println!("failed to reply: {:?}", e))
+            .map(|_| ());
+        ctx.spawn(f);
+    }
+
+    fn set_calculation_mode(
+        &mut self,
+        ctx: RpcContext<'_>,
+        req: api::SetCalculationModeRequest,
+        sink: UnarySink<api::SetCalculationModeResponse>,
+    ) {
+        let resp = SpreadsheetService::set_calculation_mode(self, req).unwrap();
+        let f = sink
+            .success(resp)
+            .map_err(move |e| println!("failed to reply: {:?}", e))
+            .map(|_| ());
+        ctx.spawn(f);
+    }
 }
 
 fn main() {
+    let workbook = service::Workbook::new(50, 26);
+    // SPREADSHEET_SEED makes RAND and RANDBETWEEN reproducible across runs
+    if let Some(seed) = env::var("SPREADSHEET_SEED")
+        .ok()
+        .and_then(|s| s.parse().ok())
+    {
+        workbook.seed(seed);
+    }
     let ss_service = SpreadsheetService {
-        cells_service: Arc::new(RwLock::new(HashMap::new())),
+        cells_service: Arc::new(RwLock::new(workbook)),
     };
 
     let env = Arc::new(Environment::new(1));
@@ -145,6 +955,11 @@ fn insert_cells_to_models(insert_cells: &[api::InsertCell]) -> Vec<models::Cell>
             col: c.col,
             value: c.value.clone(),
             display_value: "".to_owned(),
+            format: "".to_owned(),
+            typed_value: models::Value::Empty,
+            style_id: 0,
+            effective_style_id: 0,
+            stale: false,
         });
     }
     ret
@@ -158,6 +973,17 @@ fn model_cells_to_api(cells: Vec<models::Cell>) -> Vec<api::Cell> {
         api_cell.set_col(c.col);
         api_cell.set_value(c.value);
         api_cell.set_display_value(c.display_value);
+        match c.typed_value {
+            models::Value::Empty => api_cell.set_empty(api::EmptyValue::default()),
+            models::Value::Number(n) => api_cell.set_number(n),
+            models::Value::Text(s) => api_cell.set_text(s),
+            models::Value::Bool(b) => api_cell.set_bool(b),
+            models::Value::Error(code) => api_cell.set_error(code),
+            models::Value::Date(d) => api_cell.set_date(d),
+        }
+        api_cell.set_style_id(c.style_id);
+        api_cell.set_effective_style_id(c.effective_style_id);
+        api_cell.set_stale(c.stale);
         ret.push(api_cell);
     }
     ret
@@ -171,3 +997,374 @@ fn api_rect_to_model(rect: &api::Rect) -> models::Rect {
         stop_col: rect.stop_col,
     }
 }
+
+fn api_calculation_mode_to_model(mode: api::CalculationMode) -> models::CalculationMode {
+    match mode {
+        api::CalculationMode::CALCULATION_AUTOMATIC => models::CalculationMode::Automatic,
+        api::CalculationMode::CALCULATION_MANUAL => models::CalculationMode::Manual,
+        api::CalculationMode::CALCULATION_ON_DEMAND => models::CalculationMode::OnDemand,
+    }
+}
+
+fn model_rect_to_api(rect: &models::Rect) -> api::Rect {
+    let mut ret = api::Rect::default();
+    ret.set_start_row(rect.start_row);
+    ret.set_start_col(rect.start_col);
+    ret.set_stop_row(rect.stop_row);
+    ret.set_stop_col(rect.stop_col);
+    ret
+}
+
+fn model_traced_ranges_to_api(ranges: Vec<models::TracedRange>) -> Vec<api::TracedRange> {
+    let mut ret = vec![];
+    for r in ranges {
+        let mut api_range = api::TracedRange::default();
+        api_range.set_tableId(r.table);
+        api_range.set_rect(model_rect_to_api(&r.rect));
+        api_range.set_depth(r.depth);
+        ret.push(api_range);
+    }
+    ret
+}
+
+fn model_cycle_to_api(path: Vec<models::TableCellLocation>) -> api::Cycle {
+    let mut ret = api::Cycle::default();
+    for c in path {
+        let mut cell_ref = api::CellRef::default();
+        cell_ref.set_tableId(c.table);
+        cell_ref.set_row(c.loc.row);
+        cell_ref.set_col(c.loc.col);
+        ret.mut_path().push(cell_ref);
+    }
+    ret
+}
+
+// cell_styles_to_api returns the styles used by the provided cells of the given table,
+// both their own and their effective styles
+fn cell_styles_to_api(
+    workbook: &service::Workbook,
+    table_id: &str,
+    cells: &[models::Cell],
+) -> HashMap<u32, api::Style> {
+    let mut ret = HashMap::new();
+    for c in cells {
+        for id in [c.style_id, c.effective_style_id] {
+            ret.entry(id)
+                .or_insert_with(|| model_style_to_api(&workbook.style(table_id, id)));
+        }
+    }
+    ret
+}
+
+fn model_style_to_api(style: &models::Style) -> api::Style {
+    let mut ret = api::Style::default();
+    ret.set_bold(style.bold);
+    ret.set_italic(style.italic);
+    ret.set_font_size(style.font_size);
+    ret.set_text_color(style.text_color.clone());
+    ret.set_fill_color(style.fill_color.clone());
+    ret.set_top_border(model_border_to_api(&style.top_border));
+    ret.set_bottom_border(model_border_to_api(&style.bottom_border));
+    ret.set_left_border(model_border_to_api(&style.left_border));
+    ret.set_right_border(model_border_to_api(&style.right_border));
+    ret.set_horizontal_alignment(match style.horizontal_alignment {
+        models::HorizontalAlignment::General => api::HorizontalAlignment::HORIZONTAL_GENERAL,
+        models::HorizontalAlignment::Left => api::HorizontalAlignment::HORIZONTAL_LEFT,
+        models::HorizontalAlignment::Center => api::HorizontalAlignment::HORIZONTAL_CENTER,
+        models::HorizontalAlignment::Right => api::HorizontalAlignment::HORIZONTAL_RIGHT,
+    });
+    ret.set_vertical_alignment(match style.vertical_alignment {
+        models::VerticalAlignment::Bottom => api::VerticalAlignment::VERTICAL_BOTTOM,
+        models::VerticalAlignment::Middle => api::VerticalAlignment::VERTICAL_MIDDLE,
+        models::VerticalAlignment::Top => api::VerticalAlignment::VERTICAL_TOP,
+    });
+    ret.set_wrap(style.wrap);
+    if let Some(bar) = &style.data_bar {
+        let mut data_bar = api::DataBar::default();
+        data_bar.set_color(bar.color.clone());
+        data_bar.set_percent(bar.percent);
+        ret.set_data_bar(data_bar);
+    }
+    ret
+}
+
+fn model_border_to_api(border: &models::Border) -> api::Border {
+    let mut ret = api::Border::default();
+    ret.set_style(match border.style {
+        models::BorderStyle::None => api::BorderStyle::BORDER_NONE,
+        models::BorderStyle::Thin => api::BorderStyle::BORDER_THIN,
+        models::BorderStyle::Medium => api::BorderStyle::BORDER_MEDIUM,
+        models::BorderStyle::Thick => api::BorderStyle::BORDER_THICK,
+        models::BorderStyle::Dashed => api::BorderStyle::BORDER_DASHED,
+        models::BorderStyle::Dotted => api::BorderStyle::BORDER_DOTTED,
+        models::BorderStyle::Double => api::BorderStyle::BORDER_DOUBLE,
+    });
+    ret.set_color(border.color.clone());
+    ret
+}
+
+fn api_style_to_model(style: &api::Style) -> models::Style {
+    models::Style {
+        bold: style.bold,
+        italic: style.italic,
+        font_size: style.font_size,
+        text_color: style.text_color.clone(),
+        fill_color: style.fill_color.clone(),
+        top_border: api_border_to_model(style.get_top_border()),
+        bottom_border: api_border_to_model(style.get_bottom_border()),
+        left_border: api_border_to_model(style.get_left_border()),
+        right_border: api_border_to_model(style.get_right_border()),
+        horizontal_alignment: match style.horizontal_alignment {
+            api::HorizontalAlignment::HORIZONTAL_GENERAL => models::HorizontalAlignment::General,
+            api::HorizontalAlignment::HORIZONTAL_LEFT => models::HorizontalAlignment::Left,
+            api::HorizontalAlignment::HORIZONTAL_CENTER => models::HorizontalAlignment::Center,
+            api::HorizontalAlignment::HORIZONTAL_RIGHT => models::HorizontalAlignment::Right,
+        },
+        vertical_alignment: match style.vertical_alignment {
+            api::VerticalAlignment::VERTICAL_BOTTOM => models::VerticalAlignment::Bottom,
+            api::VerticalAlignment::VERTICAL_MIDDLE => models::VerticalAlignment::Middle,
+            api::VerticalAlignment::VERTICAL_TOP => models::VerticalAlignment::Top,
+        },
+        wrap: style.wrap,
+        data_bar: match style.has_data_bar() {
+            true => Some(models::DataBar {
+                color: style.get_data_bar().color.clone(),
+                percent: style.get_data_bar().percent,
+            }),
+            false => None,
+        },
+    }
+}
+
+fn api_border_to_model(border: &api::Border) -> models::Border {
+    models::Border {
+        style: match border.style {
+            api::BorderStyle::BORDER_NONE => models::BorderStyle::None,
+            api::BorderStyle::BORDER_THIN => models::BorderStyle::Thin,
+            api::BorderStyle::BORDER_MEDIUM => models::BorderStyle::Medium,
+            api::BorderStyle::BORDER_THICK => models::BorderStyle::Thick,
+            api::BorderStyle::BORDER_DASHED => models::BorderStyle::Dashed,
+            api::BorderStyle::BORDER_DOTTED => models::BorderStyle::Dotted,
+            api::BorderStyle::BORDER_DOUBLE => models::BorderStyle::Double,
+        },
+        color: border.color.clone(),
+    }
+}
+
+fn api_conditional_format_to_model(
+    cf: &api::ConditionalFormat,
+) -> Result<models::ConditionalFormat, String> {
+    use api::ConditionalFormat_oneof_rule as Rule;
+    let rule = match &cf.rule {
+        Some(Rule::cell_value(r)) => models::FormatRule::CellValue {
+            comparison: api_comparison_to_model(r.comparison),
+            values: r.get_values().to_vec(),
+            style: api_style_to_model(r.get_style()),
+        },
+        Some(Rule::formula(r)) => models::FormatRule::Formula {
+            formula: r.formula.clone(),
+            style: api_style_to_model(r.get_style()),
+        },
+        Some(Rule::color_scale(r)) => models::FormatRule::ColorScale {
+            min_color: r.min_color.clone(),
+            mid_color: r.mid_color.clone(),
+            max_color: r.max_color.clone(),
+        },
+        Some(Rule::data_bar(r)) => models::FormatRule::DataBar {
+            color: r.color.clone(),
+        },
+        Some(Rule::top_n(r)) => models::FormatRule::TopN {
+            n: r.n,
+            bottom: r.bottom,
+            percent: r.percent,
+            style: api_style_to_model(r.get_style()),
+        },
+        None => return Err("conditional format without a rule".to_owned()),
+    };
+    Ok(models::ConditionalFormat {
+        range: api_rect_to_model(cf.get_range()),
+        rule,
+    })
+}
+
+fn model_conditional_format_to_api(cf: &models::ConditionalFormat) -> api::ConditionalFormat {
+    let mut ret = api::ConditionalFormat::default();
+    ret.set_range(model_rect_to_api(&cf.range));
+    match &cf.rule {
+        models::FormatRule::CellValue {
+            comparison,
+            values,
+            style,
+        } => {
+            let mut rule = api::CellValueRule::default();
+            rule.set_comparison(model_comparison_to_api(*comparison));
+            rule.set_values(protobuf::RepeatedField::from_vec(values.clone()));
+            rule.set_style(model_style_to_api(style));
+            ret.set_cell_value(rule);
+        }
+        models::FormatRule::Formula { formula, style } => {
+            let mut rule = api::FormulaRule::default();
+            rule.set_formula(formula.clone());
+            rule.set_style(model_style_to_api(style));
+            ret.set_formula(rule);
+        }
+        models::FormatRule::ColorScale {
+            min_color,
+            mid_color,
+            max_color,
+        } => {
+            let mut rule = api::ColorScaleRule::default();
+            rule.set_min_color(min_color.clone());
+            rule.set_mid_color(mid_color.clone());
+            rule.set_max_color(max_color.clone());
+            ret.set_color_scale(rule);
+        }
+        models::FormatRule::DataBar { color } => {
+            let mut rule = api::DataBarRule::default();
+            rule.set_color(color.clone());
+            ret.set_data_bar(rule);
+        }
+        models::FormatRule::TopN {
+            n,
+            bottom,
+            percent,
+            style,
+        } => {
+            let mut rule = api::TopNRule::default();
+            rule.set_n(*n);
+            rule.set_bottom(*bottom);
+            rule.set_percent(*percent);
+            rule.set_style(model_style_to_api(style));
+            ret.set_top_n(rule);
+        }
+    }
+    ret
+}
+
+fn api_copy_mode_to_model(mode: api::CopyMode) -> models::CopyMode {
+    match mode {
+        api::CopyMode::COPY_ALL => models::CopyMode::All,
+        api::CopyMode::COPY_VALUES => models::CopyMode::Values,
+        api::CopyMode::COPY_FORMULAS => models::CopyMode::Formulas,
+        api::CopyMode::COPY_FORMATS => models::CopyMode::Formats,
+        api::CopyMode::COPY_TRANSPOSE => models::CopyMode::Transpose,
+    }
+}
+
+fn api_comparison_to_model(comparison: api::Comparison) -> models::Comparison {
+    match comparison {
+        api::Comparison::COMPARISON_EQUAL => models::Comparison::Equal,
+        api::Comparison::COMPARISON_NOT_EQUAL => models::Comparison::NotEqual,
+        api::Comparison::COMPARISON_GREATER => models::Comparison::Greater,
+        api::Comparison::COMPARISON_GREATER_OR_EQUAL => models::Comparison::GreaterOrEqual,
+        api::Comparison::COMPARISON_LESS => models::Comparison::Less,
+        api::Comparison::COMPARISON_LESS_OR_EQUAL => models::Comparison::LessOrEqual,
+        api::Comparison::COMPARISON_BETWEEN => models::Comparison::Between,
+        api::Comparison::COMPARISON_NOT_BETWEEN => models::Comparison::NotBetween,
+    }
+}
+
+fn model_comparison_to_api(comparison: models::Comparison) -> api::Comparison {
+    match comparison {
+        models::Comparison::Equal => api::Comparison::COMPARISON_EQUAL,
+        models::Comparison::NotEqual => api::Comparison::COMPARISON_NOT_EQUAL,
+        models::Comparison::Greater => api::Comparison::COMPARISON_GREATER,
+        models::Comparison::GreaterOrEqual => api::Comparison::COMPARISON_GREATER_OR_EQUAL,
+        models::Comparison::Less => api::Comparison::COMPARISON_LESS,
+        models::Comparison::LessOrEqual => api::Comparison::COMPARISON_LESS_OR_EQUAL,
+        models::Comparison::Between => api::Comparison::COMPARISON_BETWEEN,
+        models::Comparison::NotBetween => api::Comparison::COMPARISON_NOT_BETWEEN,
+    }
+}
+
+fn api_validation_rule_to_model(
+    rule: &api::ValidationRule,
+) -> Result<models::ValidationRule, String> {
+    use api::ValidationRule_oneof_criteria as Criteria;
+    let comparison = |c: &api::ComparisonCriteria| {
+        (
+            api_comparison_to_model(c.comparison),
+            c.get_values().to_vec(),
+        )
+    };
+    let criteria = match &rule.criteria {
+        Some(Criteria::list(l)) => models::Criteria::List(l.get_values().to_vec()),
+        Some(Criteria::list_range(r)) => models::Criteria::ListFromRange(api_rect_to_model(r)),
+        Some(Criteria::number(c)) => {
+            let (comparison, values) = comparison(c);
+            models::Criteria::Number { comparison, values }
+        }
+        Some(Criteria::date(c)) => {
+            let (comparison, values) = comparison(c);
+            models::Criteria::Date { comparison, values }
+        }
+        Some(Criteria::text_length(c)) => {
+            let (comparison, values) = comparison(c);
+            models::Criteria::TextLength { comparison, values }
+        }
+        Some(Criteria::formula(f)) => models::Criteria::Formula(f.clone()),
+        None => return Err("validation rule without criteria".to_owned()),
+    };
+    Ok(models::ValidationRule {
+        range: api_rect_to_model(rule.get_range()),
+        criteria,
+        mode: match rule.mode {
+            api::ValidationMode::VALIDATION_REJECT => models::ValidationMode::Reject,
+            api::ValidationMode::VALIDATION_WARN => models::ValidationMode::Warn,
+        },
+        message: rule.message.clone(),
+    })
+}
+
+fn model_validation_rule_to_api(rule: &models::ValidationRule) -> api::ValidationRule {
+    let mut ret = api::ValidationRule::default();
+    ret.set_range(model_rect_to_api(&rule.range));
+    let comparison = |comparison: &models::Comparison, values: &[String]| {
+        let mut c = api::ComparisonCriteria::default();
+        c.set_comparison(model_comparison_to_api(*comparison));
+        c.set_values(protobuf::RepeatedField::from_vec(values.to_vec()));
+        c
+    };
+    match &rule.criteria {
+        models::Criteria::List(values) => {
+            let mut list = api::ListCriteria::default();
+            list.set_values(protobuf::RepeatedField::from_vec(values.clone()));
+            ret.set_list(list);
+        }
+        models::Criteria::ListFromRange(r) => ret.set_list_range(model_rect_to_api(r)),
+        models::Criteria::Number {
+            comparison: c,
+            values,
+        } => ret.set_number(comparison(c, values)),
+        models::Criteria::Date {
+            comparison: c,
+            values,
+        } => ret.set_date(comparison(c, values)),
+        models::Criteria::TextLength {
+            comparison: c,
+            values,
+        } => ret.set_text_length(comparison(c, values)),
+        models::Criteria::Formula(f) => ret.set_formula(f.clone()),
+    }
+    ret.set_mode(match rule.mode {
+        models::ValidationMode::Reject => api::ValidationMode::VALIDATION_REJECT,
+        models::ValidationMode::Warn => api::ValidationMode::VALIDATION_WARN,
+    });
+    ret.set_message(rule.message.clone());
+    ret
+}
+
+fn model_validation_errors_to_api(
+    errors: Vec<models::ValidationError>,
+) -> Vec<api::ValidationError> {
+    let mut ret = vec![];
+    for e in errors {
+        let mut api_error = api::ValidationError::default();
+        api_error.set_row(e.row);
+        api_error.set_col(e.col);
+        api_error.set_message(e.message);
+        api_error.set_rejected(e.rejected);
+        ret.push(api_error);
+    }
+    ret
+}
@@ -0,0 +1,41 @@
+use super::super::parser::parser::EvalResult;
+
+// Aggregate sums up the values of a group of cells the way SUM, COUNT and AVG read the
+// cells of a range, so the aggregates of parts of a large range can be combined instead
+// of reading each of its cells.
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub struct Aggregate {
+    // sum of the numeric values
+    pub sum: f64,
+    // numbers is the count of numeric values
+    pub numbers: usize,
+    // values is the count of the values which aren't errors, which COUNT returns
+    pub values: usize,
+    // errors is the count of errors failing the functions reading them, #VALUE! errors
+    // being left out like the functions do
+    pub errors: usize,
+}
+
+impl Aggregate {
+    // add adds a cell's value to the aggregate
+    pub fn add(&mut self, value: &EvalResult) {
+        match value {
+            EvalResult::Error(e) if e == "#VALUE!" => {}
+            EvalResult::Error(_) => self.errors += 1,
+            EvalResult::Numeric(n) => {
+                self.sum += n;
+                self.numbers += 1;
+                self.values += 1;
+            }
+            _ => self.values += 1,
+        }
+    }
+
+    // merge adds the values of another aggregate
+    pub fn merge(&mut self, other: &Aggregate) {
+        self.sum += other.sum;
+        self.numbers += other.numbers;
+        self.values += other.values;
+        self.errors += other.errors;
+    }
+}
@@ -0,0 +1,36 @@
+// IterativeCalculation lets a table's reference cycles be computed by evaluating their
+// cells repeatedly until the values converge, e.g. for interest on an average balance
+// which depends on the interest itself. Tables without it report cycles as #CIRCULAR!.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct IterativeCalculation {
+    // max_iterations is the number of times a cycle is evaluated at most. Cycles which
+    // haven't converged by then are reported as #CONVERGENCE!.
+    pub max_iterations: u32,
+    // max_change is how much the values of a cycle may change in an iteration once they
+    // have converged
+    pub max_change: f64,
+}
+
+impl Default for IterativeCalculation {
+    fn default() -> Self {
+        IterativeCalculation {
+            max_iterations: 100,
+            max_change: 0.001,
+        }
+    }
+}
+
+// CalculationMode sets when a table's formulas are recomputed
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub enum CalculationMode {
+    // Automatic recomputes formulas as soon as the cells they read change
+    #[default]
+    Automatic,
+    // Manual leaves the formulas which would be recomputed stale until the table is
+    // recalculated, e.g. to load many cells at once
+    Manual,
+    // OnDemand leaves the formulas stale like Manual, recomputing them only once they or
+    // the cells reading them are read, e.g. so editing the inputs of a large model only
+    // recomputes the cells on screen
+    OnDemand,
+}
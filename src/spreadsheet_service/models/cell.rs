@@ -1,9 +1,25 @@
+use super::rect::Rect;
+use super::value::Value;
+
 #[derive(Debug, Clone, PartialEq)]
 pub struct Cell {
     pub row: i32,
     pub col: i32,
     pub value: String,
     pub display_value: String,
+    // format is the number format code the value is displayed with, e.g. "0.00%". An
+    // empty format displays values as they are computed.
+    pub format: String,
+    // typed_value is the value the cell evaluated to, which display_value is formatted from
+    pub typed_value: Value,
+    // style_id identifies the cell's style in its table, 0 being the default style
+    pub style_id: u32,
+    // effective_style_id identifies the style the cell is drawn with, which is its own
+    // style along with the highlights of the conditional formats applying to it.
+    pub effective_style_id: u32,
+    // stale is set for formulas of a manually calculated table which weren't recomputed
+    // since they or the cells they read changed, see CalculationMode
+    pub stale: bool,
 }
 
 #[derive(Debug, Eq, PartialOrd, Ord, Copy, Hash, Clone, PartialEq)]
@@ -28,6 +44,13 @@ impl CellLocation {
     }
 }
 
+// TableCellLocation identifies a cell within a named table.
+#[derive(Debug, Eq, Hash, Clone, PartialEq)]
+pub struct TableCellLocation {
+    pub table: String,
+    pub loc: CellLocation,
+}
+
 #[derive(Debug, Eq, Copy, Hash, Clone, PartialEq)]
 pub struct CellRange {
     pub start_row: i32,
@@ -43,6 +66,23 @@ impl CellRange {
         }
         self.stop_row = max_rows
     }
+
+    pub fn contains(&self, loc: &CellLocation) -> bool {
+        loc.row >= self.start_row
+            && loc.row <= self.stop_row
+            && loc.col >= self.start_col
+            && loc.col <= self.stop_col
+    }
+
+    // to_rect returns the rectangle of the range's cells, whose stops are exclusive
+    pub fn to_rect(&self) -> Rect {
+        Rect {
+            start_row: self.start_row,
+            stop_row: self.stop_row + 1,
+            start_col: self.start_col,
+            stop_col: self.stop_col + 1,
+        }
+    }
 }
 
 impl Cell {
@@ -72,6 +112,11 @@ impl Cell {
             col: 0,
             value: "".to_string(),
             display_value: "".to_string(),
+            format: "".to_string(),
+            typed_value: Value::Empty,
+            style_id: 0,
+            effective_style_id: 0,
+            stale: false,
         }
     }
     pub fn new(row: i32, col: i32, value: String) -> Cell {
@@ -80,6 +125,11 @@ impl Cell {
             col,
             value: value.clone(),
             display_value: String::from(""),
+            format: String::from(""),
+            typed_value: Value::Empty,
+            style_id: 0,
+            effective_style_id: 0,
+            stale: false,
         }
     }
 }
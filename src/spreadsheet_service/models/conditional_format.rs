@@ -0,0 +1,70 @@
+use super::rect::Rect;
+use super::style::Style;
+
+// ConditionalFormat highlights the cells of a range according to a rule. The rules of a
+// table are evaluated by the service whenever its values change, and the resulting style
+// of each cell is its effective style, see Cell::effective_style_id.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ConditionalFormat {
+    pub range: Rect,
+    pub rule: FormatRule,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum FormatRule {
+    // CellValue applies the style to cells whose value compares to the provided values,
+    // e.g. Between 1 and 10. The values are read like cell input, e.g. "10" or "TRUE".
+    CellValue {
+        comparison: Comparison,
+        values: Vec<String>,
+        style: Style,
+    },
+    // Formula applies the style to cells for which the formula is TRUE or a non-zero
+    // number. Its references are relative to the top left cell of the range, so =ISODD(A1)
+    // on B2:B5 checks A2 for B2 and A3 for B3.
+    Formula {
+        formula: String,
+        style: Style,
+    },
+    // ColorScale fills the numeric cells with colors from min_color for the lowest value
+    // to max_color for the highest, passing through mid_color when it isn't empty.
+    ColorScale {
+        min_color: String,
+        mid_color: String,
+        max_color: String,
+    },
+    // DataBar draws a bar in each numeric cell as long as its value relative to the range
+    DataBar {
+        color: String,
+    },
+    // TopN applies the style to the n highest numeric values, or the lowest when bottom
+    // is set. n is a percentage of the numeric values when percent is set.
+    TopN {
+        n: u32,
+        bottom: bool,
+        percent: bool,
+        style: Style,
+    },
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Comparison {
+    Equal,
+    NotEqual,
+    Greater,
+    GreaterOrEqual,
+    Less,
+    LessOrEqual,
+    Between,
+    NotBetween,
+}
+
+impl Comparison {
+    // num_values returns how many values the comparison is made against
+    pub fn num_values(&self) -> usize {
+        match self {
+            Comparison::Between | Comparison::NotBetween => 2,
+            _ => 1,
+        }
+    }
+}
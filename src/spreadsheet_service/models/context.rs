@@ -1,6 +1,32 @@
-pub trait EvalContext {
+use super::super::parser::parser::EvalResult;
+
+// EvalContext gives formulas read access to the cells they reference. Contexts are shared
+// between the threads recomputing independent cells at the same time.
+pub trait EvalContext: Sync {
     fn get_cell(&self, row: i32, col: i32) -> Option<super::Cell>;
+    // get_value returns the value the cell's formula last evaluated to
+    fn get_value(&self, row: i32, col: i32) -> Option<EvalResult>;
     fn get_cells(&self, rect: super::Rect) -> Vec<super::Cell>;
     fn num_rows(&self) -> i32;
     fn num_cols(&self) -> i32;
+
+    // aggregate returns the aggregate of the values of the cells in the provided range, or
+    // None when the context doesn't keep aggregates and the cells must be read one by one.
+    fn aggregate(&self, _range: super::CellRange) -> Option<super::Aggregate> {
+        None
+    }
+
+    // random returns a number in [0, 1) drawn from the generator held by the service.
+    fn random(&self) -> f64;
+
+    // table_name returns the name of the table this context evaluates against.
+    fn table_name(&self) -> &str {
+        ""
+    }
+
+    // table returns a context for evaluating references into another table, or None
+    // if no table with the provided name exists.
+    fn table(&self, _name: &str) -> Option<Box<dyn EvalContext + '_>> {
+        None
+    }
 }
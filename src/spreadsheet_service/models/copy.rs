@@ -0,0 +1,15 @@
+// CopyMode selects what's pasted when a range is copied, see CellsService::copy_range
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub enum CopyMode {
+    // All pastes values, formulas, formats and styles
+    #[default]
+    All,
+    // Values pastes the values the cells computed, replacing formulas with their results
+    Values,
+    // Formulas pastes values and formulas, keeping the destination's formats and styles
+    Formulas,
+    // Formats pastes number formats and styles, keeping the destination's values
+    Formats,
+    // Transpose pastes like All with the rows of the range turned into columns
+    Transpose,
+}
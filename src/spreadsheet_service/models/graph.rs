@@ -0,0 +1,155 @@
+use super::super::parser::parser::col_num_to_letters;
+use super::cell::{CellRange, TableCellLocation};
+
+// GraphFormat selects how a DependencyGraph is written out
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum GraphFormat {
+    // Dot writes the graph for Graphviz, e.g. `dot -Tsvg`
+    Dot,
+    Json,
+}
+
+// GraphEdge is a reference from the formula of a cell to the range it reads
+#[derive(Debug, Clone, PartialEq)]
+pub struct GraphEdge {
+    pub cell: TableCellLocation,
+    // table is the name of the table the range belongs to
+    pub table: String,
+    pub range: CellRange,
+}
+
+// DependencyGraph is the structure of a table's formulas: the cells holding them, the
+// ranges they reference and the reference cycles they belong to. Cells and ranges of other
+// tables appear when formulas reference across tables. Table names are empty when the
+// graph is exported from a single table.
+#[derive(Debug, Clone, PartialEq)]
+pub struct DependencyGraph {
+    // table is the name of the exported table, whose cells are written without it
+    pub table: String,
+    // cells holding formulas, ordered by table and location
+    pub cells: Vec<TableCellLocation>,
+    pub edges: Vec<GraphEdge>,
+    // cells of each reference cycle, ordered like cells
+    pub cycles: Vec<Vec<TableCellLocation>>,
+}
+
+impl DependencyGraph {
+    // write returns the graph written in the provided format
+    pub fn write(&self, format: GraphFormat) -> String {
+        match format {
+            GraphFormat::Dot => self.to_dot(),
+            GraphFormat::Json => self.to_json(),
+        }
+    }
+
+    // to_dot returns the graph in Graphviz's DOT language, with an edge from each formula
+    // to the ranges it reads. Cells in a reference cycle are grouped in a cluster.
+    pub fn to_dot(&self) -> String {
+        let mut ret = format!("digraph {} {{\n", quote(&self.table));
+        for c in &self.cells {
+            ret.push_str(&format!("    {};\n", quote(&self.cell_name(c))));
+        }
+        for e in &self.edges {
+            let name = self.range_name(&e.table, &e.range);
+            // Ranges of more than a cell are drawn apart from the cells
+            if e.range.start_row != e.range.stop_row || e.range.start_col != e.range.stop_col {
+                ret.push_str(&format!("    {} [shape=box];\n", quote(&name)));
+            }
+            ret.push_str(&format!(
+                "    {} -> {};\n",
+                quote(&self.cell_name(&e.cell)),
+                quote(&name)
+            ));
+        }
+        for (i, cycle) in self.cycles.iter().enumerate() {
+            ret.push_str(&format!(
+                "    subgraph cluster_{} {{\n        label=\"cycle\";\n        color=red;\n",
+                i
+            ));
+            for c in cycle {
+                ret.push_str(&format!("        {};\n", quote(&self.cell_name(c))));
+            }
+            ret.push_str("    }\n");
+        }
+        ret.push_str("}\n");
+        ret
+    }
+
+    // to_json returns the graph as a JSON object, e.g.
+    // {"table":"Sheet1","cells":["A1"],"edges":[{"from":"A1","to":"B1:B3"}],"cycles":[]}
+    pub fn to_json(&self) -> String {
+        let cells: Vec<String> = self
+            .cells
+            .iter()
+            .map(|c| quote(&self.cell_name(c)))
+            .collect();
+        let edges: Vec<String> = self
+            .edges
+            .iter()
+            .map(|e| {
+                format!(
+                    "{{\"from\":{},\"to\":{}}}",
+                    quote(&self.cell_name(&e.cell)),
+                    quote(&self.range_name(&e.table, &e.range))
+                )
+            })
+            .collect();
+        let cycles: Vec<String> = self
+            .cycles
+            .iter()
+            .map(|cycle| {
+                let cells: Vec<String> = cycle.iter().map(|c| quote(&self.cell_name(c))).collect();
+                format!("[{}]", cells.join(","))
+            })
+            .collect();
+        format!(
+            "{{\"table\":{},\"cells\":[{}],\"edges\":[{}],\"cycles\":[{}]}}",
+            quote(&self.table),
+            cells.join(","),
+            edges.join(","),
+            cycles.join(",")
+        )
+    }
+
+    // cell_name returns the A1 name of the cell, qualified by its table when the cell
+    // belongs to another table than the exported one, e.g. Other!B2
+    fn cell_name(&self, c: &TableCellLocation) -> String {
+        self.qualified(
+            &c.table,
+            format!("{}{}", col_num_to_letters(c.loc.col), c.loc.row + 1),
+        )
+    }
+
+    // range_name returns the A1 name of the range, e.g. A1:B3, or that of its cell for a
+    // single cell
+    fn range_name(&self, table: &str, r: &CellRange) -> String {
+        let start = format!("{}{}", col_num_to_letters(r.start_col), r.start_row + 1);
+        if r.start_row == r.stop_row && r.start_col == r.stop_col {
+            return self.qualified(table, start);
+        }
+        let stop = format!("{}{}", col_num_to_letters(r.stop_col), r.stop_row + 1);
+        self.qualified(table, format!("{}:{}", start, stop))
+    }
+
+    fn qualified(&self, table: &str, name: String) -> String {
+        match table == self.table || table.is_empty() {
+            true => name,
+            false => format!("{}!{}", table, name),
+        }
+    }
+}
+
+// quote returns the string as a quoted, escaped DOT identifier, which is also a JSON string
+fn quote(s: &str) -> String {
+    let mut ret = String::from("\"");
+    for c in s.chars() {
+        match c {
+            '"' => ret.push_str("\\\""),
+            '\\' => ret.push_str("\\\\"),
+            '\n' => ret.push_str("\\n"),
+            c => ret.push(c),
+        }
+    }
+    ret.push('"');
+    ret
+}
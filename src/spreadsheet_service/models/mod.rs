@@ -1,7 +1,27 @@
+pub use self::aggregate::*;
+pub use self::calculation::*;
 pub use self::cell::*;
+pub use self::conditional_format::*;
 pub use self::context::*;
+pub use self::copy::*;
+pub use self::graph::*;
 pub use self::rect::*;
+pub use self::shift::*;
+pub use self::style::*;
+pub use self::trace::*;
+pub use self::validation::*;
+pub use self::value::*;
 
+pub mod aggregate;
+pub mod calculation;
 pub mod cell;
+pub mod conditional_format;
 pub mod context;
+pub mod copy;
+pub mod graph;
 pub mod rect;
+pub mod shift;
+pub mod style;
+pub mod trace;
+pub mod validation;
+pub mod value;
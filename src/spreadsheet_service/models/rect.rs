@@ -1,4 +1,6 @@
-#[derive(Debug, PartialEq)]
+use super::cell::CellLocation;
+
+#[derive(Debug, Clone, PartialEq)]
 pub struct Rect {
     pub start_row: i32,
     pub start_col: i32,
@@ -15,6 +17,31 @@ impl Rect {
             stop_col: self.stop_col.clamp(0, max_col),
         }
     }
+
+    pub fn contains(&self, row: i32, col: i32) -> bool {
+        row >= self.start_row && row < self.stop_row && col >= self.start_col && col < self.stop_col
+    }
+
+    // offset returns the rectangle moved by the provided number of rows and columns
+    pub fn offset(&self, rows: i32, cols: i32) -> Rect {
+        Rect {
+            start_row: self.start_row + rows,
+            stop_row: self.stop_row + rows,
+            start_col: self.start_col + cols,
+            stop_col: self.stop_col + cols,
+        }
+    }
+
+    // locations returns the locations of the rectangle's cells in row-major order
+    pub fn locations(&self) -> Vec<CellLocation> {
+        let mut ret = vec![];
+        for row in self.start_row..self.stop_row {
+            for col in self.start_col..self.stop_col {
+                ret.push(CellLocation { row, col });
+            }
+        }
+        ret
+    }
 }
 
 pub fn width(r: &Rect) -> i32 {
@@ -0,0 +1,102 @@
+use super::cell::CellLocation;
+use super::rect::Rect;
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Axis {
+    Rows,
+    Cols,
+}
+
+// Shift describes rows or columns inserted into or deleted from a table. Cells after
+// the inserted or deleted ones move along the axis to make room or fill the gap.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Shift {
+    pub axis: Axis,
+    // at is the index of the first inserted or deleted row or column
+    pub at: i32,
+    // count is positive for inserted rows or columns and negative for deleted ones
+    pub count: i32,
+}
+
+impl Shift {
+    // index returns where the row or column at the provided index moves to, or None when
+    // it's deleted.
+    pub fn index(&self, i: i32) -> Option<i32> {
+        if i < self.at {
+            Some(i)
+        } else if self.count >= 0 {
+            Some(i + self.count)
+        } else if i < self.at - self.count {
+            None
+        } else {
+            Some(i + self.count)
+        }
+    }
+
+    // span returns the new bounds of the rows or columns from start to stop, inclusive.
+    // Spans grow with rows inserted within them and shrink with rows deleted from them,
+    // and are None once every row of them is deleted.
+    pub fn span(&self, start: i32, stop: i32) -> Option<(i32, i32)> {
+        if self.count >= 0 {
+            return Some((self.index(start)?, self.index(stop)?));
+        }
+        let deleted_stop = self.at - self.count;
+        if start >= self.at && stop < deleted_stop {
+            return None;
+        }
+        let start = match self.index(start) {
+            Some(i) => i,
+            None => self.at,
+        };
+        let stop = match self.index(stop) {
+            Some(i) => i,
+            None => self.at - 1,
+        };
+        Some((start, stop))
+    }
+
+    pub fn location(&self, loc: CellLocation) -> Option<CellLocation> {
+        match self.axis {
+            Axis::Rows => Some(CellLocation {
+                row: self.index(loc.row)?,
+                col: loc.col,
+            }),
+            Axis::Cols => Some(CellLocation {
+                row: loc.row,
+                col: self.index(loc.col)?,
+            }),
+        }
+    }
+
+    // moved_rect returns the rectangle of a table's cells at or past the inserted or deleted
+    // rows or columns, which are those moving or being deleted
+    pub fn moved_rect(&self, num_rows: i32, num_cols: i32) -> Rect {
+        let mut ret = Rect {
+            start_row: 0,
+            stop_row: num_rows,
+            start_col: 0,
+            stop_col: num_cols,
+        };
+        match self.axis {
+            Axis::Rows => ret.start_row = self.at,
+            Axis::Cols => ret.start_col = self.at,
+        }
+        ret
+    }
+
+    // rect returns the new bounds of the rectangle, or None once all of it is deleted
+    pub fn rect(&self, r: &Rect) -> Option<Rect> {
+        let mut ret = r.clone();
+        let (start, stop) = match self.axis {
+            Axis::Rows => (&mut ret.start_row, &mut ret.stop_row),
+            Axis::Cols => (&mut ret.start_col, &mut ret.stop_col),
+        };
+        if *stop <= *start {
+            return Some(r.clone());
+        }
+        let (new_start, new_stop) = self.span(*start, *stop - 1)?;
+        *start = new_start;
+        *stop = new_stop + 1;
+        Some(ret)
+    }
+}
@@ -0,0 +1,167 @@
+// Style holds how a cell is drawn. Styles are stored once per table and referenced by id
+// from each cell, see Cell::style_id.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Hash)]
+pub struct Style {
+    pub bold: bool,
+    pub italic: bool,
+    // font_size is in points, 0 uses the default size
+    pub font_size: u32,
+    // colors are written as #rrggbb, empty uses the default color
+    pub text_color: String,
+    pub fill_color: String,
+    pub top_border: Border,
+    pub bottom_border: Border,
+    pub left_border: Border,
+    pub right_border: Border,
+    pub horizontal_alignment: HorizontalAlignment,
+    pub vertical_alignment: VerticalAlignment,
+    pub wrap: bool,
+    // data_bar is drawn behind the value by conditional formats, see FormatRule::DataBar
+    pub data_bar: Option<DataBar>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct DataBar {
+    pub color: String,
+    // percent is the length of the bar relative to the width of the cell
+    pub percent: u32,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Eq, Hash)]
+pub struct Border {
+    pub style: BorderStyle,
+    pub color: String,
+}
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub enum BorderStyle {
+    #[default]
+    None,
+    Thin,
+    Medium,
+    Thick,
+    Dashed,
+    Dotted,
+    Double,
+}
+
+// HorizontalAlignment General aligns numbers to the right and text to the left
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub enum HorizontalAlignment {
+    #[default]
+    General,
+    Left,
+    Center,
+    Right,
+}
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub enum VerticalAlignment {
+    #[default]
+    Bottom,
+    Middle,
+    Top,
+}
+
+// largest font size accepted, in points
+const MAX_FONT_SIZE: u32 = 409;
+
+impl Style {
+    pub fn is_default(&self) -> bool {
+        *self == Style::default()
+    }
+
+    // merge returns this style with the named fields taken from the update, e.g. ["bold"]
+    // only changes whether the text is bold. Every field is taken when none are named.
+    pub fn merge(&self, update: &Style, fields: &[String]) -> Result<Style, String> {
+        update.validate()?;
+        if fields.is_empty() {
+            return Ok(update.clone());
+        }
+        let mut ret = self.clone();
+        for field in fields {
+            match field.as_str() {
+                "bold" => ret.bold = update.bold,
+                "italic" => ret.italic = update.italic,
+                "font_size" => ret.font_size = update.font_size,
+                "text_color" => ret.text_color = update.text_color.clone(),
+                "fill_color" => ret.fill_color = update.fill_color.clone(),
+                "top_border" => ret.top_border = update.top_border.clone(),
+                "bottom_border" => ret.bottom_border = update.bottom_border.clone(),
+                "left_border" => ret.left_border = update.left_border.clone(),
+                "right_border" => ret.right_border = update.right_border.clone(),
+                "horizontal_alignment" => ret.horizontal_alignment = update.horizontal_alignment,
+                "vertical_alignment" => ret.vertical_alignment = update.vertical_alignment,
+                "wrap" => ret.wrap = update.wrap,
+                _ => return Err(format!("unknown style field: {}", field)),
+            }
+        }
+        Ok(ret)
+    }
+
+    // overlay returns this style with the fields set in the other style, i.e. those which
+    // differ from the default style, taken from it.
+    pub fn overlay(&self, other: &Style) -> Style {
+        let mut ret = self.clone();
+        ret.bold |= other.bold;
+        ret.italic |= other.italic;
+        ret.wrap |= other.wrap;
+        if other.font_size > 0 {
+            ret.font_size = other.font_size;
+        }
+        if !other.text_color.is_empty() {
+            ret.text_color = other.text_color.clone();
+        }
+        if !other.fill_color.is_empty() {
+            ret.fill_color = other.fill_color.clone();
+        }
+        let borders = [
+            (&mut ret.top_border, &other.top_border),
+            (&mut ret.bottom_border, &other.bottom_border),
+            (&mut ret.left_border, &other.left_border),
+            (&mut ret.right_border, &other.right_border),
+        ];
+        for (border, other) in borders {
+            if other.style != BorderStyle::None {
+                *border = other.clone();
+            }
+        }
+        if other.horizontal_alignment != HorizontalAlignment::General {
+            ret.horizontal_alignment = other.horizontal_alignment;
+        }
+        if other.vertical_alignment != VerticalAlignment::Bottom {
+            ret.vertical_alignment = other.vertical_alignment;
+        }
+        if other.data_bar.is_some() {
+            ret.data_bar = other.data_bar.clone();
+        }
+        ret
+    }
+
+    // validate returns an error describing the first invalid value of the style
+    pub fn validate(&self) -> Result<(), String> {
+        if self.font_size > MAX_FONT_SIZE {
+            return Err(format!("font size must be at most {}", MAX_FONT_SIZE));
+        }
+        let colors = [
+            &self.text_color,
+            &self.fill_color,
+            &self.top_border.color,
+            &self.bottom_border.color,
+            &self.left_border.color,
+            &self.right_border.color,
+            self.data_bar
+                .as_ref()
+                .map_or(&self.fill_color, |b| &b.color),
+        ];
+        match colors.iter().find(|c| !is_color(c)) {
+            Some(c) => Err(format!("invalid color {:?}, expected #rrggbb", c)),
+            None => Ok(()),
+        }
+    }
+}
+
+pub fn is_color(s: &str) -> bool {
+    s.is_empty()
+        || (s.len() == 7 && s.starts_with('#') && s[1..].chars().all(|c| c.is_ascii_hexdigit()))
+}
@@ -0,0 +1,13 @@
+use super::rect::Rect;
+
+// TracedRange is a range found tracing the precedents or dependents of a cell, see
+// CellsService::trace_precedents. depth is the number of references between the range and
+// the traced cell, 1 for the ranges its formula references or the cells referencing it.
+#[derive(Debug, Clone, PartialEq)]
+pub struct TracedRange {
+    // table is the name of the table the range belongs to, which is empty when tracing
+    // within a single table
+    pub table: String,
+    pub rect: Rect,
+    pub depth: u32,
+}
@@ -0,0 +1,60 @@
+use super::conditional_format::Comparison;
+use super::rect::Rect;
+
+// ValidationRule restricts the values which can be entered in the cells of a range
+#[derive(Debug, Clone, PartialEq)]
+pub struct ValidationRule {
+    pub range: Rect,
+    pub criteria: Criteria,
+    pub mode: ValidationMode,
+    // message is shown when a value doesn't meet the criteria, a description of the
+    // criteria is used when it's empty.
+    pub message: String,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum Criteria {
+    // List allows the provided values, compared without regard to case
+    List(Vec<String>),
+    // ListFromRange allows the values displayed by the cells of the range
+    ListFromRange(Rect),
+    // Number allows numbers comparing to the provided values, e.g. Between 1 and 10. The
+    // values are read like cell input or evaluated when they're formulas.
+    Number {
+        comparison: Comparison,
+        values: Vec<String>,
+    },
+    // Date allows dates comparing to the provided values, e.g. Greater =DATE(2024,1,1)
+    Date {
+        comparison: Comparison,
+        values: Vec<String>,
+    },
+    // TextLength allows values whose text has a length comparing to the provided values
+    TextLength {
+        comparison: Comparison,
+        values: Vec<String>,
+    },
+    // Formula allows values for which the formula is TRUE. Its references are relative to
+    // the top left cell of the range and see the value being entered.
+    Formula(String),
+}
+
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub enum ValidationMode {
+    // Reject keeps the cell as it was when the value doesn't meet the criteria
+    #[default]
+    Reject,
+    // Warn stores the value, reporting that it doesn't meet the criteria
+    Warn,
+}
+
+// ValidationError reports a value entered in a cell which doesn't meet the criteria of a
+// validation rule.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ValidationError {
+    pub row: i32,
+    pub col: i32,
+    pub message: String,
+    // rejected is set when the value wasn't stored, see ValidationMode::Reject
+    pub rejected: bool,
+}
@@ -0,0 +1,15 @@
+// Value is the typed value a cell evaluated to. Unlike the display value it tells a number
+// apart from the text "1", or an error from text starting with '#'.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub enum Value {
+    #[default]
+    Empty,
+    Number(f64),
+    Text(String),
+    Bool(bool),
+    // Error holds the error code, e.g. "#REF!"
+    Error(String),
+    // Date holds a serial date: the days since 1899-12-30, with the time of day as the
+    // fractional part.
+    Date(f64),
+}
@@ -0,0 +1,546 @@
+use super::super::models::Value;
+use super::locale::Locale;
+use super::parser::{display_value, EvalResult};
+
+// serial date of 1970-01-01
+const UNIX_EPOCH_SERIAL: i64 = 25569;
+// numbers are displayed with at most 15 significant digits, like other spreadsheets
+const SIGNIFICANT_DIGITS: i32 = 15;
+
+const MONTHS: [&str; 12] = [
+    "January",
+    "February",
+    "March",
+    "April",
+    "May",
+    "June",
+    "July",
+    "August",
+    "September",
+    "October",
+    "November",
+    "December",
+];
+const WEEKDAYS: [&str; 7] = [
+    "Sunday",
+    "Monday",
+    "Tuesday",
+    "Wednesday",
+    "Thursday",
+    "Friday",
+    "Saturday",
+];
+
+// format_value returns the display value of an evaluation result using the provided number
+// format code and the locale's separators. Format codes are made of up to four sections
+// separated by ';' for positive numbers, negative numbers, zero and text, e.g.
+// "#,##0.00;[Red]-#,##0.00". An empty format is the same as "General".
+pub fn format_value(value: &EvalResult, format: &str, locale: &Locale) -> String {
+    match value {
+        EvalResult::Numeric(n) => format_number(*n, format, locale),
+        EvalResult::Bool(_) => locale.localized_name(&display_value(value)),
+        EvalResult::NonNumeric(s) => format_text(s, format),
+        _ => display_value(value),
+    }
+}
+
+// typed_value returns the typed value of an evaluation result. Numbers displayed with a
+// date or time format, e.g. "yyyy-mm-dd", are dates.
+pub fn typed_value(value: &EvalResult, format: &str) -> Value {
+    match value {
+        EvalResult::Numeric(n) if is_date_format(format) => Value::Date(*n),
+        EvalResult::Numeric(n) => Value::Number(*n),
+        EvalResult::Bool(b) => Value::Bool(*b),
+        // references to empty cells evaluate to empty text
+        EvalResult::NonNumeric(s) if s.is_empty() => Value::Empty,
+        EvalResult::NonNumeric(s) => Value::Text(s.to_owned()),
+        EvalResult::List(_) => Value::Empty,
+        EvalResult::Error(code) => Value::Error(code.to_owned()),
+    }
+}
+
+// is_date_format returns whether the format code displays a date or time. Literal text in
+// quotes and bracketed sections such as [Red] are ignored.
+pub fn is_date_format(format: &str) -> bool {
+    let mut quoted = false;
+    let mut bracketed = false;
+    for c in format.chars() {
+        match c {
+            '"' => quoted = !quoted,
+            '[' if !quoted => bracketed = true,
+            ']' if !quoted => bracketed = false,
+            'y' | 'm' | 'd' | 'h' | 's' | 'Y' | 'M' | 'D' | 'H' | 'S' if !quoted && !bracketed => {
+                return true
+            }
+            _ => (),
+        }
+    }
+    false
+}
+
+// general_number returns the number with up to 15 significant digits, switching to
+// scientific notation for very large and very small numbers, e.g. 0.1+0.2 is shown as 0.3.
+pub fn general_number(n: f64) -> String {
+    if !n.is_finite() {
+        return "#NUM!".to_owned();
+    }
+    if n == 0f64 {
+        return "0".to_owned();
+    }
+    let exp = n.abs().log10().floor() as i32;
+    if !(-9..SIGNIFICANT_DIGITS).contains(&exp) {
+        let s = format!("{:.*e}", (SIGNIFICANT_DIGITS - 1) as usize, n);
+        let (mantissa, exp) = s.split_at(s.find('e').unwrap());
+        let exp: i32 = exp[1..].parse().unwrap_or(0);
+        return format!(
+            "{}E{}{:02}",
+            trim_fraction(mantissa),
+            if exp < 0 { '-' } else { '+' },
+            exp.abs()
+        );
+    }
+    let decimals = (SIGNIFICANT_DIGITS - 1 - exp).max(0) as usize;
+    let s = trim_fraction(&format!("{:.*}", decimals, n));
+    match s.as_str() {
+        "-0" => "0".to_owned(),
+        _ => s,
+    }
+}
+
+// validate_format returns an error describing why the format code can't be used
+pub fn validate_format(format: &str) -> Result<(), String> {
+    let mut quoted = false;
+    let mut bracketed = false;
+    let mut escaped = false;
+    for c in format.chars() {
+        match c {
+            _ if escaped => escaped = false,
+            '\\' if !quoted => escaped = true,
+            '"' => quoted = !quoted,
+            '[' if !quoted && !bracketed => bracketed = true,
+            ']' if !quoted && bracketed => bracketed = false,
+            _ => (),
+        }
+    }
+    if quoted {
+        return Err(format!("unterminated quote in format: {}", format));
+    }
+    if bracketed || escaped {
+        return Err(format!("incomplete format: {}", format));
+    }
+    if split_sections(format).len() > 4 {
+        return Err(format!("too many sections in format: {}", format));
+    }
+    Ok(())
+}
+
+fn format_number(n: f64, format: &str, locale: &Locale) -> String {
+    let sections = split_sections(format);
+    // the second section is used for negative numbers, which it displays without a sign,
+    // and the third for zero
+    let (section, value, signed) = match (sections.len(), n) {
+        (0, _) | (1, _) => (sections.first().copied().unwrap_or(""), n, true),
+        (_, n) if n < 0f64 => (sections[1], -n, false),
+        (3..=4, n) if n == 0f64 => (sections[2], n, false),
+        _ => (sections[0], n, true),
+    };
+    let section = strip_brackets(section);
+    if section.is_empty() && sections.len() > 1 {
+        // an empty section hides the values it applies to, e.g. zeros with "0;-0;"
+        return "".to_owned();
+    }
+    if is_general(&section) {
+        let general = locale.localize_number(&general_number(value));
+        // literal text may surround General, e.g. General" units"
+        return match section.to_lowercase().find("general") {
+            Some(i) => render_literals(
+                &format!("{}\u{0}{}", &section[..i], &section[i + "general".len()..]),
+                &general,
+            ),
+            None => general,
+        };
+    }
+    if is_date_format(&section) {
+        return format_date(value, &section);
+    }
+    format_digits(value, &section, signed, locale)
+}
+
+fn format_text(s: &str, format: &str) -> String {
+    // only the fourth section applies to text, with @ standing for the text itself
+    match split_sections(format).get(3) {
+        Some(section) => render_literals(&strip_brackets(section).replace('@', "\u{0}"), s),
+        None => s.to_owned(),
+    }
+}
+
+// split_sections splits a format code on the ';' which aren't quoted
+fn split_sections(format: &str) -> Vec<&str> {
+    let mut ret = vec![];
+    let mut quoted = false;
+    let mut escaped = false;
+    let mut start = 0;
+    for (i, c) in format.char_indices() {
+        match c {
+            _ if escaped => escaped = false,
+            '\\' if !quoted => escaped = true,
+            '"' => quoted = !quoted,
+            ';' if !quoted => {
+                ret.push(&format[start..i]);
+                start = i + 1;
+            }
+            _ => (),
+        }
+    }
+    if !format.is_empty() {
+        ret.push(&format[start..]);
+    }
+    ret
+}
+
+// strip_brackets removes colors and conditions, e.g. [Red], which only affect styling
+fn strip_brackets(section: &str) -> String {
+    let mut ret = String::new();
+    let mut quoted = false;
+    let mut bracketed = false;
+    for c in section.chars() {
+        match c {
+            '"' if !bracketed => {
+                quoted = !quoted;
+                ret.push(c);
+            }
+            '[' if !quoted => bracketed = true,
+            ']' if !quoted && bracketed => bracketed = false,
+            _ if bracketed => (),
+            _ => ret.push(c),
+        }
+    }
+    ret
+}
+
+fn is_general(section: &str) -> bool {
+    section.trim().is_empty() || section.to_lowercase().contains("general")
+}
+
+// render_literals renders the literal text of a section, replacing the \0 placeholder with
+// the provided value.
+fn render_literals(section: &str, value: &str) -> String {
+    let mut ret = String::new();
+    let mut it = section.chars();
+    while let Some(c) = it.next() {
+        match c {
+            '\u{0}' => ret.push_str(value),
+            '"' => it
+                .by_ref()
+                .take_while(|c| *c != '"')
+                .for_each(|c| ret.push(c)),
+            '\\' => ret.extend(it.next()),
+            // _x leaves room for the width of x and *x repeats x to fill the cell
+            '_' => {
+                it.next();
+                ret.push(' ');
+            }
+            '*' => {
+                it.next();
+            }
+            _ => ret.push(c),
+        }
+    }
+    ret
+}
+
+// format_digits formats a number with a section made of digit placeholders (0, # and ?),
+// a decimal point, thousands separators, percent signs, scientific notation and literal
+// text, e.g. "$#,##0.00" or "0.00E+00".
+fn format_digits(n: f64, section: &str, signed: bool, locale: &Locale) -> String {
+    // the placeholders are collected into the pattern and the literal text around them is
+    // kept, with \0 marking where the number goes
+    let mut pattern = String::new();
+    let mut literals = String::new();
+    let mut percent = 0;
+    let mut it = section.chars().peekable();
+    while let Some(c) = it.next() {
+        match c {
+            '0' | '#' | '?' | '.' | ',' => {
+                if pattern.is_empty() {
+                    literals.push('\u{0}');
+                }
+                pattern.push(c);
+            }
+            'E' | 'e' if !pattern.is_empty() && matches!(it.peek(), Some('+') | Some('-')) => {
+                pattern.push('E');
+                pattern.extend(it.next());
+            }
+            '%' => {
+                percent += 1;
+                literals.push(c);
+            }
+            '"' => {
+                literals.push(c);
+                for c in it.by_ref() {
+                    literals.push(c);
+                    if c == '"' {
+                        break;
+                    }
+                }
+            }
+            '\\' | '_' | '*' => {
+                literals.push(c);
+                literals.extend(it.next());
+            }
+            _ => literals.push(c),
+        }
+    }
+    if pattern.is_empty() {
+        // a section without placeholders, e.g. "-" for zero, only shows its text
+        return render_literals(&literals, "");
+    }
+
+    let value = n.abs() * 100f64.powi(percent);
+    let (mantissa, exponent) = match pattern.find('E') {
+        Some(i) => (&pattern[..i], Some(&pattern[i + 1..])),
+        None => (pattern.as_str(), None),
+    };
+    let (int_pattern, frac_pattern) = match mantissa.find('.') {
+        Some(i) => (&mantissa[..i], Some(&mantissa[i + 1..])),
+        None => (mantissa, None),
+    };
+    // commas after the last digit placeholder scale the number by a thousand each
+    let scale = int_pattern.len() - int_pattern.trim_end_matches(',').len();
+    let value = value / 1000f64.powi(scale as i32);
+    let int_pattern = int_pattern.trim_end_matches(',');
+    let thousands = int_pattern.contains(',');
+    let min_int = int_pattern.chars().filter(|c| *c == '0').count();
+    let frac_pattern: String = frac_pattern
+        .unwrap_or("")
+        .chars()
+        .filter(|c| matches!(c, '0' | '#' | '?'))
+        .collect();
+
+    let (value, exp) = match exponent {
+        Some(_) => scientific(
+            value,
+            int_pattern.chars().filter(|c| *c != ',').count().max(1),
+            frac_pattern.len(),
+        ),
+        None => (value, 0),
+    };
+    let fixed = format!("{:.*}", frac_pattern.len(), value);
+    let (int_digits, frac_digits) = match fixed.find('.') {
+        Some(i) => (&fixed[..i], &fixed[i + 1..]),
+        None => (fixed.as_str(), ""),
+    };
+
+    let int_digits = int_digits.trim_start_matches('0');
+    let int_digits = format!("{:0>width$}", int_digits, width = min_int);
+    let int_digits = match thousands {
+        true => group_thousands(&int_digits, locale.thousands_separator),
+        false => int_digits,
+    };
+    let mut number = int_digits;
+    if !frac_pattern.is_empty() || mantissa.contains('.') {
+        number.push(locale.decimal_separator);
+        number.push_str(&format_fraction(frac_digits, &frac_pattern));
+    }
+    if let Some(exponent) = exponent {
+        let (sign, digits) = exponent.split_at(1);
+        number.push('E');
+        match (exp < 0, sign) {
+            (true, _) => number.push('-'),
+            (false, "+") => number.push('+'),
+            _ => (),
+        }
+        number.push_str(&format!("{:0>width$}", exp.abs(), width = digits.len()));
+    }
+
+    // values which round to zero are shown without a sign
+    let negative = signed && n < 0f64 && fixed.chars().any(|c| c.is_ascii_digit() && c != '0');
+    format!(
+        "{}{}",
+        if negative { "-" } else { "" },
+        render_literals(&literals, &number)
+    )
+}
+
+// scientific returns the mantissa and exponent of the number, with int_digits digits
+// before the decimal point of the mantissa once it's rounded to the provided decimals.
+fn scientific(n: f64, int_digits: usize, decimals: usize) -> (f64, i32) {
+    if n == 0f64 {
+        return (0f64, 0);
+    }
+    let mut exp = n.log10().floor() as i32 - (int_digits as i32 - 1);
+    let scale = 10f64.powi(decimals as i32);
+    // 9.999 rounds to 10.00, which is shown as 1.00E+01 instead
+    if (n / 10f64.powi(exp) * scale).round() / scale >= 10f64.powi(int_digits as i32) {
+        exp += 1;
+    }
+    (n / 10f64.powi(exp), exp)
+}
+
+// format_fraction drops the trailing zeros of the digits which only have optional
+// placeholders, showing spaces for ? placeholders.
+fn format_fraction(digits: &str, pattern: &str) -> String {
+    let mut ret: Vec<char> = digits.chars().collect();
+    let placeholders: Vec<char> = pattern.chars().collect();
+    for i in (0..ret.len()).rev() {
+        match (ret[i], placeholders[i]) {
+            ('0', '#') => ret[i] = '\u{0}',
+            ('0', '?') => ret[i] = ' ',
+            _ => break,
+        }
+    }
+    ret.into_iter().filter(|c| *c != '\u{0}').collect()
+}
+
+// format_date formats a serial date using the date and time codes of the section, e.g.
+// "yyyy-mm-dd hh:mm" or "dddd, mmmm d".
+fn format_date(serial: f64, section: &str) -> String {
+    if serial < 0f64 || !serial.is_finite() {
+        return "#####".to_owned();
+    }
+    let mut days = serial.floor() as i64;
+    let mut seconds = ((serial - serial.floor()) * 86400f64).round() as i64;
+    if seconds >= 86400 {
+        days += 1;
+        seconds -= 86400;
+    }
+    let (year, month, day) = civil_from_days(days - UNIX_EPOCH_SERIAL);
+    let weekday = (days - UNIX_EPOCH_SERIAL + 4).rem_euclid(7) as usize;
+    let (hour, minute, second) = (seconds / 3600, seconds / 60 % 60, seconds % 60);
+
+    let parts = date_parts(section);
+    let twelve_hour = parts.iter().any(|p| matches!(p, DatePart::AmPm(_)));
+    let mut ret = String::new();
+    for (i, part) in parts.iter().enumerate() {
+        match part {
+            DatePart::Literal(s) => ret.push_str(s),
+            DatePart::Code(c, len) => {
+                let s = match (c, len) {
+                    ('y', 1..=2) => format!("{:02}", year % 100),
+                    ('y', _) => format!("{:04}", year),
+                    ('m', _) if is_minute(&parts, i) => pad(minute, *len),
+                    ('m', 1..=2) => pad(month as i64, *len),
+                    ('m', 3) => MONTHS[month as usize - 1][..3].to_owned(),
+                    ('m', 5) => MONTHS[month as usize - 1][..1].to_owned(),
+                    ('m', _) => MONTHS[month as usize - 1].to_owned(),
+                    ('d', 1..=2) => pad(day as i64, *len),
+                    ('d', 3) => WEEKDAYS[weekday][..3].to_owned(),
+                    ('d', _) => WEEKDAYS[weekday].to_owned(),
+                    ('h', _) if twelve_hour => pad((hour + 11) % 12 + 1, *len),
+                    ('h', _) => pad(hour, *len),
+                    _ => pad(second, *len),
+                };
+                ret.push_str(&s);
+            }
+            DatePart::AmPm(short) => {
+                let am = hour < 12;
+                ret.push_str(match (am, short) {
+                    (true, false) => "AM",
+                    (false, false) => "PM",
+                    (true, true) => "A",
+                    (false, true) => "P",
+                });
+            }
+        }
+    }
+    ret
+}
+
+#[derive(Debug, PartialEq)]
+enum DatePart {
+    Literal(String),
+    // a date or time code along with how many times it's repeated, e.g. ('y', 4) for yyyy
+    Code(char, usize),
+    // AM/PM, or A/P when short
+    AmPm(bool),
+}
+
+fn date_parts(section: &str) -> Vec<DatePart> {
+    let chars: Vec<char> = section.chars().collect();
+    let mut parts = vec![];
+    let mut i = 0;
+    while i < chars.len() {
+        let rest = chars[i..].iter().collect::<String>().to_uppercase();
+        let c = chars[i].to_ascii_lowercase();
+        let (part, len) = match c {
+            'y' | 'm' | 'd' | 'h' | 's' => {
+                let len = chars[i..]
+                    .iter()
+                    .take_while(|n| n.to_ascii_lowercase() == c)
+                    .count();
+                (DatePart::Code(c, len), len)
+            }
+            'a' if rest.starts_with("AM/PM") => (DatePart::AmPm(false), 5),
+            'a' if rest.starts_with("A/P") => (DatePart::AmPm(true), 3),
+            '"' => {
+                let s: String = chars[i + 1..].iter().take_while(|c| **c != '"').collect();
+                let len = s.chars().count() + 2;
+                (DatePart::Literal(s), len)
+            }
+            '\\' => {
+                let s = chars.get(i + 1).map(|c| c.to_string()).unwrap_or_default();
+                (DatePart::Literal(s), 2)
+            }
+            '_' => (DatePart::Literal(" ".to_owned()), 2),
+            '*' => (DatePart::Literal("".to_owned()), 2),
+            _ => (DatePart::Literal(chars[i].to_string()), 1),
+        };
+        parts.push(part);
+        i += len;
+    }
+    parts
+}
+
+// is_minute returns whether the m code at index i stands for minutes, which is the case
+// when it follows an hour or precedes a second.
+fn is_minute(parts: &[DatePart], i: usize) -> bool {
+    let code = |p: &DatePart| match p {
+        DatePart::Code(c, _) => Some(*c),
+        _ => None,
+    };
+    match parts.get(i) {
+        Some(DatePart::Code('m', len)) if *len <= 2 => (),
+        _ => return false,
+    }
+    parts[..i].iter().rev().find_map(code) == Some('h')
+        || parts[i + 1..].iter().find_map(code) == Some('s')
+}
+
+fn pad(n: i64, len: usize) -> String {
+    match len {
+        1 => n.to_string(),
+        _ => format!("{:02}", n),
+    }
+}
+
+// civil_from_days returns the year, month and day of the days since 1970-01-01
+fn civil_from_days(days: i64) -> (i64, u32, u32) {
+    let z = days + 719468;
+    let era = z.div_euclid(146097);
+    let doe = z.rem_euclid(146097);
+    let yoe = (doe - doe / 1460 + doe / 36524 - doe / 146096) / 365;
+    let doy = doe - (365 * yoe + yoe / 4 - yoe / 100);
+    let mp = (5 * doy + 2) / 153;
+    let day = (doy - (153 * mp + 2) / 5 + 1) as u32;
+    let month = if mp < 10 { mp + 3 } else { mp - 9 } as u32;
+    let year = yoe + era * 400 + if month <= 2 { 1 } else { 0 };
+    (year, month, day)
+}
+
+fn trim_fraction(s: &str) -> String {
+    match s.contains('.') {
+        true => s.trim_end_matches('0').trim_end_matches('.').to_owned(),
+        false => s.to_owned(),
+    }
+}
+
+fn group_thousands(digits: &str, separator: char) -> String {
+    let mut ret = String::new();
+    for (i, c) in digits.chars().enumerate() {
+        if i > 0 && (digits.len() - i).is_multiple_of(3) {
+            ret.push(separator);
+        }
+        ret.push(c);
+    }
+    ret
+}
@@ -1,4 +1,6 @@
-use super::parser::EvalResult;
+use super::super::models::Aggregate;
+use super::lexer::quote_table_name;
+use super::parser::{col_num_to_letters, EvalResult};
 
 pub fn evaluate_function(name: &str, args: Vec<EvalResult>) -> EvalResult {
     match name.to_uppercase().as_str() {
@@ -13,10 +15,21 @@ pub fn evaluate_function(name: &str, args: Vec<EvalResult>) -> EvalResult {
         "ISODD" => is_odd(args),
         "AND" => and(args),
         "OR" => or(args),
+        "ADDRESS" => address(args),
         _ => EvalResult::NonNumeric("".to_owned()),
     }
 }
 
+// evaluate_aggregate_function computes SUM, COUNT or AVG from the aggregate of the values
+// of their arguments
+pub fn evaluate_aggregate_function(name: &str, aggregate: Aggregate) -> EvalResult {
+    match name.to_uppercase().as_str() {
+        "SUM" => EvalResult::Numeric(aggregate.sum),
+        "COUNT" => EvalResult::Numeric(aggregate.values as f64),
+        _ => EvalResult::Numeric(aggregate.sum / aggregate.numbers as f64),
+    }
+}
+
 pub fn sum(args: Vec<EvalResult>) -> EvalResult {
     println!("adding args: {:?}", args);
     let numbers = numeric_values(args);
@@ -109,6 +122,72 @@ pub fn or(args: Vec<EvalResult>) -> EvalResult {
     EvalResult::Bool(bools.iter().fold(false, |acc, next| acc || *next))
 }
 
+// address returns the text of a cell reference:
+// ADDRESS(row, column, [abs_num], [a1], [table_name])
+// abs_num selects which parts are absolute: 1 for both (default), 2 for the row only,
+// 3 for the column only and 4 for neither.
+pub fn address(args: Vec<EvalResult>) -> EvalResult {
+    if args.len() < 2 || args.len() > 5 {
+        return EvalResult::Error("#VALUE!".to_owned());
+    }
+    let mut args = args.into_iter();
+    let (row, col) = match (args.next(), args.next()) {
+        (Some(EvalResult::Numeric(row)), Some(EvalResult::Numeric(col))) => {
+            (row.trunc() as i32, col.trunc() as i32)
+        }
+        _ => return EvalResult::Error("#VALUE!".to_owned()),
+    };
+    let abs_num = match args.next() {
+        Some(EvalResult::Numeric(n)) => n.trunc() as i32,
+        None => 1,
+        _ => return EvalResult::Error("#VALUE!".to_owned()),
+    };
+    let a1 = match args.next() {
+        Some(EvalResult::Bool(b)) => b,
+        Some(EvalResult::Numeric(n)) => n != 0.0,
+        None => true,
+        _ => return EvalResult::Error("#VALUE!".to_owned()),
+    };
+    let table = match args.next() {
+        Some(EvalResult::NonNumeric(t)) => Some(t),
+        None => None,
+        _ => return EvalResult::Error("#VALUE!".to_owned()),
+    };
+    if row < 1 || col < 1 || !(1..=4).contains(&abs_num) {
+        return EvalResult::Error("#VALUE!".to_owned());
+    }
+
+    let row_abs = abs_num <= 2;
+    let col_abs = abs_num == 1 || abs_num == 3;
+    let reference = if a1 {
+        format!(
+            "{}{}{}{}",
+            if col_abs { "$" } else { "" },
+            col_num_to_letters(col - 1),
+            if row_abs { "$" } else { "" },
+            row
+        )
+    } else {
+        format!(
+            "{}{}",
+            if row_abs {
+                format!("R{}", row)
+            } else {
+                format!("R[{}]", row)
+            },
+            if col_abs {
+                format!("C{}", col)
+            } else {
+                format!("C[{}]", col)
+            }
+        )
+    };
+    match table {
+        Some(t) => EvalResult::NonNumeric(format!("{}!{}", quote_table_name(&t), reference)),
+        None => EvalResult::NonNumeric(reference),
+    }
+}
+
 fn bool_values(args: Vec<EvalResult>) -> Vec<bool> {
     filter_values(args, match_bool)
 }
@@ -0,0 +1,129 @@
+use super::locale::Locale;
+use super::parser::ASTNode;
+
+const CURRENCY_SYMBOLS: [char; 4] = ['$', '€', '£', '¥'];
+
+// Recognized is a literal value recognized in a cell's input along with the number format
+// implied by how it was written, e.g. "0%" for 15%. The format is empty for plain numbers.
+#[derive(Debug, PartialEq)]
+pub struct Recognized {
+    pub value: ASTNode,
+    pub format: String,
+}
+
+// recognize converts user input such as 15%, $1,200.50, 1,000, TRUE or (42) into a typed
+// value. Input which isn't a number or boolean returns None and is treated as text.
+pub fn recognize(input: &str) -> Option<Recognized> {
+    recognize_localized(input, &Locale::default())
+}
+
+// recognize_localized recognizes input written with the locale's separators and boolean
+// names, e.g. 1.234,5 or WAHR for de-DE.
+pub fn recognize_localized(input: &str, locale: &Locale) -> Option<Recognized> {
+    let trimmed = input.trim();
+    if locale.is_canonical() {
+        if let Ok(n) = trimmed.parse::<f64>() {
+            return Some(Recognized {
+                value: ASTNode::Number(n),
+                format: "".to_owned(),
+            });
+        }
+    }
+    match locale.canonical_name(trimmed).to_uppercase().as_str() {
+        "TRUE" => return Some(recognized_bool(true)),
+        "FALSE" => return Some(recognized_bool(false)),
+        _ => (),
+    }
+
+    // (42) is accounting notation for -42
+    let (mut negative, rest) = match trimmed.strip_prefix('(').and_then(|s| s.strip_suffix(')')) {
+        Some(inner) => (true, inner),
+        None => match trimmed.strip_prefix('-') {
+            Some(r) => (true, r),
+            None => (false, trimmed.strip_prefix('+').unwrap_or(trimmed)),
+        },
+    };
+    // the currency symbol may precede the number, as in $5, or follow it, as in 5 €
+    let (currency, rest) = match (rest.chars().next(), rest.chars().last()) {
+        (Some(c), _) if CURRENCY_SYMBOLS.contains(&c) => (Some((c, true)), &rest[c.len_utf8()..]),
+        (_, Some(c)) if CURRENCY_SYMBOLS.contains(&c) => (
+            Some((c, false)),
+            rest[..rest.len() - c.len_utf8()].trim_end(),
+        ),
+        _ => (None, rest),
+    };
+    // the sign may also follow the currency symbol, e.g. $-5
+    let rest = match rest.strip_prefix('-') {
+        Some(r) if !negative => {
+            negative = true;
+            r
+        }
+        _ => rest,
+    };
+    let (percent, rest) = match rest.strip_suffix('%') {
+        Some(r) => (true, r),
+        None => (false, rest),
+    };
+    let (int_part, frac_part) = match rest.find(locale.decimal_separator) {
+        Some(i) => (
+            &rest[..i],
+            Some(&rest[i + locale.decimal_separator.len_utf8()..]),
+        ),
+        None => (rest, None),
+    };
+    let thousands = int_part.contains(locale.thousands_separator);
+    if !is_integer(int_part, locale.thousands_separator)
+        || !frac_part.is_none_or(|f| f.chars().all(|c| c.is_ascii_digit()))
+        || (int_part.is_empty() && frac_part.is_none_or(|f| f.is_empty()))
+    {
+        return None;
+    }
+
+    let digits = format!(
+        "{}.{}",
+        int_part.replace(locale.thousands_separator, ""),
+        frac_part.unwrap_or_default()
+    );
+    let mut n = digits.trim_end_matches('.').parse::<f64>().ok()?;
+    if percent {
+        n /= 100f64;
+    }
+    if negative {
+        n = -n;
+    }
+
+    let decimals = if frac_part.is_some() { ".00" } else { "" };
+    let format = match currency {
+        Some((c, true)) => format!("{}#,##0{}", c, decimals),
+        Some((c, false)) => format!("#,##0{} {}", decimals, c),
+        None if percent => format!("0{}%", decimals),
+        None if thousands => format!("#,##0{}", decimals),
+        None => "".to_owned(),
+    };
+    Some(Recognized {
+        value: ASTNode::Number(n),
+        format,
+    })
+}
+
+fn recognized_bool(b: bool) -> Recognized {
+    Recognized {
+        value: ASTNode::Bool(b),
+        format: "".to_owned(),
+    }
+}
+
+// is_integer returns whether s is a run of digits, optionally split into groups of three
+// by thousands separators.
+fn is_integer(s: &str, separator: char) -> bool {
+    if !s.contains(separator) {
+        return s.chars().all(|c| c.is_ascii_digit());
+    }
+    s.split(separator).enumerate().all(|(i, group)| {
+        let valid_len = match i {
+            0 => (1..=3).contains(&group.len()),
+            _ => group.len() == 3,
+        };
+        valid_len && group.chars().all(|c| c.is_ascii_digit())
+    })
+}
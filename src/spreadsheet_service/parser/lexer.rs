@@ -1,19 +1,29 @@
-use super::parser::ASTNode;
+use super::locale::Locale;
 use std::iter::Peekable;
 use std::vec::Vec;
 
 #[derive(Debug, Clone, PartialEq)]
 pub enum Token {
     Colon,
+    Bang,
     Number(String),
     Op(char),
     Comma,
     Text(String),
     ID(String),
+    TableName(String),
+    // Error is an error code written in a formula, e.g. #REF! for a deleted reference
+    Error(String),
     Eof,
 }
 
 pub fn lex(input: &str) -> Result<Vec<Token>, &'static str> {
+    lex_localized(input, &Locale::default())
+}
+
+// lex_localized lexes a formula written with the locale's argument and decimal separators.
+// Numbers are returned in canonical form, e.g. 1,5 is lexed as Number("1.5").
+pub fn lex_localized(input: &str, locale: &Locale) -> Result<Vec<Token>, &'static str> {
     let mut result = Vec::new();
     let mut it = input.chars().peekable();
 
@@ -22,9 +32,13 @@ pub fn lex(input: &str) -> Result<Vec<Token>, &'static str> {
     while let Some(&c) = it.peek() {
         let t = match c {
             '0'..='9' => {
-                let num = lex_number(&mut it);
+                let num = lex_number(&mut it, locale.decimal_separator);
                 num
             }
+            c if c == locale.argument_separator => {
+                it.next();
+                Ok(Token::Comma)
+            }
             '"' => {
                 it.next();
                 let mut str_val = "".to_string();
@@ -36,23 +50,43 @@ pub fn lex(input: &str) -> Result<Vec<Token>, &'static str> {
                 }
                 Ok(Token::Text(str_val))
             }
+            '\'' => {
+                it.next();
+                Ok(Token::TableName(lex_quoted_name(&mut it)))
+            }
             ':' => {
                 it.next();
                 Ok(Token::Colon)
             }
-            ' ' => {
+            '#' => {
                 it.next();
-                continue;
+                let mut code = "#".to_owned();
+                while let Some(&c) = it.peek() {
+                    if !c.is_ascii_alphanumeric() {
+                        break;
+                    }
+                    code.push(c);
+                    it.next();
+                }
+                if it.peek() == Some(&'!') {
+                    it.next();
+                    code.push('!');
+                }
+                Ok(Token::Error(code))
             }
-            ',' => {
+            '!' => {
                 it.next();
-                Ok(Token::Comma)
+                Ok(Token::Bang)
+            }
+            ' ' => {
+                it.next();
+                continue;
             }
             '*' | '+' | '-' | '/' | ')' | '(' => {
                 it.next();
                 Ok(Token::Op(c))
             }
-            'A'..='z' => {
+            'A'..='z' | '$' => {
                 let id = lex_id(&mut it);
                 Ok(id)
             }
@@ -68,14 +102,23 @@ pub fn lex(input: &str) -> Result<Vec<Token>, &'static str> {
     Ok(result)
 }
 
-fn lex_number<I>(input: &mut Peekable<I>) -> Result<Token, &'static str>
+fn lex_number<I>(input: &mut Peekable<I>, decimal_separator: char) -> Result<Token, &'static str>
 where
     I: Iterator<Item = char>,
 {
     let mut val = String::new();
+    let mut decimal = false;
     // Take numbers until a non-number is encountered
-    while let Some(Ok(_)) = input.peek().map(|c| c.to_string().parse::<u8>()) {
-        val.push(input.next().unwrap());
+    while let Some(&c) = input.peek() {
+        if c == decimal_separator && !decimal {
+            decimal = true;
+            val.push('.');
+        } else if c.is_ascii_digit() {
+            val.push(c);
+        } else {
+            break;
+        }
+        input.next();
     }
     Ok(Token::Number(val))
 }
@@ -96,6 +139,38 @@ where
     Token::ID(val.to_string())
 }
 
+// lex_quoted_name reads a single-quoted table name such as 'Budget 2024'.
+// The opening quote must already be consumed. A doubled quote ('') is read as a
+// literal quote character.
+fn lex_quoted_name<I>(input: &mut Peekable<I>) -> String
+where
+    I: Iterator<Item = char>,
+{
+    let mut val = String::new();
+    while let Some(c) = input.next() {
+        if c == '\'' {
+            if input.peek() == Some(&'\'') {
+                input.next();
+            } else {
+                break;
+            }
+        }
+        val.push(c);
+    }
+    val
+}
+
+// is_id_char returns whether c may continue an ID. Localized function names such as
+// BEREICH.VERSCHIEBEN may contain dots.
 pub fn is_id_char(c: char) -> bool {
-    (c >= 'A' && c <= 'z') || (c >= '0' && c <= '9')
+    ('A'..='z').contains(&c) || c.is_ascii_digit() || c == '$' || c == '.'
+}
+
+// quote_table_name returns the table name as it must be written in a formula, quoting it
+// when it contains characters which are not valid in an ID.
+pub fn quote_table_name(name: &str) -> String {
+    if !name.is_empty() && name.chars().all(|c| c != '$' && is_id_char(c)) {
+        return name.to_owned();
+    }
+    format!("'{}'", name.replace('\'', "''"))
 }
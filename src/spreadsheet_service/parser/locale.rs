@@ -0,0 +1,170 @@
+use super::lexer::{lex_localized, quote_table_name, Token};
+
+// Locale holds the conventions a table's formulas and values are written and displayed
+// with. Formulas are stored in the canonical en-US form and translated at the edges.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Locale {
+    pub name: String,
+    pub decimal_separator: char,
+    pub thousands_separator: char,
+    pub argument_separator: char,
+    // function names and boolean literals paired as (canonical, localized)
+    names: &'static [(&'static str, &'static str)],
+}
+
+const DE_NAMES: &[(&str, &str)] = &[
+    ("SUM", "SUMME"),
+    ("AVG", "MITTELWERT"),
+    ("COUNT", "ANZAHL"),
+    ("ISEVEN", "ISTGERADE"),
+    ("ISODD", "ISTUNGERADE"),
+    ("AND", "UND"),
+    ("OR", "ODER"),
+    ("ADDRESS", "ADRESSE"),
+    ("INDIRECT", "INDIREKT"),
+    ("OFFSET", "BEREICH.VERSCHIEBEN"),
+    ("ROW", "ZEILE"),
+    ("COLUMN", "SPALTE"),
+    ("ROWS", "ZEILEN"),
+    ("COLUMNS", "SPALTEN"),
+    ("RAND", "ZUFALLSZAHL"),
+    ("RANDBETWEEN", "ZUFALLSBEREICH"),
+    ("NOW", "JETZT"),
+    ("TODAY", "HEUTE"),
+    ("TRUE", "WAHR"),
+    ("FALSE", "FALSCH"),
+];
+
+const FR_NAMES: &[(&str, &str)] = &[
+    ("SUM", "SOMME"),
+    ("AVG", "MOYENNE"),
+    ("MEDIAN", "MEDIANE"),
+    ("COUNT", "NB"),
+    ("ISEVEN", "EST.PAIR"),
+    ("ISODD", "EST.IMPAIR"),
+    ("AND", "ET"),
+    ("OR", "OU"),
+    ("ADDRESS", "ADRESSE"),
+    ("OFFSET", "DECALER"),
+    ("ROW", "LIGNE"),
+    ("COLUMN", "COLONNE"),
+    ("ROWS", "LIGNES"),
+    ("COLUMNS", "COLONNES"),
+    ("RAND", "ALEA"),
+    ("RANDBETWEEN", "ALEA.ENTRE.BORNES"),
+    ("NOW", "MAINTENANT"),
+    ("TODAY", "AUJOURDHUI"),
+    ("TRUE", "VRAI"),
+    ("FALSE", "FAUX"),
+];
+
+impl Default for Locale {
+    fn default() -> Self {
+        Locale {
+            name: "en-US".to_owned(),
+            decimal_separator: '.',
+            thousands_separator: ',',
+            argument_separator: ',',
+            names: &[],
+        }
+    }
+}
+
+impl Locale {
+    // from_name returns the locale with the provided name, e.g. "de-DE"
+    pub fn from_name(name: &str) -> Option<Locale> {
+        let (decimal_separator, thousands_separator, argument_separator, names) = match name {
+            "en-US" => return Some(Locale::default()),
+            "de-DE" => (',', '.', ';', DE_NAMES),
+            "fr-FR" => (',', ' ', ';', FR_NAMES),
+            _ => return None,
+        };
+        Some(Locale {
+            name: name.to_owned(),
+            decimal_separator,
+            thousands_separator,
+            argument_separator,
+            names,
+        })
+    }
+
+    pub fn is_canonical(&self) -> bool {
+        *self == Locale::default()
+    }
+
+    // canonical_name returns the canonical name of a function or boolean written in this
+    // locale, e.g. SUM for SUMME.
+    pub fn canonical_name(&self, name: &str) -> String {
+        let upper = name.to_uppercase();
+        match self.names.iter().find(|(_, localized)| *localized == upper) {
+            Some((canonical, _)) => canonical.to_string(),
+            None => name.to_owned(),
+        }
+    }
+
+    // localized_name returns how a canonical function or boolean name is written in this
+    // locale.
+    pub fn localized_name(&self, name: &str) -> String {
+        let upper = name.to_uppercase();
+        match self.names.iter().find(|(canonical, _)| *canonical == upper) {
+            Some((_, localized)) => localized.to_string(),
+            None => name.to_owned(),
+        }
+    }
+
+    // localize_number replaces the canonical decimal point of a formatted number
+    pub fn localize_number(&self, n: &str) -> String {
+        n.replace('.', &self.decimal_separator.to_string())
+    }
+}
+
+// canonical_formula translates a formula written in the locale into its canonical form,
+// e.g. =SUMME(A1;1,5) into =SUM(A1,1.5). Values which aren't formulas, or formulas which
+// can't be read in the locale, are returned unchanged.
+pub fn canonical_formula(input: &str, locale: &Locale) -> String {
+    translate_formula(input, locale, &Locale::default(), |n| {
+        locale.canonical_name(n)
+    })
+}
+
+// localize_formula translates a canonical formula into how it's written in the locale
+pub fn localize_formula(input: &str, locale: &Locale) -> String {
+    translate_formula(input, &Locale::default(), locale, |n| {
+        locale.localized_name(n)
+    })
+}
+
+fn translate_formula(
+    input: &str,
+    from: &Locale,
+    to: &Locale,
+    translate_name: impl Fn(&str) -> String,
+) -> String {
+    let formula = match input.strip_prefix('=') {
+        Some(f) if from != to => f,
+        _ => return input.to_owned(),
+    };
+    let tokens = match lex_localized(formula, from) {
+        Ok(tokens) => tokens,
+        Err(_) => return input.to_owned(),
+    };
+
+    let mut ret = "=".to_owned();
+    for (i, t) in tokens.iter().enumerate() {
+        match t {
+            Token::Colon => ret.push(':'),
+            Token::Bang => ret.push('!'),
+            Token::Number(n) => ret.push_str(&to.localize_number(n)),
+            Token::Op(c) => ret.push(*c),
+            Token::Comma => ret.push(to.argument_separator),
+            Token::Text(s) => ret.push_str(&format!("\"{}\"", s)),
+            // IDs followed by ! are table names rather than functions
+            Token::ID(id) if tokens.get(i + 1) == Some(&Token::Bang) => ret.push_str(id),
+            Token::ID(id) => ret.push_str(&translate_name(id)),
+            Token::TableName(name) => ret.push_str(&quote_table_name(name)),
+            Token::Error(code) => ret.push_str(code),
+            Token::Eof => (),
+        }
+    }
+    ret
+}
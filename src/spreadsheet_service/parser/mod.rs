@@ -1,10 +1,38 @@
+pub use self::format::format_value;
+pub use self::format::is_date_format;
+pub use self::format::typed_value;
+pub use self::format::validate_format;
+pub use self::input::recognize;
+pub use self::input::recognize_localized;
 pub use self::lexer::lex;
+pub use self::locale::canonical_formula;
+pub use self::locale::localize_formula;
+pub use self::locale::Locale;
 pub use self::parser::evaluate;
+pub use self::parser::evaluate_cell;
 pub use self::parser::get_refs;
+pub use self::parser::get_table_refs;
+pub use self::parser::has_dynamic_refs;
+pub use self::parser::is_volatile;
+pub use self::parser::offset_refs;
 pub use self::parser::parse;
+pub use self::parser::parse_localized;
+pub use self::parser::ASTNode;
 pub use self::parser::Error;
+pub use self::parser::EvalResult;
+pub use self::parser::Evaluation;
+pub use self::references::move_refs;
+pub use self::references::rewrite_refs;
+pub use self::references::shift_refs;
+pub use self::references::translate_refs;
+pub use self::references::FormulaRef;
+pub use self::references::RefPart;
 
+pub mod format;
 pub mod functions;
+pub mod input;
 pub mod lexer;
+pub mod locale;
 pub mod parser;
+pub mod references;
 mod test;
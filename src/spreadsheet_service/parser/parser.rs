@@ -1,9 +1,18 @@
+use super::super::models::Aggregate;
 use super::super::models::CellLocation;
 use super::super::models::CellRange;
 use super::super::models::EvalContext;
+use super::super::models::Value;
+use super::format::{general_number, typed_value};
 use super::functions::*;
+use super::input::recognize_localized;
 use super::lexer::*;
+use super::locale::Locale;
 use std::cmp;
+use std::time::{SystemTime, UNIX_EPOCH};
+
+// serial date of 1970-01-01
+const UNIX_EPOCH_SERIAL: f64 = 25569f64;
 
 #[derive(Debug, PartialEq, Clone, Copy)]
 pub enum Operator {
@@ -18,6 +27,8 @@ pub enum Operator {
 pub struct CellRef {
     pub col: i32,
     pub row: i32,
+    // table is set when the reference points into another table, e.g. Costs!A1
+    pub table: Option<String>,
 }
 
 #[derive(Debug, PartialEq, Clone)]
@@ -33,14 +44,28 @@ impl Error {
     }
 }
 
+impl std::fmt::Display for Error {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.error_text)
+    }
+}
+
 impl CellRef {
+    pub fn new(row: i32, col: i32) -> CellRef {
+        CellRef {
+            row,
+            col,
+            table: None,
+        }
+    }
+
     fn is_unbounded(&self) -> bool {
         // '-1' is a magic number referring to an unbounded reference
         self.row == -1
     }
 
     fn is_valid(&self, max_row: i32, max_col: i32) -> bool {
-        self.row < max_row && self.col < max_col
+        self.row >= 0 && self.col >= 0 && self.row < max_row && self.col < max_col
     }
 
     fn to_cell_range(&self) -> CellRange {
@@ -84,9 +109,10 @@ pub enum ASTNode {
         stop: CellRef,
     },
     ParseError(String),
+    Bool(bool),
 }
 
-#[derive(Debug, PartialEq)]
+#[derive(Debug, PartialEq, Clone)]
 pub enum EvalResult {
     Bool(bool),
     Numeric(f64),
@@ -95,7 +121,41 @@ pub enum EvalResult {
     Error(String),
 }
 
+// Evaluation holds the result of evaluating a cell's formula
+#[derive(Debug, PartialEq)]
+pub struct Evaluation {
+    pub display_value: String,
+    pub value: EvalResult,
+    // references resolved while evaluating, e.g. by INDIRECT or OFFSET. These cannot be
+    // found ahead of time by get_refs.
+    pub dynamic_refs: Vec<(Option<String>, CellRange)>,
+}
+
+// EvalState tracks an evaluation in progress
+struct EvalState {
+    // the cell whose formula is being evaluated, if known, and the table it belongs to
+    cell: Option<CellLocation>,
+    table: String,
+    dynamic_refs: Vec<(Option<String>, CellRange)>,
+}
+
+impl EvalState {
+    fn new(cell: Option<CellLocation>, table: &str) -> EvalState {
+        EvalState {
+            cell,
+            table: table.to_owned(),
+            dynamic_refs: vec![],
+        }
+    }
+}
+
 pub fn parse(input: &str) -> ASTNode {
+    parse_localized(input, &Locale::default())
+}
+
+// parse_localized parses a cell's value, reading values which aren't formulas with the
+// locale's separators. Formulas must already be in canonical form.
+pub fn parse_localized(input: &str, locale: &Locale) -> ASTNode {
     if input.starts_with('=') {
         let cell_value = input.strip_prefix('=').unwrap().to_string();
         let tokens = super::lexer::lex(&cell_value);
@@ -109,60 +169,200 @@ pub fn parse(input: &str) -> ASTNode {
             }
         }
     }
-    match input.parse::<f64>() {
-        Ok(num) => ASTNode::Number(num),
-        _ => ASTNode::Text(input.to_owned()),
+    match recognize_localized(input, locale) {
+        Some(recognized) => recognized.value,
+        None => ASTNode::Text(input.to_owned()),
+    }
+}
+
+// evaluate gets the typed value of the provided AST
+pub fn evaluate(n: ASTNode, ctx: &dyn EvalContext) -> Value {
+    let mut state = EvalState::new(None, ctx.table_name());
+    typed_value(&evaluate_internal(n, &mut state, ctx), "")
+}
+
+// evaluate_cell evaluates the formula of the cell at the provided location, returning its
+// display value along with the references resolved during evaluation.
+pub fn evaluate_cell(n: ASTNode, cell: CellLocation, ctx: &dyn EvalContext) -> Evaluation {
+    let mut state = EvalState::new(Some(cell), ctx.table_name());
+    let value = evaluate_internal(n, &mut state, ctx);
+    Evaluation {
+        display_value: display_value(&value),
+        value,
+        dynamic_refs: state.dynamic_refs,
     }
 }
 
-// evalute gets the display value for the provided AST
-pub fn evaluate(n: ASTNode, ctx: &dyn EvalContext) -> String {
-    let res = evaluate_internal(n, &mut vec![], ctx);
+pub fn display_value(res: &EvalResult) -> String {
     match res {
-        EvalResult::Numeric(n) => n.to_string(),
-        EvalResult::Bool(b) => b.to_string(),
-        EvalResult::NonNumeric(s) => s,
+        EvalResult::Numeric(n) => general_number(*n),
+        EvalResult::Bool(b) => b.to_string().to_uppercase(),
+        EvalResult::NonNumeric(s) => s.to_owned(),
         EvalResult::List(_) => "".to_owned(),
         EvalResult::Error(msg) => msg.to_owned(),
     }
 }
 
+// get_refs returns the ranges referenced by the provided AST within its own table.
 pub fn get_refs(n: &ASTNode) -> Vec<CellRange> {
+    all_refs(n)
+        .into_iter()
+        .filter_map(|(table, r)| match table {
+            None => Some(r),
+            Some(_) => None,
+        })
+        .collect()
+}
+
+// get_table_refs returns the ranges referenced by the provided AST in other tables,
+// paired with the name of the table they point into.
+pub fn get_table_refs(n: &ASTNode) -> Vec<(String, CellRange)> {
+    all_refs(n)
+        .into_iter()
+        .filter_map(|(table, r)| table.map(|t| (t, r)))
+        .collect()
+}
+
+// has_dynamic_refs returns whether the AST contains functions whose references are only
+// known once the formula is evaluated.
+pub fn has_dynamic_refs(n: &ASTNode) -> bool {
+    match n {
+        ASTNode::UnaryExpr { op: _, operand } => has_dynamic_refs(operand),
+        ASTNode::BinaryExpr { op: _, lhs, rhs } => has_dynamic_refs(lhs) || has_dynamic_refs(rhs),
+        ASTNode::Function { name, args } => {
+            is_dynamic_function(name) || args.iter().any(|arg| has_dynamic_refs(arg))
+        }
+        _ => false,
+    }
+}
+
+// is_volatile returns whether the AST calls functions whose value changes on every
+// recalculation even when none of its references do, e.g. RAND() or NOW().
+pub fn is_volatile(n: &ASTNode) -> bool {
+    match n {
+        ASTNode::UnaryExpr { op: _, operand } => is_volatile(operand),
+        ASTNode::BinaryExpr { op: _, lhs, rhs } => is_volatile(lhs) || is_volatile(rhs),
+        ASTNode::Function { name, args } => {
+            is_volatile_function(name) || args.iter().any(|arg| is_volatile(arg))
+        }
+        _ => false,
+    }
+}
+
+fn is_volatile_function(name: &str) -> bool {
+    matches!(
+        name.to_uppercase().as_str(),
+        "RAND" | "RANDBETWEEN" | "NOW" | "TODAY"
+    )
+}
+
+// is_aggregate_function returns whether the function can be computed from the aggregate
+// of its arguments' values, see aggregate_ranges
+fn is_aggregate_function(name: &str) -> bool {
+    matches!(name.to_uppercase().as_str(), "SUM" | "COUNT" | "AVG")
+}
+
+fn is_dynamic_function(name: &str) -> bool {
+    matches!(name.to_uppercase().as_str(), "INDIRECT" | "OFFSET")
+}
+
+fn is_reference_function(name: &str) -> bool {
+    is_dynamic_function(name)
+        || matches!(
+            name.to_uppercase().as_str(),
+            "ROW" | "COLUMN" | "ROWS" | "COLUMNS"
+        )
+}
+
+// offset_refs returns the AST with its references moved by the provided number of rows
+// and columns, as when a formula written for one cell is used for another.
+pub fn offset_refs(n: &ASTNode, rows: i32, cols: i32) -> ASTNode {
+    let offset = |r: &CellRef| CellRef {
+        row: if r.is_unbounded() {
+            r.row
+        } else {
+            r.row + rows
+        },
+        col: r.col + cols,
+        table: r.table.clone(),
+    };
+    match n {
+        ASTNode::UnaryExpr { op, operand } => ASTNode::UnaryExpr {
+            op: *op,
+            operand: Box::new(offset_refs(operand, rows, cols)),
+        },
+        ASTNode::BinaryExpr { op, lhs, rhs } => ASTNode::BinaryExpr {
+            op: *op,
+            lhs: Box::new(offset_refs(lhs, rows, cols)),
+            rhs: Box::new(offset_refs(rhs, rows, cols)),
+        },
+        ASTNode::Function { name, args } => ASTNode::Function {
+            name: name.clone(),
+            args: args
+                .iter()
+                .map(|a| Box::new(offset_refs(a, rows, cols)))
+                .collect(),
+        },
+        ASTNode::Ref(cell_ref) => ASTNode::Ref(offset(cell_ref)),
+        ASTNode::Range { start, stop } => ASTNode::Range {
+            start: offset(start),
+            stop: offset(stop),
+        },
+        _ => n.clone(),
+    }
+}
+
+fn all_refs(n: &ASTNode) -> Vec<(Option<String>, CellRange)> {
     let mut refs = vec![];
 
     match n {
+        ASTNode::UnaryExpr { op: _, operand } => refs.extend(all_refs(operand)),
         ASTNode::BinaryExpr { op: _, lhs, rhs } => {
-            refs.extend(get_refs(lhs));
-            refs.extend(get_refs(rhs));
+            refs.extend(all_refs(lhs));
+            refs.extend(all_refs(rhs));
         }
         ASTNode::Function { name: _, args } => {
             for arg in args {
-                refs.extend(get_refs(arg))
+                refs.extend(all_refs(arg))
             }
         }
-        ASTNode::Ref(cell_ref) => refs.push(cell_ref.to_cell_range()),
-        ASTNode::Range { start, stop } => refs.push(CellRange {
-            start_row: start.row,
-            start_col: start.col,
-            stop_row: stop.row,
-            stop_col: stop.col,
-        }),
+        ASTNode::Ref(cell_ref) => refs.push((cell_ref.table.clone(), cell_ref.to_cell_range())),
+        ASTNode::Range { start, stop } => refs.push((
+            start.table.clone(),
+            CellRange {
+                start_row: start.row,
+                start_col: start.col,
+                stop_row: stop.row,
+                stop_col: stop.col,
+            },
+        )),
         _ => (),
     }
 
     refs
 }
 
-fn evaluate_internal(
+// evaluate_in_table evaluates a reference which was qualified with a table name using
+// that table as the context.
+fn evaluate_in_table(
+    table: &str,
     n: ASTNode,
-    path: &mut Vec<CellLocation>,
+    state: &mut EvalState,
     ctx: &dyn EvalContext,
 ) -> EvalResult {
+    match ctx.table(table) {
+        Some(table_ctx) => evaluate_internal(n, state, &*table_ctx),
+        None => EvalResult::Error("#REF!".to_owned()),
+    }
+}
+
+fn evaluate_internal(n: ASTNode, state: &mut EvalState, ctx: &dyn EvalContext) -> EvalResult {
     match n {
         ASTNode::Empty => EvalResult::NonNumeric("".to_owned()),
         ASTNode::Number(n) => EvalResult::Numeric(n),
         ASTNode::Text(t) => EvalResult::NonNumeric(t),
-        ASTNode::UnaryExpr { op, operand } => match evaluate_internal(*operand, path, ctx) {
+        ASTNode::Bool(b) => EvalResult::Bool(b),
+        ASTNode::UnaryExpr { op, operand } => match evaluate_internal(*operand, state, ctx) {
             EvalResult::Error(e) => EvalResult::Error(e),
             v => match op {
                 Operator::Subtract => sub(vec![EvalResult::Numeric(0f64), v]),
@@ -171,8 +371,8 @@ fn evaluate_internal(
         },
         ASTNode::BinaryExpr { op, lhs, rhs } => {
             match (
-                evaluate_internal(*lhs, path, ctx),
-                evaluate_internal(*rhs, path, ctx),
+                evaluate_internal(*lhs, state, ctx),
+                evaluate_internal(*rhs, state, ctx),
             ) {
                 (EvalResult::Error(l), _) => EvalResult::Error(l),
                 (_, EvalResult::Error(r)) => EvalResult::Error(r),
@@ -185,55 +385,44 @@ fn evaluate_internal(
                 },
             }
         }
-        ASTNode::Function { name, args } => {
-            let mut evaluated_args = vec![];
-            let mut eval_err: Option<String> = None;
-            let mut arg_filter = |e: EvalResult| match e {
-                EvalResult::Error(msg) => {
-                    // #VALUE! errors can often be safely ignored
-                    // by functions
-                    if msg != "#VALUE!" {
-                        eval_err = Some(msg)
-                    }
-                }
-                _ => evaluated_args.push(e),
-            };
-            for arg in args {
-                let eval_res = evaluate_internal(*arg, path, ctx);
-                println!("eval res is {:?}", eval_res);
-                match eval_res {
-                    EvalResult::List(results) => {
-                        for res in results {
-                            arg_filter(*res);
-                        }
-                    }
-                    res => arg_filter(res),
-                }
-            }
-            if let Some(err_msg) = eval_err {
-                println!("eval error is {err_msg}");
-                EvalResult::Error(err_msg)
-            } else {
-                evaluate_function(&name, evaluated_args)
+        ASTNode::Function { name, args } if is_reference_function(&name) => {
+            evaluate_reference_function(&name, &args, state, ctx)
+        }
+        ASTNode::Function { name, args } if is_volatile_function(&name) => {
+            evaluate_volatile_function(&name, &args, state, ctx)
+        }
+        ASTNode::Function { name, args } if is_aggregate_function(&name) => {
+            match aggregate_ranges(&args, state, ctx) {
+                Some(aggregate) => evaluate_aggregate_function(&name, aggregate),
+                None => evaluate_arguments(&name, &args, state, ctx),
             }
         }
+        ASTNode::Function { name, args } => evaluate_arguments(&name, &args, state, ctx),
+        ASTNode::Ref(CellRef {
+            table: Some(table),
+            row,
+            col,
+        }) => evaluate_in_table(&table, ASTNode::Ref(CellRef::new(row, col)), state, ctx),
+        ASTNode::Range { start, stop } if start.table.is_some() => evaluate_in_table(
+            start.table.as_ref().unwrap(),
+            ASTNode::Range {
+                start: CellRef::new(start.row, start.col),
+                stop: CellRef::new(stop.row, stop.col),
+            },
+            state,
+            ctx,
+        ),
         ASTNode::Ref(cell_ref) => {
-            if path.contains(&cell_ref.loc()) {
+            if state.cell == Some(cell_ref.loc()) && state.table == ctx.table_name() {
                 return EvalResult::Error("#CIRCULAR".to_owned());
             }
-            path.push(cell_ref.loc());
             if !cell_ref.is_valid(ctx.num_rows(), ctx.num_cols()) {
                 return EvalResult::Error("#REF".to_owned());
             }
-            match ctx.get_cell(cell_ref.row, cell_ref.col) {
-                Some(cell) => {
-                    let parsed_val = parse(&cell.value);
-                    let res = evaluate_internal(parsed_val, path, ctx);
-                    path.pop();
-                    res
-                }
-                None => EvalResult::NonNumeric("".to_owned()),
-            }
+            // The formula graph evaluates upstream cells first, so the value they last
+            // computed is read instead of evaluating their formulas again.
+            ctx.get_value(cell_ref.row, cell_ref.col)
+                .unwrap_or_else(|| EvalResult::NonNumeric("".to_owned()))
         }
         ASTNode::ParseError(e) => EvalResult::Error(e),
         ASTNode::Range { start, mut stop } => {
@@ -241,17 +430,13 @@ fn evaluate_internal(
             if stop.is_unbounded() {
                 stop.row = ctx.num_rows() - 1;
             }
-            for i in cmp::max(start.row, 0)..stop.row + 1 {
-                for j in start.col..stop.col + 1 {
+            for i in cmp::max(start.row, 0)..cmp::min(stop.row + 1, ctx.num_rows()) {
+                for j in cmp::max(start.col, 0)..cmp::min(stop.col + 1, ctx.num_cols()) {
                     match ctx.get_cell(i, j) {
                         Some(_) => {
-                            let res = evaluate_internal(
-                                ASTNode::Ref(CellRef { row: i, col: j }),
-                                path,
-                                ctx,
-                            );
+                            let res =
+                                evaluate_internal(ASTNode::Ref(CellRef::new(i, j)), state, ctx);
                             results.push(Box::new(res));
-                            path.pop();
                         }
                         None => {}
                     }
@@ -262,6 +447,307 @@ fn evaluate_internal(
     }
 }
 
+// evaluate_arguments evaluates the arguments of a function before applying it to their
+// values, the values of ranges being passed one by one
+fn evaluate_arguments(
+    name: &str,
+    args: &[Box<ASTNode>],
+    state: &mut EvalState,
+    ctx: &dyn EvalContext,
+) -> EvalResult {
+    let mut evaluated_args = vec![];
+    let mut eval_err: Option<String> = None;
+    let mut arg_filter = |e: EvalResult| match e {
+        EvalResult::Error(msg) => {
+            // #VALUE! errors can often be safely ignored
+            // by functions
+            if msg != "#VALUE!" {
+                eval_err = Some(msg)
+            }
+        }
+        _ => evaluated_args.push(e),
+    };
+    for arg in args {
+        let eval_res = evaluate_internal((**arg).clone(), state, ctx);
+        println!("eval res is {:?}", eval_res);
+        match eval_res {
+            EvalResult::List(results) => {
+                for res in results {
+                    arg_filter(*res);
+                }
+            }
+            res => arg_filter(res),
+        }
+    }
+    if let Some(err_msg) = eval_err {
+        println!("eval error is {err_msg}");
+        EvalResult::Error(err_msg)
+    } else {
+        evaluate_function(name, evaluated_args)
+    }
+}
+
+// aggregate_ranges returns the aggregate of the arguments of an aggregate function when
+// they're all ranges whose aggregates the context keeps, so their cells aren't read one
+// by one. None is returned when the arguments must be evaluated instead, including when
+// they hold errors or the cell being evaluated.
+fn aggregate_ranges(
+    args: &[Box<ASTNode>],
+    state: &EvalState,
+    ctx: &dyn EvalContext,
+) -> Option<Aggregate> {
+    let mut ret = Aggregate::default();
+    for arg in args {
+        let (start, stop) = match &**arg {
+            ASTNode::Range { start, stop } => (start, stop),
+            _ => return None,
+        };
+        let table_ctx;
+        let ctx = match &start.table {
+            Some(table) => {
+                table_ctx = ctx.table(table)?;
+                &*table_ctx
+            }
+            None => ctx,
+        };
+        // Ranges are clamped to the table like when their cells are read
+        let range = CellRange {
+            start_row: cmp::max(start.row, 0),
+            start_col: cmp::max(start.col, 0),
+            stop_row: match stop.is_unbounded() {
+                true => ctx.num_rows() - 1,
+                false => cmp::min(stop.row, ctx.num_rows() - 1),
+            },
+            stop_col: cmp::min(stop.col, ctx.num_cols() - 1),
+        };
+        if range.start_row > range.stop_row || range.start_col > range.stop_col {
+            continue;
+        }
+        if let Some(cell) = state.cell {
+            if state.table == ctx.table_name() && range.contains(&cell) {
+                return None;
+            }
+        }
+        ret.merge(&ctx.aggregate(range)?);
+    }
+    match ret.errors {
+        0 => Some(ret),
+        _ => None,
+    }
+}
+
+// evaluate_reference_function evaluates functions which operate on references rather than
+// on the values of the cells they point to.
+fn evaluate_reference_function(
+    name: &str,
+    args: &[Box<ASTNode>],
+    state: &mut EvalState,
+    ctx: &dyn EvalContext,
+) -> EvalResult {
+    let upper = name.to_uppercase();
+    if is_dynamic_function(&upper) {
+        let resolved = match upper.as_str() {
+            "INDIRECT" => indirect(args, state, ctx),
+            _ => offset(args, state, ctx),
+        };
+        return match resolved {
+            Ok((table, range)) => {
+                state.dynamic_refs.push((table.clone(), range));
+                evaluate_internal(range_to_node(table, range), state, ctx)
+            }
+            Err(e) => e,
+        };
+    }
+
+    if args.len() > 1 {
+        return EvalResult::Error("#VALUE!".to_owned());
+    }
+    let range = match args.first() {
+        Some(arg) => match resolve_reference((**arg).clone(), state, ctx) {
+            Ok((_, range)) => range,
+            Err(e) => return e,
+        },
+        // Without arguments these functions refer to the cell being evaluated
+        None => match state.cell {
+            Some(loc) => loc.to_range(),
+            None => return EvalResult::Error("#VALUE!".to_owned()),
+        },
+    };
+    let res = match upper.as_str() {
+        "ROW" => range.start_row + 1,
+        "COLUMN" => range.start_col + 1,
+        "ROWS" => range.stop_row - range.start_row + 1,
+        _ => range.stop_col - range.start_col + 1,
+    };
+    EvalResult::Numeric(res as f64)
+}
+
+// evaluate_volatile_function evaluates functions which draw from the context's random
+// number generator or the clock.
+fn evaluate_volatile_function(
+    name: &str,
+    args: &[Box<ASTNode>],
+    state: &mut EvalState,
+    ctx: &dyn EvalContext,
+) -> EvalResult {
+    let mut values = vec![];
+    for arg in args {
+        match evaluate_internal((**arg).clone(), state, ctx) {
+            EvalResult::Numeric(n) => values.push(n),
+            EvalResult::Error(e) => return EvalResult::Error(e),
+            _ => return EvalResult::Error("#VALUE!".to_owned()),
+        }
+    }
+    match (name.to_uppercase().as_str(), values.as_slice()) {
+        ("RAND", []) => EvalResult::Numeric(ctx.random()),
+        ("RANDBETWEEN", [low, high]) => {
+            let (low, high) = (low.ceil(), high.floor());
+            if low > high {
+                return EvalResult::Error("#NUM!".to_owned());
+            }
+            EvalResult::Numeric(low + (ctx.random() * (high - low + 1f64)).floor())
+        }
+        ("NOW", []) => EvalResult::Numeric(serial_now()),
+        ("TODAY", []) => EvalResult::Numeric(serial_now().floor()),
+        _ => EvalResult::Error("#VALUE!".to_owned()),
+    }
+}
+
+// serial_now returns the current UTC time as a serial date, the number of days since
+// 1899-12-30 with the time of day as the fractional part.
+fn serial_now() -> f64 {
+    let secs = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map_or(0f64, |d| d.as_secs_f64());
+    UNIX_EPOCH_SERIAL + secs / 86400f64
+}
+
+// resolve_reference resolves an argument of a reference function to the table and range it
+// points to without evaluating the cells in it.
+fn resolve_reference(
+    n: ASTNode,
+    state: &mut EvalState,
+    ctx: &dyn EvalContext,
+) -> Result<(Option<String>, CellRange), EvalResult> {
+    let (start, stop) = match n {
+        ASTNode::Ref(r) => (r.clone(), r),
+        ASTNode::Range { start, stop } => (start, stop),
+        ASTNode::Function { name, args } => {
+            return match name.to_uppercase().as_str() {
+                "INDIRECT" => indirect(&args, state, ctx),
+                "OFFSET" => offset(&args, state, ctx),
+                _ => Err(EvalResult::Error("#VALUE!".to_owned())),
+            }
+        }
+        _ => return Err(EvalResult::Error("#VALUE!".to_owned())),
+    };
+
+    let num_rows = match &start.table {
+        Some(table) => match ctx.table(table) {
+            Some(table_ctx) => table_ctx.num_rows(),
+            None => return Err(EvalResult::Error("#REF!".to_owned())),
+        },
+        None => ctx.num_rows(),
+    };
+    let stop_row = if stop.is_unbounded() {
+        num_rows - 1
+    } else {
+        stop.row
+    };
+    Ok((
+        start.table,
+        CellRange {
+            start_row: cmp::max(start.row, 0),
+            start_col: start.col,
+            stop_row,
+            stop_col: stop.col,
+        },
+    ))
+}
+
+// indirect resolves INDIRECT(ref_text), where ref_text is a reference such as "B2" or
+// "Costs!A1:A5"
+fn indirect(
+    args: &[Box<ASTNode>],
+    state: &mut EvalState,
+    ctx: &dyn EvalContext,
+) -> Result<(Option<String>, CellRange), EvalResult> {
+    if args.len() != 1 {
+        return Err(EvalResult::Error("#VALUE!".to_owned()));
+    }
+    match evaluate_internal((*args[0]).clone(), state, ctx) {
+        EvalResult::NonNumeric(txt) => match parse(&format!("={}", txt)) {
+            n @ ASTNode::Ref(_) | n @ ASTNode::Range { .. } => resolve_reference(n, state, ctx),
+            _ => Err(EvalResult::Error("#REF!".to_owned())),
+        },
+        EvalResult::Error(e) => Err(EvalResult::Error(e)),
+        _ => Err(EvalResult::Error("#REF!".to_owned())),
+    }
+}
+
+// offset resolves OFFSET(reference, rows, cols, [height], [width]). The height and width
+// default to the size of the reference.
+fn offset(
+    args: &[Box<ASTNode>],
+    state: &mut EvalState,
+    ctx: &dyn EvalContext,
+) -> Result<(Option<String>, CellRange), EvalResult> {
+    if args.len() < 3 || args.len() > 5 {
+        return Err(EvalResult::Error("#VALUE!".to_owned()));
+    }
+    let (table, base) = resolve_reference((*args[0]).clone(), state, ctx)?;
+
+    let mut nums = vec![];
+    for arg in &args[1..] {
+        match evaluate_internal((**arg).clone(), state, ctx) {
+            EvalResult::Numeric(n) => nums.push(n.trunc() as i32),
+            EvalResult::NonNumeric(s) if s.is_empty() => nums.push(0),
+            EvalResult::Error(e) => return Err(EvalResult::Error(e)),
+            _ => return Err(EvalResult::Error("#VALUE!".to_owned())),
+        }
+    }
+    let height = nums
+        .get(2)
+        .copied()
+        .unwrap_or(base.stop_row - base.start_row + 1);
+    let width = nums
+        .get(3)
+        .copied()
+        .unwrap_or(base.stop_col - base.start_col + 1);
+    let start_row = base.start_row + nums[0];
+    let start_col = base.start_col + nums[1];
+    if height < 1 || width < 1 || start_row < 0 || start_col < 0 {
+        return Err(EvalResult::Error("#REF!".to_owned()));
+    }
+    Ok((
+        table,
+        CellRange {
+            start_row,
+            start_col,
+            stop_row: start_row + height - 1,
+            stop_col: start_col + width - 1,
+        },
+    ))
+}
+
+// range_to_node returns the AST node referring to the provided range
+fn range_to_node(table: Option<String>, r: CellRange) -> ASTNode {
+    let start = CellRef {
+        row: r.start_row,
+        col: r.start_col,
+        table,
+    };
+    if r.start_row == r.stop_row && r.start_col == r.stop_col {
+        return ASTNode::Ref(start);
+    }
+    let stop = CellRef {
+        row: r.stop_row,
+        col: r.stop_col,
+        table: start.table.clone(),
+    };
+    ASTNode::Range { start, stop }
+}
+
 pub fn prefix_binding_power(op: char) -> (u8, u8) {
     (0, 0)
 }
@@ -294,6 +780,10 @@ pub fn parse_cell_or_function(id: String, tokens: &mut Vec<Token>) -> Result<AST
             advance(tokens);
             // lhs is a function call
             let mut args = vec![];
+            if peek(tokens) == Token::Op(')') {
+                advance(tokens);
+                return Ok(ASTNode::Function { name: id, args });
+            }
             loop {
                 println!("parse cell or func tokens are {:?}", tokens);
                 let node = pratt_parse(tokens, 0)?;
@@ -313,25 +803,55 @@ pub fn parse_cell_or_function(id: String, tokens: &mut Vec<Token>) -> Result<AST
             }
             Ok(ASTNode::Function { name: id, args })
         }
-        _ => {
-            let left = parse_cell_ref(id)?;
-            if let Token::Colon = peek(tokens) {
-                advance(tokens);
-                let right = match advance(tokens) {
-                    Token::ID(ref_val) => parse_cell_ref(ref_val),
-                    t => Err(Error::new(&format!(
-                        "Could not parse value {:?} as cell reference",
-                        t
-                    ))),
-                }?;
-                Ok(ASTNode::Range {
-                    start: left,
-                    stop: right,
-                })
-            } else {
-                Ok(ASTNode::Ref(left))
-            }
-        }
+        _ => match id.to_uppercase().as_str() {
+            "TRUE" => Ok(ASTNode::Bool(true)),
+            "FALSE" => Ok(ASTNode::Bool(false)),
+            _ => parse_ref_or_range(id, None, tokens),
+        },
+    }
+}
+
+// parse_table_ref parses a reference qualified with a table name, e.g. Costs!A1 or
+// 'Budget 2024'!A1:B4. The table name must already be consumed.
+pub fn parse_table_ref(table: String, tokens: &mut Vec<Token>) -> Result<ASTNode, Error> {
+    if advance(tokens) != Token::Bang {
+        return Err(Error::new(&format!(
+            "expected '!' after table name {}",
+            table
+        )));
+    }
+    match advance(tokens) {
+        Token::ID(ref_val) => parse_ref_or_range(ref_val, Some(table), tokens),
+        t => Err(Error::new(&format!(
+            "Could not parse value {:?} as cell reference",
+            t
+        ))),
+    }
+}
+
+fn parse_ref_or_range(
+    id: String,
+    table: Option<String>,
+    tokens: &mut Vec<Token>,
+) -> Result<ASTNode, Error> {
+    let mut left = parse_cell_ref(id)?;
+    left.table = table.clone();
+    if let Token::Colon = peek(tokens) {
+        advance(tokens);
+        let mut right = match advance(tokens) {
+            Token::ID(ref_val) => parse_cell_ref(ref_val),
+            t => Err(Error::new(&format!(
+                "Could not parse value {:?} as cell reference",
+                t
+            ))),
+        }?;
+        right.table = table;
+        Ok(ASTNode::Range {
+            start: left,
+            stop: right,
+        })
+    } else {
+        Ok(ASTNode::Ref(left))
     }
 }
 
@@ -363,7 +883,13 @@ pub fn pratt_parse(tokens: &mut Vec<Token>, mbp: u8) -> Result<ASTNode, Error> {
                 txt
             ))),
         },
-        Token::ID(id) => parse_cell_or_function(id, tokens),
+        Token::Text(txt) => Ok(ASTNode::Text(txt)),
+        Token::ID(id) => match peek(tokens) {
+            Token::Bang => parse_table_ref(id, tokens),
+            _ => parse_cell_or_function(id, tokens),
+        },
+        Token::TableName(table) => parse_table_ref(table, tokens),
+        Token::Error(code) => Ok(ASTNode::ParseError(code)),
         t => Err(Error::new(&format!("unexpected token {:?}", t))),
     }?;
     loop {
@@ -439,6 +965,10 @@ fn parse_cell_ref(ref_val: String) -> Result<CellRef, Error> {
 
     let mut val = String::new();
     for c in ref_val.chars() {
+        // '$' anchors only matter when a formula is copied, so they are skipped here
+        if c == '$' {
+            continue;
+        }
         if !col_specified && (c < 'A' || c > 'z') {
             return Err(Error::new(
                 &"expected a letter but did not find one for an ID".to_owned(),
@@ -484,9 +1014,22 @@ fn parse_cell_ref(ref_val: String) -> Result<CellRef, Error> {
         col: col_letters_to_num(&col_str),
         // rows here are zero indexed, but one indexed in AST representation
         row: row - 1,
+        table: None,
     })
 }
 
+// col_num_to_letters returns the column name, e.g. "AB", for a zero indexed column
+pub fn col_num_to_letters(col: i32) -> String {
+    let mut letters = vec![];
+    let mut n = col + 1;
+    while n > 0 {
+        let rem = (n - 1) % 26;
+        letters.push((b'A' + rem as u8) as char);
+        n = (n - 1) / 26;
+    }
+    letters.iter().rev().collect()
+}
+
 fn col_letters_to_num(letters: &str) -> i32 {
     let upper = letters.to_uppercase();
     let mut total: i32 = 0;
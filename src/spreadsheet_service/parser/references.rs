@@ -0,0 +1,269 @@
+use super::super::models::{Axis, Rect, Shift};
+use super::lexer::{lex, quote_table_name, Token};
+use super::parser::col_num_to_letters;
+
+// FormulaRef is a reference as it's written in a formula, e.g. Costs!$A1:B4
+#[derive(Debug, Clone, PartialEq)]
+pub struct FormulaRef {
+    // table is set when the reference is qualified with the table it points into
+    pub table: Option<String>,
+    pub start: RefPart,
+    // stop is set for ranges
+    pub stop: Option<RefPart>,
+}
+
+// RefPart is a single cell of a reference. Its row and column are zero indexed, and the
+// row is -1 for references to whole columns such as A:A.
+#[derive(Debug, Clone, PartialEq)]
+pub struct RefPart {
+    pub row: i32,
+    pub col: i32,
+    // absolute parts are anchored with '$' and keep pointing at the same row or column
+    // when the formula is copied elsewhere
+    pub row_absolute: bool,
+    pub col_absolute: bool,
+}
+
+impl RefPart {
+    fn parse(id: &str) -> Option<RefPart> {
+        let (col_absolute, rest) = match id.strip_prefix('$') {
+            Some(rest) => (true, rest),
+            None => (false, id),
+        };
+        let letters = rest
+            .find(|c: char| !c.is_ascii_alphabetic())
+            .unwrap_or(rest.len());
+        if letters == 0 {
+            return None;
+        }
+        let (col, rest) = rest.split_at(letters);
+        let (row_absolute, row) = match rest.strip_prefix('$') {
+            Some(row) => (true, row),
+            None => (false, rest),
+        };
+        let row = match row {
+            "" if !row_absolute => -1,
+            row if row.chars().all(|c| c.is_ascii_digit()) => row.parse::<i32>().ok()? - 1,
+            _ => return None,
+        };
+        let col = col
+            .to_uppercase()
+            .chars()
+            .fold(0, |total, c| total * 26 + (c as i32 - 'A' as i32 + 1))
+            - 1;
+        Some(RefPart {
+            row,
+            col,
+            row_absolute,
+            col_absolute,
+        })
+    }
+
+    fn is_whole_column(&self) -> bool {
+        self.row == -1
+    }
+}
+
+impl std::fmt::Display for RefPart {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        if self.col_absolute {
+            write!(f, "$")?;
+        }
+        write!(f, "{}", col_num_to_letters(self.col))?;
+        if self.is_whole_column() {
+            return Ok(());
+        }
+        if self.row_absolute {
+            write!(f, "$")?;
+        }
+        write!(f, "{}", self.row + 1)
+    }
+}
+
+impl std::fmt::Display for FormulaRef {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        if let Some(table) = &self.table {
+            write!(f, "{}!", quote_table_name(table))?;
+        }
+        write!(f, "{}", self.start)?;
+        match &self.stop {
+            Some(stop) => write!(f, ":{}", stop),
+            None => Ok(()),
+        }
+    }
+}
+
+// rewrite_refs returns the formula with each of its references replaced by the one the
+// provided function returns for it, or by #REF! when it returns None. Formulas whose
+// references are all kept are returned unchanged.
+pub fn rewrite_refs(formula: &str, mut f: impl FnMut(&FormulaRef) -> Option<FormulaRef>) -> String {
+    let tokens = match formula.strip_prefix('=').map(lex) {
+        Some(Ok(tokens)) => tokens,
+        _ => return formula.to_owned(),
+    };
+
+    let mut changed = false;
+    let mut ret = "=".to_owned();
+    let mut i = 0;
+    while i < tokens.len() {
+        if let Some((r, len)) = ref_at(&tokens, i) {
+            let rewritten = f(&r);
+            changed |= rewritten.as_ref() != Some(&r);
+            match rewritten {
+                Some(r) => ret.push_str(&r.to_string()),
+                None => ret.push_str("#REF!"),
+            }
+            i += len;
+            continue;
+        }
+        match &tokens[i] {
+            Token::Colon => ret.push(':'),
+            Token::Bang => ret.push('!'),
+            Token::Number(n) => ret.push_str(n),
+            Token::Op(c) => ret.push(*c),
+            Token::Comma => ret.push(','),
+            Token::Text(s) => ret.push_str(&format!("\"{}\"", s)),
+            Token::ID(id) => ret.push_str(id),
+            Token::TableName(name) => ret.push_str(&quote_table_name(name)),
+            Token::Error(code) => ret.push_str(code),
+            Token::Eof => (),
+        }
+        i += 1;
+    }
+    match changed {
+        true => ret,
+        false => formula.to_owned(),
+    }
+}
+
+// ref_at returns the reference starting at the token with the provided index along with
+// the number of tokens it spans, or None when the token doesn't start a reference.
+fn ref_at(tokens: &[Token], i: usize) -> Option<(FormulaRef, usize)> {
+    let (table, start) = match (&tokens[i], tokens.get(i + 1)) {
+        (Token::ID(name), Some(Token::Bang)) | (Token::TableName(name), Some(Token::Bang)) => {
+            (Some(name.clone()), i + 2)
+        }
+        _ => (None, i),
+    };
+    let id = match tokens.get(start) {
+        Some(Token::ID(id)) => id,
+        _ => return None,
+    };
+    // IDs followed by '(' are functions
+    if tokens.get(start + 1) == Some(&Token::Op('(')) {
+        return None;
+    }
+    if table.is_none() && matches!(id.to_uppercase().as_str(), "TRUE" | "FALSE") {
+        return None;
+    }
+    let start_part = RefPart::parse(id)?;
+    let stop_part = match (tokens.get(start + 1), tokens.get(start + 2)) {
+        (Some(Token::Colon), Some(Token::ID(id))) => RefPart::parse(id),
+        _ => None,
+    };
+    let len = match stop_part {
+        Some(_) => start + 3 - i,
+        None => start + 1 - i,
+    };
+    let r = FormulaRef {
+        table,
+        start: start_part,
+        stop: stop_part,
+    };
+    Some((r, len))
+}
+
+// shift_refs returns the formula with its references into the provided table adjusted
+// for the rows or columns inserted into or deleted from it. References to deleted cells
+// become #REF!, while ranges grow or shrink. own_table is the table the formula belongs
+// to, which its unqualified references point into.
+pub fn shift_refs(formula: &str, own_table: &str, table: &str, shift: &Shift) -> String {
+    rewrite_refs(formula, |r| {
+        if r.table.as_deref().unwrap_or(own_table) != table {
+            return Some(r.clone());
+        }
+        let mut ret = r.clone();
+        let stop = r.stop.as_ref().unwrap_or(&r.start);
+        match shift.axis {
+            // references to whole columns keep pointing at every row
+            Axis::Rows if r.start.is_whole_column() || stop.is_whole_column() => (),
+            Axis::Rows => {
+                let (start_row, stop_row) = shift.span(r.start.row, stop.row)?;
+                ret.start.row = start_row;
+                if let Some(stop) = ret.stop.as_mut() {
+                    stop.row = stop_row;
+                }
+            }
+            Axis::Cols => {
+                let (start_col, stop_col) = shift.span(r.start.col, stop.col)?;
+                ret.start.col = start_col;
+                if let Some(stop) = ret.stop.as_mut() {
+                    stop.col = stop_col;
+                }
+            }
+        }
+        Some(ret)
+    })
+}
+
+// move_refs returns the formula with its references into the provided table adjusted for
+// the cells of the source rectangle moving by the provided number of rows and columns.
+// References within the moved block follow it, while references to the cells it replaces
+// become #REF!. Other references, including those to whole columns, are kept.
+pub fn move_refs(
+    formula: &str,
+    own_table: &str,
+    table: &str,
+    source: &Rect,
+    rows: i32,
+    cols: i32,
+) -> String {
+    let destination = source.offset(rows, cols);
+    rewrite_refs(formula, |r| {
+        let stop = r.stop.as_ref().unwrap_or(&r.start);
+        if r.table.as_deref().unwrap_or(own_table) != table
+            || r.start.is_whole_column()
+            || stop.is_whole_column()
+        {
+            return Some(r.clone());
+        }
+        let within = |rect: &Rect| {
+            rect.contains(r.start.row, r.start.col) && rect.contains(stop.row, stop.col)
+        };
+        if within(source) {
+            let mut ret = r.clone();
+            for part in std::iter::once(&mut ret.start).chain(ret.stop.as_mut()) {
+                part.row += rows;
+                part.col += cols;
+            }
+            return Some(ret);
+        }
+        match within(&destination) {
+            true => None,
+            false => Some(r.clone()),
+        }
+    })
+}
+
+// translate_refs returns the formula as it's copied by the provided number of rows and
+// columns, e.g. =A1+$B$1 copied from C1 to C2 is =A2+$B$1. Relative references move with
+// the formula, while those anchored with '$' keep pointing at the same row or column.
+// References moved before the first row or column become #REF!.
+pub fn translate_refs(formula: &str, rows: i32, cols: i32) -> String {
+    rewrite_refs(formula, |r| {
+        let mut ret = r.clone();
+        for part in std::iter::once(&mut ret.start).chain(ret.stop.as_mut()) {
+            let whole_column = part.is_whole_column();
+            if !part.row_absolute && !whole_column {
+                part.row += rows;
+            }
+            if !part.col_absolute {
+                part.col += cols;
+            }
+            if part.col < 0 || (part.row < 0 && !whole_column) {
+                return None;
+            }
+        }
+        Some(ret)
+    })
+}
@@ -1,78 +1,41 @@
 #[cfg(test)]
 mod tests {
+    pub use super::super::super::models::CellRange;
+    pub use super::super::super::models::Value;
+    pub use super::super::super::models::{Axis, Rect, Shift};
+    pub use super::super::format::*;
+    pub use super::super::input::*;
     pub use super::super::lexer::*;
+    pub use super::super::locale::*;
     pub use super::super::parser::*;
+    pub use super::super::references::*;
 
     #[test]
     fn test_parse_number() {
-        let tokens = lex(&"30".to_string());
+        let tokens = lex("30").unwrap();
 
-        assert_eq!(1, tokens.len());
-        assert_eq!(
-            &Token {
-                kind: TokenKind::Number,
-                val: "30".to_string(),
-            },
-            tokens.get(0).unwrap()
-        );
+        assert_eq!(2, tokens.len());
+        assert_eq!(&Token::Number("30".to_string()), tokens.first().unwrap());
 
-        let tokens = lex(&"30 40 50".to_string());
-        assert_eq!(3, tokens.len());
+        let tokens = lex("30 40 50").unwrap();
+        assert_eq!(4, tokens.len());
 
-        assert_eq!(
-            &Token {
-                kind: TokenKind::Number,
-                val: "30".to_string(),
-            },
-            tokens.get(0).unwrap()
-        );
-        assert_eq!(
-            &Token {
-                kind: TokenKind::Number,
-                val: "40".to_string(),
-            },
-            tokens.get(1).unwrap()
-        );
-        assert_eq!(
-            &Token {
-                kind: TokenKind::Number,
-                val: "50".to_string(),
-            },
-            tokens.get(2).unwrap()
-        );
+        assert_eq!(&Token::Number("30".to_string()), tokens.first().unwrap());
+        assert_eq!(&Token::Number("40".to_string()), tokens.get(1).unwrap());
+        assert_eq!(&Token::Number("50".to_string()), tokens.get(2).unwrap());
     }
 
     #[test]
     fn test_parse_id() {
-        let tokens = lex(&"this_id".to_string());
+        let tokens = lex("this_id").unwrap();
 
-        assert_eq!(1, tokens.len());
-        assert_eq!(
-            &Token {
-                kind: TokenKind::ID,
-                val: "this_id".to_string(),
-            },
-            tokens.get(0).unwrap()
-        );
+        assert_eq!(2, tokens.len());
+        assert_eq!(&Token::ID("this_id".to_string()), tokens.first().unwrap());
     }
 
     #[test]
     fn test_parse() {
-        let tokens = &mut vec![
-            Token {
-                kind: TokenKind::Number,
-                val: "30".to_string(),
-            },
-            Token {
-                kind: TokenKind::BinaryExpr,
-                val: "+".to_string(),
-            },
-            Token {
-                kind: TokenKind::Number,
-                val: "40".to_string(),
-            },
-        ];
-        let node = parse_internal(tokens).unwrap();
+        let node = parse("=30+40");
 
         assert_eq!(
             node,
@@ -86,41 +49,7 @@ mod tests {
 
     #[test]
     fn test_parse_function() {
-        let tokens = &mut vec![
-            Token {
-                kind: TokenKind::ID,
-                val: "ABC".to_string(),
-            },
-            Token {
-                kind: TokenKind::LParen,
-                val: "(".to_string(),
-            },
-            Token {
-                kind: TokenKind::Number,
-                val: "20".to_string(),
-            },
-            Token {
-                kind: TokenKind::BinaryExpr,
-                val: "+".to_string(),
-            },
-            Token {
-                kind: TokenKind::Number,
-                val: "40".to_string(),
-            },
-            Token {
-                kind: TokenKind::Comma,
-                val: ",".to_string(),
-            },
-            Token {
-                kind: TokenKind::Number,
-                val: "60".to_string(),
-            },
-            Token {
-                kind: TokenKind::RParen,
-                val: ")".to_string(),
-            },
-        ];
-        let node = parse_internal(tokens).unwrap();
+        let node = parse("=ABC(20+40, 60)");
 
         assert_eq!(
             node,
@@ -140,11 +69,334 @@ mod tests {
 
     #[test]
     fn test_parse_cell_ref() {
-        let tokens = &mut vec![Token {
-            kind: TokenKind::ID,
-            val: "B1".to_string(),
-        }];
-        let node = parse_internal(tokens).unwrap();
-        assert_eq!(node, ASTNode::Ref(CellRef { col: 1, row: 0 }));
+        let node = parse("=B1");
+        assert_eq!(node, ASTNode::Ref(CellRef::new(0, 1)));
+    }
+
+    #[test]
+    fn test_parse_table_ref() {
+        let tokens = lex("'Budget ''24'!B4").unwrap();
+        assert_eq!(
+            vec![
+                Token::TableName("Budget '24".to_string()),
+                Token::Bang,
+                Token::ID("B4".to_string()),
+                Token::Eof,
+            ],
+            tokens
+        );
+
+        let node = parse("='Budget 2024'!B4");
+        assert_eq!(
+            node,
+            ASTNode::Ref(CellRef {
+                row: 3,
+                col: 1,
+                table: Some("Budget 2024".to_string()),
+            })
+        );
+
+        let node = parse("=SUM(Costs!A:A)");
+        let column = CellRef {
+            row: -1,
+            col: 0,
+            table: Some("Costs".to_string()),
+        };
+        assert_eq!(
+            node,
+            ASTNode::Function {
+                name: "SUM".to_string(),
+                args: vec![Box::new(ASTNode::Range {
+                    start: column.clone(),
+                    stop: column,
+                })],
+            }
+        );
+        assert_eq!(Vec::<CellRange>::new(), get_refs(&node));
+        assert_eq!(1, get_table_refs(&node).len());
+    }
+
+    #[test]
+    fn test_recognize_input() {
+        let number = |input: &str| match recognize(input) {
+            Some(Recognized {
+                value: ASTNode::Number(n),
+                format,
+            }) => Some((n, format)),
+            _ => None,
+        };
+        assert_eq!(Some((0.15, "0%".to_string())), number("15%"));
+        assert_eq!(Some((0.125, "0.00%".to_string())), number("12.5%"));
+        assert_eq!(Some((1200.5, "$#,##0.00".to_string())), number("$1,200.50"));
+        assert_eq!(Some((-5.0, "$#,##0".to_string())), number("-$5"));
+        assert_eq!(Some((1000.0, "#,##0".to_string())), number("1,000"));
+        assert_eq!(Some((-42.0, "".to_string())), number("(42)"));
+        assert_eq!(Some((7.0, "".to_string())), number(" 7 "));
+        assert_eq!(None, number("1,00"));
+        assert_eq!(None, number("$"));
+        assert_eq!(None, number("12 apples"));
+
+        assert_eq!(ASTNode::Bool(true), parse("TRUE"));
+        assert_eq!(ASTNode::Bool(false), parse("false"));
+        assert_eq!(ASTNode::Bool(true), parse("=TRUE"));
+        assert_eq!(ASTNode::Text("(A1)".to_string()), parse("(A1)"));
+    }
+
+    #[test]
+    fn test_typed_value() {
+        assert_eq!(
+            Value::Number(2f64),
+            typed_value(&EvalResult::Numeric(2f64), "0.00")
+        );
+        assert_eq!(
+            Value::Date(45000f64),
+            typed_value(&EvalResult::Numeric(45000f64), "d/m/yy")
+        );
+        assert_eq!(
+            Value::Number(1f64),
+            typed_value(&EvalResult::Numeric(1f64), "0 \"days\"")
+        );
+        assert_eq!(
+            Value::Number(-1f64),
+            typed_value(&EvalResult::Numeric(-1f64), "0;[Red]-0")
+        );
+        assert_eq!(
+            Value::Empty,
+            typed_value(&EvalResult::NonNumeric("".to_string()), "")
+        );
+        assert_eq!(
+            Value::Error("#NUM!".to_string()),
+            typed_value(&EvalResult::Error("#NUM!".to_string()), "")
+        );
+    }
+
+    #[test]
+    fn test_format_value() {
+        let n = |n: f64| EvalResult::Numeric(n);
+        let en = Locale::default();
+        assert_eq!("0.15", format_value(&n(0.15), "", &en));
+        assert_eq!("15%", format_value(&n(0.15), "0%", &en));
+        assert_eq!("12.50%", format_value(&n(0.125), "0.00%", &en));
+        assert_eq!("$1,200.50", format_value(&n(1200.5), "$#,##0.00", &en));
+        assert_eq!("-$1,234,567", format_value(&n(-1234567.0), "$#,##0", &en));
+        assert_eq!("1,000", format_value(&n(1000.0), "#,##0", &en));
+        assert_eq!("0", format_value(&n(-0.2), "#,##0", &en));
+        assert_eq!("TRUE", format_value(&EvalResult::Bool(true), "0%", &en));
+
+        // General shows up to 15 significant digits
+        assert_eq!("0.333333333333333", format_value(&n(1.0 / 3.0), "", &en));
+        assert_eq!("0.3", format_value(&n(0.1 + 0.2), "General", &en));
+        assert_eq!("-2.5", format_value(&n(-2.5), "", &en));
+        assert_eq!("1E+20", format_value(&n(1e20), "", &en));
+        assert_eq!(
+            "1.23456789012346E+17",
+            format_value(&n(123456789012345678.0), "", &en)
+        );
+        assert_eq!("1.5E-10", format_value(&n(1.5e-10), "", &en));
+        assert_eq!("3 kg", format_value(&n(3.0), "General\" kg\"", &en));
+
+        assert_eq!("1.23", format_value(&n(1.23456), "0.00", &en));
+        assert_eq!("3.1", format_value(&n(3.1), "0.0#", &en));
+        assert_eq!(".50", format_value(&n(0.5), "#.00", &en));
+        assert_eq!("1,235", format_value(&n(1234567.0), "#,##0,", &en));
+        assert_eq!("1.23E+04", format_value(&n(12345.0), "0.00E+00", &en));
+        assert_eq!("1.23E-04", format_value(&n(0.000123), "0.00E+00", &en));
+        assert_eq!("1.00E+01", format_value(&n(9.999), "0.00E+00", &en));
+
+        // sections for positive, negative and zero values, and text
+        let sections = "0.00;[Red](0.00);\"zero\";\"Note: \"@";
+        assert_eq!("3.00", format_value(&n(3.0), sections, &en));
+        assert_eq!("(3.00)", format_value(&n(-3.0), sections, &en));
+        assert_eq!("zero", format_value(&n(0.0), sections, &en));
+        let text = EvalResult::NonNumeric("hi".to_string());
+        assert_eq!("Note: hi", format_value(&text, sections, &en));
+        assert_eq!("-3.00", format_value(&n(-3.0), "0.00;[Red]-0.00", &en));
+        assert_eq!("", format_value(&n(0.0), "0;-0;", &en));
+
+        // serial dates and times
+        let date = n(45306.75);
+        assert_eq!("2024-01-15", format_value(&date, "yyyy-mm-dd", &en));
+        assert_eq!("15/1/24", format_value(&date, "d/m/yy", &en));
+        assert_eq!(
+            "Monday, January 15, 2024",
+            format_value(&date, "dddd, mmmm d, yyyy", &en)
+        );
+        assert_eq!("Jan 24", format_value(&date, "mmm yy", &en));
+        assert_eq!("18:00:00", format_value(&date, "hh:mm:ss", &en));
+        assert_eq!("6:00 PM", format_value(&date, "h:mm AM/PM", &en));
+        assert_eq!(
+            "1970-01-01 00:00",
+            format_value(&n(25569.0), "yyyy-mm-dd hh:mm", &en)
+        );
+        assert_eq!("#####", format_value(&n(-1.0), "yyyy-mm-dd", &en));
+
+        let de = Locale::from_name("de-DE").unwrap();
+        assert_eq!("0,5", format_value(&n(0.5), "", &de));
+        assert_eq!("1,23E+04", format_value(&n(12345.0), "0.00E+00", &de));
+
+        assert!(validate_format("0.00;[Red]-0.00").is_ok());
+        assert!(validate_format("0.00 \"kg").is_err());
+        assert!(validate_format("[Red0.00").is_err());
+        assert!(validate_format("0;0;0;@;0").is_err());
+    }
+
+    #[test]
+    fn test_locale() {
+        let de = Locale::from_name("de-DE").unwrap();
+        assert_eq!(
+            vec![
+                Token::ID("SUMME".to_string()),
+                Token::Op('('),
+                Token::ID("A1".to_string()),
+                Token::Comma,
+                Token::Number("1.5".to_string()),
+                Token::Op(')'),
+                Token::Eof,
+            ],
+            lex_localized("SUMME(A1; 1,5)", &de).unwrap()
+        );
+        assert_eq!(
+            "=SUM(A1,1.5)+Costs!A1",
+            canonical_formula("=SUMME(A1; 1,5)+Costs!A1", &de)
+        );
+        assert_eq!(
+            "=BEREICH.VERSCHIEBEN(A1;1;0)*WAHR",
+            localize_formula("=OFFSET(A1, 1, 0)*TRUE", &de)
+        );
+        assert_eq!(
+            "=SUM(A1, 1)",
+            canonical_formula("=SUM(A1, 1)", &Locale::default())
+        );
+        assert!(Locale::from_name("xx-XX").is_none());
+
+        let number = |input: &str| match recognize_localized(input, &de) {
+            Some(Recognized {
+                value: ASTNode::Number(n),
+                format,
+            }) => Some((n, format)),
+            _ => None,
+        };
+        assert_eq!(Some((1234.5, "#,##0.00".to_string())), number("1.234,5"));
+        assert_eq!(
+            Some((1234.5, "#,##0.00 €".to_string())),
+            number("1.234,50 €")
+        );
+        assert_eq!(Some((0.155, "0.00%".to_string())), number("15,5%"));
+        assert_eq!(None, number("1.5"));
+        assert_eq!(ASTNode::Bool(true), parse_localized("wahr", &de));
+
+        assert_eq!("1,5", format_value(&EvalResult::Numeric(1.5), "", &de));
+        assert_eq!(
+            "1.234,50 €",
+            format_value(&EvalResult::Numeric(1234.5), "#,##0.00 €", &de)
+        );
+        assert_eq!("FALSCH", format_value(&EvalResult::Bool(false), "", &de));
+    }
+
+    #[test]
+    fn test_shift_refs() {
+        let rows = |at, count| Shift {
+            axis: Axis::Rows,
+            at,
+            count,
+        };
+        let cols = |at, count| Shift {
+            axis: Axis::Cols,
+            at,
+            count,
+        };
+
+        // Inserting rows moves the references below them and grows the ranges around them
+        // Anchored references still point at the same cells once they move
+        assert_eq!(
+            "=SUM(A1:A6)+B7*$C$4",
+            shift_refs("=SUM(A1:A4)+B5*$C$2", "", "", &rows(1, 2))
+        );
+        assert_eq!("=A1+B2", shift_refs("=A1+B2", "", "", &rows(5, 2)));
+        // Deleting rows shrinks the ranges and turns references to deleted cells into #REF!
+        assert_eq!(
+            "=SUM(A1:A2)+#REF!+B2",
+            shift_refs("=SUM(A1:A4)+B3+B4", "", "", &rows(1, -2))
+        );
+        assert_eq!(
+            "=SUM(#REF!)",
+            shift_refs("=SUM(A2:A3)", "", "", &rows(1, -2))
+        );
+        assert_eq!(
+            "=SUM(A:A)+SUM(C:D)",
+            shift_refs("=SUM(A:A)+SUM(B:C)", "", "", &cols(1, 1))
+        );
+        assert_eq!("=SUM(A:A)", shift_refs("=SUM(A:A)", "", "", &rows(0, 3)));
+
+        // Only references into the shifted table are adjusted
+        assert_eq!(
+            "=A1+Costs!A3+'Budget 2024'!A1",
+            shift_refs(
+                "=A1+Costs!A1+'Budget 2024'!A1",
+                "Sales",
+                "Costs",
+                &rows(0, 2)
+            )
+        );
+        assert_eq!(
+            "=A3+Costs!A1",
+            shift_refs("=A1+Costs!A1", "Sales", "Sales", &rows(0, 2))
+        );
+        // Functions and booleans aren't references
+        assert_eq!(
+            "=AND(TRUE,ROW(A3))",
+            shift_refs("=AND(TRUE,ROW(A1))", "", "", &rows(0, 2))
+        );
+
+        let renamed = rewrite_refs("=A1+Costs!B2:C3", |r| {
+            Some(FormulaRef {
+                table: r.table.as_ref().map(|_| "Other costs".to_string()),
+                ..r.clone()
+            })
+        });
+        assert_eq!("=A1+'Other costs'!B2:C3", renamed);
+        assert_eq!(
+            Token::Error("#REF!".to_string()),
+            lex("#REF!+1").unwrap()[0]
+        );
+        assert_eq!(ASTNode::ParseError("#REF!".to_string()), parse("=#REF!"));
+    }
+
+    #[test]
+    fn test_move_refs() {
+        // B2:C3 moves to E5:F6
+        let source = Rect {
+            start_row: 1,
+            stop_row: 3,
+            start_col: 1,
+            stop_col: 3,
+        };
+        let moved = |formula: &str| move_refs(formula, "", "", &source, 3, 3);
+
+        assert_eq!("=E5+SUM(E5:F6)*$F$6", moved("=B2+SUM(B2:C3)*$C$3"));
+        // Ranges reaching out of the moved block are kept
+        assert_eq!("=SUM(A1:C3)+SUM(B:B)", moved("=SUM(A1:C3)+SUM(B:B)"));
+        // References to the replaced cells become #REF!
+        assert_eq!("=#REF!+SUM(#REF!)+G7", moved("=E5+SUM(E5:F6)+G7"));
+        assert_eq!(
+            "=Costs!B2+E5",
+            move_refs("=Costs!B2+B2", "Sales", "Sales", &source, 3, 3)
+        );
+        assert_eq!(
+            "=Costs!E5+B2",
+            move_refs("=Costs!B2+B2", "Sales", "Costs", &source, 3, 3)
+        );
+    }
+
+    #[test]
+    fn test_translate_refs() {
+        assert_eq!("=A2+B2", translate_refs("=A1+B1", 1, 0));
+        assert_eq!(
+            "=SUM($A1:B$1)+$C$3+Costs!D2",
+            translate_refs("=SUM($A1:A$1)+$C$3+Costs!C2", 0, 1)
+        );
+        assert_eq!("=SUM(C:C)", translate_refs("=SUM(B:B)", 5, 1));
+        assert_eq!("=B1+#REF!", translate_refs("=B3+A1", -2, 0));
+        assert_eq!("=A1", translate_refs("=A1", 0, 0));
     }
 }
@@ -0,0 +1,280 @@
+use super::super::models;
+use super::super::models::context::EvalContext;
+use super::super::models::{Comparison, ConditionalFormat, FormatRule, Style};
+use super::super::parser;
+use super::super::parser::{ASTNode, EvalResult, Locale};
+use std::cmp::Ordering;
+
+// validate returns an error describing why the conditional format can't be used
+pub fn validate(cf: &ConditionalFormat) -> Result<(), String> {
+    let r = &cf.range;
+    if r.start_row < 0 || r.start_col < 0 || r.stop_row <= r.start_row || r.stop_col <= r.start_col
+    {
+        return Err(format!("invalid range for conditional format: {:?}", r));
+    }
+    let colors: Vec<&String> = match &cf.rule {
+        FormatRule::CellValue {
+            comparison,
+            values,
+            style,
+        } => {
+            if values.len() != comparison.num_values() {
+                return Err(format!(
+                    "{:?} compares against {} values",
+                    comparison,
+                    comparison.num_values()
+                ));
+            }
+            style.validate()?;
+            vec![]
+        }
+        FormatRule::Formula { formula, style } => {
+            if !formula.starts_with('=') {
+                return Err(format!(
+                    "conditional format formula must start with '=': {}",
+                    formula
+                ));
+            }
+            if let ASTNode::ParseError(e) = parser::parse(formula) {
+                return Err(e);
+            }
+            style.validate()?;
+            vec![]
+        }
+        FormatRule::ColorScale {
+            min_color,
+            mid_color,
+            max_color,
+        } => {
+            if min_color.is_empty() || max_color.is_empty() {
+                return Err("color scales need a min and a max color".to_owned());
+            }
+            vec![min_color, mid_color, max_color]
+        }
+        FormatRule::DataBar { color } if color.is_empty() => {
+            return Err("data bars need a color".to_owned())
+        }
+        FormatRule::DataBar { color } => vec![color],
+        FormatRule::TopN { style, .. } => {
+            style.validate()?;
+            vec![]
+        }
+    };
+    match colors.into_iter().find(|c| !models::is_color(c)) {
+        Some(c) => Err(format!("invalid color {:?}, expected #rrggbb", c)),
+        None => Ok(()),
+    }
+}
+
+// evaluate_rule returns the style each cell of the range gets from the conditional format.
+// The cells are provided along with their values, which are None for empty cells.
+pub fn evaluate_rule(
+    cf: &ConditionalFormat,
+    cells: &[(models::CellLocation, Option<EvalResult>)],
+    ctx: &dyn EvalContext,
+    locale: &Locale,
+) -> Vec<(models::CellLocation, Style)> {
+    let numbers: Vec<f64> = cells
+        .iter()
+        .filter_map(|(_, v)| match v {
+            Some(EvalResult::Numeric(n)) => Some(*n),
+            _ => None,
+        })
+        .collect();
+    let number = |v: &Option<EvalResult>| match v {
+        Some(EvalResult::Numeric(n)) => Some(*n),
+        _ => None,
+    };
+
+    match &cf.rule {
+        FormatRule::CellValue {
+            comparison,
+            values,
+            style,
+        } => {
+            let operands: Vec<EvalResult> = values.iter().map(|v| rule_value(v, locale)).collect();
+            cells
+                .iter()
+                .filter(|(_, v)| match v {
+                    Some(v) => compare(v, *comparison, &operands),
+                    None => false,
+                })
+                .map(|(loc, _)| (*loc, style.clone()))
+                .collect()
+        }
+        FormatRule::Formula { formula, style } => {
+            let ast = parser::parse(formula);
+            let (start_row, start_col) = (cf.range.start_row, cf.range.start_col);
+            cells
+                .iter()
+                .filter(|(loc, _)| {
+                    // the formula may read the cell it's highlighting, so it's evaluated
+                    // on its own rather than as the cell's formula
+                    let ast = parser::offset_refs(&ast, loc.row - start_row, loc.col - start_col);
+                    match parser::evaluate(ast, ctx) {
+                        models::Value::Bool(b) => b,
+                        models::Value::Number(n) => n != 0f64,
+                        _ => false,
+                    }
+                })
+                .map(|(loc, _)| (*loc, style.clone()))
+                .collect()
+        }
+        FormatRule::ColorScale {
+            min_color,
+            mid_color,
+            max_color,
+        } => {
+            let (min, max) = bounds(&numbers);
+            cells
+                .iter()
+                .filter_map(|(loc, v)| number(v).map(|n| (loc, n)))
+                .map(|(loc, n)| {
+                    let t = if max > min {
+                        (n - min) / (max - min)
+                    } else {
+                        0f64
+                    };
+                    let fill_color = match mid_color.is_empty() {
+                        true => interpolate(min_color, max_color, t),
+                        false if t < 0.5 => interpolate(min_color, mid_color, t * 2f64),
+                        false => interpolate(mid_color, max_color, (t - 0.5) * 2f64),
+                    };
+                    let style = Style {
+                        fill_color,
+                        ..Default::default()
+                    };
+                    (*loc, style)
+                })
+                .collect()
+        }
+        FormatRule::DataBar { color } => {
+            let (min, max) = bounds(&numbers);
+            // bars start from zero unless every value is on the same side of it
+            let (min, max) = (min.min(0f64), max.max(0f64));
+            cells
+                .iter()
+                .filter_map(|(loc, v)| number(v).map(|n| (loc, n)))
+                .map(|(loc, n)| {
+                    let t = if max > min {
+                        (n - min) / (max - min)
+                    } else {
+                        1f64
+                    };
+                    let style = Style {
+                        data_bar: Some(models::DataBar {
+                            color: color.clone(),
+                            percent: (t * 100f64).round() as u32,
+                        }),
+                        ..Default::default()
+                    };
+                    (*loc, style)
+                })
+                .collect()
+        }
+        FormatRule::TopN {
+            n,
+            bottom,
+            percent,
+            style,
+        } => {
+            let mut sorted = numbers.clone();
+            sorted.sort_by(|a, b| match bottom {
+                true => a.partial_cmp(b).unwrap_or(Ordering::Equal),
+                false => b.partial_cmp(a).unwrap_or(Ordering::Equal),
+            });
+            let count = match percent {
+                true => (sorted.len() as f64 * *n as f64 / 100f64).ceil() as usize,
+                false => *n as usize,
+            };
+            let threshold = match count.min(sorted.len()) {
+                0 => return vec![],
+                count => sorted[count - 1],
+            };
+            cells
+                .iter()
+                .filter_map(|(loc, v)| number(v).map(|n| (loc, n)))
+                .filter(|(_, n)| match bottom {
+                    true => *n <= threshold,
+                    false => *n >= threshold,
+                })
+                .map(|(loc, _)| (*loc, style.clone()))
+                .collect()
+        }
+    }
+}
+
+// rule_value reads a value a cell is compared against like cell input, e.g. "10" is a
+// number and "TRUE" a boolean.
+pub fn rule_value(s: &str, locale: &Locale) -> EvalResult {
+    match parser::recognize_localized(s, locale).map(|r| r.value) {
+        Some(ASTNode::Number(n)) => EvalResult::Numeric(n),
+        Some(ASTNode::Bool(b)) => EvalResult::Bool(b),
+        _ => EvalResult::NonNumeric(s.to_owned()),
+    }
+}
+
+pub fn compare(value: &EvalResult, comparison: Comparison, operands: &[EvalResult]) -> bool {
+    let ord = |other: &EvalResult| match (value, other) {
+        (EvalResult::Numeric(a), EvalResult::Numeric(b)) => a.partial_cmp(b),
+        (EvalResult::Bool(a), EvalResult::Bool(b)) => Some(a.cmp(b)),
+        // text is compared without regard to case
+        (EvalResult::NonNumeric(a), EvalResult::NonNumeric(b)) => {
+            Some(a.to_lowercase().cmp(&b.to_lowercase()))
+        }
+        _ => None,
+    };
+    let between = || {
+        let (lo, hi) = match ord_values(&operands[0], &operands[1]) {
+            Some(Ordering::Greater) => (&operands[1], &operands[0]),
+            _ => (&operands[0], &operands[1]),
+        };
+        matches!(ord(lo), Some(Ordering::Greater) | Some(Ordering::Equal))
+            && matches!(ord(hi), Some(Ordering::Less) | Some(Ordering::Equal))
+    };
+    match comparison {
+        Comparison::Equal => ord(&operands[0]) == Some(Ordering::Equal),
+        Comparison::NotEqual => ord(&operands[0]) != Some(Ordering::Equal),
+        Comparison::Greater => ord(&operands[0]) == Some(Ordering::Greater),
+        Comparison::GreaterOrEqual => matches!(
+            ord(&operands[0]),
+            Some(Ordering::Greater) | Some(Ordering::Equal)
+        ),
+        Comparison::Less => ord(&operands[0]) == Some(Ordering::Less),
+        Comparison::LessOrEqual => {
+            matches!(
+                ord(&operands[0]),
+                Some(Ordering::Less) | Some(Ordering::Equal)
+            )
+        }
+        Comparison::Between => between(),
+        Comparison::NotBetween => !between(),
+    }
+}
+
+fn ord_values(a: &EvalResult, b: &EvalResult) -> Option<Ordering> {
+    match (a, b) {
+        (EvalResult::Numeric(a), EvalResult::Numeric(b)) => a.partial_cmp(b),
+        _ => None,
+    }
+}
+
+fn bounds(numbers: &[f64]) -> (f64, f64) {
+    let min = numbers.iter().cloned().fold(f64::INFINITY, f64::min);
+    let max = numbers.iter().cloned().fold(f64::NEG_INFINITY, f64::max);
+    (min, max)
+}
+
+// interpolate returns the color at t between the from and to colors, written as #rrggbb
+fn interpolate(from: &str, to: &str, t: f64) -> String {
+    let channel = |color: &str, i: usize| u8::from_str_radix(&color[1 + i * 2..3 + i * 2], 16);
+    let mut ret = "#".to_owned();
+    for i in 0..3 {
+        let (a, b) = match (channel(from, i), channel(to, i)) {
+            (Ok(a), Ok(b)) => (a as f64, b as f64),
+            _ => return to.to_owned(),
+        };
+        ret.push_str(&format!("{:02x}", (a + (b - a) * t).round() as u8));
+    }
+    ret
+}
@@ -0,0 +1,143 @@
+use super::super::models;
+use super::super::parser;
+use super::super::parser::{EvalResult, Locale};
+
+const WEEKDAYS: &[&str] = &[
+    "Monday",
+    "Tuesday",
+    "Wednesday",
+    "Thursday",
+    "Friday",
+    "Saturday",
+    "Sunday",
+];
+const WEEKDAYS_SHORT: &[&str] = &["Mon", "Tue", "Wed", "Thu", "Fri", "Sat", "Sun"];
+const MONTHS: &[&str] = &[
+    "January",
+    "February",
+    "March",
+    "April",
+    "May",
+    "June",
+    "July",
+    "August",
+    "September",
+    "October",
+    "November",
+    "December",
+];
+const MONTHS_SHORT: &[&str] = &[
+    "Jan", "Feb", "Mar", "Apr", "May", "Jun", "Jul", "Aug", "Sep", "Oct", "Nov", "Dec",
+];
+
+// series_values returns the values continuing the series formed by a line of source cells,
+// e.g. 1, 2 continues with 3, 4, a single date with the following days and Mon, Tue with
+// Wed, Thu. The source cells are provided along with their computed values. None is
+// returned when the cells don't form a series, in which case they're repeated instead.
+pub fn series_values(
+    line: &[(models::Cell, Option<EvalResult>)],
+    count: usize,
+    locale: &Locale,
+) -> Option<Vec<String>> {
+    if line.is_empty()
+        || line
+            .iter()
+            .any(|(c, _)| c.value.is_empty() || c.is_formula())
+    {
+        return None;
+    }
+    let numbers: Option<Vec<f64>> = line
+        .iter()
+        .map(|(_, v)| match v {
+            Some(EvalResult::Numeric(n)) => Some(*n),
+            _ => None,
+        })
+        .collect();
+    match numbers {
+        Some(numbers) => number_series(&numbers, &line[0].0.format, count, locale),
+        None => name_series(line, count),
+    }
+}
+
+fn number_series(
+    numbers: &[f64],
+    format: &str,
+    count: usize,
+    locale: &Locale,
+) -> Option<Vec<String>> {
+    // A single number is repeated while a single date is followed by the next days
+    let step = match numbers {
+        [_] if parser::is_date_format(format) => 1f64,
+        [_] => return None,
+        _ => numbers[1] - numbers[0],
+    };
+    let consistent = numbers
+        .windows(2)
+        .all(|w| (w[1] - w[0] - step).abs() <= 1e-9 * step.abs().max(1f64));
+    if !consistent {
+        return None;
+    }
+    // Results are rounded to the precision of the source numbers, so 0.1, 0.2 continues
+    // with 0.3 rather than 0.30000000000000004
+    let decimals = numbers
+        .iter()
+        .map(|n| n.to_string().split('.').nth(1).map_or(0, |d| d.len()))
+        .max()
+        .unwrap_or(0)
+        .min(15) as i32;
+    let scale = 10f64.powi(decimals);
+    let last = numbers[numbers.len() - 1];
+    let values = (1..=count)
+        .map(|i| {
+            let n = ((last + step * i as f64) * scale).round() / scale;
+            n.to_string()
+                .replace('.', &locale.decimal_separator.to_string())
+        })
+        .collect();
+    Some(values)
+}
+
+fn name_series(line: &[(models::Cell, Option<EvalResult>)], count: usize) -> Option<Vec<String>> {
+    let names = [WEEKDAYS, WEEKDAYS_SHORT, MONTHS, MONTHS_SHORT]
+        .iter()
+        .find(|names| {
+            line.iter()
+                .all(|(c, _)| names.iter().any(|n| n.eq_ignore_ascii_case(&c.value)))
+        })?;
+    let indices: Vec<i32> = line
+        .iter()
+        .map(|(c, _)| {
+            names
+                .iter()
+                .position(|n| n.eq_ignore_ascii_case(&c.value))
+                .unwrap() as i32
+        })
+        .collect();
+    let len = names.len() as i32;
+    let step = match indices.as_slice() {
+        [_] => 1,
+        _ => (indices[1] - indices[0]).rem_euclid(len),
+    };
+    if !indices
+        .windows(2)
+        .all(|w| (w[1] - w[0]).rem_euclid(len) == step)
+    {
+        return None;
+    }
+    // The names are written in the case of the first source cell, e.g. MON or mon
+    let first = &line[0].0.value;
+    let case = |name: &str| {
+        if *first == first.to_uppercase() {
+            name.to_uppercase()
+        } else if *first == first.to_lowercase() {
+            name.to_lowercase()
+        } else {
+            name.to_owned()
+        }
+    };
+    let last = indices[indices.len() - 1];
+    let values = (1..=count as i32)
+        .map(|i| case(names[(last + step * i).rem_euclid(len) as usize]))
+        .collect();
+    Some(values)
+}
@@ -1,13 +1,17 @@
 use super::super::models;
+use super::recalc;
+use super::recalc::Recalc;
 use rstar::{Envelope, Point, PointDistance, RTree, RTreeObject, AABB};
-use std::collections::{HashMap, HashSet};
-use std::iter::FromIterator;
+use std::collections::{BTreeSet, HashMap, HashSet};
 
 pub struct FormulaGraph {
     rt: RTree<RTreeNode>,
 
-    dependents_map: HashMap<models::CellRange, HashSet<models::CellLocation>>,
     dependencies_map: HashMap<models::CellLocation, HashSet<models::CellRange>>,
+
+    // cells whose formulas call volatile functions, kept ordered so recalculation draws
+    // random numbers in a reproducible order
+    volatile: BTreeSet<models::CellLocation>,
 }
 
 #[derive(Debug, Copy, Clone, PartialEq)]
@@ -16,11 +20,6 @@ struct RTreeNode {
     points_to: models::CellRange,
 }
 
-pub struct InsertResult {
-    pub inserted_cells: Vec<models::CellLocation>,
-    pub circular_cells: Vec<models::CellLocation>,
-}
-
 impl RTreeObject for RTreeNode {
     type Envelope = rstar::AABB<models::CellLocation>;
     fn envelope(&self) -> Self::Envelope {
@@ -128,164 +127,139 @@ impl FormulaGraph {
     pub fn new() -> FormulaGraph {
         FormulaGraph {
             rt: RTree::new_with_params(),
-            dependents_map: HashMap::new(),
             dependencies_map: HashMap::new(),
+            volatile: BTreeSet::new(),
         }
     }
 
-    // insert_cell inserts the provided cell into the formula graph
-    // and returns a stack of the affected cell ranges.
-    // These cell ranges should be recomputed by popping from this stack.
-    // Cells lower in the stack may depend on cells evaluated higher in it.
-    pub fn insert_cell(
+    // set_dependencies replaces the references the cell's formula makes without finding the
+    // cells to recompute, e.g. when a batch of cells is recomputed at once afterwards.
+    pub fn set_dependencies(
         &mut self,
-        cell: models::Cell,
+        cell_loc: models::CellLocation,
         dependencies: Vec<models::CellRange>,
-    ) -> InsertResult {
-        println!("insert cell {:?}", cell);
-
-        // Delete any existing dependencies this cell has marked
-        for (_, deps) in &self.dependencies_map {
-            for dep in deps {
-                let to_delete = RTreeNode {
-                    cell: cell.loc(),
-                    points_to: dep.clone(),
-                };
-                if self.rt.contains(&to_delete) {
-                    println!("deleting RTreeNode: {:?}", to_delete);
-                    self.rt.remove(&to_delete);
-                }
-                (*self.dependents_map.entry(*dep).or_insert(HashSet::new())).remove(&cell.loc());
+    ) {
+        // Only the references the cell made before are removed, so inserting a cell costs
+        // the same however many formulas the table has
+        self.remove_dependencies(cell_loc);
+        if dependencies.is_empty() {
+            return;
+        }
+        let deps = self.dependencies_map.entry(cell_loc).or_default();
+        for d in dependencies {
+            // A formula referencing the same range twice has a single node for it
+            if deps.insert(d) {
+                self.rt.insert(RTreeNode {
+                    cell: cell_loc,
+                    points_to: d,
+                });
             }
         }
+    }
 
-        // For each dependency:
-        // 1) Mark the dependency and insert an RTree node containing the dependency's boundaries.
-        // 2) Mark the inserted cell as a dependent of the dependency
-        for d in dependencies {
-            let to_insert = RTreeNode {
-                cell: cell.loc(),
-                points_to: d.clone(),
-            };
-            println!("inserting rtree node: {:?}", to_insert);
-            self.rt.insert(to_insert);
-            (*self
-                .dependencies_map
-                .entry(cell.loc())
-                .or_insert(HashSet::new()))
-            .insert(d);
+    // remove_cell removes the references the cell's formula made, e.g. once the cell is
+    // cleared. Cells referencing it are kept as its dependents.
+    pub fn remove_cell(&mut self, cell_loc: models::CellLocation) {
+        self.remove_dependencies(cell_loc);
+        self.volatile.remove(&cell_loc);
+    }
 
-            (*self.dependents_map.entry(d).or_insert(HashSet::new())).insert(cell.loc());
+    fn remove_dependencies(&mut self, cell_loc: models::CellLocation) {
+        for dep in self.dependencies_map.remove(&cell_loc).unwrap_or_default() {
+            self.rt.remove(&RTreeNode {
+                cell: cell_loc,
+                points_to: dep,
+            });
         }
+    }
 
-        // Find all the dependents and update dependents map for the inserted cell
-        let mut existing: Vec<models::CellLocation> = self
-            .rt
-            .locate_all_at_point(&cell.loc())
+    // dependents returns the cells whose formulas directly reference the provided cell,
+    // either by pointing at it or at a range containing it.
+    pub fn dependents(&self, cell_loc: models::CellLocation) -> Vec<models::CellLocation> {
+        self.rt
+            .locate_all_at_point(&cell_loc)
             .map(|e| e.cell)
-            .collect();
-        println!("initial dfs for {:?}", cell.loc());
-        println!("initial dependents are {:?}", existing);
-
-        let res = self.cells_to_eval(cell.loc(), &existing);
-
-        for e in &existing {
-            println!("found existing: {:?}", e);
-            if *e == cell.loc() {
-                continue;
-            }
-            (*self
-                .dependents_map
-                .entry(cell.to_range().clone())
-                .or_insert(HashSet::new()))
-            .insert(e.clone());
-        }
+            .collect()
+    }
 
-        res
+    // dependencies returns the ranges the cell's formula references
+    pub fn dependencies(&self, cell_loc: models::CellLocation) -> Vec<models::CellRange> {
+        let mut ret: Vec<models::CellRange> = self
+            .dependencies_map
+            .get(&cell_loc)
+            .map(|deps| deps.iter().cloned().collect())
+            .unwrap_or_default();
+        ret.sort_by_key(|r| (r.start_row, r.start_col, r.stop_row, r.stop_col));
+        ret
     }
 
-    // cells_to_eval returns the list of cells which must be evaluated in order to evaluate
-    // the provided cell's formula.
-    fn cells_to_eval(
-        &self,
-        cell_loc: models::CellLocation,
-        dependents: &Vec<models::CellLocation>,
-    ) -> InsertResult {
-        let mut visited_set = HashSet::new();
-        let mut stack = vec![];
-        let mut circular = vec![];
+    // dependencies_in returns the cells of the provided range whose formulas reference
+    // other cells
+    pub fn dependencies_in(&self, r: &models::CellRange) -> Vec<models::CellLocation> {
+        // Small ranges are looked up cell by cell, and large ones such as whole columns
+        // are matched against the formulas instead
+        let area = (r.stop_row - r.start_row + 1) as i64 * (r.stop_col - r.start_col + 1) as i64;
+        let found: BTreeSet<models::CellLocation> = match area <= self.dependencies_map.len() as i64
+        {
+            true => r
+                .to_rect()
+                .locations()
+                .into_iter()
+                .filter(|loc| self.dependencies_map.contains_key(loc))
+                .collect(),
+            false => self
+                .dependencies_map
+                .keys()
+                .filter(|loc| r.contains(loc))
+                .cloned()
+                .collect(),
+        };
+        found.into_iter().collect()
+    }
 
-        println!("initial stack is {:?}", stack);
-        self.dfs(
-            &mut dependents.clone(),
-            &mut stack,
-            &mut visited_set,
-            &mut vec![cell_loc],
-            &mut circular,
+    // dependents_in returns the cells whose formulas reference any cell of the provided
+    // rectangle, either directly or through a range overlapping it.
+    pub fn dependents_in(&self, r: &models::Rect) -> Vec<models::CellLocation> {
+        if r.stop_row <= r.start_row || r.stop_col <= r.start_col {
+            return vec![];
+        }
+        let envelope = AABB::from_corners(
+            models::CellLocation {
+                row: r.start_row,
+                col: r.start_col,
+            },
+            models::CellLocation {
+                row: r.stop_row - 1,
+                col: r.stop_col - 1,
+            },
         );
-        let mut ret = vec![];
+        let found: BTreeSet<models::CellLocation> = self
+            .rt
+            .locate_in_envelope_intersecting(&envelope)
+            .map(|e| e.cell)
+            .collect();
+        found.into_iter().collect()
+    }
 
-        println!("cell_to_eval stack is {:?}", stack);
-        for s in stack.iter() {
-            ret.push(models::CellLocation {
-                row: s.row,
-                col: s.col,
-            });
+    // set_volatile marks whether the cell's formula must be recomputed on every recalculation
+    pub fn set_volatile(&mut self, cell_loc: models::CellLocation, volatile: bool) {
+        if volatile {
+            self.volatile.insert(cell_loc);
+        } else {
+            self.volatile.remove(&cell_loc);
         }
+    }
 
-        println!(
-            "insert complete. Circular cells {:?} non-circular {:?}",
-            circular, stack
-        );
-        if !circular.contains(&cell_loc) {
-            ret.push(cell_loc);
-        }
-        InsertResult {
-            inserted_cells: ret.clone(),
-            circular_cells: circular.clone(),
-        }
+    pub fn volatile_cells(&self) -> Vec<models::CellLocation> {
+        self.volatile.iter().cloned().collect()
     }
 
-    fn dfs(
+    // recalc_order returns the provided cells and their dependents in the order they must
+    // be recomputed, with the cells of reference cycles grouped together.
+    pub fn recalc_order(
         &self,
-        deps: &mut Vec<models::CellLocation>,
-        stack: &mut Vec<models::CellLocation>,
-        visited_set: &mut HashSet<models::CellLocation>,
-        path: &mut Vec<models::CellLocation>,
-        circular: &mut Vec<models::CellLocation>,
-    ) {
-        println!("dfs dependents are {:?}", deps);
-        if deps.len() == 0 {
-            return;
-        }
-        for d in deps {
-            if circular.contains(d) {
-                continue;
-            }
-            if (&path).contains(d) {
-                println!("path: {:?}", path);
-                for c in path.iter() {
-                    println!("adding {:?} to circulars", c);
-                    circular.push(c.clone());
-                    visited_set.insert(c.clone());
-                }
-            } else {
-                path.push(d.clone());
-                println!("dfs for {:?}", d.to_range());
-                self.dfs(
-                    &mut match self.dependents_map.get(&d.to_range()) {
-                        Some(hs) => hs.clone().into_iter().collect(),
-                        None => vec![],
-                    },
-                    stack,
-                    visited_set,
-                    path,
-                    circular,
-                );
-            }
-            path.pop();
-            visited_set.insert(d.clone());
-            stack.push(d.clone());
-        }
+        cells: &[models::CellLocation],
+    ) -> Vec<Recalc<models::CellLocation>> {
+        recalc::order(cells, |c| self.dependents(*c))
     }
 }
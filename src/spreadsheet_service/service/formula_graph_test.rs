@@ -2,6 +2,21 @@
 mod tests {
     pub use super::super::super::models::*;
     pub use super::super::formula_graph::FormulaGraph;
+    pub use super::super::recalc::Recalc;
+    use std::time::Instant;
+
+    // insert records the cell's references and returns the cells to recompute in the order
+    // they're evaluated, leaving out reference cycles
+    fn insert(fg: &mut FormulaGraph, cell: &Cell, deps: Vec<CellRange>) -> Vec<CellLocation> {
+        fg.set_dependencies(cell.loc(), deps);
+        fg.recalc_order(&[cell.loc()])
+            .into_iter()
+            .filter_map(|r| match r {
+                Recalc::Cell(c) => Some(c),
+                Recalc::Cycle(_) => None,
+            })
+            .collect()
+    }
 
     #[test]
     fn test_insert_cell() {
@@ -14,41 +29,176 @@ mod tests {
             col: 0,
             value: "=B1".to_string(),
             display_value: "0".to_string(),
+            format: "".to_string(),
+            typed_value: Value::Empty,
+            style_id: 0,
+            effective_style_id: 0,
+            stale: false,
         };
         let b1 = Cell {
             row: 0,
             col: 1,
             value: "10".to_string(),
             display_value: "10".to_string(),
+            format: "".to_string(),
+            typed_value: Value::Empty,
+            style_id: 0,
+            effective_style_id: 0,
+            stale: false,
         };
-        let mut to_eval = fg.insert_cell(b1.clone(), vec![]);
-        assert_eq!(Vec::<CellLocation>::new(), to_eval);
-
-        to_eval = fg.insert_cell(a1.clone(), vec![b1.to_range()]);
-        assert_eq!(Vec::<CellLocation>::new(), to_eval);
+        // The inserted cell is evaluated first, before its dependents
+        let mut to_eval = insert(&mut fg, &b1, vec![]);
+        assert_eq!(vec![b1.loc()], to_eval);
 
-        to_eval = fg.insert_cell(b1.clone(), vec![]);
+        to_eval = insert(&mut fg, &a1, vec![b1.to_range()]);
         assert_eq!(vec![a1.loc()], to_eval);
 
+        to_eval = insert(&mut fg, &b1, vec![]);
+        assert_eq!(vec![b1.loc(), a1.loc()], to_eval);
+
         // Add a third dependency, A1 --> B1 --> C1
         let c1 = Cell {
             row: 0,
             col: 2,
             value: "20".to_string(),
             display_value: "20".to_string(),
+            format: "".to_string(),
+            typed_value: Value::Empty,
+            style_id: 0,
+            effective_style_id: 0,
+            stale: false,
         };
 
-        to_eval = fg.insert_cell(c1.clone(), vec![]);
-        assert_eq!(Vec::<CellLocation>::new(), to_eval);
+        to_eval = insert(&mut fg, &c1, vec![]);
+        assert_eq!(vec![c1.loc()], to_eval);
 
         // Add the dependency on cell C1. A1 should be recomputed
-        to_eval = fg.insert_cell(b1.clone(), vec![c1.to_range()]);
-        assert_eq!(vec![a1.loc()], to_eval);
+        to_eval = insert(&mut fg, &b1, vec![c1.to_range()]);
+        assert_eq!(vec![b1.loc(), a1.loc()], to_eval);
 
         // Modify C1. B1 and A1 should be recomputed in that order
-        to_eval = fg.insert_cell(c1.clone(), vec![]);
-        assert_eq!(2, to_eval.len());
-        assert_eq!(b1.loc(), to_eval.pop().unwrap());
-        assert_eq!(a1.loc(), to_eval.pop().unwrap());
+        to_eval = insert(&mut fg, &c1, vec![]);
+        assert_eq!(vec![c1.loc(), b1.loc(), a1.loc()], to_eval);
+
+        // B1 no longer references C1
+        to_eval = insert(&mut fg, &b1, vec![]);
+        assert_eq!(vec![b1.loc(), a1.loc()], to_eval);
+        to_eval = insert(&mut fg, &c1, vec![]);
+        assert_eq!(vec![c1.loc()], to_eval);
+    }
+
+    #[test]
+    fn test_recalc_order() {
+        let mut fg = FormulaGraph::new();
+        let cell = |row, col| Cell::new(row, col, "".to_string());
+        let range = |start_row, stop_row| CellRange {
+            start_row,
+            start_col: 0,
+            stop_row,
+            stop_col: 0,
+        };
+
+        // B1 and C1 both reference A1, and D1 references both of them along with a
+        // range containing A1, so each is recomputed once after what it references
+        insert(&mut fg, &cell(0, 1), vec![cell(0, 0).to_range()]);
+        insert(&mut fg, &cell(0, 2), vec![cell(0, 0).to_range()]);
+        insert(
+            &mut fg,
+            &cell(0, 3),
+            vec![cell(0, 1).to_range(), cell(0, 2).to_range(), range(0, 1)],
+        );
+        let to_eval = insert(&mut fg, &cell(0, 0), vec![]);
+        assert_eq!(4, to_eval.len());
+        assert_eq!(cell(0, 0).loc(), to_eval[0]);
+        assert_eq!(cell(0, 3).loc(), to_eval[3]);
+
+        // Dependents referencing a range of a dependent are recomputed as well, even when
+        // their formula was inserted after it
+        insert(&mut fg, &cell(1, 1), vec![range(2, 3)]);
+        let to_eval = insert(&mut fg, &cell(2, 0), vec![]);
+        assert_eq!(vec![cell(2, 0).loc(), cell(1, 1).loc()], to_eval);
+        insert(&mut fg, &cell(2, 0), vec![cell(0, 1).to_range()]);
+        let to_eval = insert(&mut fg, &cell(0, 1), vec![cell(0, 0).to_range()]);
+        assert!(to_eval.contains(&cell(1, 1).loc()));
+
+        // A1 --> B1 --> C1 --> A1 is a cycle, which is recomputed before the cells depending
+        // on it
+        fg.set_dependencies(cell(0, 2).loc(), vec![cell(0, 1).to_range()]);
+        fg.set_dependencies(cell(0, 0).loc(), vec![cell(0, 2).to_range()]);
+        let order = fg.recalc_order(&[cell(0, 1).loc()]);
+        let cycle = order.iter().position(|r| matches!(r, Recalc::Cycle(_)));
+        let d1 = order
+            .iter()
+            .position(|r| *r == Recalc::Cell(cell(0, 3).loc()));
+        assert!(cycle.unwrap() < d1.unwrap());
+        match &order[cycle.unwrap()] {
+            Recalc::Cycle(cycle) => {
+                let mut cycle = cycle.clone();
+                cycle.sort();
+                assert_eq!(
+                    vec![cell(0, 0).loc(), cell(0, 1).loc(), cell(0, 2).loc()],
+                    cycle
+                );
+            }
+            Recalc::Cell(_) => unreachable!(),
+        }
+
+        // Every cell of a cycle is found, including those reaching it through a cell whose
+        // search already completed: A6 <-> B6 with C6 referencing A6 and B6 referencing C6
+        fg.set_dependencies(
+            cell(5, 1).loc(),
+            vec![cell(5, 0).to_range(), cell(5, 2).to_range()],
+        );
+        fg.set_dependencies(cell(5, 0).loc(), vec![cell(5, 1).to_range()]);
+        fg.set_dependencies(cell(5, 2).loc(), vec![cell(5, 0).to_range()]);
+        for start in 0..3 {
+            match fg.recalc_order(&[cell(5, start).loc()]).as_slice() {
+                [Recalc::Cycle(cycle)] => assert_eq!(3, cycle.len()),
+                order => panic!("unexpected order {:?}", order),
+            }
+        }
+
+        // A cell referencing itself is a cycle of its own
+        fg.set_dependencies(cell(7, 0).loc(), vec![cell(7, 0).to_range()]);
+        assert_eq!(
+            vec![Recalc::Cycle(vec![cell(7, 0).loc()])],
+            fg.recalc_order(&[cell(7, 0).loc()])
+        );
+    }
+
+    // Run with cargo test --release -- --ignored bench_set_dependencies --nocapture
+    #[test]
+    #[ignore]
+    fn bench_set_dependencies() {
+        let mut fg = FormulaGraph::new();
+        let rows = 100_000;
+        let start = Instant::now();
+
+        // Each cell of column B references the cell to its left and the one above it, and
+        // each cell of column C the sum of the column B cells above it
+        for row in 0..rows {
+            let b = Cell::new(row, 1, "".to_string());
+            let mut deps = vec![Cell::new(row, 0, "".to_string()).to_range()];
+            if row > 0 {
+                deps.push(Cell::new(row - 1, 1, "".to_string()).to_range());
+            }
+            fg.set_dependencies(b.loc(), deps);
+            let sum = CellRange {
+                start_row: (row - 10).max(0),
+                start_col: 1,
+                stop_row: row,
+                stop_col: 1,
+            };
+            fg.set_dependencies(CellLocation { row, col: 2 }, vec![sum]);
+        }
+        let inserted = start.elapsed();
+        println!("inserted {} formulas in {:?}", rows * 2, inserted);
+
+        // Changing the top of the chain recomputes every formula once
+        let start = Instant::now();
+        let order = fg.recalc_order(&[CellLocation { row: 0, col: 0 }]);
+        assert_eq!(rows as usize * 2 + 1, order.len());
+        println!("recomputed the chain in {:?}", start.elapsed());
+        assert!(inserted.as_secs() < 30);
     }
 }
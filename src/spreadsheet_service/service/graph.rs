@@ -0,0 +1,88 @@
+use super::super::models;
+use super::recalc;
+use super::recalc::Recalc;
+use super::trace;
+use std::collections::HashSet;
+
+// export builds the dependency graph of a table from the cells holding its formulas. When
+// a cell is provided, the graph only holds the cells it reads and the cells reading it,
+// directly or not, along with the cell itself. precedents returns the ranges a cell's
+// formula references, formulas_in the cells holding formulas within a range and
+// dependents the cells directly reading a cell.
+pub fn export(
+    table: &str,
+    cells: Vec<models::TableCellLocation>,
+    from: Option<models::TableCellLocation>,
+    precedents: impl Fn(&models::TableCellLocation) -> Vec<(String, models::CellRange)>,
+    formulas_in: impl Fn(&str, &models::CellRange) -> Vec<models::TableCellLocation>,
+    dependents: impl Fn(&models::TableCellLocation) -> Vec<models::TableCellLocation>,
+) -> models::DependencyGraph {
+    let mut cells = match from {
+        Some(c) => {
+            let read = trace::trace(vec![c.clone()], 0, |c| {
+                precedents(c)
+                    .iter()
+                    .flat_map(|(table, r)| formulas_in(table, r))
+                    .collect()
+            });
+            let reading = trace::trace(vec![c.clone()], 0, |c| dependents(c));
+            let mut found: HashSet<models::TableCellLocation> =
+                read.into_iter().chain(reading).map(|(c, _)| c).collect();
+            // The provided cell is only a node of the graph when it holds a formula, the
+            // others being found through references
+            if precedents(&c).is_empty() {
+                found.remove(&c);
+            }
+            found.into_iter().collect()
+        }
+        None => cells,
+    };
+    sort(&mut cells);
+
+    let mut edges = vec![];
+    for c in &cells {
+        let mut refs = precedents(c);
+        refs.sort_by_key(|(table, r)| {
+            (
+                table.clone(),
+                (r.start_row, r.start_col, r.stop_row, r.stop_col),
+            )
+        });
+        edges.extend(refs.into_iter().map(|(table, range)| models::GraphEdge {
+            cell: c.clone(),
+            table,
+            range,
+        }));
+    }
+
+    // Cycles are found among the graph's cells only
+    let found: HashSet<models::TableCellLocation> = cells.iter().cloned().collect();
+    let mut cycles = vec![];
+    for r in recalc::order(&cells, |c| {
+        dependents(c)
+            .into_iter()
+            .filter(|d| found.contains(d))
+            .collect()
+    }) {
+        if let Recalc::Cycle(mut cycle) = r {
+            sort(&mut cycle);
+            cycles.push(cycle);
+        }
+    }
+    cycles.sort_by(|a, b| key(&a[0]).cmp(&key(&b[0])));
+
+    models::DependencyGraph {
+        table: table.to_owned(),
+        cells,
+        edges,
+        cycles,
+    }
+}
+
+fn sort(cells: &mut [models::TableCellLocation]) {
+    cells.sort_by(|a, b| key(a).cmp(&key(b)));
+}
+
+fn key(c: &models::TableCellLocation) -> (&str, models::CellLocation) {
+    (&c.table, c.loc)
+}
@@ -1,6 +1,20 @@
+mod conditional_format;
+mod fill;
 mod formula_graph;
 mod formula_graph_test;
+mod graph;
+mod random;
+mod recalc;
 mod service;
+mod service_test;
+mod style_table;
+mod table_graph;
+mod trace;
+mod validation;
+mod workbook;
+mod workbook_test;
 
 pub use service::CellsService;
 pub use service::MemoryCellsService;
+pub use workbook::Workbook;
+pub use workbook::WorkbookContext;
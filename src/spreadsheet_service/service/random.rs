@@ -0,0 +1,45 @@
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::time::{SystemTime, UNIX_EPOCH};
+
+const GAMMA: u64 = 0x9e37_79b9_7f4a_7c15;
+
+// Random is a seedable SplitMix64 generator used by RAND and RANDBETWEEN. The same seed
+// always produces the same sequence, which keeps tests and replays reproducible.
+pub struct Random {
+    state: AtomicU64,
+}
+
+impl Random {
+    pub fn new(seed: u64) -> Self {
+        Random {
+            state: AtomicU64::new(seed),
+        }
+    }
+
+    // from_time seeds the generator from the current time
+    pub fn from_time() -> Self {
+        let nanos = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .map_or(0, |d| d.as_nanos() as u64);
+        Random::new(nanos)
+    }
+
+    pub fn seed(&self, seed: u64) {
+        self.state.store(seed, Ordering::SeqCst);
+    }
+
+    pub fn next_u64(&self) -> u64 {
+        let mut z = self
+            .state
+            .fetch_add(GAMMA, Ordering::SeqCst)
+            .wrapping_add(GAMMA);
+        z = (z ^ (z >> 30)).wrapping_mul(0xbf58_476d_1ce4_e5b9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94d0_49bb_1331_11eb);
+        z ^ (z >> 31)
+    }
+
+    // next_f64 returns a number uniformly distributed in [0, 1)
+    pub fn next_f64(&self) -> f64 {
+        (self.next_u64() >> 11) as f64 / (1u64 << 53) as f64
+    }
+}
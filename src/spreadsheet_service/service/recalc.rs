@@ -0,0 +1,518 @@
+use super::super::models;
+use super::super::parser;
+use super::super::parser::{ASTNode, EvalResult, Evaluation};
+use std::collections::{HashMap, HashSet, VecDeque};
+use std::hash::Hash;
+use std::thread;
+
+// Levels with fewer cells than this are evaluated on the calling thread, as they're not
+// worth the cost of starting threads for
+const PARALLEL_THRESHOLD: usize = 256;
+
+// the most times a recalculation evaluates formulas again once INDIRECT or OFFSET found
+// they read cells before they were recomputed
+const MAX_PASSES: usize = 8;
+
+// default_threads returns the number of threads recalculations use unless configured
+pub fn default_threads() -> usize {
+    thread::available_parallelism().map_or(1, |n| n.get())
+}
+
+// Recalc is a step of a recalculation: a single cell, or the cells of a reference cycle
+// which are computed together
+#[derive(Debug, Clone, PartialEq)]
+pub enum Recalc<L> {
+    Cell(L),
+    Cycle(Vec<L>),
+}
+
+// Formulas is what a recalculation reads and writes: the formulas of cells located by L,
+// the values they last evaluated to and the cells reading them. A table implements it for
+// its own cells and a workbook for the cells of all its tables, so both are recomputed by
+// evaluate.
+pub trait Formulas<L> {
+    // formula returns the parsed formula of the cell, if it holds one
+    fn formula(&self, c: &L) -> Option<&ASTNode>;
+
+    // evaluate computes the cell's formula against the current values
+    fn evaluate(&self, c: &L, formula: &ASTNode) -> Evaluation;
+
+    // value returns the value the cell last evaluated to
+    fn value(&self, c: &L) -> Option<EvalResult>;
+
+    // set_value replaces the value the cell's formula evaluated to
+    fn set_value(&mut self, c: &L, value: EvalResult);
+
+    // store_evaluation stores the value the cell's formula evaluated to along with the
+    // references resolved while evaluating it, returning the cell
+    fn store_evaluation(&mut self, c: &L, evaluation: Evaluation) -> Option<models::Cell>;
+
+    // set_error makes the cell's formula evaluate to the error code, returning the cell
+    fn set_error(&mut self, c: &L, code: &str) -> Option<models::Cell>;
+
+    // dependents returns the cells directly reading the cell
+    fn dependents(&self, c: &L) -> Vec<L>;
+
+    // cycle_settings returns the settings the reference cycle is computed by iteration
+    // with, or None when it's reported as #CIRCULAR!
+    fn cycle_settings(&self, cycle: &[L]) -> Option<models::IterativeCalculation>;
+
+    // threads returns the number of threads formulas are evaluated with
+    fn threads(&self) -> usize;
+}
+
+// order returns the provided cells and everything depending on them in the order they
+// must be evaluated. Cells which reference each other, directly or through other cells,
+// are grouped into cycles, found as the strongly connected components of the dependency
+// graph with Tarjan's algorithm. Cycles list their cells in the order they were found.
+pub fn order<L: Clone + Eq + Hash>(
+    cells: &[L],
+    dependents: impl Fn(&L) -> Vec<L>,
+) -> Vec<Recalc<L>> {
+    // links holds the index each visited cell was found at along with the lowest index of
+    // the cells on the stack it reaches
+    let mut links: HashMap<L, (usize, usize)> = HashMap::new();
+    let mut on_stack = HashSet::new();
+    let mut stack = vec![];
+    let mut self_referencing = HashSet::new();
+    let mut ret = vec![];
+
+    // The search is iterative so long chains of references don't overflow the call stack.
+    // path holds the cells being visited along with their dependents left to visit. The
+    // cells are visited in reverse so the first provided cell is the first evaluated when
+    // the cells don't depend on each other.
+    for root in cells.iter().rev() {
+        if links.contains_key(root) {
+            continue;
+        }
+        let mut path = vec![];
+        visit(
+            root,
+            &dependents,
+            &mut links,
+            &mut on_stack,
+            &mut stack,
+            &mut path,
+        );
+        while let Some((c, c_dependents)) = path.last_mut() {
+            let c = c.clone();
+            match c_dependents.pop() {
+                Some(d) if !links.contains_key(&d) => visit(
+                    &d,
+                    &dependents,
+                    &mut links,
+                    &mut on_stack,
+                    &mut stack,
+                    &mut path,
+                ),
+                Some(d) => {
+                    if d == c {
+                        self_referencing.insert(c.clone());
+                    }
+                    if on_stack.contains(&d) {
+                        let d_index = links[&d].0;
+                        let link = links.get_mut(&c).unwrap();
+                        link.1 = link.1.min(d_index);
+                    }
+                }
+                None => {
+                    path.pop();
+                    let (c_index, c_low) = links[&c];
+                    if let Some((parent, _)) = path.last() {
+                        let link = links.get_mut(parent).unwrap();
+                        link.1 = link.1.min(c_low);
+                    }
+                    if c_low != c_index {
+                        continue;
+                    }
+                    // c is the first cell found of a component, which holds the cells
+                    // above it on the stack
+                    let pos = stack.iter().rposition(|s| *s == c).unwrap();
+                    let component: Vec<L> = stack.drain(pos..).collect();
+                    for s in &component {
+                        on_stack.remove(s);
+                    }
+                    match component.len() == 1 && !self_referencing.contains(&c) {
+                        true => ret.push(Recalc::Cell(c)),
+                        false => ret.push(Recalc::Cycle(component)),
+                    }
+                }
+            }
+        }
+    }
+
+    // Components are found after every component depending on them
+    ret.reverse();
+    ret
+}
+
+fn visit<L: Clone + Eq + Hash>(
+    c: &L,
+    dependents: &impl Fn(&L) -> Vec<L>,
+    links: &mut HashMap<L, (usize, usize)>,
+    on_stack: &mut HashSet<L>,
+    stack: &mut Vec<L>,
+    path: &mut Vec<(L, Vec<L>)>,
+) {
+    let index = links.len();
+    links.insert(c.clone(), (index, index));
+    on_stack.insert(c.clone());
+    stack.push(c.clone());
+    path.push((c.clone(), dependents(c)));
+}
+
+// defer splits a recalculation order into the steps evaluated now and the cells left for
+// later: those which must be deferred and, as their values aren't known yet, the cells
+// reading them. Cycles are deferred as a whole. dependents returns the cells directly
+// reading a cell.
+pub fn defer<L: Clone + Eq + Hash>(
+    order: Vec<Recalc<L>>,
+    must_defer: impl Fn(&L) -> bool,
+    dependents: impl Fn(&L) -> Vec<L>,
+) -> (Vec<Recalc<L>>, Vec<L>) {
+    let mut deferred = vec![];
+    // the cells reading a deferred cell, which are reached after it in the order
+    let mut tainted = HashSet::new();
+    let mut ret = vec![];
+    for r in order {
+        let cells = match &r {
+            Recalc::Cell(c) => std::slice::from_ref(c),
+            Recalc::Cycle(cycle) => cycle.as_slice(),
+        };
+        if !cells.iter().any(|c| tainted.contains(c) || must_defer(c)) {
+            ret.push(r);
+            continue;
+        }
+        for c in cells {
+            tainted.extend(dependents(c));
+            deferred.push(c.clone());
+        }
+    }
+    (ret, deferred)
+}
+
+// cycle_path returns the shortest path through the cells of a reference cycle leading from
+// the provided cell back to it, each cell of the path referencing the next one, e.g.
+// [A1, C3, A1] when A1 references C3 which references A1.
+pub fn cycle_path<L: Clone + Eq + Hash>(
+    cell: &L,
+    cycle: &[L],
+    dependents: impl Fn(&L) -> Vec<L>,
+) -> Vec<L> {
+    let members: HashSet<&L> = cycle.iter().collect();
+    // parents holds the cell each reached cell references on the way back to the cell
+    let mut parents: HashMap<L, L> = HashMap::new();
+    let mut queue = VecDeque::from([cell.clone()]);
+    while let Some(c) = queue.pop_front() {
+        for d in dependents(&c) {
+            if d == *cell {
+                let mut path = vec![cell.clone()];
+                let mut p = c;
+                loop {
+                    path.push(p.clone());
+                    if p == *cell {
+                        return path;
+                    }
+                    p = parents[&p].clone();
+                }
+            }
+            if members.contains(&d) && !parents.contains_key(&d) {
+                parents.insert(d.clone(), c.clone());
+                queue.push_back(d);
+            }
+        }
+    }
+    vec![cell.clone()]
+}
+
+// iterate evaluates the cells of a reference cycle one after the other, each reading the
+// values the others computed last, until no value changes by more than the maximum change
+// or the maximum number of iterations is reached. evaluate computes and stores a cell's
+// value, returning by how much it changed. Whether the values converged is returned.
+pub fn iterate<L>(
+    cycle: &[L],
+    settings: &models::IterativeCalculation,
+    mut evaluate: impl FnMut(&L) -> f64,
+) -> bool {
+    for _ in 0..settings.max_iterations {
+        let mut change = 0f64;
+        for c in cycle {
+            change = change.max(evaluate(c));
+        }
+        if change <= settings.max_change {
+            return true;
+        }
+    }
+    false
+}
+
+// change returns by how much a cell's value changed, which is infinite for values other
+// than numbers which changed
+pub fn change(old: Option<&EvalResult>, new: &EvalResult) -> f64 {
+    match (old, new) {
+        (Some(EvalResult::Numeric(old)), EvalResult::Numeric(new)) => (new - old).abs(),
+        (Some(old), new) if old == new => 0f64,
+        _ => f64::INFINITY,
+    }
+}
+
+// levels groups cells, provided in the order they must be evaluated, so each cell is in a
+// later level than every provided cell it depends on. The cells of a level don't depend
+// on each other and can be evaluated at the same time. Cells keep their order within a
+// level.
+pub fn levels<L: Clone + Eq + Hash>(order: &[L], dependents: impl Fn(&L) -> Vec<L>) -> Vec<Vec<L>> {
+    let mut level: HashMap<L, usize> = order.iter().map(|c| (c.clone(), 0)).collect();
+    let mut ret: Vec<Vec<L>> = vec![];
+    for c in order {
+        let l = level[c];
+        for d in dependents(c) {
+            if let Some(dl) = level.get_mut(&d) {
+                *dl = (*dl).max(l + 1);
+            }
+        }
+        if ret.len() <= l {
+            ret.resize(l + 1, vec![]);
+        }
+        ret[l].push(c.clone());
+    }
+    ret
+}
+
+// evaluate_level evaluates the formulas of a level of cells across up to the provided
+// number of threads, returning the evaluations in the order of the formulas. Volatile
+// formulas are evaluated first, one after the other, so the random numbers they draw
+// don't depend on the number of threads.
+pub fn evaluate_level<L: Sync>(
+    formulas: &[(L, &ASTNode)],
+    threads: usize,
+    evaluate: impl Fn(&L, &ASTNode) -> Evaluation + Sync,
+) -> Vec<Evaluation> {
+    let mut ret: Vec<Option<Evaluation>> = formulas
+        .iter()
+        .map(|(c, f)| match parser::is_volatile(f) {
+            true => Some(evaluate(c, f)),
+            false => None,
+        })
+        .collect();
+    let rest: Vec<usize> = (0..formulas.len()).filter(|i| ret[*i].is_none()).collect();
+    let evaluations = map_parallel(&rest, threads, |i| {
+        let (c, f) = &formulas[*i];
+        evaluate(c, f)
+    });
+    for (i, evaluation) in rest.into_iter().zip(evaluations) {
+        ret[i] = Some(evaluation);
+    }
+    ret.into_iter().map(Option::unwrap).collect()
+}
+
+// map_parallel applies f to each item, splitting the items across up to the provided
+// number of threads, and returns the results in the order of the items.
+fn map_parallel<T: Sync, R: Send>(
+    items: &[T],
+    threads: usize,
+    f: impl Fn(&T) -> R + Sync,
+) -> Vec<R> {
+    if threads <= 1 || items.len() < PARALLEL_THRESHOLD {
+        return items.iter().map(f).collect();
+    }
+    let chunk_size = items.len().div_ceil(threads);
+    let f = &f;
+    thread::scope(|s| {
+        let handles: Vec<_> = items
+            .chunks(chunk_size)
+            .map(|chunk| s.spawn(move || chunk.iter().map(f).collect::<Vec<R>>()))
+            .collect();
+        handles
+            .into_iter()
+            .flat_map(|h| h.join().unwrap())
+            .collect()
+    })
+}
+
+// evaluate evaluates the steps of a recalculation in order, returning the evaluated cells
+// along with their locations. The cells INDIRECT and OFFSET read are only known once
+// their formulas are evaluated, so a formula may have read a cell before it was
+// recomputed in the same recalculation. Such formulas and their dependents are evaluated
+// again afterwards.
+pub fn evaluate<L, F>(formulas: &mut F, order: Vec<Recalc<L>>) -> Vec<(L, models::Cell)>
+where
+    L: Clone + Eq + Hash + Sync,
+    F: Formulas<L> + Sync,
+{
+    let mut ret: Vec<(L, models::Cell)> = vec![];
+    // the index of each evaluated cell in ret, so cells evaluated again are only returned
+    // once
+    let mut found: HashMap<L, usize> = HashMap::new();
+    let mut order = order;
+    // Formulas whose references keep moving as they're evaluated again, e.g. INDIRECT
+    // cycles, stop after a few passes
+    for _ in 0..MAX_PASSES {
+        let (evaluated, cycles) = evaluate_pass(formulas, order);
+        let rounds: HashMap<L, usize> = evaluated
+            .iter()
+            .map(|(round, c, _)| (c.clone(), *round))
+            .collect();
+        let mut reread = vec![];
+        let mut seen = HashSet::new();
+        for (round, c, _) in &evaluated {
+            for d in formulas.dependents(c) {
+                let read_early = match rounds.get(&d) {
+                    Some(r) => r < round || r == round && !cycles.contains(round),
+                    None => false,
+                };
+                if read_early && seen.insert(d.clone()) {
+                    reread.push(d);
+                }
+            }
+        }
+        for (_, c, cell) in evaluated {
+            match found.get(&c) {
+                Some(i) => ret[*i].1 = cell,
+                None => {
+                    found.insert(c.clone(), ret.len());
+                    ret.push((c, cell));
+                }
+            }
+        }
+        if reread.is_empty() {
+            break;
+        }
+        order = self::order(&reread, |c| {
+            formulas
+                .dependents(c)
+                .into_iter()
+                .filter(|d| rounds.contains_key(d))
+                .collect()
+        });
+    }
+    ret
+}
+
+// evaluate_pass evaluates the steps of a recalculation in order, returning the evaluated
+// cells along with the round they were evaluated in and the rounds which were cycles.
+// The cells of a round are evaluated together: those of a level read the values of
+// earlier rounds only, while those of a cycle read one another.
+#[allow(clippy::type_complexity)]
+fn evaluate_pass<L, F>(
+    formulas: &mut F,
+    order: Vec<Recalc<L>>,
+) -> (Vec<(usize, L, models::Cell)>, HashSet<usize>)
+where
+    L: Clone + Eq + Hash + Sync,
+    F: Formulas<L> + Sync,
+{
+    let mut rounds: Vec<Vec<(L, models::Cell)>> = vec![];
+    let mut cycles = HashSet::new();
+    // The cells between reference cycles are evaluated together
+    let mut acyclic = vec![];
+    for r in order {
+        match r {
+            Recalc::Cell(c) => acyclic.push(c),
+            Recalc::Cycle(cycle) => {
+                rounds.extend(evaluate_levels(formulas, std::mem::take(&mut acyclic)));
+                cycles.insert(rounds.len());
+                rounds.push(evaluate_cycle(formulas, &cycle));
+            }
+        }
+    }
+    rounds.extend(evaluate_levels(formulas, acyclic));
+    let ret = rounds
+        .into_iter()
+        .enumerate()
+        .flat_map(|(round, cells)| cells.into_iter().map(move |(c, cell)| (round, c, cell)))
+        .collect();
+    (ret, cycles)
+}
+
+// evaluate_levels evaluates the provided cells, which don't form reference cycles, in
+// order, returning the evaluated cells of each level. The cells are evaluated a level at
+// a time, each level across the recalculation threads.
+fn evaluate_levels<L, F>(formulas: &mut F, cells: Vec<L>) -> Vec<Vec<(L, models::Cell)>>
+where
+    L: Clone + Eq + Hash + Sync,
+    F: Formulas<L> + Sync,
+{
+    let mut ret = vec![];
+    for level in levels(&cells, |c| formulas.dependents(c)) {
+        // The cells of a level only read the values of earlier levels, so they're all
+        // evaluated before any of their values is stored
+        let evaluations = {
+            let f: &F = formulas;
+            let level: Vec<(L, &ASTNode)> = level
+                .into_iter()
+                .filter_map(|c| {
+                    let formula = f.formula(&c)?;
+                    Some((c, formula))
+                })
+                .collect();
+            let evaluations =
+                evaluate_level(&level, f.threads(), |c, formula| f.evaluate(c, formula));
+            level
+                .into_iter()
+                .map(|(c, _)| c)
+                .zip(evaluations)
+                .collect::<Vec<_>>()
+        };
+        let mut cells = vec![];
+        for (c, evaluation) in evaluations {
+            if let Some(cell) = formulas.store_evaluation(&c, evaluation) {
+                cells.push((c, cell));
+            }
+        }
+        ret.push(cells);
+    }
+    ret
+}
+
+// evaluate_cycle computes the cells of a reference cycle. They're evaluated repeatedly
+// until they converge when the cycle is computed by iteration, and are reported as
+// #CIRCULAR! otherwise, or as #CONVERGENCE! when they don't converge.
+fn evaluate_cycle<L: Clone + Eq + Hash, F: Formulas<L>>(
+    formulas: &mut F,
+    cycle: &[L],
+) -> Vec<(L, models::Cell)> {
+    let settings = match formulas.cycle_settings(cycle) {
+        Some(settings) => settings,
+        None => return set_errors(formulas, cycle, "#CIRCULAR!"),
+    };
+    // Iteration starts from the cells' last values, or from 0 for those holding errors
+    // such as the cycle being reported as #CIRCULAR!
+    for c in cycle {
+        if let Some(EvalResult::Error(_)) = formulas.value(c) {
+            formulas.set_value(c, EvalResult::Numeric(0f64));
+        }
+    }
+    // the cells as last evaluated
+    let mut cells = HashMap::new();
+    let converged = iterate(cycle, &settings, |c| {
+        let formula = match formulas.formula(c) {
+            Some(formula) => formula.clone(),
+            None => return 0f64,
+        };
+        let evaluation = formulas.evaluate(c, &formula);
+        let change = change(formulas.value(c).as_ref(), &evaluation.value);
+        if let Some(cell) = formulas.store_evaluation(c, evaluation) {
+            cells.insert(c.clone(), cell);
+        }
+        change
+    });
+    match converged {
+        true => cycle
+            .iter()
+            .filter_map(|c| Some((c.clone(), cells.remove(c)?)))
+            .collect(),
+        false => set_errors(formulas, cycle, "#CONVERGENCE!"),
+    }
+}
+
+// set_errors makes the formulas of the provided cells evaluate to the error code
+fn set_errors<L: Clone, F: Formulas<L>>(
+    formulas: &mut F,
+    cells: &[L],
+    code: &str,
+) -> Vec<(L, models::Cell)> {
+    cells
+        .iter()
+        .filter_map(|c| Some((c.clone(), formulas.set_error(c, code)?)))
+        .collect()
+}
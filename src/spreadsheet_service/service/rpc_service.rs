@@ -37,7 +37,7 @@ impl SpreadsheetService {
         &mut self,
         req: api::InsertCellsRequest,
     ) -> Result<api::InsertCellsResponse, ()> {
-        let insert_res: Result<Vec<models::Cell>, parser::Error>;
+        let insert_res: Result<(Vec<models::Cell>, Vec<models::ValidationError>), parser::Error>;
         {
             let cells = insert_cells_to_models(req.get_cells());
             self.create_table_if_not_exists(req.get_tableId());
@@ -49,7 +49,7 @@ impl SpreadsheetService {
         }
         let mut resp = api::InsertCellsResponse::default();
         match insert_res {
-            Ok(inserted_cells) => {
+            Ok((inserted_cells, _)) => {
                 resp.set_cells(protobuf::RepeatedField::from_vec(model_cells_to_api(
                     inserted_cells,
                 )));
@@ -1,26 +1,174 @@
 use super::super::models;
 use super::super::models::context::EvalContext;
 use super::super::parser;
+use super::super::parser::{ASTNode, EvalResult, Locale};
+use super::conditional_format;
+use super::fill;
 use super::formula_graph::FormulaGraph;
+use super::graph;
+use super::random::Random;
+use super::recalc;
+use super::recalc::Recalc;
+use super::style_table::StyleTable;
+use super::trace;
+use super::validation;
+use std::cmp;
+use std::collections::{BTreeMap, BTreeSet};
+use std::sync::Arc;
 
 pub trait CellsService {
-    // insert_cells inserts the provided list of cells into the store.
+    // insert_cells inserts the provided list of cells into the store. Cells whose value is
+    // rejected by a validation rule are left as they were. The stored and recomputed cells
+    // are returned along with the validation errors of the provided cells, see
+    // validate_cells.
+    #[allow(clippy::type_complexity)]
     fn insert_cells(
         &mut self,
         cells: &Vec<models::Cell>,
-    ) -> Result<Vec<models::Cell>, parser::Error>;
+    ) -> Result<(Vec<models::Cell>, Vec<models::ValidationError>), parser::Error>;
+
+    // validate_cells checks the provided cells against the validation rules, returning an
+    // error for each cell whose value doesn't meet them.
+    fn validate_cells(&self, cells: &[models::Cell]) -> Vec<models::ValidationError>;
 
-    // get_cells returns a Vector of cells in the provided rectangle
+    // get_cells returns a Vector of cells in the provided rectangle which have a value or
+    // a style.
     fn get_cells(&self, r: models::Rect) -> Vec<models::Cell>;
+
+    // recalculate recomputes the volatile cells and their dependents, along with the
+    // stale cells of a manually calculated table, returning the recomputed cells.
+    fn recalculate(&mut self) -> Vec<models::Cell>;
+
+    // recalculate_range recomputes the stale cells of the provided rectangle along with
+    // the stale cells they read, leaving the others stale. The recomputed cells are
+    // returned.
+    fn recalculate_range(&mut self, r: models::Rect) -> Vec<models::Cell>;
+
+    // clear_cells removes the values of the cells in the provided rectangle, keeping their
+    // formats and styles. The cleared cells are returned along with their recomputed
+    // dependents, which see them as blank.
+    fn clear_cells(&mut self, r: models::Rect) -> Vec<models::Cell>;
+
+    // set_styles sets the named fields of the style of every cell in the provided
+    // rectangle, or the whole style when no fields are named. The styled cells are
+    // returned.
+    fn set_styles(
+        &mut self,
+        r: models::Rect,
+        style: &models::Style,
+        fields: &[String],
+    ) -> Result<Vec<models::Cell>, parser::Error>;
+
+    // style returns the style with the provided id
+    fn style(&self, id: u32) -> models::Style;
+
+    // insert_rows inserts count empty rows before the row at the provided index, moving the
+    // cells below them down. References to the moved cells follow them, and ranges spanning
+    // the inserted rows grow. The cells of the moved rows are returned, including empty
+    // ones, along with the recomputed cells.
+    fn insert_rows(&mut self, at: i32, count: i32) -> Result<Vec<models::Cell>, parser::Error>;
+
+    // delete_rows deletes count rows starting at the provided index, moving the cells below
+    // them up. References to the deleted cells become #REF!, and ranges spanning the deleted
+    // rows shrink. The cells returned are those of insert_rows.
+    fn delete_rows(&mut self, at: i32, count: i32) -> Result<Vec<models::Cell>, parser::Error>;
+
+    // insert_cols inserts columns like insert_rows inserts rows
+    fn insert_cols(&mut self, at: i32, count: i32) -> Result<Vec<models::Cell>, parser::Error>;
+
+    // delete_cols deletes columns like delete_rows deletes rows
+    fn delete_cols(&mut self, at: i32, count: i32) -> Result<Vec<models::Cell>, parser::Error>;
+
+    // move_range moves the cells of the source rectangle, along with their formats and
+    // styles, so its top left cell lands on the provided row and column, replacing the
+    // cells there. Formulas referencing the moved cells follow them, while references to
+    // the replaced cells become #REF!. The cells of the source and destination are
+    // returned, including empty ones, along with the recomputed cells.
+    fn move_range(
+        &mut self,
+        source: models::Rect,
+        row: i32,
+        col: i32,
+    ) -> Result<Vec<models::Cell>, parser::Error>;
+
+    // copy_range pastes the cells of the source rectangle so its top left cell lands on the
+    // provided row and column. Relative references of the pasted formulas are translated
+    // by the distance they're copied, e.g. =A1 copied from B1 to B2 is =A2, while those
+    // anchored with '$' are kept. The pasted cells are returned, including empty ones,
+    // along with the recomputed cells.
+    fn copy_range(
+        &mut self,
+        source: models::Rect,
+        row: i32,
+        col: i32,
+        mode: models::CopyMode,
+    ) -> Result<Vec<models::Cell>, parser::Error>;
+
+    // fill extends the cells of the source rectangle over the target rectangle, which
+    // starts with the source and continues down or to the right. Each row or column of
+    // the source continues its series, e.g. 1, 2 with 3, 4 or Mon with Tue, and is
+    // repeated otherwise with its formulas translated like copy_range does. The filled
+    // cells are returned along with the recomputed cells.
+    fn fill(
+        &mut self,
+        source: models::Rect,
+        target: models::Rect,
+    ) -> Result<Vec<models::Cell>, parser::Error>;
+
+    // trace_precedents returns the ranges the formula of the cell at the provided row and
+    // column references, followed by the ranges the formulas within them reference in turn,
+    // up to max_depth references away from the cell, 0 meaning no limit. Each range is
+    // returned once, nearest first.
+    fn trace_precedents(&self, row: i32, col: i32, max_depth: u32) -> Vec<models::TracedRange>;
+
+    // trace_dependents returns the cells whose formulas reference the cell at the provided
+    // row and column, either directly or through a range containing it, followed by the
+    // cells referencing them in turn like trace_precedents.
+    fn trace_dependents(&self, row: i32, col: i32, max_depth: u32) -> Vec<models::TracedRange>;
+
+    // export_graph returns the dependency graph of the formulas, or the part of it reachable
+    // from the provided cell: the cells it reads and those reading it, directly or not.
+    fn export_graph(&self, from: Option<models::CellLocation>) -> models::DependencyGraph;
 }
 
+// AGGREGATE_ROWS is the number of rows of a column whose values are aggregated together,
+// see MemoryCellsService::aggregate
+const AGGREGATE_ROWS: i32 = 64;
+
 pub struct MemoryCellsService {
     num_rows: i32,
     num_cols: i32,
     // data stored in row-major order
     data: Vec<models::Cell>,
+    // parsed formulas and computed values of the cells in data, in the same order
+    compiled: Vec<Option<CompiledCell>>,
 
     formula_graph: FormulaGraph,
+    // generator used by RAND and RANDBETWEEN, shared by the tables of a workbook
+    random: Arc<Random>,
+    locale: Locale,
+    styles: StyleTable,
+    conditional_formats: Vec<models::ConditionalFormat>,
+    // cells whose effective style differs from their own style
+    highlighted: BTreeSet<models::CellLocation>,
+    validation_rules: Vec<models::ValidationRule>,
+    // the number of threads formulas are recomputed with
+    recalc_threads: usize,
+    // settings for computing reference cycles by iteration, which are reported as
+    // #CIRCULAR! without them
+    iterative_calculation: Option<models::IterativeCalculation>,
+    calculation_mode: models::CalculationMode,
+    // formulas left to recompute while the table is calculated manually
+    stale: BTreeSet<models::CellLocation>,
+    // aggregates of the values of each block of AGGREGATE_ROWS rows of a column, indexed
+    // by block and then by column, which are kept up to date as values are stored
+    aggregates: Vec<models::Aggregate>,
+}
+
+// CompiledCell caches a cell's parsed formula along with the value it last evaluated to
+struct CompiledCell {
+    ast: ASTNode,
+    value: EvalResult,
 }
 
 impl EvalContext for MemoryCellsService {
@@ -28,6 +176,10 @@ impl EvalContext for MemoryCellsService {
         self.get_cell(row, col)
     }
 
+    fn get_value(&self, row: i32, col: i32) -> Option<EvalResult> {
+        self.compiled(row, col).map(|c| c.value.clone())
+    }
+
     fn get_cells(&self, rect: models::Rect) -> Vec<models::Cell> {
         <_ as CellsService>::get_cells(self, rect)
     }
@@ -39,57 +191,30 @@ impl EvalContext for MemoryCellsService {
     fn num_cols(&self) -> i32 {
         self.num_cols
     }
+
+    fn aggregate(&self, range: models::CellRange) -> Option<models::Aggregate> {
+        Some(self.aggregate(&range))
+    }
+
+    fn random(&self) -> f64 {
+        self.random.next_f64()
+    }
 }
 
 impl CellsService for MemoryCellsService {
     fn insert_cells(
         &mut self,
         cells: &Vec<models::Cell>,
-    ) -> Result<Vec<models::Cell>, parser::Error> {
-        let mut ret_cells = vec![];
-
-        for c in cells {
-            self.set_cell(&c);
-        }
+    ) -> Result<(Vec<models::Cell>, Vec<models::ValidationError>), parser::Error> {
+        let cells: Vec<models::Cell> = cells.iter().map(|c| self.input_cell(c)).collect();
+        let errors = self.validation_errors(&cells, self);
+        let cells = validation::accepted_cells(&cells, &errors);
+        Ok((self.store_cells(&cells), errors))
+    }
 
-        // Recalculate after inserting values for all cells
-        for c in cells {
-            let cc = self.get_cell(c.row, c.col).unwrap_or_else(|| models::Cell {
-                row: c.row,
-                col: c.col,
-                value: "".to_owned(),
-                display_value: "".to_owned(),
-            });
-
-            // Update the formula graph and recompute necessary cells
-            let formula = parser::parse(&cc.value);
-            let mut refs = parser::get_refs(&formula);
-            refs.iter_mut().for_each(|r| (*r).clamp(self.num_rows));
-            let mut insert_res = self.formula_graph.insert_cell(cc.clone(), refs);
-
-            while let Some(c) = insert_res.inserted_cells.pop() {
-                // We don't need to check refs again here since the formula graph already computed
-                // all the required re-evals.
-                let mut eval_cell = self.get_cell(c.row, c.col).unwrap().clone();
-                let formula = parser::parse(&eval_cell.value);
-                let display_value = parser::evaluate(formula, self);
-                eval_cell.display_value = display_value;
-                self.set_cell(&eval_cell);
-                ret_cells.push(eval_cell);
-            }
-
-            while let Some(c) = insert_res.circular_cells.pop() {
-                println!("circular cell {:?} \n", c);
-                let display_value = "#CIRCULAR!".to_owned();
-                let mut eval_cell = self.get_cell(c.row, c.col).unwrap().clone();
-                if eval_cell.is_formula() {
-                    eval_cell.display_value = display_value;
-                }
-                self.set_cell(&eval_cell);
-                ret_cells.push(eval_cell);
-            }
-        }
-        Ok(ret_cells)
+    fn validate_cells(&self, cells: &[models::Cell]) -> Vec<models::ValidationError> {
+        let cells: Vec<models::Cell> = cells.iter().map(|c| self.input_cell(c)).collect();
+        self.validation_errors(&cells, self)
     }
 
     fn get_cells(&self, r: models::Rect) -> Vec<models::Cell> {
@@ -100,34 +225,1403 @@ impl CellsService for MemoryCellsService {
                 let c = self.get_cell(row, col);
                 if !c.is_none() {
                     result_cells.push(c.unwrap().clone());
+                    continue;
+                }
+                // Empty cells are returned when they're styled, e.g. with a fill color
+                let c = &self.data[row_major_idx(row, col, self.num_cols) as usize];
+                if c.style_id != 0 || c.effective_style_id != 0 {
+                    result_cells.push(c.clone());
                 }
             }
         }
-        result_cells
+        self.localize_cells(result_cells)
+    }
+
+    fn recalculate(&mut self) -> Vec<models::Cell> {
+        let mut cells = self.formula_graph.volatile_cells();
+        cells.extend(self.stale.iter().cloned());
+        let order = self.formula_graph.recalc_order(&cells);
+        let ret_cells = self.evaluate_order(order);
+        let ret_cells = self.with_conditional_formats(ret_cells);
+        self.localize_cells(ret_cells)
+    }
+
+    fn recalculate_range(&mut self, r: models::Rect) -> Vec<models::Cell> {
+        let first = self
+            .stale
+            .iter()
+            .filter(|c| r.contains(c.row, c.col))
+            .cloned()
+            .collect();
+        let cells: BTreeSet<models::CellLocation> = trace::trace(first, 0, |c| {
+            self.dependencies(*c)
+                .iter()
+                .flat_map(|d| self.stale_in(d))
+                .collect()
+        })
+        .into_iter()
+        .map(|(c, _)| c)
+        .collect();
+        // Only the cells found are recomputed, in the order of their references
+        let order = recalc::order(&cells.iter().cloned().collect::<Vec<_>>(), |c| {
+            self.dependents(*c)
+                .into_iter()
+                .filter(|d| cells.contains(d))
+                .collect()
+        });
+        let ret_cells = self.evaluate_order(order);
+        let ret_cells = self.with_conditional_formats(ret_cells);
+        self.localize_cells(ret_cells)
+    }
+
+    fn clear_cells(&mut self, r: models::Rect) -> Vec<models::Cell> {
+        let cleared = self.remove_cells(r);
+        let mut ret_cells: Vec<models::Cell> = cleared
+            .iter()
+            .map(|loc| self.stored_cell(loc.row, loc.col))
+            .collect();
+
+        // Every recalculation also recomputes the volatile cells
+        let found: BTreeSet<models::CellLocation> = cleared.iter().cloned().collect();
+        let mut cells = cleared;
+        cells.extend(
+            self.formula_graph
+                .volatile_cells()
+                .into_iter()
+                .filter(|v| !found.contains(v)),
+        );
+        ret_cells.extend(self.recalculate_cells(cells));
+        let ret_cells = self.with_conditional_formats(ret_cells);
+        self.localize_cells(ret_cells)
+    }
+
+    fn set_styles(
+        &mut self,
+        r: models::Rect,
+        style: &models::Style,
+        fields: &[String],
+    ) -> Result<Vec<models::Cell>, parser::Error> {
+        // Check the update before styling any cell
+        models::Style::default()
+            .merge(style, fields)
+            .map_err(|e| parser::Error::new(&e))?;
+        let mut ret_cells = vec![];
+        let clamped = r.clamp(self.num_rows, self.num_cols);
+        for row in clamped.start_row..clamped.stop_row {
+            for col in clamped.start_col..clamped.stop_col {
+                let idx = row_major_idx(row, col, self.num_cols) as usize;
+                let merged = self
+                    .styles
+                    .get(self.data[idx].style_id)
+                    .merge(style, fields)
+                    .map_err(|e| parser::Error::new(&e))?;
+                let cell = &mut self.data[idx];
+                cell.row = row;
+                cell.col = col;
+                cell.style_id = self.styles.id(merged);
+                cell.effective_style_id = cell.style_id;
+                ret_cells.push(cell.clone());
+            }
+        }
+        let ret_cells = self.with_conditional_formats(ret_cells);
+        Ok(self.localize_cells(ret_cells))
+    }
+
+    fn style(&self, id: u32) -> models::Style {
+        self.styles.get(id).clone()
+    }
+
+    fn insert_rows(&mut self, at: i32, count: i32) -> Result<Vec<models::Cell>, parser::Error> {
+        self.shift(models::Axis::Rows, at, count)
+    }
+
+    fn delete_rows(&mut self, at: i32, count: i32) -> Result<Vec<models::Cell>, parser::Error> {
+        self.shift(models::Axis::Rows, at, -count)
+    }
+
+    fn insert_cols(&mut self, at: i32, count: i32) -> Result<Vec<models::Cell>, parser::Error> {
+        self.shift(models::Axis::Cols, at, count)
+    }
+
+    fn delete_cols(&mut self, at: i32, count: i32) -> Result<Vec<models::Cell>, parser::Error> {
+        self.shift(models::Axis::Cols, at, -count)
+    }
+
+    fn move_range(
+        &mut self,
+        source: models::Rect,
+        row: i32,
+        col: i32,
+    ) -> Result<Vec<models::Cell>, parser::Error> {
+        let mut cells = self.move_cells(&source, row, col)?;
+        let destination = source.offset(row - source.start_row, col - source.start_col);
+        let mut moved = source.locations();
+        moved.extend(destination.locations());
+        cells.extend(moved.iter().cloned());
+        let recomputed = self.recalculate_cells(cells);
+        Ok(self.moved_cells(&moved, recomputed))
+    }
+
+    fn copy_range(
+        &mut self,
+        source: models::Rect,
+        row: i32,
+        col: i32,
+        mode: models::CopyMode,
+    ) -> Result<Vec<models::Cell>, parser::Error> {
+        let cells = self.copied_cells(&source, row, col, mode)?;
+        let recomputed = self.store_cells(&cells);
+        let pasted: Vec<models::CellLocation> = cells.iter().map(|c| c.loc()).collect();
+        Ok(self.moved_cells(&pasted, recomputed))
+    }
+
+    fn fill(
+        &mut self,
+        source: models::Rect,
+        target: models::Rect,
+    ) -> Result<Vec<models::Cell>, parser::Error> {
+        let cells = self.filled_cells(&source, &target)?;
+        let recomputed = self.store_cells(&cells);
+        let filled: Vec<models::CellLocation> = cells.iter().map(|c| c.loc()).collect();
+        Ok(self.moved_cells(&filled, recomputed))
+    }
+
+    fn trace_precedents(&self, row: i32, col: i32, max_depth: u32) -> Vec<models::TracedRange> {
+        let first = self.dependencies(models::CellLocation { row, col });
+        trace::trace(first, max_depth, |r| {
+            self.dependencies_in(r)
+                .into_iter()
+                .flat_map(|c| self.dependencies(c))
+                .collect()
+        })
+        .into_iter()
+        .map(|(r, depth)| models::TracedRange {
+            table: String::new(),
+            rect: r.to_rect(),
+            depth,
+        })
+        .collect()
+    }
+
+    fn trace_dependents(&self, row: i32, col: i32, max_depth: u32) -> Vec<models::TracedRange> {
+        let first = self.dependents(models::CellLocation { row, col });
+        trace::trace(first, max_depth, |c| self.dependents(*c))
+            .into_iter()
+            .map(|(c, depth)| models::TracedRange {
+                table: String::new(),
+                rect: c.to_range().to_rect(),
+                depth,
+            })
+            .collect()
+    }
+
+    fn export_graph(&self, from: Option<models::CellLocation>) -> models::DependencyGraph {
+        let local = |loc| models::TableCellLocation {
+            table: String::new(),
+            loc,
+        };
+        graph::export(
+            "",
+            self.formula_cells()
+                .iter()
+                .map(|c| local(c.loc()))
+                .collect(),
+            from.map(local),
+            |c| {
+                self.dependencies(c.loc)
+                    .into_iter()
+                    .map(|r| (String::new(), r))
+                    .collect()
+            },
+            |_, r| self.dependencies_in(r).into_iter().map(local).collect(),
+            |c| self.dependents(c.loc).into_iter().map(local).collect(),
+        )
     }
 }
 
 impl MemoryCellsService {
     pub fn new(num_rows: i32, num_cols: i32) -> Self {
+        MemoryCellsService::with_random(num_rows, num_cols, Arc::new(Random::from_time()))
+    }
+
+    // with_random returns a table drawing random numbers from the provided generator
+    pub(super) fn with_random(num_rows: i32, num_cols: i32, random: Arc<Random>) -> Self {
         MemoryCellsService {
             num_cols,
             num_rows,
             data: vec![models::Cell::empty(); (num_cols * num_rows) as usize],
+            compiled: (0..num_cols * num_rows).map(|_| None).collect(),
             formula_graph: FormulaGraph::new(),
+            random,
+            locale: Locale::default(),
+            styles: StyleTable::new(),
+            conditional_formats: vec![],
+            highlighted: BTreeSet::new(),
+            validation_rules: vec![],
+            recalc_threads: recalc::default_threads(),
+            iterative_calculation: None,
+            calculation_mode: models::CalculationMode::Automatic,
+            stale: BTreeSet::new(),
+            aggregates: vec![
+                models::Aggregate::default();
+                (num_blocks(num_rows) * num_cols) as usize
+            ],
         }
     }
     pub fn get_cell(&self, row: i32, col: i32) -> Option<models::Cell> {
-        let c = self.data[row_major_idx(row, col, self.num_cols) as usize].clone();
+        if row < 0 || col < 0 || row >= self.num_rows || col >= self.num_cols {
+            return None;
+        }
+        let mut c = self.data[row_major_idx(row, col, self.num_cols) as usize].clone();
+        c.stale = self.stale.contains(&models::CellLocation { row, col });
         match c.value.as_ref() {
             "" => None,
             _ => Some(c),
         }
     }
+    // stored_cell returns the cell as it's stored, including empty cells which only hold a
+    // format or a style.
+    pub fn stored_cell(&self, row: i32, col: i32) -> models::Cell {
+        let mut cell = self.data[row_major_idx(row, col, self.num_cols) as usize].clone();
+        cell.row = row;
+        cell.col = col;
+        cell.stale = self.stale.contains(&cell.loc());
+        cell
+    }
+
+    // remove_cells removes the values of the cells in the provided rectangle along with
+    // the references their formulas made, returning the locations of the cleared cells.
+    // Their dependents aren't recomputed.
+    pub fn remove_cells(&mut self, r: models::Rect) -> Vec<models::CellLocation> {
+        let mut cleared = vec![];
+        let clamped = r.clamp(self.num_rows, self.num_cols);
+        for row in clamped.start_row..clamped.stop_row {
+            for col in clamped.start_col..clamped.stop_col {
+                let idx = row_major_idx(row, col, self.num_cols) as usize;
+                if self.data[idx].value.is_empty() {
+                    continue;
+                }
+                let cell = &mut self.data[idx];
+                cell.row = row;
+                cell.col = col;
+                cell.value = "".to_owned();
+                cell.display_value = "".to_owned();
+                cell.typed_value = models::Value::Empty;
+                self.compiled[idx] = None;
+                self.formula_graph.remove_cell(cell.loc());
+                self.stale.remove(&cell.loc());
+                cleared.push(cell.loc());
+            }
+        }
+        self.refresh_aggregates(&cleared);
+        cleared
+    }
+
     pub fn set_cell(&mut self, cell: &models::Cell) {
-        self.data[row_major_idx(cell.row, cell.col, self.num_cols) as usize] = cell.clone();
+        let idx = row_major_idx(cell.row, cell.col, self.num_cols) as usize;
+        // The formula is only parsed again when the cell's value changes
+        if self.data[idx].value != cell.value {
+            self.compiled[idx] = self.compile(cell);
+        }
+        self.data[idx] = cell.clone();
+        self.refresh_aggregates(&[cell.loc()]);
+        // Stored cells are up to date until marked stale again
+        self.stale.remove(&cell.loc());
+    }
+
+    pub fn locale(&self) -> &Locale {
+        &self.locale
+    }
+
+    // set_locale changes the conventions the table is written and displayed with. Formulas
+    // are stored canonically and keep their meaning, while values are read again using the
+    // new separators. The recomputed cells are returned.
+    pub fn set_locale(&mut self, locale: Locale) -> Vec<models::Cell> {
+        let cells = self.compile_locale(locale);
+        let ret_cells = self.recalculate_cells(cells);
+        let ret_cells = self.with_conditional_formats(ret_cells);
+        self.localize_cells(ret_cells)
+    }
+
+    // compile_locale changes the table's locale and reads its values and formulas again
+    // without recomputing them, returning the locations of the cells read, see set_locale.
+    pub fn compile_locale(&mut self, locale: Locale) -> Vec<models::CellLocation> {
+        self.locale = locale;
+        let mut cells = vec![];
+        for idx in 0..self.data.len() {
+            if self.data[idx].value.is_empty() {
+                continue;
+            }
+            self.compiled[idx] = self.compile(&self.data[idx]);
+            cells.push(self.data[idx].loc());
+        }
+        self.refresh_aggregates(&cells);
+        cells
+    }
+
+    // set_iterative_calculation sets whether the table's reference cycles are computed by
+    // evaluating their cells repeatedly until they converge, see IterativeCalculation. The
+    // formulas are recomputed with the new settings and returned.
+    pub fn set_iterative_calculation(
+        &mut self,
+        settings: Option<models::IterativeCalculation>,
+    ) -> Result<Vec<models::Cell>, parser::Error> {
+        if let Some(s) = &settings {
+            if s.max_iterations == 0 || s.max_change.is_nan() || s.max_change < 0f64 {
+                return Err(parser::Error::new(&format!(
+                    "invalid iterative calculation settings {:?}",
+                    s
+                )));
+            }
+        }
+        self.iterative_calculation = settings;
+        let formulas = self.formula_cells().iter().map(|c| c.loc()).collect();
+        let ret_cells = self.recalculate_cells(formulas);
+        let ret_cells = self.with_conditional_formats(ret_cells);
+        Ok(self.localize_cells(ret_cells))
+    }
+
+    pub fn iterative_calculation(&self) -> Option<models::IterativeCalculation> {
+        self.iterative_calculation
+    }
+
+    // set_calculation_mode sets when the table's formulas are recomputed. Switching to
+    // automatic calculation recomputes the stale cells, which are returned.
+    pub fn set_calculation_mode(&mut self, mode: models::CalculationMode) -> Vec<models::Cell> {
+        self.calculation_mode = mode;
+        if mode != models::CalculationMode::Automatic {
+            return vec![];
+        }
+        let stale: Vec<models::CellLocation> = self.stale.iter().cloned().collect();
+        let ret_cells = self.recalculate_cells(stale);
+        let ret_cells = self.with_conditional_formats(ret_cells);
+        self.localize_cells(ret_cells)
+    }
+
+    pub fn calculation_mode(&self) -> models::CalculationMode {
+        self.calculation_mode
+    }
+
+    // read_cells returns the cells of the provided rectangle like get_cells does. When the
+    // table is calculated on demand, the stale cells of the rectangle are recomputed first
+    // along with the stale cells they read.
+    pub fn read_cells(&mut self, r: models::Rect) -> Vec<models::Cell> {
+        if self.calculation_mode == models::CalculationMode::OnDemand {
+            self.recalculate_range(r.clone());
+        }
+        <_ as CellsService>::get_cells(self, r)
+    }
+
+    // mark_stale marks the provided cells' formulas as left to recompute, returning the
+    // cells
+    pub fn mark_stale(&mut self, cells: &[models::CellLocation]) -> Vec<models::Cell> {
+        for c in cells {
+            if self.is_formula(*c) {
+                self.stale.insert(*c);
+            }
+        }
+        cells
+            .iter()
+            .filter_map(|c| self.get_cell(c.row, c.col))
+            .collect()
+    }
+
+    // stale_cells returns the formulas left to recompute, in row-major order
+    pub fn stale_cells(&self) -> Vec<models::CellLocation> {
+        self.stale.iter().cloned().collect()
+    }
+
+    // has_stale returns whether any formula is left to recompute
+    pub fn has_stale(&self) -> bool {
+        !self.stale.is_empty()
+    }
+
+    // stale_in returns the formulas of the provided range left to recompute
+    pub fn stale_in(&self, r: &models::CellRange) -> Vec<models::CellLocation> {
+        let start = models::CellLocation {
+            row: r.start_row,
+            col: r.start_col,
+        };
+        let stop = models::CellLocation {
+            row: r.stop_row,
+            col: r.stop_col,
+        };
+        if stop < start {
+            return vec![];
+        }
+        self.stale
+            .range(start..=stop)
+            .filter(|c| r.contains(c))
+            .cloned()
+            .collect()
+    }
+
+    pub fn is_formula(&self, cell_loc: models::CellLocation) -> bool {
+        self.get_ast(cell_loc.row, cell_loc.col).is_some()
+            && self.data[row_major_idx(cell_loc.row, cell_loc.col, self.num_cols) as usize]
+                .is_formula()
+    }
+
+    // localize_cells returns the cells with their formulas written for the table's locale
+    pub fn localize_cells(&self, mut cells: Vec<models::Cell>) -> Vec<models::Cell> {
+        if !self.locale.is_canonical() {
+            for c in cells.iter_mut().filter(|c| c.is_formula()) {
+                c.value = parser::localize_formula(&c.value, &self.locale);
+            }
+        }
+        cells
+    }
+
+    // input_cell returns the cell to store for a value entered by the user. Formulas are
+    // translated from the table's locale into canonical form. The cell keeps its style, and
+    // its number format unless the entry implies one, e.g. 15% or $1,200.50.
+    pub fn input_cell(&self, cell: &models::Cell) -> models::Cell {
+        let mut ret = cell.clone();
+        let existing = &self.data[row_major_idx(cell.row, cell.col, self.num_cols) as usize];
+        ret.style_id = existing.style_id;
+        ret.effective_style_id = existing.effective_style_id;
+        let implied = match cell.is_formula() {
+            true => {
+                ret.value = parser::canonical_formula(&cell.value, &self.locale);
+                None
+            }
+            false => parser::recognize_localized(&cell.value, &self.locale).map(|r| r.format),
+        };
+        match implied {
+            Some(format) if !format.is_empty() => ret.format = format,
+            _ if ret.format.is_empty() => {
+                let idx = row_major_idx(cell.row, cell.col, self.num_cols) as usize;
+                ret.format = self.data[idx].format.clone();
+            }
+            _ => (),
+        }
+        ret
+    }
+
+    // store_cells stores the provided cells as they are, e.g. once input_cell translated
+    // them, and recomputes the cells depending on them. The stored cells are returned
+    // along with the recomputed cells.
+    pub fn store_cells(&mut self, cells: &[models::Cell]) -> Vec<models::Cell> {
+        let mut to_recalc = vec![];
+        for cell in cells {
+            self.set_cell(cell);
+        }
+
+        // Update the formula graph for all cells before recomputing, so the cells depending
+        // on several of them are computed once, after all of them
+        for c in cells {
+            let (mut refs, volatile) = match self.get_ast(c.row, c.col) {
+                Some(formula) => (parser::get_refs(formula), parser::is_volatile(formula)),
+                None => (vec![], false),
+            };
+            refs.iter_mut().for_each(|r| (*r).clamp(self.num_rows));
+            self.formula_graph.set_volatile(c.loc(), volatile);
+            self.formula_graph.set_dependencies(c.loc(), refs);
+            to_recalc.push(c.loc());
+        }
+
+        // Every recalculation also recomputes the volatile cells
+        to_recalc.extend(self.formula_graph.volatile_cells());
+        let ret_cells = self.recalculate_cells(to_recalc);
+        let ret_cells = self.with_conditional_formats(ret_cells);
+        self.localize_cells(ret_cells)
+    }
+
+    // set_format sets the number format of the cells in the provided rectangle, including
+    // empty ones, and returns the cells whose display value was formatted again.
+    pub fn set_format(
+        &mut self,
+        r: models::Rect,
+        format: &str,
+    ) -> Result<Vec<models::Cell>, parser::Error> {
+        parser::validate_format(format).map_err(|e| parser::Error::new(&e))?;
+        let mut ret_cells = vec![];
+        let clamped = r.clamp(self.num_rows, self.num_cols);
+        for row in clamped.start_row..clamped.stop_row {
+            for col in clamped.start_col..clamped.stop_col {
+                let idx = row_major_idx(row, col, self.num_cols) as usize;
+                let mut cell = self.data[idx].clone();
+                cell.row = row;
+                cell.col = col;
+                cell.format = format.to_owned();
+                if let Some(c) = self.compiled(row, col) {
+                    cell.display_value = parser::format_value(&c.value, format, &self.locale);
+                    cell.typed_value = parser::typed_value(&c.value, format);
+                    ret_cells.push(cell.clone());
+                }
+                self.data[idx] = cell;
+            }
+        }
+        Ok(self.localize_cells(ret_cells))
+    }
+
+    // set_conditional_formats replaces the conditional formats of the table, returning the
+    // cells whose effective style changed. Earlier formats take priority over later ones
+    // when several set the same style field of a cell.
+    pub fn set_conditional_formats(
+        &mut self,
+        formats: Vec<models::ConditionalFormat>,
+    ) -> Result<Vec<models::Cell>, parser::Error> {
+        for cf in &formats {
+            conditional_format::validate(cf).map_err(|e| parser::Error::new(&e))?;
+        }
+        self.conditional_formats = formats;
+        let ret_cells = self.apply_conditional_formats();
+        Ok(self.localize_cells(ret_cells))
+    }
+
+    pub fn conditional_formats(&self) -> &[models::ConditionalFormat] {
+        &self.conditional_formats
+    }
+
+    // apply_conditional_formats evaluates the conditional formats against the current
+    // values, returning the cells whose effective style changed.
+    pub fn apply_conditional_formats(&mut self) -> Vec<models::Cell> {
+        let mut styles: BTreeMap<models::CellLocation, models::Style> = BTreeMap::new();
+        // Later formats are applied first so earlier ones override them
+        for cf in self.conditional_formats.iter().rev() {
+            let r = cf.range.clamp(self.num_rows, self.num_cols);
+            let mut cells = vec![];
+            for row in r.start_row..r.stop_row {
+                for col in r.start_col..r.stop_col {
+                    let value = self.compiled(row, col).map(|c| c.value.clone());
+                    cells.push((models::CellLocation { row, col }, value));
+                }
+            }
+            for (loc, style) in conditional_format::evaluate_rule(cf, &cells, self, &self.locale) {
+                let idx = row_major_idx(loc.row, loc.col, self.num_cols) as usize;
+                let base = styles
+                    .remove(&loc)
+                    .unwrap_or_else(|| self.styles.get(self.data[idx].style_id).clone());
+                styles.insert(loc, base.overlay(&style));
+            }
+        }
+
+        let mut ret_cells = vec![];
+        let highlighted: BTreeSet<models::CellLocation> = styles.keys().cloned().collect();
+        // Cells which are no longer highlighted go back to their own style
+        for loc in self.highlighted.difference(&highlighted) {
+            let cell = &mut self.data[row_major_idx(loc.row, loc.col, self.num_cols) as usize];
+            if cell.effective_style_id != cell.style_id {
+                cell.effective_style_id = cell.style_id;
+                ret_cells.push(cell.clone());
+            }
+        }
+        for (loc, style) in styles {
+            let id = self.styles.id(style);
+            let cell = &mut self.data[row_major_idx(loc.row, loc.col, self.num_cols) as usize];
+            if cell.effective_style_id != id {
+                cell.row = loc.row;
+                cell.col = loc.col;
+                cell.effective_style_id = id;
+                ret_cells.push(cell.clone());
+            }
+        }
+        self.highlighted = highlighted;
+        ret_cells
+    }
+
+    // with_conditional_formats applies the conditional formats once the provided cells
+    // were recomputed, returning them with their effective styles along with the other
+    // cells whose effective style changed.
+    pub fn with_conditional_formats(&mut self, mut cells: Vec<models::Cell>) -> Vec<models::Cell> {
+        let changed = self.apply_conditional_formats();
+        for c in cells.iter_mut() {
+            c.effective_style_id =
+                self.data[row_major_idx(c.row, c.col, self.num_cols) as usize].effective_style_id;
+        }
+        for c in changed {
+            if !cells.iter().any(|existing| existing.loc() == c.loc()) {
+                cells.push(c);
+            }
+        }
+        cells
+    }
+
+    // set_validation_rules replaces the validation rules of the table. The values already
+    // stored are kept, rules only apply to the values entered afterwards.
+    pub fn set_validation_rules(
+        &mut self,
+        rules: Vec<models::ValidationRule>,
+    ) -> Result<(), parser::Error> {
+        for rule in &rules {
+            validation::validate(rule).map_err(|e| parser::Error::new(&e))?;
+        }
+        self.validation_rules = rules;
+        Ok(())
+    }
+
+    pub fn validation_rules(&self) -> &[models::ValidationRule] {
+        &self.validation_rules
+    }
+
+    // list_values returns the values which can be entered in the cell when a list rule
+    // applies to it, e.g. to show them in a dropdown.
+    pub fn list_values(&self, row: i32, col: i32) -> Option<Vec<String>> {
+        self.validation_rules
+            .iter()
+            .filter(|r| r.range.contains(row, col))
+            .find_map(|r| validation::list_values(r, self))
+    }
+
+    // validation_errors checks the provided cells, as returned by input_cell, against the
+    // validation rules like validate_cells does, evaluating formulas against the provided
+    // context, e.g. to read the other tables of a workbook.
+    pub fn validation_errors(
+        &self,
+        cells: &[models::Cell],
+        ctx: &dyn EvalContext,
+    ) -> Vec<models::ValidationError> {
+        let mut errors = vec![];
+        for c in cells {
+            // Cells without rules aren't evaluated
+            if !self
+                .validation_rules
+                .iter()
+                .any(|r| r.range.contains(c.row, c.col))
+            {
+                continue;
+            }
+            let value = match self.compile(c) {
+                Some(compiled) if c.is_formula() => {
+                    parser::evaluate_cell(compiled.ast, c.loc(), ctx).value
+                }
+                Some(compiled) => compiled.value,
+                // cells can always be cleared
+                None => continue,
+            };
+            let mut failures = self
+                .validation_rules
+                .iter()
+                .filter(|r| r.range.contains(c.row, c.col))
+                .filter_map(|r| {
+                    validation::check(r, c, &value, ctx, &self.locale).map(|m| (r.mode, m))
+                });
+            // A rejection takes priority over warnings
+            let first = failures.next();
+            let error = match first {
+                Some((models::ValidationMode::Warn, _)) => failures
+                    .find(|(mode, _)| *mode == models::ValidationMode::Reject)
+                    .or(first),
+                _ => first,
+            };
+            if let Some((mode, message)) = error {
+                errors.push(models::ValidationError {
+                    row: c.row,
+                    col: c.col,
+                    message,
+                    rejected: mode == models::ValidationMode::Reject,
+                });
+            }
+        }
+        errors
+    }
+
+    // new_shift checks the rows or columns to insert or delete fit in the table
+    pub fn new_shift(
+        &self,
+        axis: models::Axis,
+        at: i32,
+        count: i32,
+    ) -> Result<models::Shift, parser::Error> {
+        let size = match axis {
+            models::Axis::Rows => self.num_rows,
+            models::Axis::Cols => self.num_cols,
+        };
+        if count == 0 || at < 0 || at >= size || at - count.min(0) > size {
+            return Err(parser::Error::new(&format!(
+                "invalid rows or columns: {} at {}",
+                count, at
+            )));
+        }
+        // Inserting moves the last rows or columns off the table, which must be empty. Only
+        // those are read, and none when deleting.
+        let is_empty = |row, col| {
+            self.data[row_major_idx(row, col, self.num_cols) as usize]
+                .value
+                .is_empty()
+        };
+        let pushed_out = (cmp::max(at, size - count)..size).any(|i| match axis {
+            models::Axis::Rows => (0..self.num_cols).any(|col| !is_empty(i, col)),
+            models::Axis::Cols => (0..self.num_rows).any(|row| !is_empty(row, i)),
+        });
+        if pushed_out {
+            return Err(parser::Error::new(
+                "not enough room to insert: cells would be moved off the table",
+            ));
+        }
+        Ok(models::Shift { axis, at, count })
+    }
+
+    // shift_cells moves the cells following the inserted or deleted rows or columns and
+    // adjusts the references of the table's formulas to them, returning the locations of
+    // every cell which moved or was emptied along with those of the formulas which moved
+    // or reference the moved cells. Only these formulas are parsed and recorded in the
+    // formula graph again, and values aren't recomputed.
+    pub fn shift_cells(
+        &mut self,
+        shift: &models::Shift,
+    ) -> (Vec<models::CellLocation>, Vec<models::CellLocation>) {
+        let moved = shift.moved_rect(self.num_rows, self.num_cols);
+        let shifted = moved.locations();
+        let mut formulas: BTreeSet<models::CellLocation> = self
+            .formula_graph
+            .dependents_in(&moved)
+            .into_iter()
+            .collect();
+        formulas.extend(shifted.iter().filter(|loc| self.is_formula(**loc)));
+        for loc in &formulas {
+            self.formula_graph.remove_cell(*loc);
+        }
+
+        // The moved cells are taken out before being stored at their new locations, as
+        // they may replace one another
+        let mut cells = vec![];
+        for loc in &shifted {
+            let idx = row_major_idx(loc.row, loc.col, self.num_cols) as usize;
+            let cell = std::mem::replace(&mut self.data[idx], models::Cell::empty());
+            let stale = self.stale.remove(loc);
+            cells.push((*loc, cell, self.compiled[idx].take(), stale));
+        }
+        for (loc, mut cell, compiled, stale) in cells {
+            let loc = match shift.location(loc) {
+                Some(loc) if loc.row < self.num_rows && loc.col < self.num_cols => loc,
+                _ => continue,
+            };
+            cell.row = loc.row;
+            cell.col = loc.col;
+            let idx = row_major_idx(loc.row, loc.col, self.num_cols) as usize;
+            self.data[idx] = cell;
+            self.compiled[idx] = compiled;
+            if stale {
+                self.stale.insert(loc);
+            }
+        }
+        self.refresh_aggregates(&shifted);
+
+        let formulas: Vec<models::CellLocation> = formulas
+            .into_iter()
+            .filter_map(|loc| shift.location(loc))
+            .filter(|loc| loc.row < self.num_rows && loc.col < self.num_cols)
+            .collect();
+        self.rewrite_formulas_at(&formulas, |f| parser::shift_refs(f, "", "", shift));
+        for loc in &formulas {
+            let (refs, volatile) = match self.get_ast(loc.row, loc.col) {
+                Some(formula) => (parser::get_refs(formula), parser::is_volatile(formula)),
+                None => continue,
+            };
+            let cell = self.data[row_major_idx(loc.row, loc.col, self.num_cols) as usize].clone();
+            self.update_dependencies(&cell, refs);
+            self.set_volatile(*loc, volatile);
+        }
+
+        self.conditional_formats = std::mem::take(&mut self.conditional_formats)
+            .into_iter()
+            .filter_map(|mut cf| {
+                cf.range = shift.rect(&cf.range)?;
+                Some(cf)
+            })
+            .collect();
+        self.validation_rules = std::mem::take(&mut self.validation_rules)
+            .into_iter()
+            .filter_map(|mut rule| {
+                rule.range = shift.rect(&rule.range)?;
+                Some(rule)
+            })
+            .collect();
+        self.highlighted = self
+            .highlighted
+            .iter()
+            .filter_map(|loc| shift.location(*loc))
+            .collect();
+
+        (shifted, formulas)
+    }
+
+    // rewrite_formulas_at replaces the formula of the cells at the provided locations with
+    // the one the provided function returns for it, returning the locations of the cells
+    // whose formula changed. The formula graph isn't updated.
+    pub fn rewrite_formulas_at(
+        &mut self,
+        locs: &[models::CellLocation],
+        mut f: impl FnMut(&str) -> String,
+    ) -> Vec<models::CellLocation> {
+        let mut changed = vec![];
+        for loc in locs {
+            let idx = row_major_idx(loc.row, loc.col, self.num_cols) as usize;
+            if !self.data[idx].is_formula() {
+                continue;
+            }
+            let formula = f(&self.data[idx].value);
+            if formula != self.data[idx].value {
+                self.data[idx].value = formula;
+                self.compiled[idx] = self.compile(&self.data[idx]);
+                changed.push(*loc);
+            }
+        }
+        self.refresh_aggregates(&changed);
+        changed
+    }
+
+    // formula_cells returns the cells holding a formula
+    pub fn formula_cells(&self) -> Vec<models::Cell> {
+        self.data
+            .iter()
+            .filter(|c| c.is_formula())
+            .cloned()
+            .collect()
+    }
+
+    // shift inserts or deletes rows or columns, see CellsService::insert_rows
+    fn shift(
+        &mut self,
+        axis: models::Axis,
+        at: i32,
+        count: i32,
+    ) -> Result<Vec<models::Cell>, parser::Error> {
+        let shift = self.new_shift(axis, at, count)?;
+        let (shifted, mut formulas) = self.shift_cells(&shift);
+        // Every recalculation also recomputes the volatile cells
+        formulas.extend(self.formula_graph.volatile_cells());
+        let recomputed = self.recalculate_cells(formulas);
+        Ok(self.moved_cells(&shifted, recomputed))
+    }
+
+    // moved_cells returns the moved cells as they are once recomputed, including empty
+    // ones, followed by the other recomputed cells.
+    pub fn moved_cells(
+        &mut self,
+        moved: &[models::CellLocation],
+        recomputed: Vec<models::Cell>,
+    ) -> Vec<models::Cell> {
+        let mut seen = BTreeSet::new();
+        let mut ret_cells = vec![];
+        let recomputed = recomputed.iter().map(|c| c.loc());
+        for loc in moved.iter().cloned().chain(recomputed) {
+            if seen.insert(loc) {
+                ret_cells.push(self.stored_cell(loc.row, loc.col));
+            }
+        }
+        let ret_cells = self.with_conditional_formats(ret_cells);
+        self.localize_cells(ret_cells)
+    }
+
+    // copied_cells returns the cells to store for pasting the source rectangle at the
+    // provided row and column, see CellsService::copy_range.
+    pub fn copied_cells(
+        &self,
+        source: &models::Rect,
+        row: i32,
+        col: i32,
+        mode: models::CopyMode,
+    ) -> Result<Vec<models::Cell>, parser::Error> {
+        let (height, width) = match mode {
+            models::CopyMode::Transpose => (
+                source.stop_col - source.start_col,
+                source.stop_row - source.start_row,
+            ),
+            _ => (
+                source.stop_row - source.start_row,
+                source.stop_col - source.start_col,
+            ),
+        };
+        let destination = models::Rect {
+            start_row: row,
+            stop_row: row + height,
+            start_col: col,
+            stop_col: col + width,
+        };
+        if height <= 0 || width <= 0 || !self.in_table(source) || !self.in_table(&destination) {
+            return Err(parser::Error::new(&format!(
+                "invalid copy of {:?} to row {} and column {}",
+                source, row, col
+            )));
+        }
+
+        let mut cells = vec![];
+        for loc in source.locations() {
+            let (i, j) = (loc.row - source.start_row, loc.col - source.start_col);
+            let (dest_row, dest_col) = match mode {
+                models::CopyMode::Transpose => (row + j, col + i),
+                _ => (row + i, col + j),
+            };
+            let src = self.stored_cell(loc.row, loc.col);
+            let mut cell = self.stored_cell(dest_row, dest_col);
+            match mode {
+                models::CopyMode::Formats => (),
+                models::CopyMode::Values if src.is_formula() => {
+                    cell.value = match self.get_value(loc.row, loc.col) {
+                        Some(v) => self.value_input(&v),
+                        None => "".to_owned(),
+                    }
+                }
+                models::CopyMode::Values => cell.value = src.value.clone(),
+                _ => cell.value = self.translated_value(&src, dest_row, dest_col),
+            }
+            if let models::CopyMode::All | models::CopyMode::Formats | models::CopyMode::Transpose =
+                mode
+            {
+                cell.format = src.format.clone();
+                cell.style_id = src.style_id;
+                cell.effective_style_id = src.style_id;
+            }
+            cells.push(cell);
+        }
+        Ok(cells)
+    }
+
+    // filled_cells returns the cells to store for filling the target rectangle from the
+    // source rectangle, see CellsService::fill.
+    pub fn filled_cells(
+        &self,
+        source: &models::Rect,
+        target: &models::Rect,
+    ) -> Result<Vec<models::Cell>, parser::Error> {
+        let same_cols = (source.start_col, source.stop_col) == (target.start_col, target.stop_col);
+        let same_rows = (source.start_row, source.stop_row) == (target.start_row, target.stop_row);
+        let axis = if same_cols
+            && source.start_row == target.start_row
+            && target.stop_row > source.stop_row
+        {
+            models::Axis::Rows
+        } else if same_rows
+            && source.start_col == target.start_col
+            && target.stop_col > source.stop_col
+        {
+            models::Axis::Cols
+        } else {
+            return Err(parser::Error::new(
+                "the fill target must extend the source down or to the right",
+            ));
+        };
+        if source.stop_row <= source.start_row
+            || source.stop_col <= source.start_col
+            || !self.in_table(target)
+        {
+            return Err(parser::Error::new(&format!(
+                "invalid fill of {:?} from {:?}",
+                target, source
+            )));
+        }
+
+        // Each row or column of the source is a line which continues along the axis
+        let (lines, len, count) = match axis {
+            models::Axis::Rows => (
+                source.start_col..source.stop_col,
+                source.stop_row - source.start_row,
+                target.stop_row - source.stop_row,
+            ),
+            models::Axis::Cols => (
+                source.start_row..source.stop_row,
+                source.stop_col - source.start_col,
+                target.stop_col - source.stop_col,
+            ),
+        };
+        let loc = |line: i32, i: i32| match axis {
+            models::Axis::Rows => (source.start_row + i, line),
+            models::Axis::Cols => (line, source.start_col + i),
+        };
+        let mut cells = vec![];
+        for line in lines {
+            let src: Vec<(models::Cell, Option<EvalResult>)> = (0..len)
+                .map(|i| {
+                    let (row, col) = loc(line, i);
+                    (self.stored_cell(row, col), self.get_value(row, col))
+                })
+                .collect();
+            let series = fill::series_values(&src, count as usize, &self.locale);
+            for i in 0..count {
+                let (row, col) = loc(line, len + i);
+                let (src, _) = &src[(i % len) as usize];
+                let mut cell = self.stored_cell(row, col);
+                cell.value = match &series {
+                    Some(values) => values[i as usize].clone(),
+                    None => self.translated_value(src, row, col),
+                };
+                cell.format = src.format.clone();
+                cell.style_id = src.style_id;
+                cell.effective_style_id = src.style_id;
+                cells.push(cell);
+            }
+        }
+        Ok(cells)
+    }
+
+    // translated_value returns the value of the cell as it's copied to the provided row
+    // and column, with the relative references of its formula translated.
+    fn translated_value(&self, cell: &models::Cell, row: i32, col: i32) -> String {
+        match cell.is_formula() {
+            true => parser::translate_refs(&cell.value, row - cell.row, col - cell.col),
+            false => cell.value.clone(),
+        }
+    }
+
+    // value_input returns a computed value written like cell input in the table's locale
+    fn value_input(&self, value: &EvalResult) -> String {
+        match value {
+            EvalResult::Numeric(n) => n
+                .to_string()
+                .replace('.', &self.locale.decimal_separator.to_string()),
+            EvalResult::Bool(_) => parser::format_value(value, "", &self.locale),
+            EvalResult::NonNumeric(s) | EvalResult::Error(s) => s.clone(),
+            EvalResult::List(_) => "".to_owned(),
+        }
+    }
+
+    fn in_table(&self, r: &models::Rect) -> bool {
+        r.clamp(self.num_rows, self.num_cols) == *r
+    }
+
+    // move_cells moves the cells of the source rectangle so its top left cell lands on the
+    // provided row and column, see CellsService::move_range. The formulas referencing the
+    // moved or replaced cells are found through the formula graph and rewritten. The
+    // locations of the formulas which moved or were rewritten are returned. Values aren't
+    // recomputed.
+    pub fn move_cells(
+        &mut self,
+        source: &models::Rect,
+        row: i32,
+        col: i32,
+    ) -> Result<Vec<models::CellLocation>, parser::Error> {
+        let (rows, cols) = (row - source.start_row, col - source.start_col);
+        let destination = source.offset(rows, cols);
+        if source.stop_row <= source.start_row
+            || source.stop_col <= source.start_col
+            || !self.in_table(source)
+            || !self.in_table(&destination)
+        {
+            return Err(parser::Error::new(&format!(
+                "invalid move of {:?} to row {} and column {}",
+                source, row, col
+            )));
+        }
+
+        // Formulas found in the destination are replaced unless they're being moved
+        let mut referrers = self.formula_graph.dependents_in(source);
+        referrers.extend(self.formula_graph.dependents_in(&destination));
+        let referrers: BTreeSet<models::CellLocation> = referrers
+            .into_iter()
+            .filter(|loc| {
+                !source.contains(loc.row, loc.col) && !destination.contains(loc.row, loc.col)
+            })
+            .collect();
+
+        let mut taken = vec![];
+        let cleared: BTreeSet<models::CellLocation> = source
+            .locations()
+            .into_iter()
+            .chain(destination.locations())
+            .collect();
+        for &loc in &cleared {
+            let idx = row_major_idx(loc.row, loc.col, self.num_cols) as usize;
+            self.formula_graph.remove_cell(loc);
+            let cell = std::mem::replace(&mut self.data[idx], models::Cell::empty());
+            let compiled = self.compiled[idx].take();
+            if source.contains(loc.row, loc.col) {
+                taken.push((loc, cell, compiled));
+            }
+        }
+        let mut formulas: Vec<models::CellLocation> = referrers.into_iter().collect();
+        for (loc, mut cell, compiled) in taken {
+            let idx = row_major_idx(loc.row + rows, loc.col + cols, self.num_cols) as usize;
+            cell.row = loc.row + rows;
+            cell.col = loc.col + cols;
+            // Highlights are evaluated again for the cell's new location
+            cell.effective_style_id = cell.style_id;
+            if cell.is_formula() {
+                formulas.push(cell.loc());
+            }
+            self.data[idx] = cell;
+            self.compiled[idx] = compiled;
+        }
+
+        for loc in &formulas {
+            let idx = row_major_idx(loc.row, loc.col, self.num_cols) as usize;
+            let formula = parser::move_refs(&self.data[idx].value, "", "", source, rows, cols);
+            if formula != self.data[idx].value {
+                self.data[idx].value = formula;
+                self.compiled[idx] = self.compile(&self.data[idx]);
+            }
+            let cell = self.data[idx].clone();
+            let (refs, volatile) = match self.get_ast(loc.row, loc.col) {
+                Some(formula) => (parser::get_refs(formula), parser::is_volatile(formula)),
+                None => continue,
+            };
+            self.update_dependencies(&cell, refs);
+            self.set_volatile(*loc, volatile);
+        }
+        let moved: Vec<models::CellLocation> =
+            cleared.into_iter().chain(formulas.clone()).collect();
+        self.refresh_aggregates(&moved);
+        Ok(formulas)
+    }
+
+    // get_ast returns the parsed formula of the cell
+    pub fn get_ast(&self, row: i32, col: i32) -> Option<&ASTNode> {
+        self.compiled(row, col).map(|c| &c.ast)
+    }
+
+    // set_value stores the value the cell's formula evaluated to, which is read by the
+    // formulas referencing it.
+    pub fn set_value(&mut self, row: i32, col: i32, value: EvalResult) {
+        let idx = row_major_idx(row, col, self.num_cols) as usize;
+        match self.compiled.get_mut(idx) {
+            Some(Some(c)) => c.value = value,
+            _ => return,
+        }
+        self.refresh_aggregates(&[models::CellLocation { row, col }]);
+    }
+
+    // aggregate returns the aggregate of the values of the cells in the provided range,
+    // combining the aggregates kept for the blocks of rows it covers entirely with the
+    // values of its other cells. The range must lie within the table.
+    pub fn aggregate(&self, r: &models::CellRange) -> models::Aggregate {
+        let mut ret = models::Aggregate::default();
+        for col in r.start_col..=r.stop_col {
+            let mut row = r.start_row;
+            while row <= r.stop_row {
+                let block = row / AGGREGATE_ROWS;
+                let stop = (block + 1) * AGGREGATE_ROWS - 1;
+                if row % AGGREGATE_ROWS == 0 && stop <= r.stop_row {
+                    ret.merge(&self.aggregates[(block * self.num_cols + col) as usize]);
+                    row = stop + 1;
+                } else {
+                    self.add_to_aggregate(&mut ret, row, col);
+                    row += 1;
+                }
+            }
+        }
+        ret
+    }
+
+    // refresh_aggregates computes again the aggregates of the blocks holding the provided
+    // cells once their values changed. Blocks are summed again rather than updated with the
+    // difference, which would leave the rounding errors of large values in their sums.
+    fn refresh_aggregates(&mut self, cells: &[models::CellLocation]) {
+        let blocks: BTreeSet<(i32, i32)> = cells
+            .iter()
+            .map(|c| (c.row / AGGREGATE_ROWS, c.col))
+            .collect();
+        for (block, col) in blocks {
+            let mut aggregate = models::Aggregate::default();
+            let stop = cmp::min((block + 1) * AGGREGATE_ROWS, self.num_rows);
+            for row in block * AGGREGATE_ROWS..stop {
+                self.add_to_aggregate(&mut aggregate, row, col);
+            }
+            self.aggregates[(block * self.num_cols + col) as usize] = aggregate;
+        }
+    }
+
+    // add_to_aggregate adds the value of the cell to the aggregate, reading empty cells
+    // like ranges do
+    fn add_to_aggregate(&self, aggregate: &mut models::Aggregate, row: i32, col: i32) {
+        let idx = row_major_idx(row, col, self.num_cols) as usize;
+        if let Some(value) = self.aggregated_value(idx) {
+            aggregate.add(&value);
+        }
+    }
+
+    // aggregated_value returns the value the cell adds to aggregates, or None when it's
+    // empty
+    fn aggregated_value(&self, idx: usize) -> Option<EvalResult> {
+        if self.data[idx].value.is_empty() {
+            return None;
+        }
+        match &self.compiled[idx] {
+            Some(c) => Some(c.value.clone()),
+            None => Some(EvalResult::NonNumeric("".to_owned())),
+        }
+    }
+
+    // update_dependencies records the cell's references in the formula graph without
+    // recomputing any values.
+    pub fn update_dependencies(&mut self, cell: &models::Cell, mut refs: Vec<models::CellRange>) {
+        refs.iter_mut().for_each(|r| (*r).clamp(self.num_rows));
+        self.formula_graph.set_dependencies(cell.loc(), refs);
+    }
+
+    // set_volatile marks whether the cell must be recomputed on every recalculation
+    pub fn set_volatile(&mut self, cell_loc: models::CellLocation, volatile: bool) {
+        self.formula_graph.set_volatile(cell_loc, volatile);
+    }
+
+    pub fn volatile_cells(&self) -> Vec<models::CellLocation> {
+        self.formula_graph.volatile_cells()
+    }
+
+    // seed resets the generator used by RAND and RANDBETWEEN
+    pub fn seed(&self, seed: u64) {
+        self.random.seed(seed);
+    }
+
+    // set_recalc_threads sets the number of threads formulas are recomputed with, 1 being
+    // a serial recalculation. The results are the same whatever the number of threads.
+    pub fn set_recalc_threads(&mut self, threads: usize) {
+        self.recalc_threads = threads.max(1);
+    }
+
+    // dependents returns the cells which directly reference the provided cell
+    pub fn dependents(&self, cell_loc: models::CellLocation) -> Vec<models::CellLocation> {
+        self.formula_graph.dependents(cell_loc)
+    }
+
+    // dependencies returns the ranges the cell's formula references
+    pub fn dependencies(&self, cell_loc: models::CellLocation) -> Vec<models::CellRange> {
+        self.formula_graph.dependencies(cell_loc)
+    }
+
+    // dependencies_in returns the cells of the provided range whose formulas reference
+    // other cells of the table
+    pub fn dependencies_in(&self, r: &models::CellRange) -> Vec<models::CellLocation> {
+        self.formula_graph.dependencies_in(r)
+    }
+
+    fn compile(&self, cell: &models::Cell) -> Option<CompiledCell> {
+        if cell.value.is_empty() {
+            return None;
+        }
+        let ast = parser::parse_localized(&cell.value, &self.locale);
+        // Plain values don't reference other cells and can be computed right away
+        let value = match cell.is_formula() {
+            true => EvalResult::NonNumeric("".to_owned()),
+            false => parser::evaluate_cell(ast.clone(), cell.loc(), self).value,
+        };
+        Some(CompiledCell { ast, value })
+    }
+
+    fn compiled(&self, row: i32, col: i32) -> Option<&CompiledCell> {
+        if row < 0 || col < 0 || row >= self.num_rows || col >= self.num_cols {
+            return None;
+        }
+        self.compiled[row_major_idx(row, col, self.num_cols) as usize].as_ref()
+    }
+
+    // recalculate_cells recomputes the provided cells along with their dependents, each of
+    // them once and in the order of their references. Formulas are marked stale instead
+    // unless the table is calculated automatically.
+    fn recalculate_cells(&mut self, cells: Vec<models::CellLocation>) -> Vec<models::Cell> {
+        let order = self.formula_graph.recalc_order(&cells);
+        if self.calculation_mode == models::CalculationMode::Automatic {
+            return self.evaluate_order(order);
+        }
+        let (order, deferred) = recalc::defer(
+            order,
+            |c| self.is_formula(*c),
+            |c| self.formula_graph.dependents(*c),
+        );
+        let mut ret_cells = self.mark_stale(&deferred);
+        ret_cells.extend(self.evaluate_order(order));
+        ret_cells
+    }
+
+    // evaluate_order evaluates the steps of a recalculation in order, returning the
+    // evaluated cells
+    fn evaluate_order(&mut self, order: Vec<Recalc<models::CellLocation>>) -> Vec<models::Cell> {
+        recalc::evaluate(self, order)
+            .into_iter()
+            .map(|(_, cell)| cell)
+            .collect()
+    }
+
+    // store_value stores the value the cell's formula evaluated to, formatting it for
+    // display, and returns the cell. Cells without a formula are left as they are.
+    pub fn store_value(
+        &mut self,
+        c: models::CellLocation,
+        value: EvalResult,
+    ) -> Option<models::Cell> {
+        self.get_ast(c.row, c.col)?;
+        let mut eval_cell = self.get_cell(c.row, c.col)?;
+        eval_cell.display_value = parser::format_value(&value, &eval_cell.format, &self.locale);
+        eval_cell.typed_value = parser::typed_value(&value, &eval_cell.format);
+        eval_cell.stale = false;
+        self.set_cell(&eval_cell);
+        self.set_value(c.row, c.col, value);
+        Some(eval_cell)
+    }
+
+    // store_error makes the cell's formula evaluate to the error code, returning the cell
+    pub fn store_error(&mut self, c: models::CellLocation, code: &str) -> Option<models::Cell> {
+        let mut eval_cell = self.get_cell(c.row, c.col)?;
+        if eval_cell.is_formula() {
+            eval_cell.display_value = code.to_owned();
+            eval_cell.typed_value = models::Value::Error(code.to_owned());
+            eval_cell.stale = false;
+            self.set_cell(&eval_cell);
+            self.set_value(c.row, c.col, EvalResult::Error(code.to_owned()));
+        }
+        Some(eval_cell)
+    }
+}
+
+impl recalc::Formulas<models::CellLocation> for MemoryCellsService {
+    fn formula(&self, c: &models::CellLocation) -> Option<&ASTNode> {
+        self.get_ast(c.row, c.col)
+    }
+
+    fn evaluate(&self, c: &models::CellLocation, formula: &ASTNode) -> parser::Evaluation {
+        parser::evaluate_cell(formula.clone(), *c, self)
+    }
+
+    fn value(&self, c: &models::CellLocation) -> Option<EvalResult> {
+        self.get_value(c.row, c.col)
+    }
+
+    fn set_value(&mut self, c: &models::CellLocation, value: EvalResult) {
+        MemoryCellsService::set_value(self, c.row, c.col, value);
+    }
+
+    fn store_evaluation(
+        &mut self,
+        c: &models::CellLocation,
+        evaluation: parser::Evaluation,
+    ) -> Option<models::Cell> {
+        let formula = self.get_ast(c.row, c.col)?;
+        let refs = match parser::has_dynamic_refs(formula) {
+            true => Some(parser::get_refs(formula)),
+            false => None,
+        };
+        let eval_cell = self.store_value(*c, evaluation.value)?;
+        if let Some(mut refs) = refs {
+            // Record the references found while evaluating so the cell is recomputed when
+            // the cells it resolved to change.
+            refs.extend(
+                evaluation
+                    .dynamic_refs
+                    .into_iter()
+                    .filter(|(table, _)| table.is_none())
+                    .map(|(_, r)| r),
+            );
+            self.update_dependencies(&eval_cell, refs);
+        }
+        Some(eval_cell)
+    }
+
+    fn set_error(&mut self, c: &models::CellLocation, code: &str) -> Option<models::Cell> {
+        self.store_error(*c, code)
+    }
+
+    fn dependents(&self, c: &models::CellLocation) -> Vec<models::CellLocation> {
+        self.formula_graph.dependents(*c)
+    }
+
+    fn cycle_settings(
+        &self,
+        _cycle: &[models::CellLocation],
+    ) -> Option<models::IterativeCalculation> {
+        self.iterative_calculation
+    }
+
+    fn threads(&self) -> usize {
+        self.recalc_threads
     }
 }
 
 fn row_major_idx(row: i32, col: i32, num_cols: i32) -> i32 {
     (row * num_cols) + col
 }
+
+// num_blocks returns the number of blocks of AGGREGATE_ROWS rows covering the rows
+fn num_blocks(num_rows: i32) -> i32 {
+    (num_rows + AGGREGATE_ROWS - 1) / AGGREGATE_ROWS
+}
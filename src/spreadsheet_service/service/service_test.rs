@@ -0,0 +1,1523 @@
+#[cfg(test)]
+mod tests {
+    pub use super::super::super::models::*;
+    pub use super::super::super::parser;
+    pub use super::super::service::{CellsService, MemoryCellsService};
+
+    fn display_value(svc: &MemoryCellsService, row: i32, col: i32) -> String {
+        svc.get_cell(row, col)
+            .map(|c| c.display_value)
+            .unwrap_or_default()
+    }
+
+    #[test]
+    fn test_indirect() {
+        let mut svc = MemoryCellsService::new(50, 26);
+        svc.insert_cells(&vec![
+            Cell::new(0, 0, "B2".to_string()),
+            Cell::new(1, 1, "5".to_string()),
+            Cell::new(0, 2, "=INDIRECT(A1)*2".to_string()),
+            Cell::new(1, 2, "=INDIRECT(ADDRESS(2,2))".to_string()),
+        ])
+        .unwrap();
+        assert_eq!("10", display_value(&svc, 0, 2));
+        assert_eq!("5", display_value(&svc, 1, 2));
+
+        // The cells INDIRECT resolved to are tracked as dependencies
+        let (inserted, _) = svc
+            .insert_cells(&vec![Cell::new(1, 1, "7".to_string())])
+            .unwrap();
+        assert!(inserted
+            .iter()
+            .any(|c| c.loc() == CellLocation { row: 0, col: 2 }));
+        assert_eq!("14", display_value(&svc, 0, 2));
+        assert_eq!("7", display_value(&svc, 1, 2));
+
+        // Changing the reference text follows the new target
+        svc.insert_cells(&vec![
+            Cell::new(2, 1, "1".to_string()),
+            Cell::new(0, 0, "B3".to_string()),
+        ])
+        .unwrap();
+        assert_eq!("2", display_value(&svc, 0, 2));
+        svc.insert_cells(&vec![Cell::new(2, 1, "4".to_string())])
+            .unwrap();
+        assert_eq!("8", display_value(&svc, 0, 2));
+
+        svc.insert_cells(&vec![Cell::new(0, 0, "not a ref".to_string())])
+            .unwrap();
+        assert_eq!("#REF!", display_value(&svc, 0, 2));
+
+        // A formula pointed at a cell recomputed in the same batch reads its new value
+        svc.insert_cells(&vec![
+            Cell::new(0, 5, "B4".to_string()),
+            Cell::new(3, 1, "1".to_string()),
+            Cell::new(4, 1, "=B4*10".to_string()),
+            Cell::new(0, 3, "=INDIRECT(F1)".to_string()),
+        ])
+        .unwrap();
+        assert_eq!("1", display_value(&svc, 0, 3));
+        svc.insert_cells(&vec![
+            Cell::new(0, 5, "B5".to_string()),
+            Cell::new(3, 1, "2".to_string()),
+        ])
+        .unwrap();
+        assert_eq!("20", display_value(&svc, 4, 1));
+        assert_eq!("20", display_value(&svc, 0, 3));
+    }
+
+    #[test]
+    fn test_offset() {
+        let mut svc = MemoryCellsService::new(50, 26);
+        svc.insert_cells(&vec![
+            Cell::new(0, 0, "1".to_string()),
+            Cell::new(1, 0, "2".to_string()),
+            Cell::new(2, 0, "3".to_string()),
+            Cell::new(0, 1, "=SUM(OFFSET(A1,0,0,3,1))".to_string()),
+            Cell::new(1, 1, "=OFFSET(A1,2,0)".to_string()),
+            Cell::new(2, 1, "=OFFSET(A1,-1,0)".to_string()),
+        ])
+        .unwrap();
+        assert_eq!("6", display_value(&svc, 0, 1));
+        assert_eq!("3", display_value(&svc, 1, 1));
+        assert_eq!("#REF!", display_value(&svc, 2, 1));
+
+        svc.insert_cells(&vec![Cell::new(2, 0, "10".to_string())])
+            .unwrap();
+        assert_eq!("13", display_value(&svc, 0, 1));
+        assert_eq!("10", display_value(&svc, 1, 1));
+    }
+
+    #[test]
+    fn test_reference_info_functions() {
+        let mut svc = MemoryCellsService::new(50, 26);
+        svc.insert_cells(&vec![
+            Cell::new(4, 2, "=ROW()".to_string()),
+            Cell::new(5, 2, "=COLUMN(C7)".to_string()),
+            Cell::new(6, 2, "=ROWS(A1:B5)".to_string()),
+            Cell::new(7, 2, "=COLUMNS(A1:B5)".to_string()),
+            Cell::new(8, 2, "=ADDRESS(3,2)".to_string()),
+            Cell::new(9, 2, "=ADDRESS(3,2,4)".to_string()),
+            Cell::new(10, 2, "=ADDRESS(3,28,2,1,\"My Table\")".to_string()),
+            Cell::new(11, 2, "=ADDRESS(3,2,1,0)".to_string()),
+        ])
+        .unwrap();
+        assert_eq!("5", display_value(&svc, 4, 2));
+        assert_eq!("3", display_value(&svc, 5, 2));
+        assert_eq!("5", display_value(&svc, 6, 2));
+        assert_eq!("2", display_value(&svc, 7, 2));
+        assert_eq!("$B$3", display_value(&svc, 8, 2));
+        assert_eq!("B3", display_value(&svc, 9, 2));
+        assert_eq!("'My Table'!AB$3", display_value(&svc, 10, 2));
+        assert_eq!("R3C2", display_value(&svc, 11, 2));
+    }
+
+    #[test]
+    fn test_volatile_functions() {
+        let cells = vec![
+            Cell::new(0, 0, "=RAND()".to_string()),
+            Cell::new(0, 1, "=RANDBETWEEN(1,6)".to_string()),
+            Cell::new(0, 2, "=NOW()".to_string()),
+            Cell::new(0, 3, "=A1".to_string()),
+        ];
+        let mut svc = MemoryCellsService::new(50, 26);
+        svc.seed(42);
+        svc.insert_cells(&cells).unwrap();
+
+        // The same seed produces the same values
+        let mut other = MemoryCellsService::new(50, 26);
+        other.seed(42);
+        other.insert_cells(&cells).unwrap();
+        assert_eq!(display_value(&svc, 0, 0), display_value(&other, 0, 0));
+        assert_eq!(display_value(&svc, 0, 1), display_value(&other, 0, 1));
+
+        let rand: f64 = display_value(&svc, 0, 0).parse().unwrap();
+        assert!((0f64..1f64).contains(&rand));
+        let dice: f64 = display_value(&svc, 0, 1).parse().unwrap();
+        assert!((1f64..=6f64).contains(&dice) && dice.fract() == 0f64);
+        // NOW is a serial date, days since 1899-12-30
+        let now: f64 = display_value(&svc, 0, 2).parse().unwrap();
+        assert!(now > 45000f64);
+        // Dependents read the value the volatile cell computed rather than drawing again
+        assert_eq!(display_value(&svc, 0, 0), display_value(&svc, 0, 3));
+
+        // Volatile cells and their dependents are recomputed on every recalculation
+        let recalculated = svc.recalculate();
+        for col in 0..4 {
+            assert!(recalculated
+                .iter()
+                .any(|c| c.loc() == CellLocation { row: 0, col }));
+        }
+        assert_ne!(rand.to_string(), display_value(&svc, 0, 0));
+
+        let rand = display_value(&svc, 0, 0);
+        let (inserted, _) = svc
+            .insert_cells(&vec![Cell::new(5, 5, "1".to_string())])
+            .unwrap();
+        assert_eq!(5, inserted.len());
+        assert_ne!(rand, display_value(&svc, 0, 0));
+
+        assert_eq!(
+            Vec::<CellLocation>::new(),
+            MemoryCellsService::new(50, 26).volatile_cells()
+        );
+    }
+
+    #[test]
+    fn test_references_read_computed_values() {
+        let mut svc = MemoryCellsService::new(50, 26);
+        // Each cell references the previous one twice, which would take 2^40 evaluations if
+        // references evaluated the formulas they point to.
+        let mut cells = vec![Cell::new(0, 0, "1".to_string())];
+        for row in 1..40 {
+            cells.push(Cell::new(row, 0, format!("=A{}+A{}", row, row)));
+        }
+        cells.push(Cell::new(0, 1, "=SUM(A1:A40)".to_string()));
+        svc.insert_cells(&cells).unwrap();
+        assert_eq!("549755813888", display_value(&svc, 39, 0));
+        assert_eq!("1099511627775", display_value(&svc, 0, 1));
+
+        svc.insert_cells(&vec![Cell::new(0, 0, "2".to_string())])
+            .unwrap();
+        assert_eq!("1099511627776", display_value(&svc, 39, 0));
+        assert_eq!("2199023255550", display_value(&svc, 0, 1));
+    }
+
+    #[test]
+    fn test_input_recognition() {
+        let mut svc = MemoryCellsService::new(50, 26);
+        svc.insert_cells(&vec![
+            Cell::new(0, 0, "15%".to_string()),
+            Cell::new(1, 0, "$1,200.50".to_string()),
+            Cell::new(2, 0, "1,000".to_string()),
+            Cell::new(3, 0, "(42)".to_string()),
+            Cell::new(4, 0, "TRUE".to_string()),
+            Cell::new(5, 0, "=SUM(A1:A4)".to_string()),
+            Cell::new(6, 0, "=AND(A5)".to_string()),
+        ])
+        .unwrap();
+        // Values are shown the way they were entered
+        assert_eq!("15%", display_value(&svc, 0, 0));
+        assert_eq!("$1,200.50", display_value(&svc, 1, 0));
+        assert_eq!("1,000", display_value(&svc, 2, 0));
+        assert_eq!("-42", display_value(&svc, 3, 0));
+        assert_eq!("TRUE", display_value(&svc, 4, 0));
+        // and computed with as numbers and booleans
+        assert_eq!("2158.65", display_value(&svc, 5, 0));
+        assert_eq!("TRUE", display_value(&svc, 6, 0));
+
+        // A plain entry keeps the format the cell already has
+        svc.insert_cells(&vec![Cell::new(0, 0, "0.2".to_string())])
+            .unwrap();
+        assert_eq!("20%", display_value(&svc, 0, 0));
+        assert_eq!("0%", svc.get_cell(0, 0).unwrap().format);
+    }
+
+    #[test]
+    fn test_typed_values() {
+        let mut svc = MemoryCellsService::new(50, 26);
+        let mut today = Cell::new(0, 4, "=TODAY()".to_string());
+        today.format = "yyyy-mm-dd".to_string();
+        svc.insert_cells(&vec![
+            Cell::new(0, 0, "1".to_string()),
+            Cell::new(0, 1, "'1".to_string()),
+            Cell::new(0, 2, "=AND(TRUE)".to_string()),
+            Cell::new(0, 3, "=INDIRECT(\"not a ref\")".to_string()),
+            today,
+            Cell::new(0, 5, "=Z1".to_string()),
+            Cell::new(0, 6, "#REF!".to_string()),
+        ])
+        .unwrap();
+        let typed_value = |col| svc.get_cell(0, col).unwrap().typed_value;
+        assert_eq!(Value::Number(1f64), typed_value(0));
+        assert_eq!(Value::Text("'1".to_string()), typed_value(1));
+        assert_eq!(Value::Bool(true), typed_value(2));
+        assert_eq!(Value::Error("#REF!".to_string()), typed_value(3));
+        assert!(matches!(typed_value(4), Value::Date(_)));
+        assert_eq!(Value::Empty, typed_value(5));
+        // text which looks like an error code is still text
+        assert_eq!(Value::Text("#REF!".to_string()), typed_value(6));
+
+        svc.insert_cells(&vec![Cell::new(1, 0, "=A2".to_string())])
+            .unwrap();
+        assert_eq!(
+            Value::Error("#CIRCULAR!".to_string()),
+            svc.get_cell(1, 0).unwrap().typed_value
+        );
+    }
+
+    #[test]
+    fn test_number_formats() {
+        let mut svc = MemoryCellsService::new(50, 26);
+        svc.insert_cells(&vec![
+            Cell::new(0, 0, "=1/3".to_string()),
+            Cell::new(1, 0, "=0.1+0.2".to_string()),
+        ])
+        .unwrap();
+        assert_eq!("0.333333333333333", display_value(&svc, 0, 0));
+        assert_eq!("0.3", display_value(&svc, 1, 0));
+
+        // Formats apply to empty cells too, and are kept when values are entered
+        let rect = Rect {
+            start_row: 0,
+            stop_row: 3,
+            start_col: 0,
+            stop_col: 1,
+        };
+        let formatted = svc.set_format(rect, "0.00;[Red]-0.00").unwrap();
+        assert_eq!(2, formatted.len());
+        assert_eq!("0.33", formatted[0].display_value);
+        svc.insert_cells(&vec![Cell::new(2, 0, "=-A1".to_string())])
+            .unwrap();
+        assert_eq!("-0.33", display_value(&svc, 2, 0));
+        assert_eq!("0.00;[Red]-0.00", svc.get_cell(2, 0).unwrap().format);
+
+        let rect = Rect {
+            start_row: 0,
+            stop_row: 1,
+            start_col: 0,
+            stop_col: 1,
+        };
+        svc.set_format(rect, "yyyy-mm-dd").unwrap();
+        assert!(matches!(
+            svc.get_cell(0, 0).unwrap().typed_value,
+            Value::Date(_)
+        ));
+        let rect = Rect {
+            start_row: 0,
+            stop_row: 1,
+            start_col: 0,
+            stop_col: 1,
+        };
+        assert!(svc.set_format(rect, "0.00 \"kg").is_err());
+    }
+
+    #[test]
+    fn test_styles() {
+        let mut svc = MemoryCellsService::new(50, 26);
+        svc.insert_cells(&vec![Cell::new(0, 0, "1".to_string())])
+            .unwrap();
+        let rect = |stop_row, stop_col| Rect {
+            start_row: 0,
+            stop_row,
+            start_col: 0,
+            stop_col,
+        };
+        let bold = Style {
+            bold: true,
+            ..Default::default()
+        };
+        let styled = svc.set_styles(rect(2, 2), &bold, &[]).unwrap();
+        assert_eq!(4, styled.len());
+        // Cells with the same style share its id
+        assert!(styled.iter().all(|c| c.style_id == styled[0].style_id));
+        assert!(svc.style(styled[0].style_id).bold);
+
+        // Only the named fields are changed
+        let fill = Style {
+            fill_color: "#ffff00".to_string(),
+            ..Default::default()
+        };
+        svc.set_styles(rect(1, 1), &fill, &["fill_color".to_string()])
+            .unwrap();
+        let a1 = svc.style(svc.get_cell(0, 0).unwrap().style_id);
+        assert!(a1.bold);
+        assert_eq!("#ffff00", a1.fill_color);
+
+        // Styled empty cells are returned, and keep their style once a value is entered
+        assert_eq!(4, <_ as CellsService>::get_cells(&svc, rect(5, 5)).len());
+        svc.insert_cells(&vec![Cell::new(1, 1, "=A1".to_string())])
+            .unwrap();
+        assert!(svc.style(svc.get_cell(1, 1).unwrap().style_id).bold);
+
+        let invalid = Style {
+            text_color: "red".to_string(),
+            ..Default::default()
+        };
+        assert!(svc.set_styles(rect(1, 1), &invalid, &[]).is_err());
+        assert!(svc
+            .set_styles(rect(1, 1), &bold, &["underline".to_string()])
+            .is_err());
+        assert!(a1.bold);
+    }
+
+    #[test]
+    fn test_conditional_formats() {
+        let mut svc = MemoryCellsService::new(50, 26);
+        let cells = (0..5)
+            .map(|row| Cell::new(row, 0, (row + 1).to_string()))
+            .collect();
+        svc.insert_cells(&cells).unwrap();
+        let column = |col| Rect {
+            start_row: 0,
+            stop_row: 5,
+            start_col: col,
+            stop_col: col + 1,
+        };
+        let bold = Style {
+            bold: true,
+            ..Default::default()
+        };
+        let blue = Style {
+            text_color: "#0000ff".to_string(),
+            ..Default::default()
+        };
+        let highlighted = svc
+            .set_conditional_formats(vec![
+                ConditionalFormat {
+                    range: column(0),
+                    rule: FormatRule::CellValue {
+                        comparison: Comparison::Greater,
+                        values: vec!["3".to_string()],
+                        style: bold.clone(),
+                    },
+                },
+                ConditionalFormat {
+                    range: column(0),
+                    rule: FormatRule::ColorScale {
+                        min_color: "#ff0000".to_string(),
+                        mid_color: "".to_string(),
+                        max_color: "#00ff00".to_string(),
+                    },
+                },
+                ConditionalFormat {
+                    range: column(0),
+                    rule: FormatRule::DataBar {
+                        color: "#638ec6".to_string(),
+                    },
+                },
+                // references are relative to the top left cell of the range
+                ConditionalFormat {
+                    range: column(1),
+                    rule: FormatRule::Formula {
+                        formula: "=ISODD(A1)".to_string(),
+                        style: blue.clone(),
+                    },
+                },
+            ])
+            .unwrap();
+        // Every cell of column A is filled, and B1, B3 and B5 are blue
+        assert_eq!(8, highlighted.len());
+        let style = |svc: &MemoryCellsService, row, col| {
+            let c = <_ as CellsService>::get_cells(
+                svc,
+                Rect {
+                    start_row: row,
+                    stop_row: row + 1,
+                    start_col: col,
+                    stop_col: col + 1,
+                },
+            );
+            svc.style(c.first().map_or(0, |c| c.effective_style_id))
+        };
+        assert!(!style(&svc, 2, 0).bold);
+        assert!(style(&svc, 3, 0).bold);
+        assert_eq!("#ff0000", style(&svc, 0, 0).fill_color);
+        assert_eq!("#808000", style(&svc, 2, 0).fill_color);
+        assert_eq!("#00ff00", style(&svc, 4, 0).fill_color);
+        assert_eq!(Some(60), style(&svc, 2, 0).data_bar.map(|b| b.percent));
+        assert_eq!("#0000ff", style(&svc, 0, 1).text_color);
+        assert_eq!("", style(&svc, 1, 1).text_color);
+        // the cell's own style stays as it was
+        assert_eq!(0, svc.get_cell(3, 0).unwrap().style_id);
+
+        // Highlights follow the values as they change
+        let (inserted, _) = svc
+            .insert_cells(&vec![Cell::new(4, 0, "0".to_string())])
+            .unwrap();
+        assert!(!style(&svc, 4, 0).bold);
+        assert_eq!("#ff0000", style(&svc, 4, 0).fill_color);
+        // A1 moves along the color scale as the lowest value changed
+        let a1 = inserted.iter().find(|c| c.row == 0 && c.col == 0).unwrap();
+        assert_eq!("#bf4000", svc.style(a1.effective_style_id).fill_color);
+        assert_eq!("", style(&svc, 4, 1).text_color);
+        svc.insert_cells(&vec![Cell::new(4, 0, "7".to_string())])
+            .unwrap();
+        assert_eq!("#0000ff", style(&svc, 4, 1).text_color);
+
+        // The first format takes priority
+        svc.set_conditional_formats(vec![
+            ConditionalFormat {
+                range: column(0),
+                rule: FormatRule::TopN {
+                    n: 1,
+                    bottom: false,
+                    percent: false,
+                    style: blue.clone(),
+                },
+            },
+            ConditionalFormat {
+                range: column(0),
+                rule: FormatRule::CellValue {
+                    comparison: Comparison::Between,
+                    values: vec!["5".to_string(), "10".to_string()],
+                    style: Style {
+                        text_color: "#ff0000".to_string(),
+                        ..bold.clone()
+                    },
+                },
+            },
+        ])
+        .unwrap();
+        assert_eq!("#0000ff", style(&svc, 4, 0).text_color);
+        assert!(style(&svc, 4, 0).bold);
+        assert_eq!(Style::default(), style(&svc, 0, 1));
+
+        let invalid = ConditionalFormat {
+            range: column(0),
+            rule: FormatRule::CellValue {
+                comparison: Comparison::Between,
+                values: vec!["5".to_string()],
+                style: bold,
+            },
+        };
+        assert!(svc.set_conditional_formats(vec![invalid]).is_err());
+        assert_eq!(2, svc.conditional_formats().len());
+    }
+
+    #[test]
+    fn test_validation() {
+        let mut svc = MemoryCellsService::new(50, 26);
+        let column = |col| Rect {
+            start_row: 0,
+            stop_row: 10,
+            start_col: col,
+            stop_col: col + 1,
+        };
+        svc.insert_cells(&vec![
+            Cell::new(0, 5, "Open".to_string()),
+            Cell::new(1, 5, "Closed".to_string()),
+            Cell::new(2, 5, "Open".to_string()),
+        ])
+        .unwrap();
+        svc.set_validation_rules(vec![
+            ValidationRule {
+                range: column(0),
+                criteria: Criteria::List(vec!["Red".to_string(), "Green".to_string()]),
+                mode: ValidationMode::Reject,
+                message: "".to_string(),
+            },
+            ValidationRule {
+                range: column(1),
+                criteria: Criteria::Number {
+                    comparison: Comparison::Between,
+                    values: vec!["1".to_string(), "=F10+10".to_string()],
+                },
+                mode: ValidationMode::Reject,
+                message: "Pick a number from 1 to 10".to_string(),
+            },
+            ValidationRule {
+                range: column(2),
+                criteria: Criteria::TextLength {
+                    comparison: Comparison::LessOrEqual,
+                    values: vec!["3".to_string()],
+                },
+                mode: ValidationMode::Warn,
+                message: "".to_string(),
+            },
+            // references are relative to the top left cell and see the new value
+            ValidationRule {
+                range: column(3),
+                criteria: Criteria::Formula("=ISEVEN(D1)".to_string()),
+                mode: ValidationMode::Reject,
+                message: "".to_string(),
+            },
+            ValidationRule {
+                range: column(4),
+                criteria: Criteria::ListFromRange(column(5)),
+                mode: ValidationMode::Reject,
+                message: "".to_string(),
+            },
+        ])
+        .unwrap();
+
+        let cells = vec![
+            Cell::new(0, 0, "green".to_string()),
+            Cell::new(1, 0, "Blue".to_string()),
+            Cell::new(0, 1, "=2*5".to_string()),
+            Cell::new(1, 1, "11".to_string()),
+            Cell::new(2, 1, "text".to_string()),
+            Cell::new(0, 2, "abcd".to_string()),
+            Cell::new(1, 2, "abc".to_string()),
+            Cell::new(0, 3, "4".to_string()),
+            Cell::new(0, 4, "Closed".to_string()),
+            Cell::new(1, 4, "Pending".to_string()),
+        ];
+        let errors = svc.validate_cells(&cells);
+        let error = |row, col| errors.iter().find(|e| e.row == row && e.col == col);
+        assert_eq!(5, errors.len());
+        assert_eq!(
+            "value must be one of: Red, Green",
+            error(1, 0).unwrap().message
+        );
+        assert_eq!("Pick a number from 1 to 10", error(1, 1).unwrap().message);
+        assert!(error(2, 1).unwrap().rejected);
+        assert!(!error(0, 2).unwrap().rejected);
+        assert_eq!(
+            "text length must be less than or equal to 3",
+            error(0, 2).unwrap().message
+        );
+        assert!(error(0, 3).is_none());
+        assert!(error(1, 4).unwrap().rejected);
+
+        // Rejected values aren't stored, while warnings are
+        svc.insert_cells(&cells).unwrap();
+        assert_eq!("green", svc.get_cell(0, 0).unwrap().value);
+        assert!(svc.get_cell(1, 0).is_none());
+        assert_eq!("10", display_value(&svc, 0, 1));
+        assert!(svc.get_cell(1, 1).is_none());
+        assert_eq!("abcd", display_value(&svc, 0, 2));
+        assert_eq!("Closed", display_value(&svc, 0, 4));
+        assert!(svc.get_cell(1, 4).is_none());
+
+        let rejected = svc.validate_cells(&[Cell::new(1, 3, "-1".to_string())]);
+        assert_eq!("value must satisfy =ISEVEN(D1)", rejected[0].message);
+        // Cells can always be cleared
+        assert!(svc
+            .validate_cells(&[Cell::new(0, 0, "".to_string())])
+            .is_empty());
+
+        assert_eq!(
+            Some(vec!["Open".to_string(), "Closed".to_string()]),
+            svc.list_values(3, 4)
+        );
+        assert_eq!(
+            Some(vec!["Red".to_string(), "Green".to_string()]),
+            svc.list_values(0, 0)
+        );
+        assert_eq!(None, svc.list_values(0, 1));
+
+        let invalid = ValidationRule {
+            range: column(0),
+            criteria: Criteria::List(vec![]),
+            mode: ValidationMode::Reject,
+            message: "".to_string(),
+        };
+        assert!(svc.set_validation_rules(vec![invalid]).is_err());
+        assert_eq!(5, svc.validation_rules().len());
+    }
+
+    #[test]
+    fn test_clear_cells() {
+        let mut svc = MemoryCellsService::new(50, 26);
+        svc.insert_cells(&vec![
+            Cell::new(0, 0, "1".to_string()),
+            Cell::new(1, 0, "2".to_string()),
+            Cell::new(2, 0, "=SUM(A1:A2)".to_string()),
+            Cell::new(0, 1, "=A1".to_string()),
+            Cell::new(0, 2, "=B1+1".to_string()),
+        ])
+        .unwrap();
+        let bold = Style {
+            bold: true,
+            ..Default::default()
+        };
+        let a2 = Rect {
+            start_row: 1,
+            stop_row: 2,
+            start_col: 0,
+            stop_col: 1,
+        };
+        svc.set_styles(a2.clone(), &bold, &[]).unwrap();
+
+        let cleared = svc.clear_cells(Rect {
+            start_row: 0,
+            stop_row: 1,
+            start_col: 0,
+            stop_col: 1,
+        });
+        assert!(svc.get_cell(0, 0).is_none());
+        assert_eq!("", cleared[0].value);
+        // Dependents see the cleared cell as blank
+        assert_eq!("", display_value(&svc, 0, 1));
+        assert_eq!(Value::Empty, svc.get_cell(0, 1).unwrap().typed_value);
+        assert_eq!("1", display_value(&svc, 0, 2));
+        assert_eq!("2", display_value(&svc, 2, 0));
+        assert!(cleared.iter().any(|c| c.row == 0 && c.col == 2));
+
+        // Cleared formulas no longer depend on the cells they referenced
+        svc.clear_cells(Rect {
+            start_row: 0,
+            stop_row: 1,
+            start_col: 1,
+            stop_col: 2,
+        });
+        assert_eq!(
+            vec![CellLocation { row: 2, col: 0 }],
+            svc.dependents(CellLocation { row: 0, col: 0 })
+        );
+        let (inserted, _) = svc
+            .insert_cells(&vec![Cell::new(0, 0, "5".to_string())])
+            .unwrap();
+        assert!(!inserted.iter().any(|c| c.row == 0 && c.col == 1));
+        assert_eq!("1", display_value(&svc, 0, 2));
+
+        // The style of a cleared cell is kept
+        svc.clear_cells(a2.clone());
+        let cells = <_ as CellsService>::get_cells(&svc, a2);
+        assert_eq!("", cells[0].value);
+        assert!(svc.style(cells[0].style_id).bold);
+        assert_eq!("5", display_value(&svc, 2, 0));
+    }
+
+    #[test]
+    fn test_locale() {
+        let mut svc = MemoryCellsService::new(50, 26);
+        svc.set_locale(parser::Locale::from_name("de-DE").unwrap());
+        let (inserted, _) = svc
+            .insert_cells(&vec![
+                Cell::new(0, 0, "2,5".to_string()),
+                Cell::new(1, 0, "=SUMME(A1;1,5)/2".to_string()),
+            ])
+            .unwrap();
+        assert_eq!("2", display_value(&svc, 1, 0));
+        // Formulas are stored canonically and returned as written in the locale
+        assert_eq!("=SUM(A1,1.5)/2", svc.get_cell(1, 0).unwrap().value);
+        assert!(inserted.iter().any(|c| c.value == "=SUMME(A1;1,5)/2"));
+
+        svc.insert_cells(&vec![Cell::new(0, 0, "3,25".to_string())])
+            .unwrap();
+        assert_eq!("3,25", display_value(&svc, 0, 0));
+        assert_eq!("2,375", display_value(&svc, 1, 0));
+
+        // Switching back keeps the formula's meaning
+        svc.set_locale(parser::Locale::default());
+        let cells = <_ as CellsService>::get_cells(
+            &svc,
+            Rect {
+                start_row: 1,
+                stop_row: 2,
+                start_col: 0,
+                stop_col: 1,
+            },
+        );
+        assert_eq!("=SUM(A1,1.5)/2", cells[0].value);
+    }
+
+    #[test]
+    fn test_insert_delete_rows() {
+        let mut svc = MemoryCellsService::new(10, 5);
+        svc.insert_cells(&vec![
+            Cell::new(0, 0, "1".to_string()),
+            Cell::new(1, 0, "2".to_string()),
+            Cell::new(2, 0, "3".to_string()),
+            Cell::new(3, 0, "=SUM(A1:A3)".to_string()),
+            Cell::new(3, 1, "=A2*10".to_string()),
+            Cell::new(0, 2, "=A3".to_string()),
+        ])
+        .unwrap();
+        let validation = ValidationRule {
+            range: Rect {
+                start_row: 3,
+                stop_row: 5,
+                start_col: 0,
+                stop_col: 1,
+            },
+            criteria: Criteria::List(vec!["1".to_string()]),
+            mode: ValidationMode::Reject,
+            message: "".to_string(),
+        };
+        svc.set_validation_rules(vec![validation]).unwrap();
+
+        // Inserting rows within a range grows it and moves the cells below
+        let shifted = svc.insert_rows(1, 2).unwrap();
+        assert_eq!("=SUM(A1:A5)", svc.get_cell(5, 0).unwrap().value);
+        assert_eq!("=A4*10", svc.get_cell(5, 1).unwrap().value);
+        assert_eq!("=A5", svc.get_cell(0, 2).unwrap().value);
+        assert_eq!("6", display_value(&svc, 5, 0));
+        assert_eq!("20", display_value(&svc, 5, 1));
+        assert!(svc.get_cell(1, 0).is_none());
+        // Every cell of the moved rows is returned so emptied cells can be redrawn
+        assert!(shifted
+            .iter()
+            .any(|c| c.row == 1 && c.col == 0 && c.value.is_empty()));
+        assert!(shifted.iter().any(|c| c.row == 0 && c.col == 2));
+        assert_eq!(5, svc.validation_rules()[0].range.start_row);
+
+        // Values entered in the new rows are summed
+        svc.insert_cells(&vec![Cell::new(1, 0, "4".to_string())])
+            .unwrap();
+        assert_eq!("10", display_value(&svc, 5, 0));
+
+        // Deleting rows shrinks ranges, while references to deleted cells become #REF!
+        svc.delete_rows(2, 2).unwrap();
+        assert_eq!("=SUM(A1:A3)", svc.get_cell(3, 0).unwrap().value);
+        assert_eq!("=#REF!*10", svc.get_cell(3, 1).unwrap().value);
+        assert_eq!("#REF!", display_value(&svc, 3, 1));
+        assert_eq!("8", display_value(&svc, 3, 0));
+        assert_eq!("=A3", svc.get_cell(0, 2).unwrap().value);
+        assert_eq!("3", display_value(&svc, 0, 2));
+        assert_eq!(3, svc.validation_rules()[0].range.start_row);
+
+        // Dependencies follow the moved cells
+        svc.insert_cells(&vec![Cell::new(2, 0, "5".to_string())])
+            .unwrap();
+        assert_eq!("10", display_value(&svc, 3, 0));
+        assert_eq!("5", display_value(&svc, 0, 2));
+
+        // Columns shift like rows
+        svc.insert_cols(0, 1).unwrap();
+        assert_eq!("=SUM(B1:B3)", svc.get_cell(3, 1).unwrap().value);
+        assert_eq!("=B3", svc.get_cell(0, 3).unwrap().value);
+        svc.delete_cols(1, 1).unwrap();
+        assert!(svc.get_cell(3, 0).is_none());
+        assert_eq!("=#REF!*10", svc.get_cell(3, 1).unwrap().value);
+        assert_eq!("=#REF!", svc.get_cell(0, 2).unwrap().value);
+
+        // Cells can't be pushed off the table
+        assert!(svc.insert_cols(0, 4).is_err());
+        assert!(svc.delete_rows(9, 2).is_err());
+        assert!(svc.insert_rows(0, 7).is_err());
+    }
+
+    #[test]
+    fn test_shift_recomputes_moved_cells() {
+        let mut svc = MemoryCellsService::new(10, 3);
+        svc.insert_cells(&vec![
+            Cell::new(0, 0, "1".to_string()),
+            Cell::new(5, 0, "2".to_string()),
+            Cell::new(0, 1, "=A1*2".to_string()),
+            Cell::new(1, 1, "=SUM(A1:A8)".to_string()),
+            Cell::new(2, 1, "=A6".to_string()),
+            Cell::new(4, 1, "=A1+1".to_string()),
+        ])
+        .unwrap();
+
+        // Formulas neither moving nor reading the moved cells aren't recomputed
+        let shifted = svc.insert_rows(3, 1).unwrap();
+        let returned =
+            |cells: &[Cell], row, col| cells.iter().any(|c| c.row == row && c.col == col);
+        assert!(!returned(&shifted, 0, 1));
+        assert!(returned(&shifted, 1, 1));
+        assert!(returned(&shifted, 2, 1));
+        assert!(returned(&shifted, 5, 1));
+        assert_eq!("=SUM(A1:A9)", svc.get_cell(1, 1).unwrap().value);
+        assert_eq!("=A7", svc.get_cell(2, 1).unwrap().value);
+        assert_eq!("2", display_value(&svc, 5, 1));
+
+        let shifted = svc.delete_rows(6, 1).unwrap();
+        assert!(!returned(&shifted, 0, 1));
+        assert_eq!("1", display_value(&svc, 1, 1));
+        assert_eq!("#REF!", display_value(&svc, 2, 1));
+
+        // Formulas left stale keep being stale once moved
+        svc.set_calculation_mode(CalculationMode::Manual);
+        svc.insert_cells(&vec![Cell::new(0, 0, "5".to_string())])
+            .unwrap();
+        assert!(svc.get_cell(5, 1).unwrap().stale);
+        svc.insert_rows(0, 1).unwrap();
+        assert_eq!(
+            vec![(1, 1), (2, 1), (3, 1), (6, 1)],
+            svc.stale_cells()
+                .iter()
+                .map(|c| (c.row, c.col))
+                .collect::<Vec<_>>()
+        );
+    }
+
+    #[test]
+    fn test_move_range() {
+        let mut svc = MemoryCellsService::new(10, 5);
+        svc.insert_cells(&vec![
+            Cell::new(0, 0, "1".to_string()),
+            Cell::new(1, 0, "2".to_string()),
+            Cell::new(2, 0, "=A1+A2".to_string()),
+            Cell::new(0, 1, "=SUM(A1:A2)".to_string()),
+            Cell::new(4, 1, "7".to_string()),
+            Cell::new(0, 2, "=B5*2".to_string()),
+        ])
+        .unwrap();
+        let bold = Style {
+            bold: true,
+            ..Default::default()
+        };
+        let a1 = Rect {
+            start_row: 0,
+            stop_row: 1,
+            start_col: 0,
+            stop_col: 1,
+        };
+        svc.set_styles(a1, &bold, &[]).unwrap();
+
+        // A1:A3 moves to B4:B6, replacing B5
+        let source = Rect {
+            start_row: 0,
+            stop_row: 3,
+            start_col: 0,
+            stop_col: 1,
+        };
+        let moved = svc.move_range(source, 3, 1).unwrap();
+        assert!(svc.get_cell(0, 0).is_none());
+        assert_eq!("1", svc.get_cell(3, 1).unwrap().value);
+        assert!(svc.style(svc.get_cell(3, 1).unwrap().style_id).bold);
+        // The moved formula and the formulas referencing the moved cells follow them
+        assert_eq!("=B4+B5", svc.get_cell(5, 1).unwrap().value);
+        assert_eq!("3", display_value(&svc, 5, 1));
+        assert_eq!("=SUM(B4:B5)", svc.get_cell(0, 1).unwrap().value);
+        assert_eq!("3", display_value(&svc, 0, 1));
+        // The replaced cell's referrers get #REF!
+        assert_eq!("=#REF!*2", svc.get_cell(0, 2).unwrap().value);
+        assert_eq!("#REF!", display_value(&svc, 0, 2));
+        assert!(moved
+            .iter()
+            .any(|c| c.row == 0 && c.col == 0 && c.value.is_empty()));
+        assert!(moved.iter().any(|c| c.row == 5 && c.col == 1));
+
+        // Dependencies follow the moved cells
+        svc.insert_cells(&vec![Cell::new(4, 1, "5".to_string())])
+            .unwrap();
+        assert_eq!("6", display_value(&svc, 5, 1));
+        assert_eq!("6", display_value(&svc, 0, 1));
+        let (inserted, _) = svc
+            .insert_cells(&vec![Cell::new(1, 0, "100".to_string())])
+            .unwrap();
+        assert!(!inserted.iter().any(|c| c.row == 0 && c.col == 1));
+
+        // Overlapping moves keep the moved values
+        let source = Rect {
+            start_row: 3,
+            stop_row: 6,
+            start_col: 1,
+            stop_col: 2,
+        };
+        svc.move_range(source, 4, 1).unwrap();
+        assert_eq!("=B5+B6", svc.get_cell(6, 1).unwrap().value);
+        assert_eq!("6", display_value(&svc, 6, 1));
+        assert_eq!("=SUM(B5:B6)", svc.get_cell(0, 1).unwrap().value);
+
+        let outside = Rect {
+            start_row: 8,
+            stop_row: 10,
+            start_col: 0,
+            stop_col: 1,
+        };
+        assert!(svc.move_range(outside, 9, 0).is_err());
+    }
+
+    #[test]
+    fn test_copy_range() {
+        let mut svc = MemoryCellsService::new(10, 5);
+        svc.insert_cells(&vec![
+            Cell::new(0, 0, "1".to_string()),
+            Cell::new(0, 1, "2".to_string()),
+            Cell::new(1, 0, "3".to_string()),
+            Cell::new(1, 1, "4".to_string()),
+            Cell::new(0, 2, "=A1+B1*$A$1".to_string()),
+        ])
+        .unwrap();
+        let bold = Style {
+            bold: true,
+            ..Default::default()
+        };
+        let c1 = Rect {
+            start_row: 0,
+            stop_row: 1,
+            start_col: 2,
+            stop_col: 3,
+        };
+        svc.set_styles(c1.clone(), &bold, &[]).unwrap();
+        svc.set_format(c1.clone(), "0.00").unwrap();
+
+        // Relative references follow the pasted formula while anchored ones are kept
+        let pasted = svc.copy_range(c1.clone(), 1, 2, CopyMode::All).unwrap();
+        assert_eq!("=A2+B2*$A$1", svc.get_cell(1, 2).unwrap().value);
+        assert_eq!("7.00", display_value(&svc, 1, 2));
+        assert!(svc.style(svc.get_cell(1, 2).unwrap().style_id).bold);
+        assert_eq!(1, pasted.len());
+
+        // Pasting values replaces formulas with their results
+        svc.copy_range(c1.clone(), 2, 3, CopyMode::Values).unwrap();
+        assert_eq!("3", svc.get_cell(2, 3).unwrap().value);
+        assert_eq!(0, svc.get_cell(2, 3).unwrap().style_id);
+        svc.insert_cells(&vec![Cell::new(0, 0, "2".to_string())])
+            .unwrap();
+        assert_eq!("3", display_value(&svc, 2, 3));
+
+        // Formulas keep the destination's format, formats keep its value
+        svc.copy_range(c1.clone(), 3, 2, CopyMode::Formulas)
+            .unwrap();
+        assert_eq!("=A4+B4*$A$1", svc.get_cell(3, 2).unwrap().value);
+        assert_eq!("", svc.get_cell(3, 2).unwrap().format);
+        svc.copy_range(c1, 2, 3, CopyMode::Formats).unwrap();
+        assert_eq!("3", svc.get_cell(2, 3).unwrap().value);
+        assert_eq!("3.00", display_value(&svc, 2, 3));
+
+        // Transposing turns rows into columns
+        let block = Rect {
+            start_row: 0,
+            stop_row: 2,
+            start_col: 0,
+            stop_col: 2,
+        };
+        svc.copy_range(block, 5, 0, CopyMode::Transpose).unwrap();
+        assert_eq!("3", svc.get_cell(5, 1).unwrap().value);
+        assert_eq!("2", svc.get_cell(6, 0).unwrap().value);
+
+        let outside = Rect {
+            start_row: 0,
+            stop_row: 2,
+            start_col: 0,
+            stop_col: 1,
+        };
+        assert!(svc.copy_range(outside, 9, 0, CopyMode::All).is_err());
+    }
+
+    #[test]
+    fn test_fill() {
+        let mut svc = MemoryCellsService::new(10, 6);
+        svc.insert_cells(&vec![
+            Cell::new(0, 0, "1".to_string()),
+            Cell::new(1, 0, "3".to_string()),
+            Cell::new(0, 1, "0.1".to_string()),
+            Cell::new(1, 1, "0.2".to_string()),
+            Cell::new(0, 2, "Mon".to_string()),
+            Cell::new(0, 3, "=A1*2".to_string()),
+            Cell::new(0, 4, "a".to_string()),
+            Cell::new(1, 4, "b".to_string()),
+            Cell::new(0, 5, "NOVEMBER".to_string()),
+        ])
+        .unwrap();
+        let rect = |start_col, stop_row, stop_col| Rect {
+            start_row: 0,
+            stop_row,
+            start_col,
+            stop_col,
+        };
+        let value = |svc: &MemoryCellsService, row, col| svc.get_cell(row, col).unwrap().value;
+
+        let filled = svc.fill(rect(0, 2, 2), rect(0, 5, 2)).unwrap();
+        assert_eq!(6, filled.len());
+        assert_eq!(
+            ["5", "7", "9"],
+            [value(&svc, 2, 0), value(&svc, 3, 0), value(&svc, 4, 0)]
+        );
+        assert_eq!("0.5", value(&svc, 4, 1));
+        svc.fill(rect(2, 1, 4), rect(2, 3, 4)).unwrap();
+        assert_eq!(["Tue", "Wed"], [value(&svc, 1, 2), value(&svc, 2, 2)]);
+        // Formulas are repeated with their references translated
+        assert_eq!("=A3*2", value(&svc, 2, 3));
+        assert_eq!("10", display_value(&svc, 2, 3));
+        // Text which isn't a series is repeated
+        svc.fill(rect(4, 2, 5), rect(4, 5, 5)).unwrap();
+        assert_eq!(
+            ["a", "b", "a"],
+            [value(&svc, 2, 4), value(&svc, 3, 4), value(&svc, 4, 4)]
+        );
+        svc.fill(rect(5, 1, 6), rect(5, 3, 6)).unwrap();
+        assert_eq!(
+            ["DECEMBER", "JANUARY"],
+            [value(&svc, 1, 5), value(&svc, 2, 5)]
+        );
+
+        // A single date continues with the next days, and fills go right too
+        svc.insert_cells(&vec![Cell::new(6, 0, "45322".to_string())])
+            .unwrap();
+        let date = Rect {
+            start_row: 6,
+            stop_row: 7,
+            start_col: 0,
+            stop_col: 1,
+        };
+        svc.set_format(date.clone(), "yyyy-mm-dd").unwrap();
+        assert_eq!("2024-01-31", display_value(&svc, 6, 0));
+        let target = Rect {
+            stop_col: 3,
+            ..date.clone()
+        };
+        svc.fill(date.clone(), target).unwrap();
+        assert_eq!("2024-02-02", display_value(&svc, 6, 2));
+
+        assert!(svc.fill(date, rect(0, 2, 6)).is_err());
+    }
+
+    #[test]
+    fn test_batch_recalc() {
+        let mut svc = MemoryCellsService::new(1000, 3);
+        svc.insert_cells(&vec![Cell::new(0, 1, "=SUM(A1:A1000)".to_string())])
+            .unwrap();
+
+        // The SUM depending on every pasted cell is computed once, after all of them
+        let pasted: Vec<Cell> = (0..1000)
+            .map(|row| Cell::new(row, 0, (row + 1).to_string()))
+            .collect();
+        let (inserted, _) = svc.insert_cells(&pasted).unwrap();
+        assert_eq!(1001, inserted.len());
+        assert_eq!(
+            1,
+            inserted.iter().filter(|c| (c.row, c.col) == (0, 1)).count()
+        );
+        assert_eq!("500500", display_value(&svc, 0, 1));
+
+        // Cells are computed after the cells they reference, whatever order they're
+        // inserted in
+        let (inserted, _) = svc
+            .insert_cells(&vec![
+                Cell::new(0, 2, "=C2*2".to_string()),
+                Cell::new(1, 2, "=C3+1".to_string()),
+                Cell::new(2, 2, "=B1/500500".to_string()),
+            ])
+            .unwrap();
+        let rows: Vec<i32> = inserted.iter().map(|c| c.row).collect();
+        assert_eq!(vec![2, 1, 0], rows);
+        assert_eq!("4", display_value(&svc, 0, 2));
+    }
+
+    #[test]
+    fn test_parallel_recalc() {
+        // Each row is a chain of its own, reading A1 and drawing random numbers
+        let mut cells = vec![];
+        for row in 0..1000 {
+            let r = row + 1;
+            cells.push(Cell::new(row, 0, r.to_string()));
+            cells.push(Cell::new(row, 1, format!("=A{}*$A$1+RAND()", r)));
+            cells.push(Cell::new(
+                row,
+                2,
+                format!("=B{}+INDIRECT(ADDRESS({},1))", r, r),
+            ));
+            cells.push(Cell::new(row, 3, format!("=SUM(A{}:C{})", r, r)));
+        }
+        let recalc = |threads| {
+            let mut svc = MemoryCellsService::new(1000, 4);
+            svc.seed(11);
+            svc.set_recalc_threads(threads);
+            let (mut updated, _) = svc.insert_cells(&cells).unwrap();
+            // Changing A1 recomputes every chain
+            updated.extend(
+                svc.insert_cells(&vec![Cell::new(0, 0, "3".to_string())])
+                    .unwrap()
+                    .0,
+            );
+            updated.extend(svc.recalculate());
+            let rect = Rect {
+                start_row: 0,
+                stop_row: 1000,
+                start_col: 0,
+                stop_col: 4,
+            };
+            (updated, <_ as CellsService>::get_cells(&svc, rect))
+        };
+
+        // Parallel and serial recalculations give the same results in the same order
+        let (updated, cells) = recalc(1);
+        assert_eq!((updated.clone(), cells.clone()), recalc(4));
+        assert_eq!(4000, cells.len());
+        let value = |row: i32, col: i32| -> f64 {
+            cells[(row * 4 + col) as usize]
+                .display_value
+                .parse()
+                .unwrap()
+        };
+        assert!((value(499, 2) - value(499, 1) - 500f64).abs() < 1e-9);
+        assert!(value(499, 1) >= 1500f64 && value(499, 1) < 1501f64);
+    }
+
+    #[test]
+    fn test_iterative_calculation() {
+        let mut svc = MemoryCellsService::new(10, 5);
+        svc.insert_cells(&vec![
+            Cell::new(0, 1, "1000".to_string()),
+            Cell::new(1, 1, "=(B1+B3)/2*0.1".to_string()),
+            Cell::new(2, 1, "=B1+B2".to_string()),
+            Cell::new(3, 1, "=B3*2".to_string()),
+        ])
+        .unwrap();
+        assert_eq!("#CIRCULAR!", display_value(&svc, 1, 1));
+        assert_eq!("#CIRCULAR!", display_value(&svc, 2, 1));
+
+        // B2 = (1000 + 1000 + B2) / 20 converges to 2000 / 19
+        let settings = IterativeCalculation {
+            max_iterations: 100,
+            max_change: 0.0001,
+        };
+        let updated = svc.set_iterative_calculation(Some(settings)).unwrap();
+        assert_eq!(3, updated.len());
+        let value =
+            |svc: &MemoryCellsService, row| -> f64 { display_value(svc, row, 1).parse().unwrap() };
+        assert!((value(&svc, 1) - 2000f64 / 19f64).abs() < 0.001);
+        assert!((value(&svc, 3) - 2f64 * (1000f64 + 2000f64 / 19f64)).abs() < 0.01);
+
+        // Changing a cell the cycle reads iterates it again
+        svc.insert_cells(&vec![Cell::new(0, 1, "100".to_string())])
+            .unwrap();
+        assert!((value(&svc, 1) - 200f64 / 19f64).abs() < 0.001);
+
+        // Cycles which don't settle within the allowed iterations are reported as such
+        svc.insert_cells(&vec![
+            Cell::new(0, 0, "=A2+1".to_string()),
+            Cell::new(1, 0, "=A1".to_string()),
+        ])
+        .unwrap();
+        assert_eq!("#CONVERGENCE!", display_value(&svc, 0, 0));
+        assert_eq!("#CONVERGENCE!", display_value(&svc, 1, 0));
+
+        svc.set_iterative_calculation(None).unwrap();
+        assert_eq!("#CIRCULAR!", display_value(&svc, 0, 0));
+        assert_eq!("#CIRCULAR!", display_value(&svc, 1, 1));
+
+        for settings in [
+            IterativeCalculation {
+                max_iterations: 0,
+                max_change: 0.1,
+            },
+            IterativeCalculation {
+                max_iterations: 10,
+                max_change: -1f64,
+            },
+        ] {
+            assert!(svc.set_iterative_calculation(Some(settings)).is_err());
+        }
+        assert_eq!(None, svc.iterative_calculation());
+    }
+
+    #[test]
+    fn test_trace() {
+        let mut svc = MemoryCellsService::new(10, 6);
+        svc.insert_cells(&vec![
+            Cell::new(0, 0, "1".to_string()),
+            Cell::new(1, 0, "2".to_string()),
+            Cell::new(0, 1, "=A1*2".to_string()),
+            Cell::new(1, 1, "=SUM(A1:A2)".to_string()),
+            Cell::new(0, 2, "=B1+B2".to_string()),
+            Cell::new(0, 3, "=C1".to_string()),
+            Cell::new(0, 4, "=F1".to_string()),
+            Cell::new(0, 5, "=E1".to_string()),
+        ])
+        .unwrap();
+        let rect = |start_row, start_col, stop_row, stop_col| Rect {
+            start_row,
+            start_col,
+            stop_row,
+            stop_col,
+        };
+        let traced = |ranges: Vec<TracedRange>| -> Vec<(Rect, u32)> {
+            ranges.into_iter().map(|r| (r.rect, r.depth)).collect()
+        };
+
+        assert_eq!(
+            vec![(rect(0, 2, 1, 3), 1)],
+            traced(svc.trace_precedents(0, 3, 1))
+        );
+        // Ranges are traced through the formulas within them
+        assert_eq!(
+            vec![
+                (rect(0, 2, 1, 3), 1),
+                (rect(0, 1, 1, 2), 2),
+                (rect(1, 1, 2, 2), 2),
+                (rect(0, 0, 1, 1), 3),
+                (rect(0, 0, 2, 1), 3),
+            ],
+            traced(svc.trace_precedents(0, 3, 0))
+        );
+        assert!(svc.trace_precedents(0, 0, 0).is_empty());
+
+        // Cells referencing a range containing A1 are among its dependents
+        let mut dependents = traced(svc.trace_dependents(0, 0, 2));
+        dependents.sort_by_key(|(r, depth)| (*depth, r.start_row, r.start_col));
+        assert_eq!(
+            vec![
+                (rect(0, 1, 1, 2), 1),
+                (rect(1, 1, 2, 2), 1),
+                (rect(0, 2, 1, 3), 2),
+            ],
+            dependents
+        );
+        assert_eq!(4, svc.trace_dependents(0, 0, 0).len());
+
+        // Cells of a cycle are found once, including the traced cell
+        assert_eq!(
+            vec![(rect(0, 5, 1, 6), 1), (rect(0, 4, 1, 5), 2)],
+            traced(svc.trace_dependents(0, 4, 0))
+        );
+    }
+
+    #[test]
+    fn test_manual_calculation() {
+        let mut svc = MemoryCellsService::new(10, 3);
+        svc.set_calculation_mode(CalculationMode::Manual);
+        let (inserted, _) = svc
+            .insert_cells(&vec![
+                Cell::new(0, 0, "1".to_string()),
+                Cell::new(1, 0, "=A1*2".to_string()),
+                Cell::new(2, 0, "=A2+1".to_string()),
+                Cell::new(3, 0, "=A1+100".to_string()),
+            ])
+            .unwrap();
+        // Values are stored while formulas are left stale
+        let stale = |cells: &[Cell]| -> Vec<(i32, bool)> {
+            let mut ret: Vec<(i32, bool)> = cells.iter().map(|c| (c.row, c.stale)).collect();
+            ret.sort();
+            ret
+        };
+        assert_eq!(
+            vec![(0, false), (1, true), (2, true), (3, true)],
+            stale(&inserted)
+        );
+        assert_eq!("1", display_value(&svc, 0, 0));
+        assert_eq!("", display_value(&svc, 1, 0));
+        let rect = |start_row, stop_row| Rect {
+            start_row,
+            stop_row,
+            start_col: 0,
+            stop_col: 1,
+        };
+        let cells = <_ as CellsService>::get_cells(&svc, rect(0, 4));
+        assert_eq!(
+            vec![(0, false), (1, true), (2, true), (3, true)],
+            stale(&cells)
+        );
+
+        // Recalculating A3 recomputes the stale A2 it reads, leaving A4 stale
+        let recalculated = svc.recalculate_range(rect(2, 3));
+        assert_eq!(vec![(1, false), (2, false)], stale(&recalculated));
+        assert_eq!("3", display_value(&svc, 2, 0));
+        assert!(svc.get_cell(3, 0).unwrap().stale);
+
+        svc.insert_cells(&vec![Cell::new(0, 0, "5".to_string())])
+            .unwrap();
+        assert_eq!("3", display_value(&svc, 2, 0));
+        assert_eq!(
+            vec![(0, false), (1, true), (2, true), (3, true)],
+            stale(&<_ as CellsService>::get_cells(&svc, rect(0, 4)))
+        );
+        let recalculated = svc.recalculate();
+        assert_eq!(3, recalculated.len());
+        assert_eq!("11", display_value(&svc, 2, 0));
+        assert_eq!("105", display_value(&svc, 3, 0));
+        assert!(svc.stale_cells().is_empty());
+
+        // Switching back to automatic calculation recomputes the stale cells
+        svc.insert_cells(&vec![Cell::new(0, 0, "2".to_string())])
+            .unwrap();
+        let recomputed = svc.set_calculation_mode(CalculationMode::Automatic);
+        assert_eq!(3, recomputed.len());
+        assert_eq!("5", display_value(&svc, 2, 0));
+        svc.insert_cells(&vec![Cell::new(0, 0, "3".to_string())])
+            .unwrap();
+        assert_eq!("7", display_value(&svc, 2, 0));
+        assert!(svc.stale_cells().is_empty());
+    }
+
+    #[test]
+    fn test_on_demand_calculation() {
+        let mut svc = MemoryCellsService::new(10, 3);
+        svc.set_calculation_mode(CalculationMode::OnDemand);
+        svc.insert_cells(&vec![
+            Cell::new(0, 0, "1".to_string()),
+            Cell::new(1, 0, "=A1*2".to_string()),
+            Cell::new(2, 0, "=A2+1".to_string()),
+            Cell::new(3, 0, "=A1+100".to_string()),
+        ])
+        .unwrap();
+        assert_eq!(3, svc.stale_cells().len());
+        let rect = |start_row, stop_row| Rect {
+            start_row,
+            stop_row,
+            start_col: 0,
+            stop_col: 1,
+        };
+
+        // Reading A3 recomputes the stale A2 it reads, leaving A4 stale
+        let cells = svc.read_cells(rect(2, 3));
+        assert_eq!("3", cells[0].display_value);
+        assert!(!cells[0].stale);
+        assert_eq!("2", display_value(&svc, 1, 0));
+        assert!(svc.get_cell(3, 0).unwrap().stale);
+
+        svc.insert_cells(&vec![Cell::new(0, 0, "5".to_string())])
+            .unwrap();
+        assert_eq!("3", display_value(&svc, 2, 0));
+        let cells = svc.read_cells(rect(0, 4));
+        let values: Vec<&str> = cells.iter().map(|c| c.display_value.as_str()).collect();
+        assert_eq!(vec!["5", "10", "11", "105"], values);
+        assert!(svc.stale_cells().is_empty());
+
+        // Reading manually calculated cells leaves them stale
+        svc.set_calculation_mode(CalculationMode::Manual);
+        svc.insert_cells(&vec![Cell::new(0, 0, "2".to_string())])
+            .unwrap();
+        assert!(svc.read_cells(rect(2, 3))[0].stale);
+        assert_eq!("11", display_value(&svc, 2, 0));
+    }
+
+    #[test]
+    fn test_export_graph() {
+        let mut svc = MemoryCellsService::new(10, 3);
+        svc.insert_cells(&vec![
+            Cell::new(0, 0, "1".to_string()),
+            Cell::new(1, 0, "=A1*2".to_string()),
+            Cell::new(0, 1, "=SUM(A1:A3)".to_string()),
+            Cell::new(0, 2, "=C2".to_string()),
+            Cell::new(1, 2, "=C1".to_string()),
+        ])
+        .unwrap();
+        assert_eq!(
+            concat!(
+                "digraph \"\" {\n",
+                "    \"B1\";\n",
+                "    \"C1\";\n",
+                "    \"A2\";\n",
+                "    \"C2\";\n",
+                "    \"A1:A3\" [shape=box];\n",
+                "    \"B1\" -> \"A1:A3\";\n",
+                "    \"C1\" -> \"C2\";\n",
+                "    \"A2\" -> \"A1\";\n",
+                "    \"C2\" -> \"C1\";\n",
+                "    subgraph cluster_0 {\n",
+                "        label=\"cycle\";\n",
+                "        color=red;\n",
+                "        \"C1\";\n",
+                "        \"C2\";\n",
+                "    }\n",
+                "}\n",
+            ),
+            svc.export_graph(None).write(GraphFormat::Dot)
+        );
+
+        // Restricting the graph to A1 leaves out the cycle
+        assert_eq!(
+            concat!(
+                r#"{"table":"","cells":["B1","A2"],"#,
+                r#""edges":[{"from":"B1","to":"A1:A3"},{"from":"A2","to":"A1"}],"cycles":[]}"#,
+            ),
+            svc.export_graph(Some(CellLocation { row: 0, col: 0 }))
+                .write(GraphFormat::Json)
+        );
+    }
+
+    #[test]
+    fn test_empty_average() {
+        let mut svc = MemoryCellsService::new(300, 3);
+        // The aggregates of the range are only used without the single cell argument
+        svc.insert_cells(&vec![
+            Cell::new(0, 0, "=AVG(B1:B200)".to_string()),
+            Cell::new(1, 0, "=AVG(B1:B200, C1)".to_string()),
+        ])
+        .unwrap();
+        // Neither divides by the missing count of numbers silently
+        assert_eq!("#NUM!", svc.get_cell(0, 0).unwrap().display_value);
+        assert_eq!("#NUM!", svc.get_cell(1, 0).unwrap().display_value);
+
+        // Values taken out of the range leave it empty again
+        svc.insert_cells(&vec![Cell::new(3, 1, "4".to_string())])
+            .unwrap();
+        assert_eq!("4", svc.get_cell(0, 0).unwrap().display_value);
+        assert_eq!("4", svc.get_cell(1, 0).unwrap().display_value);
+        svc.insert_cells(&vec![Cell::new(3, 1, "text".to_string())])
+            .unwrap();
+        assert_eq!("#NUM!", svc.get_cell(0, 0).unwrap().display_value);
+        assert_eq!("#NUM!", svc.get_cell(1, 0).unwrap().display_value);
+    }
+
+    #[test]
+    fn test_aggregate_cache() {
+        let mut svc = MemoryCellsService::new(300, 3);
+        let mut cells = vec![];
+        for row in 0..290 {
+            match row {
+                r if r % 7 == 0 => cells.push(Cell::new(r, 0, format!("x{}", r))),
+                r if r % 11 == 0 => {}
+                r => cells.push(Cell::new(r, 0, format!("{}.5", r))),
+            }
+        }
+        cells.push(Cell::new(0, 2, "=SUM(A:A)".to_string()));
+        cells.push(Cell::new(1, 2, "=COUNT(A1:A300)".to_string()));
+        cells.push(Cell::new(2, 2, "=AVG(A5:A260)".to_string()));
+        svc.insert_cells(&cells).unwrap();
+
+        // aggregate_of reads the values of the range one by one
+        let aggregate_of = |svc: &MemoryCellsService, r: CellRange| {
+            let mut ret = Aggregate::default();
+            for row in r.start_row..=r.stop_row {
+                for col in r.start_col..=r.stop_col {
+                    if svc.get_cell(row, col).is_some() {
+                        let value = <_ as EvalContext>::get_value(svc, row, col)
+                            .unwrap_or(parser::EvalResult::NonNumeric("".to_owned()));
+                        ret.add(&value);
+                    }
+                }
+            }
+            ret
+        };
+        let range = |start_row, stop_row| CellRange {
+            start_row,
+            start_col: 0,
+            stop_row,
+            stop_col: 0,
+        };
+        let number = |svc: &MemoryCellsService, row| match svc.get_cell(row, 2).unwrap().typed_value
+        {
+            Value::Number(n) => n,
+            v => panic!("unexpected value {:?}", v),
+        };
+        let check = |svc: &MemoryCellsService| {
+            let column = aggregate_of(svc, range(0, 299));
+            assert_eq!(column, svc.aggregate(&range(0, 299)));
+            assert_eq!(column.sum, number(svc, 0));
+            assert_eq!(column.values as f64, number(svc, 1));
+            let part = aggregate_of(svc, range(4, 259));
+            assert_eq!(part, svc.aggregate(&range(4, 259)));
+            assert_eq!(part.sum / part.numbers as f64, number(svc, 2));
+            for (start, stop) in [(0, 0), (3, 70), (63, 64), (64, 127), (100, 299)] {
+                assert_eq!(
+                    aggregate_of(svc, range(start, stop)),
+                    svc.aggregate(&range(start, stop))
+                );
+            }
+        };
+        check(&svc);
+
+        // Editing, clearing and moving cells update the totals
+        svc.insert_cells(&vec![Cell::new(100, 0, "1000".to_string())])
+            .unwrap();
+        check(&svc);
+        svc.clear_cells(Rect {
+            start_row: 120,
+            stop_row: 200,
+            start_col: 0,
+            stop_col: 1,
+        });
+        check(&svc);
+        svc.move_range(
+            Rect {
+                start_row: 0,
+                stop_row: 10,
+                start_col: 0,
+                stop_col: 1,
+            },
+            10,
+            1,
+        )
+        .unwrap();
+        check(&svc);
+        svc.insert_rows(0, 3).unwrap();
+        assert_eq!("=SUM(A:A)", svc.get_cell(3, 2).unwrap().value);
+        assert_eq!(
+            aggregate_of(&svc, range(0, 299)),
+            svc.aggregate(&range(0, 299))
+        );
+
+        // Errors in a range are reported like when its cells are read
+        svc.insert_cells(&vec![Cell::new(50, 0, "=A51".to_string())])
+            .unwrap();
+        assert!(display_value(&svc, 3, 2).starts_with('#'));
+    }
+
+    #[test]
+    fn test_aggregate_rounding() {
+        let mut svc = MemoryCellsService::new(100, 3);
+        svc.insert_cells(&vec![
+            Cell::new(0, 1, "1".to_string()),
+            Cell::new(0, 2, "=SUM(B1:B64)".to_string()),
+            Cell::new(1, 2, "=SUM(B1:B2)".to_string()),
+        ])
+        .unwrap();
+        // A large value taken out of the block's sum doesn't leave its rounding error behind
+        svc.insert_cells(&vec![Cell::new(1, 1, "1e17".to_string())])
+            .unwrap();
+        svc.insert_cells(&vec![Cell::new(1, 1, "0".to_string())])
+            .unwrap();
+        assert_eq!("1", display_value(&svc, 0, 2));
+        assert_eq!("1", display_value(&svc, 1, 2));
+    }
+}
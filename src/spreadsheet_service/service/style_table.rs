@@ -0,0 +1,36 @@
+use super::super::models::Style;
+use std::collections::HashMap;
+
+// StyleTable stores each distinct style of a table once, so cells only hold the id of
+// their style. The default style always has id 0.
+pub struct StyleTable {
+    styles: Vec<Style>,
+    ids: HashMap<Style, u32>,
+}
+
+impl StyleTable {
+    pub fn new() -> Self {
+        let mut ids = HashMap::new();
+        ids.insert(Style::default(), 0);
+        StyleTable {
+            styles: vec![Style::default()],
+            ids,
+        }
+    }
+
+    // id returns the id of the provided style, adding it to the table if it's new
+    pub fn id(&mut self, style: Style) -> u32 {
+        if let Some(id) = self.ids.get(&style) {
+            return *id;
+        }
+        let id = self.styles.len() as u32;
+        self.styles.push(style.clone());
+        self.ids.insert(style, id);
+        id
+    }
+
+    // get returns the style with the provided id, or the default style for unknown ids
+    pub fn get(&self, id: u32) -> &Style {
+        self.styles.get(id as usize).unwrap_or(&self.styles[0])
+    }
+}
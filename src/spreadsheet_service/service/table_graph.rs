@@ -0,0 +1,138 @@
+use super::super::models;
+use rstar::{RTree, RTreeObject, AABB};
+use std::collections::HashMap;
+
+// TableGraph tracks references which point from a cell in one table into another table.
+// References within a single table are tracked by that table's FormulaGraph.
+pub struct TableGraph {
+    // R-trees of references, keyed by the name of the table they point into
+    rts: HashMap<String, RTree<TableRefNode>>,
+
+    dependencies_map: HashMap<models::TableCellLocation, Vec<(String, models::CellRange)>>,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+struct TableRefNode {
+    cell: models::TableCellLocation,
+    points_to: models::CellRange,
+}
+
+impl RTreeObject for TableRefNode {
+    type Envelope = AABB<models::CellLocation>;
+    fn envelope(&self) -> Self::Envelope {
+        AABB::from_corners(
+            models::CellLocation {
+                row: self.points_to.start_row,
+                col: self.points_to.start_col,
+            },
+            models::CellLocation {
+                row: self.points_to.stop_row,
+                col: self.points_to.stop_col,
+            },
+        )
+    }
+}
+
+impl TableGraph {
+    pub fn new() -> TableGraph {
+        TableGraph {
+            rts: HashMap::new(),
+            dependencies_map: HashMap::new(),
+        }
+    }
+
+    // set_dependencies replaces the references the provided cell makes into other tables.
+    pub fn set_dependencies(
+        &mut self,
+        cell: models::TableCellLocation,
+        dependencies: Vec<(String, models::CellRange)>,
+    ) {
+        if let Some(existing) = self.dependencies_map.remove(&cell) {
+            for (table, range) in existing {
+                if let Some(rt) = self.rts.get_mut(&table) {
+                    rt.remove(&TableRefNode {
+                        cell: cell.clone(),
+                        points_to: range,
+                    });
+                }
+            }
+        }
+        if dependencies.is_empty() {
+            return;
+        }
+
+        for (table, range) in &dependencies {
+            self.rts
+                .entry(table.clone())
+                .or_default()
+                .insert(TableRefNode {
+                    cell: cell.clone(),
+                    points_to: *range,
+                });
+        }
+        self.dependencies_map.insert(cell, dependencies);
+    }
+
+    // dependents_in returns the cells in other tables whose formulas reference any cell of
+    // the provided rectangle of the given table.
+    pub fn dependents_in(&self, table: &str, r: &models::Rect) -> Vec<models::TableCellLocation> {
+        let rt = match self.rts.get(table) {
+            Some(rt) if r.stop_row > r.start_row && r.stop_col > r.start_col => rt,
+            _ => return vec![],
+        };
+        let envelope = AABB::from_corners(
+            models::CellLocation {
+                row: r.start_row,
+                col: r.start_col,
+            },
+            models::CellLocation {
+                row: r.stop_row - 1,
+                col: r.stop_col - 1,
+            },
+        );
+        let mut ret: Vec<models::TableCellLocation> = vec![];
+        for n in rt.locate_in_envelope_intersecting(&envelope) {
+            if !ret.contains(&n.cell) {
+                ret.push(n.cell.clone());
+            }
+        }
+        ret
+    }
+
+    // dependents returns the cells in other tables whose formulas reference the provided
+    // cell, either directly or through a range containing it.
+    pub fn dependents(&self, cell: &models::TableCellLocation) -> Vec<models::TableCellLocation> {
+        match self.rts.get(&cell.table) {
+            Some(rt) => rt
+                .locate_in_envelope_intersecting(&AABB::from_point(cell.loc))
+                .map(|n| n.cell.clone())
+                .collect(),
+            None => vec![],
+        }
+    }
+
+    // dependencies returns the ranges of other tables the cell's formula references
+    pub fn dependencies(
+        &self,
+        cell: &models::TableCellLocation,
+    ) -> Vec<(String, models::CellRange)> {
+        self.dependencies_map.get(cell).cloned().unwrap_or_default()
+    }
+
+    // dependencies_in returns the cells of the provided range of the given table whose
+    // formulas reference other tables
+    pub fn dependencies_in(
+        &self,
+        table: &str,
+        r: &models::CellRange,
+    ) -> Vec<models::TableCellLocation> {
+        let mut ret: Vec<models::TableCellLocation> = self
+            .dependencies_map
+            .keys()
+            .filter(|c| c.table == table && r.contains(&c.loc))
+            .cloned()
+            .collect();
+        ret.sort_by_key(|c| c.loc);
+        ret
+    }
+}
@@ -0,0 +1,33 @@
+use std::collections::HashSet;
+use std::hash::Hash;
+
+// trace walks references breadth first from the nodes one reference away from the traced
+// cell, with next returning the nodes one reference further. Each node reached within
+// max_depth references, 0 meaning no limit, is returned once along with the fewest
+// references it's reached through, in the order the nodes are found.
+pub fn trace<N: Clone + Eq + Hash>(
+    first: Vec<N>,
+    max_depth: u32,
+    next: impl Fn(&N) -> Vec<N>,
+) -> Vec<(N, u32)> {
+    let mut seen = HashSet::new();
+    let mut ret = vec![];
+    let mut level: Vec<N> = first
+        .into_iter()
+        .filter(|n| seen.insert(n.clone()))
+        .collect();
+    let mut depth = 1;
+    while !level.is_empty() {
+        ret.extend(level.iter().map(|n| (n.clone(), depth)));
+        if depth == max_depth {
+            break;
+        }
+        level = level
+            .iter()
+            .flat_map(&next)
+            .filter(|n| seen.insert(n.clone()))
+            .collect();
+        depth += 1;
+    }
+    ret
+}
@@ -0,0 +1,280 @@
+use super::super::models;
+use super::super::models::context::EvalContext;
+use super::super::models::{Comparison, Criteria, ValidationRule};
+use super::super::parser;
+use super::super::parser::{ASTNode, EvalResult, Locale};
+use super::conditional_format::{compare, rule_value};
+use std::collections::HashSet;
+
+// validate returns an error describing why the validation rule can't be used
+pub fn validate(rule: &ValidationRule) -> Result<(), String> {
+    let valid_rect = |r: &models::Rect| {
+        r.start_row >= 0 && r.start_col >= 0 && r.stop_row > r.start_row && r.stop_col > r.start_col
+    };
+    if !valid_rect(&rule.range) {
+        return Err(format!(
+            "invalid range for validation rule: {:?}",
+            rule.range
+        ));
+    }
+    match &rule.criteria {
+        Criteria::List(values) if values.is_empty() => {
+            Err("list validation needs at least one value".to_owned())
+        }
+        Criteria::List(_) => Ok(()),
+        Criteria::ListFromRange(r) if !valid_rect(r) => {
+            Err(format!("invalid range for list validation: {:?}", r))
+        }
+        Criteria::ListFromRange(_) => Ok(()),
+        Criteria::Number { comparison, values }
+        | Criteria::Date { comparison, values }
+        | Criteria::TextLength { comparison, values } => {
+            if values.len() != comparison.num_values() {
+                return Err(format!(
+                    "{:?} compares against {} values",
+                    comparison,
+                    comparison.num_values()
+                ));
+            }
+            values
+                .iter()
+                .filter(|v| v.starts_with('='))
+                .try_for_each(|v| validate_formula(v))
+        }
+        Criteria::Formula(formula) => validate_formula(formula),
+    }
+}
+
+fn validate_formula(formula: &str) -> Result<(), String> {
+    if !formula.starts_with('=') {
+        return Err(format!(
+            "validation formula must start with '=': {}",
+            formula
+        ));
+    }
+    match parser::parse(formula) {
+        ASTNode::ParseError(e) => Err(e),
+        _ => Ok(()),
+    }
+}
+
+// accepted_cells returns the provided cells without those the validation errors reject
+pub fn accepted_cells(
+    cells: &[models::Cell],
+    errors: &[models::ValidationError],
+) -> Vec<models::Cell> {
+    let rejected: HashSet<models::CellLocation> = errors
+        .iter()
+        .filter(|e| e.rejected)
+        .map(|e| models::CellLocation {
+            row: e.row,
+            col: e.col,
+        })
+        .collect();
+    cells
+        .iter()
+        .filter(|c| !rejected.contains(&c.loc()))
+        .cloned()
+        .collect()
+}
+
+// check returns the message describing why the value entered in the cell doesn't meet
+// the criteria of the rule, or None when it does.
+pub fn check(
+    rule: &ValidationRule,
+    cell: &models::Cell,
+    value: &EvalResult,
+    ctx: &dyn EvalContext,
+    locale: &Locale,
+) -> Option<String> {
+    let valid = match &rule.criteria {
+        Criteria::List(values) => values
+            .iter()
+            .any(|v| compare(value, Comparison::Equal, &[rule_value(v, locale)])),
+        Criteria::ListFromRange(r) => list_range_values(r, ctx)
+            .iter()
+            .any(|v| compare(value, Comparison::Equal, std::slice::from_ref(v))),
+        Criteria::Number { comparison, values } => match value {
+            EvalResult::Numeric(_) => compare(value, *comparison, &bounds(values, ctx, locale)),
+            _ => false,
+        },
+        Criteria::Date { comparison, values } => match value {
+            EvalResult::Numeric(n) if *n >= 0f64 => {
+                compare(value, *comparison, &bounds(values, ctx, locale))
+            }
+            _ => false,
+        },
+        Criteria::TextLength { comparison, values } => {
+            let len = parser::format_value(value, "", locale).chars().count();
+            compare(
+                &EvalResult::Numeric(len as f64),
+                *comparison,
+                &bounds(values, ctx, locale),
+            )
+        }
+        Criteria::Formula(formula) => {
+            let ast = parser::offset_refs(
+                &parser::parse(formula),
+                cell.row - rule.range.start_row,
+                cell.col - rule.range.start_col,
+            );
+            let candidate = Candidate { ctx, cell, value };
+            match parser::evaluate(ast, &candidate) {
+                models::Value::Bool(b) => b,
+                models::Value::Number(n) => n != 0f64,
+                _ => false,
+            }
+        }
+    };
+    match valid {
+        true => None,
+        false if !rule.message.is_empty() => Some(rule.message.clone()),
+        false => Some(describe(&rule.criteria)),
+    }
+}
+
+// list_values returns the values allowed by a list rule, in the order they're listed
+pub fn list_values(rule: &ValidationRule, ctx: &dyn EvalContext) -> Option<Vec<String>> {
+    match &rule.criteria {
+        Criteria::List(values) => Some(values.clone()),
+        Criteria::ListFromRange(r) => {
+            let mut ret: Vec<String> = vec![];
+            for c in ctx.get_cells(r.clone()) {
+                if !c.display_value.is_empty() && !ret.contains(&c.display_value) {
+                    ret.push(c.display_value);
+                }
+            }
+            Some(ret)
+        }
+        _ => None,
+    }
+}
+
+fn list_range_values(r: &models::Rect, ctx: &dyn EvalContext) -> Vec<EvalResult> {
+    let r = r.clamp(ctx.num_rows(), ctx.num_cols());
+    let mut ret = vec![];
+    for row in r.start_row..r.stop_row {
+        for col in r.start_col..r.stop_col {
+            if let Some(v) = ctx.get_value(row, col) {
+                ret.push(v);
+            }
+        }
+    }
+    ret
+}
+
+// bounds reads the values a rule compares against, evaluating those which are formulas
+fn bounds(values: &[String], ctx: &dyn EvalContext, locale: &Locale) -> Vec<EvalResult> {
+    values
+        .iter()
+        .map(|v| match v.starts_with('=') {
+            true => match parser::evaluate(parser::parse(v), ctx) {
+                models::Value::Number(n) | models::Value::Date(n) => EvalResult::Numeric(n),
+                models::Value::Bool(b) => EvalResult::Bool(b),
+                models::Value::Text(s) => EvalResult::NonNumeric(s),
+                _ => EvalResult::NonNumeric("".to_owned()),
+            },
+            false => rule_value(v, locale),
+        })
+        .collect()
+}
+
+// describe returns the default message of a rule, e.g. "value must be a number between 1
+// and 10"
+fn describe(criteria: &Criteria) -> String {
+    let comparison = |comparison: &Comparison, values: &[String]| {
+        let op = match comparison {
+            Comparison::Equal => "equal to",
+            Comparison::NotEqual => "not equal to",
+            Comparison::Greater => "greater than",
+            Comparison::GreaterOrEqual => "greater than or equal to",
+            Comparison::Less => "less than",
+            Comparison::LessOrEqual => "less than or equal to",
+            Comparison::Between => "between",
+            Comparison::NotBetween => "not between",
+        };
+        format!("{} {}", op, values.join(" and "))
+    };
+    match criteria {
+        Criteria::List(values) => format!("value must be one of: {}", values.join(", ")),
+        Criteria::ListFromRange(_) => "value must be one of the listed values".to_owned(),
+        Criteria::Number {
+            comparison: c,
+            values,
+        } => {
+            format!("value must be a number {}", comparison(c, values))
+        }
+        Criteria::Date {
+            comparison: c,
+            values,
+        } => {
+            format!("value must be a date {}", comparison(c, values))
+        }
+        Criteria::TextLength {
+            comparison: c,
+            values,
+        } => {
+            format!("text length must be {}", comparison(c, values))
+        }
+        Criteria::Formula(formula) => format!("value must satisfy {}", formula),
+    }
+}
+
+// Candidate evaluates formulas as if the value being validated was already stored
+struct Candidate<'a> {
+    ctx: &'a dyn EvalContext,
+    cell: &'a models::Cell,
+    value: &'a EvalResult,
+}
+
+impl<'a> Candidate<'a> {
+    fn is_candidate(&self, row: i32, col: i32) -> bool {
+        self.cell.row == row && self.cell.col == col
+    }
+}
+
+impl<'a> EvalContext for Candidate<'a> {
+    fn get_cell(&self, row: i32, col: i32) -> Option<models::Cell> {
+        match self.is_candidate(row, col) {
+            true => Some(self.cell.clone()),
+            false => self.ctx.get_cell(row, col),
+        }
+    }
+
+    fn get_value(&self, row: i32, col: i32) -> Option<EvalResult> {
+        match self.is_candidate(row, col) {
+            true => Some(self.value.clone()),
+            false => self.ctx.get_value(row, col),
+        }
+    }
+
+    fn get_cells(&self, rect: models::Rect) -> Vec<models::Cell> {
+        let contains = rect.contains(self.cell.row, self.cell.col);
+        let mut cells = self.ctx.get_cells(rect);
+        cells.retain(|c| !self.is_candidate(c.row, c.col));
+        if contains {
+            cells.push(self.cell.clone());
+        }
+        cells
+    }
+
+    fn num_rows(&self) -> i32 {
+        self.ctx.num_rows()
+    }
+
+    fn num_cols(&self) -> i32 {
+        self.ctx.num_cols()
+    }
+
+    fn random(&self) -> f64 {
+        self.ctx.random()
+    }
+
+    fn table_name(&self) -> &str {
+        self.ctx.table_name()
+    }
+
+    fn table(&self, name: &str) -> Option<Box<dyn EvalContext + '_>> {
+        self.ctx.table(name)
+    }
+}
@@ -0,0 +1,1144 @@
+use super::super::models;
+use super::super::models::context::EvalContext;
+use super::super::parser;
+use super::super::parser::EvalResult;
+use super::graph;
+use super::random::Random;
+use super::recalc;
+use super::recalc::Recalc;
+use super::service::{CellsService, MemoryCellsService};
+use super::table_graph::TableGraph;
+use super::trace;
+use super::validation;
+use std::collections::{HashMap, HashSet};
+use std::sync::Arc;
+
+// Workbook holds a set of named tables whose formulas may reference cells in one another,
+// e.g. ='Budget 2024'!B4 or =SUM(Costs!A:A).
+pub struct Workbook {
+    // dimensions used for newly created tables
+    num_rows: i32,
+    num_cols: i32,
+
+    tables: HashMap<String, MemoryCellsService>,
+    table_graph: TableGraph,
+    // generator used by RAND and RANDBETWEEN, shared with the tables
+    random: Arc<Random>,
+    // the number of threads formulas are recomputed with
+    recalc_threads: usize,
+}
+
+// WorkbookContext evaluates formulas against a single table of a workbook while
+// resolving table-qualified references against the other tables.
+pub struct WorkbookContext<'a> {
+    tables: &'a HashMap<String, MemoryCellsService>,
+    table: String,
+    random: &'a Random,
+}
+
+impl EvalContext for WorkbookContext<'_> {
+    fn get_cell(&self, row: i32, col: i32) -> Option<models::Cell> {
+        self.tables
+            .get(&self.table)
+            .and_then(|t| t.get_cell(row, col))
+    }
+
+    fn get_value(&self, row: i32, col: i32) -> Option<EvalResult> {
+        self.tables
+            .get(&self.table)
+            .and_then(|t| t.get_value(row, col))
+    }
+
+    fn get_cells(&self, rect: models::Rect) -> Vec<models::Cell> {
+        match self.tables.get(&self.table) {
+            Some(t) => <_ as CellsService>::get_cells(t, rect),
+            None => vec![],
+        }
+    }
+
+    fn num_rows(&self) -> i32 {
+        self.tables
+            .get(&self.table)
+            .map_or(0, EvalContext::num_rows)
+    }
+
+    fn aggregate(&self, range: models::CellRange) -> Option<models::Aggregate> {
+        self.tables
+            .get(&self.table)
+            .and_then(|t| EvalContext::aggregate(t, range))
+    }
+
+    fn num_cols(&self) -> i32 {
+        self.tables
+            .get(&self.table)
+            .map_or(0, EvalContext::num_cols)
+    }
+
+    fn random(&self) -> f64 {
+        self.random.next_f64()
+    }
+
+    fn table_name(&self) -> &str {
+        &self.table
+    }
+
+    fn table(&self, name: &str) -> Option<Box<dyn EvalContext + '_>> {
+        if !self.tables.contains_key(name) {
+            return None;
+        }
+        Some(Box::new(WorkbookContext {
+            tables: self.tables,
+            table: name.to_owned(),
+            random: self.random,
+        }))
+    }
+}
+
+impl Workbook {
+    pub fn new(num_rows: i32, num_cols: i32) -> Self {
+        Workbook {
+            num_rows,
+            num_cols,
+            tables: HashMap::new(),
+            table_graph: TableGraph::new(),
+            random: Arc::new(Random::from_time()),
+            recalc_threads: recalc::default_threads(),
+        }
+    }
+
+    // set_recalc_threads sets the number of threads formulas are recomputed with, see
+    // MemoryCellsService::set_recalc_threads.
+    pub fn set_recalc_threads(&mut self, threads: usize) {
+        self.recalc_threads = threads.max(1);
+    }
+
+    pub fn create_table_if_not_exists(&mut self, table_id: &str) {
+        let (num_rows, num_cols) = (self.num_rows, self.num_cols);
+        let random = &self.random;
+        self.tables
+            .entry(table_id.to_owned())
+            .or_insert_with(|| MemoryCellsService::with_random(num_rows, num_cols, random.clone()));
+    }
+
+    // insert_cells inserts the provided cells into the given table and recomputes every
+    // cell which depends on them, including cells in other tables. The returned cells
+    // are those of the given table whose display value was recomputed, along with the
+    // validation errors of the provided cells, see validate_cells.
+    #[allow(clippy::type_complexity)]
+    pub fn insert_cells(
+        &mut self,
+        table_id: &str,
+        cells: &[models::Cell],
+    ) -> Result<(Vec<models::Cell>, Vec<models::ValidationError>), parser::Error> {
+        self.create_table_if_not_exists(table_id);
+        let t = &self.tables[table_id];
+        let cells: Vec<models::Cell> = cells.iter().map(|c| t.input_cell(c)).collect();
+        let errors = t.validation_errors(&cells, &self.context(table_id));
+        let cells = validation::accepted_cells(&cells, &errors);
+        Ok((self.store_cells(table_id, &cells), errors))
+    }
+
+    // copy_range pastes the cells of the given table's source rectangle at the provided
+    // row and column, see CellsService::copy_range. The returned cells are the pasted
+    // cells along with the recomputed cells of the given table.
+    pub fn copy_range(
+        &mut self,
+        table_id: &str,
+        source: models::Rect,
+        row: i32,
+        col: i32,
+        mode: models::CopyMode,
+    ) -> Result<Vec<models::Cell>, parser::Error> {
+        self.create_table_if_not_exists(table_id);
+        let cells = self.tables[table_id].copied_cells(&source, row, col, mode)?;
+        let recomputed = self.store_cells(table_id, &cells);
+        let pasted: Vec<models::CellLocation> = cells.iter().map(|c| c.loc()).collect();
+        let t = self.tables.get_mut(table_id).unwrap();
+        Ok(t.moved_cells(&pasted, recomputed))
+    }
+
+    // fill extends the cells of the given table's source rectangle over the target
+    // rectangle, see CellsService::fill. The returned cells are the filled cells along
+    // with the recomputed cells of the given table.
+    pub fn fill(
+        &mut self,
+        table_id: &str,
+        source: models::Rect,
+        target: models::Rect,
+    ) -> Result<Vec<models::Cell>, parser::Error> {
+        self.create_table_if_not_exists(table_id);
+        let cells = self.tables[table_id].filled_cells(&source, &target)?;
+        let recomputed = self.store_cells(table_id, &cells);
+        let filled: Vec<models::CellLocation> = cells.iter().map(|c| c.loc()).collect();
+        let t = self.tables.get_mut(table_id).unwrap();
+        Ok(t.moved_cells(&filled, recomputed))
+    }
+
+    // store_cells stores the provided cells of the given table as they are and recomputes
+    // every cell which depends on them, returning the recomputed cells of the table.
+    fn store_cells(&mut self, table_id: &str, cells: &[models::Cell]) -> Vec<models::Cell> {
+        let mut inserted = vec![];
+        for cell in cells {
+            self.set_cell(table_id, cell);
+            self.update_dependencies(table_id, cell, vec![]);
+            inserted.push(models::TableCellLocation {
+                table: table_id.to_owned(),
+                loc: cell.loc(),
+            });
+        }
+
+        // Every recalculation also recomputes the volatile cells of all tables
+        let found: HashSet<models::TableCellLocation> = inserted.iter().cloned().collect();
+        inserted.extend(
+            self.volatile_cells()
+                .into_iter()
+                .filter(|v| !found.contains(v)),
+        );
+        self.evaluate_cells(table_id, inserted)
+    }
+
+    // clear_cells removes the values of the given table's cells in the provided rectangle
+    // and recomputes every cell which depends on them, including cells in other tables.
+    // The returned cells are the cleared cells along with the recomputed cells of the
+    // given table.
+    pub fn clear_cells(&mut self, table_id: &str, r: models::Rect) -> Vec<models::Cell> {
+        self.create_table_if_not_exists(table_id);
+        let cleared = self.tables.get_mut(table_id).unwrap().remove_cells(r);
+        let mut cells = vec![];
+        for loc in &cleared {
+            let cell_loc = models::TableCellLocation {
+                table: table_id.to_owned(),
+                loc: *loc,
+            };
+            self.table_graph.set_dependencies(cell_loc.clone(), vec![]);
+            cells.push(cell_loc);
+        }
+
+        // Every recalculation also recomputes the volatile cells of all tables
+        let found: HashSet<models::TableCellLocation> = cells.iter().cloned().collect();
+        cells.extend(
+            self.volatile_cells()
+                .into_iter()
+                .filter(|v| !found.contains(v)),
+        );
+        let recomputed = self.evaluate_cells(table_id, cells);
+        let mut ret_cells: Vec<models::Cell> = cleared
+            .iter()
+            .map(|loc| self.tables[table_id].stored_cell(loc.row, loc.col))
+            .collect();
+        ret_cells.extend(recomputed);
+        ret_cells
+    }
+
+    // insert_rows inserts count empty rows into the given table before the row at the
+    // provided index. References to the moved cells follow them, including those made from
+    // other tables. The returned cells are those of the given table which moved or were
+    // recomputed, see CellsService::insert_rows.
+    pub fn insert_rows(
+        &mut self,
+        table_id: &str,
+        at: i32,
+        count: i32,
+    ) -> Result<Vec<models::Cell>, parser::Error> {
+        self.shift(table_id, models::Axis::Rows, at, count)
+    }
+
+    // delete_rows deletes count rows of the given table starting at the provided index.
+    // References to the deleted cells become #REF!, including those made from other tables.
+    pub fn delete_rows(
+        &mut self,
+        table_id: &str,
+        at: i32,
+        count: i32,
+    ) -> Result<Vec<models::Cell>, parser::Error> {
+        self.shift(table_id, models::Axis::Rows, at, -count)
+    }
+
+    // insert_cols inserts columns into the given table like insert_rows inserts rows
+    pub fn insert_cols(
+        &mut self,
+        table_id: &str,
+        at: i32,
+        count: i32,
+    ) -> Result<Vec<models::Cell>, parser::Error> {
+        self.shift(table_id, models::Axis::Cols, at, count)
+    }
+
+    // delete_cols deletes columns of the given table like delete_rows deletes rows
+    pub fn delete_cols(
+        &mut self,
+        table_id: &str,
+        at: i32,
+        count: i32,
+    ) -> Result<Vec<models::Cell>, parser::Error> {
+        self.shift(table_id, models::Axis::Cols, at, -count)
+    }
+
+    // move_range moves the cells of the given table's source rectangle so its top left cell
+    // lands on the provided row and column. Formulas of every table referencing the moved
+    // cells follow them, see CellsService::move_range. The returned cells are those of the
+    // source and destination along with the recomputed cells of the given table.
+    pub fn move_range(
+        &mut self,
+        table_id: &str,
+        source: models::Rect,
+        row: i32,
+        col: i32,
+    ) -> Result<Vec<models::Cell>, parser::Error> {
+        self.create_table_if_not_exists(table_id);
+        let (rows, cols) = (row - source.start_row, col - source.start_col);
+        let destination = source.offset(rows, cols);
+        let mut referrers = self.table_graph.dependents_in(table_id, &source);
+        for r in self.table_graph.dependents_in(table_id, &destination) {
+            if !referrers.contains(&r) {
+                referrers.push(r);
+            }
+        }
+        let formulas = self
+            .tables
+            .get_mut(table_id)
+            .unwrap()
+            .move_cells(&source, row, col)?;
+
+        let mut moved = source.locations();
+        moved.extend(destination.locations());
+        for loc in &moved {
+            let cell_loc = models::TableCellLocation {
+                table: table_id.to_owned(),
+                loc: *loc,
+            };
+            self.table_graph.set_dependencies(cell_loc, vec![]);
+        }
+
+        let mut cells: Vec<models::TableCellLocation> = formulas
+            .into_iter()
+            .map(|loc| models::TableCellLocation {
+                table: table_id.to_owned(),
+                loc,
+            })
+            .collect();
+        for mut r in referrers {
+            // Formulas of the moved table follow the moved block or are replaced by it
+            if r.table == table_id && source.contains(r.loc.row, r.loc.col) {
+                r.loc.row += rows;
+                r.loc.col += cols;
+            } else if r.table == table_id && destination.contains(r.loc.row, r.loc.col) {
+                continue;
+            }
+            if let Some(t) = self.tables.get_mut(&r.table) {
+                t.rewrite_formulas_at(&[r.loc], |f| {
+                    parser::move_refs(f, "", table_id, &source, rows, cols)
+                });
+            }
+            if !cells.contains(&r) {
+                cells.push(r);
+            }
+        }
+        for cell_loc in &cells {
+            let cell = self.tables[&cell_loc.table].stored_cell(cell_loc.loc.row, cell_loc.loc.col);
+            if cell.is_formula() {
+                self.update_dependencies(&cell_loc.table, &cell, vec![]);
+            }
+        }
+
+        cells.extend(moved.iter().map(|loc| models::TableCellLocation {
+            table: table_id.to_owned(),
+            loc: *loc,
+        }));
+        let recomputed = self.evaluate_cells(table_id, cells);
+        let t = self.tables.get_mut(table_id).unwrap();
+        Ok(t.moved_cells(&moved, recomputed))
+    }
+
+    // recalculate recomputes the volatile cells of every table along with the stale cells
+    // of the given table, including the stale cells of other tables they read, and every
+    // cell depending on them. The returned cells are those of the given table which were
+    // recomputed.
+    pub fn recalculate(&mut self, table_id: &str) -> Vec<models::Cell> {
+        let mut cells = self.volatile_cells();
+        cells.extend(self.stale_cells(table_id, None));
+        let order = recalc::order(&cells, |c| self.dependents(c));
+        self.evaluate_order(table_id, order, vec![])
+    }
+
+    // recalculate_range recomputes the stale cells of the given table's rectangle along
+    // with the stale cells, in any table, they read. Other cells are left stale. The
+    // returned cells are those of the given table which were recomputed.
+    pub fn recalculate_range(&mut self, table_id: &str, r: models::Rect) -> Vec<models::Cell> {
+        let cells = self.stale_cells(table_id, Some(&r));
+        let found: HashSet<models::TableCellLocation> = cells.iter().cloned().collect();
+        let order = recalc::order(&cells, |c| {
+            self.dependents(c)
+                .into_iter()
+                .filter(|d| found.contains(d))
+                .collect()
+        });
+        self.evaluate_order(table_id, order, vec![])
+    }
+
+    // set_calculation_mode sets when the given table's formulas are recomputed. Switching
+    // to automatic calculation recomputes its stale cells like recalculate does, and the
+    // recomputed cells of the given table are returned.
+    pub fn set_calculation_mode(
+        &mut self,
+        table_id: &str,
+        mode: models::CalculationMode,
+    ) -> Vec<models::Cell> {
+        self.create_table_if_not_exists(table_id);
+        let stale = self.stale_cells(table_id, None);
+        self.tables
+            .get_mut(table_id)
+            .unwrap()
+            .set_calculation_mode(mode);
+        match mode {
+            models::CalculationMode::Automatic => self.evaluate_cells(table_id, stale),
+            models::CalculationMode::Manual | models::CalculationMode::OnDemand => vec![],
+        }
+    }
+
+    // set_locale changes the locale of the given table, recomputing its values along with
+    // the cells in other tables depending on them. The returned cells are those of the
+    // given table which were recomputed.
+    pub fn set_locale(&mut self, table_id: &str, locale: parser::Locale) -> Vec<models::Cell> {
+        self.create_table_if_not_exists(table_id);
+        // The table's formulas are recomputed against the workbook so references to other
+        // tables resolve
+        let cells = self
+            .tables
+            .get_mut(table_id)
+            .unwrap()
+            .compile_locale(locale)
+            .into_iter()
+            .map(|loc| models::TableCellLocation {
+                table: table_id.to_owned(),
+                loc,
+            })
+            .collect();
+        self.evaluate_cells(table_id, cells)
+    }
+
+    // set_iterative_calculation sets whether reference cycles through the given table are
+    // computed by iteration, see IterativeCalculation. Cycles spanning several tables are
+    // only iterated when each of them enables it. The returned cells are the given table's
+    // recomputed formulas.
+    pub fn set_iterative_calculation(
+        &mut self,
+        table_id: &str,
+        settings: Option<models::IterativeCalculation>,
+    ) -> Result<Vec<models::Cell>, parser::Error> {
+        self.create_table_if_not_exists(table_id);
+        self.tables
+            .get_mut(table_id)
+            .unwrap()
+            .set_iterative_calculation(settings)?;
+        let formulas = self
+            .tables
+            .iter()
+            .flat_map(|(table, t)| {
+                t.formula_cells()
+                    .into_iter()
+                    .map(move |c| models::TableCellLocation {
+                        table: table.clone(),
+                        loc: c.loc(),
+                    })
+            })
+            .collect();
+        Ok(self.evaluate_cells(table_id, formulas))
+    }
+
+    // set_format sets the number format of the given table's cells in the provided
+    // rectangle, returning the cells whose display value changed.
+    pub fn set_format(
+        &mut self,
+        table_id: &str,
+        r: models::Rect,
+        format: &str,
+    ) -> Result<Vec<models::Cell>, parser::Error> {
+        self.create_table_if_not_exists(table_id);
+        self.tables.get_mut(table_id).unwrap().set_format(r, format)
+    }
+
+    // set_styles sets the named style fields of the given table's cells in the provided
+    // rectangle, returning the styled cells.
+    pub fn set_styles(
+        &mut self,
+        table_id: &str,
+        r: models::Rect,
+        style: &models::Style,
+        fields: &[String],
+    ) -> Result<Vec<models::Cell>, parser::Error> {
+        self.create_table_if_not_exists(table_id);
+        self.tables
+            .get_mut(table_id)
+            .unwrap()
+            .set_styles(r, style, fields)
+    }
+
+    // set_conditional_formats replaces the conditional formats of the given table,
+    // returning its cells whose effective style changed.
+    pub fn set_conditional_formats(
+        &mut self,
+        table_id: &str,
+        formats: Vec<models::ConditionalFormat>,
+    ) -> Result<Vec<models::Cell>, parser::Error> {
+        self.create_table_if_not_exists(table_id);
+        self.tables
+            .get_mut(table_id)
+            .unwrap()
+            .set_conditional_formats(formats)
+    }
+
+    pub fn conditional_formats(&self, table_id: &str) -> Vec<models::ConditionalFormat> {
+        match self.tables.get(table_id) {
+            Some(t) => t.conditional_formats().to_vec(),
+            None => vec![],
+        }
+    }
+
+    // set_validation_rules replaces the validation rules of the given table
+    pub fn set_validation_rules(
+        &mut self,
+        table_id: &str,
+        rules: Vec<models::ValidationRule>,
+    ) -> Result<(), parser::Error> {
+        self.create_table_if_not_exists(table_id);
+        self.tables
+            .get_mut(table_id)
+            .unwrap()
+            .set_validation_rules(rules)
+    }
+
+    pub fn validation_rules(&self, table_id: &str) -> Vec<models::ValidationRule> {
+        match self.tables.get(table_id) {
+            Some(t) => t.validation_rules().to_vec(),
+            None => vec![],
+        }
+    }
+
+    // export_graph returns the dependency graph of the given table's formulas, see
+    // CellsService::export_graph. The graph holds the cells and ranges of other tables
+    // referenced by or referencing the table's formulas, and its reference cycles are
+    // those found among its cells. The provided cell may belong to any table.
+    pub fn export_graph(
+        &self,
+        table_id: &str,
+        from: Option<models::TableCellLocation>,
+    ) -> models::DependencyGraph {
+        let cells = match self.tables.get(table_id) {
+            Some(t) => t
+                .formula_cells()
+                .iter()
+                .map(|c| models::TableCellLocation {
+                    table: table_id.to_owned(),
+                    loc: c.loc(),
+                })
+                .collect(),
+            None => vec![],
+        };
+        graph::export(
+            table_id,
+            cells,
+            from,
+            |c| self.precedents(c),
+            |table, r| {
+                let mut cells = self.table_graph.dependencies_in(table, r);
+                if let Some(t) = self.tables.get(table) {
+                    cells.extend(t.dependencies_in(r).into_iter().map(|loc| {
+                        models::TableCellLocation {
+                            table: table.to_owned(),
+                            loc,
+                        }
+                    }));
+                }
+                cells
+            },
+            |c| self.dependents(c),
+        )
+    }
+
+    // validate_cells checks the provided cells against the validation rules of the given
+    // table, returning an error for each cell whose value doesn't meet them. Formulas
+    // are evaluated against the workbook so rules can reference other tables.
+    pub fn validate_cells(
+        &self,
+        table_id: &str,
+        cells: &[models::Cell],
+    ) -> Vec<models::ValidationError> {
+        match self.tables.get(table_id) {
+            Some(t) => {
+                let cells: Vec<models::Cell> = cells.iter().map(|c| t.input_cell(c)).collect();
+                t.validation_errors(&cells, &self.context(table_id))
+            }
+            None => vec![],
+        }
+    }
+
+    // list_values returns the values which can be entered in a cell of the given table
+    // when a list rule applies to it.
+    pub fn list_values(&self, table_id: &str, row: i32, col: i32) -> Option<Vec<String>> {
+        self.tables.get(table_id)?.list_values(row, col)
+    }
+
+    // trace_precedents returns the ranges, in any table, the formula of the given table's
+    // cell references, followed by those the formulas within them reference in turn, see
+    // CellsService::trace_precedents.
+    pub fn trace_precedents(
+        &self,
+        table_id: &str,
+        row: i32,
+        col: i32,
+        max_depth: u32,
+    ) -> Vec<models::TracedRange> {
+        let first = self.precedents(&models::TableCellLocation {
+            table: table_id.to_owned(),
+            loc: models::CellLocation { row, col },
+        });
+        trace::trace(first, max_depth, |(table, r)| {
+            let mut cells = self.table_graph.dependencies_in(table, r);
+            if let Some(t) = self.tables.get(table) {
+                cells.extend(t.dependencies_in(r).into_iter().map(|loc| {
+                    models::TableCellLocation {
+                        table: table.clone(),
+                        loc,
+                    }
+                }));
+            }
+            cells.iter().flat_map(|c| self.precedents(c)).collect()
+        })
+        .into_iter()
+        .map(|((table, r), depth)| models::TracedRange {
+            table,
+            rect: r.to_rect(),
+            depth,
+        })
+        .collect()
+    }
+
+    // trace_dependents returns the cells, in any table, which reference the given table's
+    // cell, followed by those referencing them in turn, see CellsService::trace_dependents.
+    pub fn trace_dependents(
+        &self,
+        table_id: &str,
+        row: i32,
+        col: i32,
+        max_depth: u32,
+    ) -> Vec<models::TracedRange> {
+        let first = self.dependents(&models::TableCellLocation {
+            table: table_id.to_owned(),
+            loc: models::CellLocation { row, col },
+        });
+        trace::trace(first, max_depth, |c| self.dependents(c))
+            .into_iter()
+            .map(|(c, depth)| models::TracedRange {
+                table: c.table,
+                rect: c.loc.to_range().to_rect(),
+                depth,
+            })
+            .collect()
+    }
+
+    // cycles returns the reference cycles the given table's formulas belong to, as the
+    // path leading from each of the table's cells in a cycle back to that cell, e.g.
+    // [A1, C3, A1] when A1 references C3 which references A1. The paths are ordered by
+    // their first cell.
+    pub fn cycles(&self, table_id: &str) -> Vec<Vec<models::TableCellLocation>> {
+        let cells: Vec<models::TableCellLocation> = match self.tables.get(table_id) {
+            Some(t) => t
+                .formula_cells()
+                .iter()
+                .map(|c| models::TableCellLocation {
+                    table: table_id.to_owned(),
+                    loc: c.loc(),
+                })
+                .collect(),
+            None => return vec![],
+        };
+        let mut ret = vec![];
+        for r in recalc::order(&cells, |c| self.dependents(c)) {
+            if let Recalc::Cycle(cycle) = r {
+                for c in cycle.iter().filter(|c| c.table == table_id) {
+                    ret.push(recalc::cycle_path(c, &cycle, |c| self.dependents(c)));
+                }
+            }
+        }
+        ret.sort_by_key(|path| path[0].loc);
+        ret
+    }
+
+    // style returns the style with the provided id in the given table
+    pub fn style(&self, table_id: &str, id: u32) -> models::Style {
+        match self.tables.get(table_id) {
+            Some(t) => t.style(id),
+            None => models::Style::default(),
+        }
+    }
+
+    // seed resets the generator used by RAND and RANDBETWEEN in every table, including
+    // those created afterwards
+    pub fn seed(&self, seed: u64) {
+        self.random.seed(seed);
+    }
+
+    // get_cells returns the cells of the given table located in the provided rectangle
+    pub fn get_cells(&self, table_id: &str, r: models::Rect) -> Vec<models::Cell> {
+        match self.tables.get(table_id) {
+            Some(t) => <_ as CellsService>::get_cells(t, r),
+            None => vec![],
+        }
+    }
+
+    // has_table returns whether a table with the given name exists
+    pub fn has_table(&self, table_id: &str) -> bool {
+        self.tables.contains_key(table_id)
+    }
+
+    // needs_recalc returns whether read_cells recomputes cells before reading the given
+    // table's rectangle, i.e. whether stale cells of a table calculated on demand are
+    // within it or feed it.
+    pub fn needs_recalc(&self, table_id: &str, r: &models::Rect) -> bool {
+        self.stale_cells(table_id, Some(r))
+            .iter()
+            .any(|c| self.has_calculation_mode(c, models::CalculationMode::OnDemand))
+    }
+
+    // read_cells returns the cells of the given table's rectangle like get_cells does, first
+    // recomputing the stale cells it holds along with the stale cells, in any table, they
+    // read. Cells depending on the stale formulas of a manually calculated table are left
+    // stale until it is recalculated.
+    pub fn read_cells(&mut self, table_id: &str, r: models::Rect) -> Vec<models::Cell> {
+        let cells = self.stale_cells(table_id, Some(&r));
+        let stale: HashSet<models::TableCellLocation> = cells.iter().cloned().collect();
+        let manual = cells
+            .iter()
+            .filter(|c| self.has_calculation_mode(c, models::CalculationMode::Manual))
+            .cloned()
+            .collect();
+        let blocked: HashSet<models::TableCellLocation> = trace::trace(manual, 0, |c| {
+            self.dependents(c)
+                .into_iter()
+                .filter(|d| stale.contains(d))
+                .collect()
+        })
+        .into_iter()
+        .map(|(c, _)| c)
+        .collect();
+        let cells: Vec<models::TableCellLocation> =
+            cells.into_iter().filter(|c| !blocked.contains(c)).collect();
+        if !cells.is_empty() {
+            let found: HashSet<models::TableCellLocation> = cells.iter().cloned().collect();
+            let order = recalc::order(&cells, |c| {
+                self.dependents(c)
+                    .into_iter()
+                    .filter(|d| found.contains(d))
+                    .collect()
+            });
+            self.evaluate_order(table_id, order, vec![]);
+        }
+        self.get_cells(table_id, r)
+    }
+
+    // context returns an EvalContext for evaluating formulas of the given table
+    pub fn context(&self, table_id: &str) -> WorkbookContext<'_> {
+        WorkbookContext {
+            tables: &self.tables,
+            table: table_id.to_owned(),
+            random: &self.random,
+        }
+    }
+
+    // evaluate_cells recomputes the provided cells and everything depending on them,
+    // returning the recomputed cells of the given table.
+    fn evaluate_cells(
+        &mut self,
+        table_id: &str,
+        cells: Vec<models::TableCellLocation>,
+    ) -> Vec<models::Cell> {
+        let order = recalc::order(&cells, |c| self.dependents(c));
+        let recomputed: HashSet<models::TableCellLocation> = order
+            .iter()
+            .flat_map(|r| match r {
+                Recalc::Cell(c) => vec![c.clone()],
+                Recalc::Cycle(cycle) => cycle.clone(),
+            })
+            .collect();
+        // Formulas of tables which aren't calculated automatically, along with the cells
+        // reading them or other stale cells, are left stale
+        let has_stale = self.tables.values().any(|t| t.has_stale());
+        let (order, deferred) = recalc::defer(
+            order,
+            |c| {
+                self.is_deferred_formula(c)
+                    || has_stale
+                        && self
+                            .stale_precedents(c)
+                            .iter()
+                            .any(|p| !recomputed.contains(p))
+            },
+            |c| self.dependents(c),
+        );
+        let mut stale = vec![];
+        for c in deferred {
+            if let Some(t) = self.tables.get_mut(&c.table) {
+                let cells = t.mark_stale(&[c.loc]);
+                if c.table == table_id {
+                    stale.extend(cells);
+                }
+            }
+        }
+        self.evaluate_order(table_id, order, stale)
+    }
+
+    // evaluate_order evaluates the steps of a recalculation in order, returning the given
+    // table's evaluated cells after the provided cells.
+    fn evaluate_order(
+        &mut self,
+        table_id: &str,
+        order: Vec<Recalc<models::TableCellLocation>>,
+        mut ret_cells: Vec<models::Cell>,
+    ) -> Vec<models::Cell> {
+        ret_cells.extend(
+            recalc::evaluate(self, order)
+                .into_iter()
+                .filter(|(c, _)| c.table == table_id)
+                .map(|(_, cell)| cell),
+        );
+        // Cells of other tables may have been recomputed too, changing their highlights
+        for (name, t) in self.tables.iter_mut() {
+            if name != table_id {
+                t.apply_conditional_formats();
+            }
+        }
+        match self.tables.get_mut(table_id) {
+            Some(t) => {
+                let ret_cells = t.with_conditional_formats(ret_cells);
+                t.localize_cells(ret_cells)
+            }
+            None => ret_cells,
+        }
+    }
+
+    // shift inserts or deletes rows or columns of the given table, adjusting the references
+    // made to its cells from every table before recomputing the formulas which moved or
+    // reference the moved cells, along with their dependents.
+    fn shift(
+        &mut self,
+        table_id: &str,
+        axis: models::Axis,
+        at: i32,
+        count: i32,
+    ) -> Result<Vec<models::Cell>, parser::Error> {
+        self.create_table_if_not_exists(table_id);
+        let t = &self.tables[table_id];
+        let shift = t.new_shift(axis, at, count)?;
+
+        // Only the references between tables made from or to the moved cells change
+        let moved = shift.moved_rect(EvalContext::num_rows(t), EvalContext::num_cols(t));
+        let mut cells: HashSet<models::TableCellLocation> = self
+            .table_graph
+            .dependents_in(table_id, &moved)
+            .into_iter()
+            .collect();
+        cells.extend(self.table_graph.dependencies_in(
+            table_id,
+            &models::CellRange {
+                start_row: moved.start_row,
+                start_col: moved.start_col,
+                stop_row: moved.stop_row - 1,
+                stop_col: moved.stop_col - 1,
+            },
+        ));
+        for c in &cells {
+            self.table_graph.set_dependencies(c.clone(), vec![]);
+        }
+
+        let t = self.tables.get_mut(table_id).unwrap();
+        let (shifted, formulas) = t.shift_cells(&shift);
+        let mut cells: Vec<models::TableCellLocation> = cells
+            .into_iter()
+            .filter_map(|c| match c.table == table_id {
+                true => Some(models::TableCellLocation {
+                    table: c.table,
+                    loc: shift.location(c.loc)?,
+                }),
+                false => Some(c),
+            })
+            .chain(formulas.into_iter().map(|loc| models::TableCellLocation {
+                table: table_id.to_owned(),
+                loc,
+            }))
+            .collect::<HashSet<_>>()
+            .into_iter()
+            .collect();
+        cells.sort_by(|a, b| (&a.table, a.loc).cmp(&(&b.table, b.loc)));
+
+        for c in &cells {
+            let t = match self.tables.get_mut(&c.table) {
+                Some(t) => t,
+                None => continue,
+            };
+            t.rewrite_formulas_at(&[c.loc], |f| parser::shift_refs(f, "", table_id, &shift));
+            if let Some(cell) = t.get_cell(c.loc.row, c.loc.col) {
+                self.update_dependencies(&c.table, &cell, vec![]);
+            }
+        }
+
+        // Every recalculation also recomputes the volatile cells of all tables
+        let found: HashSet<models::TableCellLocation> = cells.iter().cloned().collect();
+        let volatile: Vec<models::TableCellLocation> = self
+            .volatile_cells()
+            .into_iter()
+            .filter(|v| !found.contains(v))
+            .collect();
+        cells.extend(volatile);
+        let recomputed = self.evaluate_cells(table_id, cells);
+        let t = self.tables.get_mut(table_id).unwrap();
+        Ok(t.moved_cells(&shifted, recomputed))
+    }
+
+    // volatile_cells returns the volatile cells of every table, ordered by table name
+    fn volatile_cells(&self) -> Vec<models::TableCellLocation> {
+        let mut names: Vec<&String> = self.tables.keys().collect();
+        names.sort();
+        names
+            .into_iter()
+            .flat_map(|name| {
+                self.tables[name]
+                    .volatile_cells()
+                    .into_iter()
+                    .map(move |loc| models::TableCellLocation {
+                        table: name.clone(),
+                        loc,
+                    })
+            })
+            .collect()
+    }
+
+    fn set_cell(&mut self, table_id: &str, cell: &models::Cell) {
+        if let Some(t) = self.tables.get_mut(table_id) {
+            t.set_cell(cell);
+        }
+    }
+
+    // update_dependencies records the references the cell's formula makes, both within its
+    // own table and into others, along with any references resolved while evaluating it.
+    fn update_dependencies(
+        &mut self,
+        table_id: &str,
+        cell: &models::Cell,
+        dynamic_refs: Vec<(Option<String>, models::CellRange)>,
+    ) {
+        let (mut refs, mut table_refs, volatile) = match self
+            .tables
+            .get(table_id)
+            .and_then(|t| t.get_ast(cell.row, cell.col))
+        {
+            Some(formula) => (
+                parser::get_refs(formula),
+                parser::get_table_refs(formula),
+                parser::is_volatile(formula),
+            ),
+            None => (vec![], vec![], false),
+        };
+        for (table, r) in dynamic_refs {
+            match table {
+                Some(t) => table_refs.push((t, r)),
+                None => refs.push(r),
+            }
+        }
+        let table_refs = table_refs
+            .into_iter()
+            .map(|(table, mut r)| {
+                r.clamp(self.table_rows(&table));
+                (table, r)
+            })
+            .collect();
+
+        if let Some(t) = self.tables.get_mut(table_id) {
+            t.update_dependencies(cell, refs);
+            t.set_volatile(cell.loc(), volatile);
+        }
+        self.table_graph.set_dependencies(
+            models::TableCellLocation {
+                table: table_id.to_owned(),
+                loc: cell.loc(),
+            },
+            table_refs,
+        );
+    }
+
+    fn table_rows(&self, table_id: &str) -> i32 {
+        self.tables
+            .get(table_id)
+            .map_or(self.num_rows, EvalContext::num_rows)
+    }
+
+    // stale_cells returns the stale cells of the given table within the provided
+    // rectangle, or all of them without one, along with the stale cells of any table they
+    // read, directly or not.
+    fn stale_cells(
+        &self,
+        table_id: &str,
+        r: Option<&models::Rect>,
+    ) -> Vec<models::TableCellLocation> {
+        let first = match self.tables.get(table_id) {
+            Some(t) => t
+                .stale_cells()
+                .into_iter()
+                .filter(|c| match r {
+                    Some(r) => r.contains(c.row, c.col),
+                    None => true,
+                })
+                .map(|loc| models::TableCellLocation {
+                    table: table_id.to_owned(),
+                    loc,
+                })
+                .collect(),
+            None => vec![],
+        };
+        trace::trace(first, 0, |c| self.stale_precedents(c))
+            .into_iter()
+            .map(|(c, _)| c)
+            .collect()
+    }
+
+    // stale_precedents returns the stale cells, in any table, the provided cell's formula
+    // references
+    fn stale_precedents(
+        &self,
+        cell_loc: &models::TableCellLocation,
+    ) -> Vec<models::TableCellLocation> {
+        let mut ret = vec![];
+        for (table, range) in self.precedents(cell_loc) {
+            if let Some(t) = self.tables.get(&table) {
+                ret.extend(
+                    t.stale_in(&range)
+                        .into_iter()
+                        .map(|loc| models::TableCellLocation {
+                            table: table.clone(),
+                            loc,
+                        }),
+                );
+            }
+        }
+        ret
+    }
+
+    // is_deferred_formula returns whether the cell holds a formula of a table which isn't
+    // calculated automatically
+    fn is_deferred_formula(&self, cell_loc: &models::TableCellLocation) -> bool {
+        self.has_calculation_mode(cell_loc, models::CalculationMode::Manual)
+            || self.has_calculation_mode(cell_loc, models::CalculationMode::OnDemand)
+    }
+
+    // has_calculation_mode returns whether the cell holds a formula of a table calculated
+    // in the provided mode
+    fn has_calculation_mode(
+        &self,
+        cell_loc: &models::TableCellLocation,
+        mode: models::CalculationMode,
+    ) -> bool {
+        match self.tables.get(&cell_loc.table) {
+            Some(t) => t.calculation_mode() == mode && t.is_formula(cell_loc.loc),
+            None => false,
+        }
+    }
+
+    // precedents returns the ranges, in any table, the provided cell's formula references
+    fn precedents(&self, cell_loc: &models::TableCellLocation) -> Vec<(String, models::CellRange)> {
+        let mut ret: Vec<(String, models::CellRange)> = match self.tables.get(&cell_loc.table) {
+            Some(t) => t
+                .dependencies(cell_loc.loc)
+                .into_iter()
+                .map(|r| (cell_loc.table.clone(), r))
+                .collect(),
+            None => vec![],
+        };
+        ret.extend(self.table_graph.dependencies(cell_loc));
+        ret
+    }
+
+    // dependents returns the cells, in any table, which directly reference the provided cell
+    fn dependents(&self, cell_loc: &models::TableCellLocation) -> Vec<models::TableCellLocation> {
+        let mut ret = self.table_graph.dependents(cell_loc);
+        if let Some(t) = self.tables.get(&cell_loc.table) {
+            ret.extend(t.dependents(cell_loc.loc).into_iter().map(|loc| {
+                models::TableCellLocation {
+                    table: cell_loc.table.clone(),
+                    loc,
+                }
+            }));
+        }
+        ret
+    }
+}
+
+impl recalc::Formulas<models::TableCellLocation> for Workbook {
+    fn formula(&self, c: &models::TableCellLocation) -> Option<&parser::ASTNode> {
+        self.tables.get(&c.table)?.get_ast(c.loc.row, c.loc.col)
+    }
+
+    fn evaluate(
+        &self,
+        c: &models::TableCellLocation,
+        formula: &parser::ASTNode,
+    ) -> parser::Evaluation {
+        parser::evaluate_cell(formula.clone(), c.loc, &self.context(&c.table))
+    }
+
+    fn value(&self, c: &models::TableCellLocation) -> Option<EvalResult> {
+        self.tables.get(&c.table)?.get_value(c.loc.row, c.loc.col)
+    }
+
+    fn set_value(&mut self, c: &models::TableCellLocation, value: EvalResult) {
+        if let Some(t) = self.tables.get_mut(&c.table) {
+            t.set_value(c.loc.row, c.loc.col, value);
+        }
+    }
+
+    fn store_evaluation(
+        &mut self,
+        c: &models::TableCellLocation,
+        evaluation: parser::Evaluation,
+    ) -> Option<models::Cell> {
+        let t = self.tables.get_mut(&c.table)?;
+        let dynamic = parser::has_dynamic_refs(t.get_ast(c.loc.row, c.loc.col)?);
+        let eval_cell = t.store_value(c.loc, evaluation.value)?;
+        if dynamic {
+            self.update_dependencies(&c.table, &eval_cell, evaluation.dynamic_refs);
+        }
+        Some(eval_cell)
+    }
+
+    fn set_error(&mut self, c: &models::TableCellLocation, code: &str) -> Option<models::Cell> {
+        self.tables.get_mut(&c.table)?.store_error(c.loc, code)
+    }
+
+    fn dependents(&self, c: &models::TableCellLocation) -> Vec<models::TableCellLocation> {
+        Workbook::dependents(self, c)
+    }
+
+    // A cycle spanning several tables is only computed by iteration when each of them
+    // enables it, in which case the lowest of their limits apply
+    fn cycle_settings(
+        &self,
+        cycle: &[models::TableCellLocation],
+    ) -> Option<models::IterativeCalculation> {
+        cycle.iter().try_fold(None, |ret, c| {
+            let s = self.tables.get(&c.table)?.iterative_calculation()?;
+            Some(Some(match ret {
+                Some(models::IterativeCalculation {
+                    max_iterations,
+                    max_change,
+                }) => models::IterativeCalculation {
+                    max_iterations: s.max_iterations.min(max_iterations),
+                    max_change: s.max_change.min(max_change),
+                },
+                None => s,
+            }))
+        })?
+    }
+
+    fn threads(&self) -> usize {
+        self.recalc_threads
+    }
+}
@@ -0,0 +1,851 @@
+#[cfg(test)]
+mod tests {
+    pub use super::super::super::models::*;
+    pub use super::super::super::parser::Locale;
+    pub use super::super::workbook::Workbook;
+
+    fn display_value(wb: &Workbook, table: &str, row: i32, col: i32) -> String {
+        wb.get_cells(
+            table,
+            Rect {
+                start_row: row,
+                stop_row: row + 1,
+                start_col: col,
+                stop_col: col + 1,
+            },
+        )
+        .pop()
+        .map(|c| c.display_value)
+        .unwrap_or_default()
+    }
+
+    #[test]
+    fn test_cross_table_refs() {
+        let mut wb = Workbook::new(50, 26);
+
+        wb.insert_cells(
+            "Costs",
+            &[
+                Cell::new(0, 0, "10".to_string()),
+                Cell::new(1, 0, "5".to_string()),
+            ],
+        )
+        .unwrap();
+        wb.insert_cells("Budget 2024", &[Cell::new(3, 1, "=Costs!A2*2".to_string())])
+            .unwrap();
+        wb.insert_cells(
+            "Summary",
+            &[
+                Cell::new(0, 0, "=SUM(Costs!A:A)".to_string()),
+                Cell::new(1, 0, "='Budget 2024'!B4+1".to_string()),
+            ],
+        )
+        .unwrap();
+        assert_eq!("15", display_value(&wb, "Summary", 0, 0));
+        assert_eq!("11", display_value(&wb, "Summary", 1, 0));
+
+        // Editing Costs recalculates its dependents in the other tables
+        let (inserted, _) = wb
+            .insert_cells("Costs", &[Cell::new(1, 0, "7".to_string())])
+            .unwrap();
+        assert_eq!(1, inserted.len());
+        assert_eq!("17", display_value(&wb, "Summary", 0, 0));
+        assert_eq!("14", display_value(&wb, "Budget 2024", 3, 1));
+        assert_eq!("15", display_value(&wb, "Summary", 1, 0));
+    }
+
+    #[test]
+    fn test_missing_table_ref() {
+        let mut wb = Workbook::new(50, 26);
+
+        wb.insert_cells("Sheet1", &[Cell::new(0, 0, "=Other!A1".to_string())])
+            .unwrap();
+        assert_eq!("#REF!", display_value(&wb, "Sheet1", 0, 0));
+
+        // Creating the referenced table resolves the reference
+        wb.insert_cells("Other", &[Cell::new(0, 0, "3".to_string())])
+            .unwrap();
+        assert_eq!("3", display_value(&wb, "Sheet1", 0, 0));
+    }
+
+    #[test]
+    fn test_cross_table_cycle() {
+        let mut wb = Workbook::new(50, 26);
+
+        wb.insert_cells("Sheet1", &[Cell::new(0, 0, "=Other!A1".to_string())])
+            .unwrap();
+        wb.insert_cells("Other", &[Cell::new(0, 0, "=Sheet1!A1".to_string())])
+            .unwrap();
+        assert_eq!("#CIRCULAR!", display_value(&wb, "Sheet1", 0, 0));
+        assert_eq!("#CIRCULAR!", display_value(&wb, "Other", 0, 0));
+
+        // The same location in different tables is not a cycle
+        wb.insert_cells("Other", &[Cell::new(0, 0, "=Third!A1".to_string())])
+            .unwrap();
+        wb.insert_cells("Third", &[Cell::new(0, 0, "4".to_string())])
+            .unwrap();
+        assert_eq!("4", display_value(&wb, "Sheet1", 0, 0));
+    }
+
+    #[test]
+    fn test_cross_table_indirect() {
+        let mut wb = Workbook::new(50, 26);
+
+        wb.insert_cells("Costs", &[Cell::new(0, 0, "2".to_string())])
+            .unwrap();
+        wb.insert_cells(
+            "Summary",
+            &[Cell::new(0, 0, "=INDIRECT(\"Costs!A1\")+1".to_string())],
+        )
+        .unwrap();
+        assert_eq!("3", display_value(&wb, "Summary", 0, 0));
+
+        wb.insert_cells("Costs", &[Cell::new(0, 0, "5".to_string())])
+            .unwrap();
+        assert_eq!("6", display_value(&wb, "Summary", 0, 0));
+    }
+
+    #[test]
+    fn test_recalculate() {
+        let mut wb = Workbook::new(50, 26);
+        wb.seed(7);
+
+        wb.insert_cells("Sheet1", &[Cell::new(0, 0, "=RAND()".to_string())])
+            .unwrap();
+        let first = display_value(&wb, "Sheet1", 0, 0);
+        wb.insert_cells("Other", &[Cell::new(0, 0, "=Sheet1!A1".to_string())])
+            .unwrap();
+        let rand = display_value(&wb, "Sheet1", 0, 0);
+        assert_ne!(first, rand);
+
+        // Dependents of volatile cells in other tables are recomputed as well
+        let recalculated = wb.recalculate("Other");
+        assert_eq!(1, recalculated.len());
+        assert_ne!(rand, display_value(&wb, "Sheet1", 0, 0));
+
+        // The same seed replays the same sequence
+        let mut other = Workbook::new(50, 26);
+        other.seed(7);
+        other
+            .insert_cells("Sheet1", &[Cell::new(0, 0, "=RAND()".to_string())])
+            .unwrap();
+        assert_eq!(first, display_value(&other, "Sheet1", 0, 0));
+    }
+
+    #[test]
+    fn test_seeded_tables() {
+        // Tables evaluating formulas on their own, e.g. for conditional formats, draw from
+        // the workbook's generator, including tables created after seeding
+        let highlighted = || {
+            let mut wb = Workbook::new(50, 26);
+            wb.seed(3);
+            let column = Rect {
+                start_row: 0,
+                stop_row: 50,
+                start_col: 0,
+                stop_col: 1,
+            };
+            wb.set_conditional_formats(
+                "Sheet1",
+                vec![ConditionalFormat {
+                    range: column.clone(),
+                    rule: FormatRule::Formula {
+                        formula: "=RANDBETWEEN(0, 1)".to_string(),
+                        style: Style {
+                            bold: true,
+                            ..Style::default()
+                        },
+                    },
+                }],
+            )
+            .unwrap();
+            wb.get_cells("Sheet1", column)
+                .iter()
+                .map(|c| c.row)
+                .collect::<Vec<i32>>()
+        };
+        let rows = highlighted();
+        assert!(!rows.is_empty());
+        assert_eq!(rows, highlighted());
+    }
+
+    #[test]
+    fn test_validation() {
+        let mut wb = Workbook::new(50, 26);
+        wb.set_validation_rules(
+            "Orders",
+            vec![ValidationRule {
+                range: Rect {
+                    start_row: 0,
+                    stop_row: 50,
+                    start_col: 0,
+                    stop_col: 1,
+                },
+                criteria: Criteria::Number {
+                    comparison: Comparison::Greater,
+                    values: vec!["0".to_string()],
+                },
+                mode: ValidationMode::Reject,
+                message: "".to_string(),
+            }],
+        )
+        .unwrap();
+        let cells = vec![
+            Cell::new(0, 0, "3".to_string()),
+            Cell::new(1, 0, "-3".to_string()),
+        ];
+        let errors = wb.validate_cells("Orders", &cells);
+        assert_eq!(1, errors.len());
+        assert_eq!("value must be a number greater than 0", errors[0].message);
+
+        let (inserted, errors) = wb.insert_cells("Orders", &cells).unwrap();
+        assert_eq!(1, inserted.len());
+        assert_eq!(1, errors.len());
+        assert_eq!((1, 0), (errors[0].row, errors[0].col));
+        assert_eq!("3", display_value(&wb, "Orders", 0, 0));
+        assert_eq!("", display_value(&wb, "Orders", 1, 0));
+        // Other tables aren't validated by the rules
+        assert!(wb.validate_cells("Customers", &cells).is_empty());
+        assert_eq!(1, wb.validation_rules("Orders").len());
+    }
+
+    #[test]
+    fn test_validation_across_tables() {
+        let mut wb = Workbook::new(50, 26);
+        wb.insert_cells("Limits", &[Cell::new(0, 0, "10".to_string())])
+            .unwrap();
+        wb.set_validation_rules(
+            "Orders",
+            vec![ValidationRule {
+                range: Rect {
+                    start_row: 0,
+                    stop_row: 50,
+                    start_col: 0,
+                    stop_col: 1,
+                },
+                criteria: Criteria::Number {
+                    comparison: Comparison::LessOrEqual,
+                    values: vec!["=Limits!A1".to_string()],
+                },
+                mode: ValidationMode::Reject,
+                message: "".to_string(),
+            }],
+        )
+        .unwrap();
+        let cells = vec![
+            Cell::new(0, 0, "5".to_string()),
+            Cell::new(1, 0, "20".to_string()),
+        ];
+        let (_, errors) = wb.insert_cells("Orders", &cells).unwrap();
+        assert_eq!(1, errors.len());
+        assert_eq!((1, 0), (errors[0].row, errors[0].col));
+        assert_eq!("5", display_value(&wb, "Orders", 0, 0));
+        assert_eq!("", display_value(&wb, "Orders", 1, 0));
+    }
+
+    #[test]
+    fn test_clear_cells() {
+        let mut wb = Workbook::new(50, 26);
+        wb.insert_cells("Costs", &[Cell::new(0, 0, "10".to_string())])
+            .unwrap();
+        wb.insert_cells(
+            "Summary",
+            &[
+                Cell::new(0, 0, "=Costs!A1".to_string()),
+                Cell::new(1, 0, "=A1+1".to_string()),
+            ],
+        )
+        .unwrap();
+        assert_eq!("11", display_value(&wb, "Summary", 1, 0));
+
+        let cleared = wb.clear_cells(
+            "Costs",
+            Rect {
+                start_row: 0,
+                stop_row: 1,
+                start_col: 0,
+                stop_col: 1,
+            },
+        );
+        assert_eq!(1, cleared.len());
+        assert_eq!("", display_value(&wb, "Summary", 0, 0));
+        assert_eq!("1", display_value(&wb, "Summary", 1, 0));
+
+        // Clearing a formula removes its references into other tables
+        wb.clear_cells(
+            "Summary",
+            Rect {
+                start_row: 0,
+                stop_row: 1,
+                start_col: 0,
+                stop_col: 1,
+            },
+        );
+        wb.insert_cells("Costs", &[Cell::new(0, 0, "4".to_string())])
+            .unwrap();
+        assert_eq!("", display_value(&wb, "Summary", 0, 0));
+        assert_eq!("1", display_value(&wb, "Summary", 1, 0));
+    }
+
+    #[test]
+    fn test_insert_delete_rows() {
+        let mut wb = Workbook::new(10, 5);
+        wb.insert_cells(
+            "Costs",
+            &[
+                Cell::new(0, 0, "10".to_string()),
+                Cell::new(1, 0, "20".to_string()),
+                Cell::new(2, 0, "=SUM(A1:A2)".to_string()),
+            ],
+        )
+        .unwrap();
+        wb.insert_cells(
+            "Summary",
+            &[
+                Cell::new(0, 0, "=Costs!A3".to_string()),
+                Cell::new(0, 1, "=Costs!A1+A1".to_string()),
+                Cell::new(1, 0, "=SUM(Costs!A2:A3)".to_string()),
+            ],
+        )
+        .unwrap();
+        assert_eq!("30", display_value(&wb, "Summary", 0, 0));
+
+        // References from other tables follow the moved cells
+        wb.insert_rows("Costs", 0, 1).unwrap();
+        let value = |wb: &Workbook, table: &str, row, col| {
+            wb.get_cells(
+                table,
+                Rect {
+                    start_row: row,
+                    stop_row: row + 1,
+                    start_col: col,
+                    stop_col: col + 1,
+                },
+            )[0]
+            .value
+            .clone()
+        };
+        assert_eq!("=SUM(A2:A3)", value(&wb, "Costs", 3, 0));
+        assert_eq!("=Costs!A4", value(&wb, "Summary", 0, 0));
+        assert_eq!("=Costs!A2+A1", value(&wb, "Summary", 0, 1));
+        assert_eq!("30", display_value(&wb, "Summary", 0, 0));
+        assert_eq!("40", display_value(&wb, "Summary", 0, 1));
+
+        wb.insert_cells("Costs", &[Cell::new(1, 0, "5".to_string())])
+            .unwrap();
+        assert_eq!("25", display_value(&wb, "Summary", 0, 0));
+        assert_eq!("30", display_value(&wb, "Summary", 0, 1));
+
+        // Deleting the referenced rows leaves #REF! in the other tables
+        wb.delete_rows("Costs", 1, 1).unwrap();
+        assert_eq!("=#REF!+A1", value(&wb, "Summary", 0, 1));
+        assert_eq!("#REF!", display_value(&wb, "Summary", 0, 1));
+        assert_eq!("=SUM(Costs!A2:A3)", value(&wb, "Summary", 1, 0));
+        assert_eq!("=Costs!A3", value(&wb, "Summary", 0, 0));
+        assert_eq!("20", display_value(&wb, "Summary", 0, 0));
+
+        // Rows of the referencing table move its own formulas without touching the others
+        wb.insert_rows("Summary", 0, 1).unwrap();
+        assert_eq!("=#REF!+A2", value(&wb, "Summary", 1, 1));
+        assert_eq!("=Costs!A3", value(&wb, "Summary", 1, 0));
+        wb.insert_cells("Costs", &[Cell::new(1, 0, "7".to_string())])
+            .unwrap();
+        assert_eq!("7", display_value(&wb, "Summary", 1, 0));
+
+        // Formulas above the moved rows aren't recomputed
+        let shifted = wb.insert_rows("Summary", 5, 1).unwrap();
+        assert!(shifted.iter().all(|c| c.row >= 5));
+        assert_eq!("=Costs!A3", value(&wb, "Summary", 1, 0));
+    }
+
+    #[test]
+    fn test_move_range() {
+        let mut wb = Workbook::new(10, 5);
+        wb.insert_cells(
+            "Costs",
+            &[
+                Cell::new(0, 0, "10".to_string()),
+                Cell::new(1, 0, "=A1*2".to_string()),
+            ],
+        )
+        .unwrap();
+        wb.insert_cells(
+            "Summary",
+            &[
+                Cell::new(0, 0, "=Costs!A1+Costs!A2".to_string()),
+                Cell::new(1, 0, "=A1".to_string()),
+            ],
+        )
+        .unwrap();
+        assert_eq!("30", display_value(&wb, "Summary", 0, 0));
+
+        let source = Rect {
+            start_row: 0,
+            stop_row: 2,
+            start_col: 0,
+            stop_col: 1,
+        };
+        wb.move_range("Costs", source, 2, 2).unwrap();
+        let value = |wb: &Workbook, table: &str, row, col| {
+            wb.get_cells(
+                table,
+                Rect {
+                    start_row: row,
+                    stop_row: row + 1,
+                    start_col: col,
+                    stop_col: col + 1,
+                },
+            )[0]
+            .value
+            .clone()
+        };
+        assert_eq!("=C3*2", value(&wb, "Costs", 3, 2));
+        assert_eq!("=Costs!C3+Costs!C4", value(&wb, "Summary", 0, 0));
+        assert_eq!("30", display_value(&wb, "Summary", 0, 0));
+
+        wb.insert_cells("Costs", &[Cell::new(2, 2, "1".to_string())])
+            .unwrap();
+        assert_eq!("3", display_value(&wb, "Summary", 0, 0));
+        assert_eq!("3", display_value(&wb, "Summary", 1, 0));
+    }
+
+    #[test]
+    fn test_copy_range() {
+        let mut wb = Workbook::new(10, 5);
+        wb.insert_cells(
+            "Costs",
+            &[
+                Cell::new(0, 0, "10".to_string()),
+                Cell::new(1, 0, "20".to_string()),
+            ],
+        )
+        .unwrap();
+        wb.insert_cells("Summary", &[Cell::new(0, 0, "=Costs!A1*2".to_string())])
+            .unwrap();
+        let a1 = Rect {
+            start_row: 0,
+            stop_row: 1,
+            start_col: 0,
+            stop_col: 1,
+        };
+        wb.copy_range("Summary", a1.clone(), 1, 0, CopyMode::All)
+            .unwrap();
+        assert_eq!("40", display_value(&wb, "Summary", 1, 0));
+
+        let target = Rect {
+            stop_row: 3,
+            ..a1.clone()
+        };
+        wb.fill("Summary", a1, target).unwrap();
+        assert_eq!(
+            "=Costs!A3*2",
+            wb.get_cells(
+                "Summary",
+                Rect {
+                    start_row: 2,
+                    stop_row: 3,
+                    start_col: 0,
+                    stop_col: 1,
+                }
+            )[0]
+            .value
+        );
+        wb.insert_cells("Costs", &[Cell::new(2, 0, "5".to_string())])
+            .unwrap();
+        assert_eq!("10", display_value(&wb, "Summary", 2, 0));
+    }
+
+    #[test]
+    fn test_parallel_recalc() {
+        let recalc = |threads| {
+            let mut wb = Workbook::new(1000, 2);
+            wb.seed(5);
+            wb.set_recalc_threads(threads);
+            let values: Vec<Cell> = (0..1000)
+                .map(|row| Cell::new(row, 0, (row + 1).to_string()))
+                .collect();
+            wb.insert_cells("Sheet1", &values).unwrap();
+            let formulas: Vec<Cell> = (0..1000)
+                .flat_map(|row| {
+                    vec![
+                        Cell::new(row, 0, format!("=Sheet1!A{}*2+RAND()", row + 1)),
+                        Cell::new(row, 1, format!("=A{}+Sheet1!$A$1", row + 1)),
+                    ]
+                })
+                .collect();
+            wb.insert_cells("Sheet2", &formulas).unwrap();
+            wb.insert_cells("Sheet1", &[Cell::new(0, 0, "7".to_string())])
+                .unwrap();
+            let rect = Rect {
+                start_row: 0,
+                stop_row: 1000,
+                start_col: 0,
+                stop_col: 2,
+            };
+            wb.get_cells("Sheet2", rect)
+        };
+
+        let cells = recalc(1);
+        assert_eq!(cells, recalc(4));
+        let b2: f64 = cells[3].display_value.parse().unwrap();
+        let a2: f64 = cells[2].display_value.parse().unwrap();
+        assert!((b2 - a2 - 7f64).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_iterative_calculation() {
+        let mut wb = Workbook::new(50, 26);
+        wb.insert_cells("Costs", &[Cell::new(0, 0, "=Rates!A1/2+1".to_string())])
+            .unwrap();
+        wb.insert_cells("Rates", &[Cell::new(0, 0, "=Costs!A1".to_string())])
+            .unwrap();
+        assert_eq!("#CIRCULAR!", display_value(&wb, "Costs", 0, 0));
+
+        // Cycles spanning tables are only iterated when all of them enable it
+        let settings = IterativeCalculation {
+            max_iterations: 100,
+            max_change: 0.0001,
+        };
+        wb.set_iterative_calculation("Costs", Some(settings))
+            .unwrap();
+        assert_eq!("#CIRCULAR!", display_value(&wb, "Rates", 0, 0));
+
+        let updated = wb
+            .set_iterative_calculation("Rates", Some(settings))
+            .unwrap();
+        assert_eq!(1, updated.len());
+        // A1 = A1 / 2 + 1 converges to 2
+        for table in ["Costs", "Rates"] {
+            let value: f64 = display_value(&wb, table, 0, 0).parse().unwrap();
+            assert!((value - 2f64).abs() < 0.001);
+        }
+        assert!(wb
+            .set_iterative_calculation(
+                "Rates",
+                Some(IterativeCalculation {
+                    max_iterations: 0,
+                    max_change: 0.1,
+                }),
+            )
+            .is_err());
+    }
+
+    #[test]
+    fn test_trace() {
+        let mut wb = Workbook::new(50, 26);
+        wb.insert_cells("Costs", &[Cell::new(0, 0, "5".to_string())])
+            .unwrap();
+        wb.insert_cells(
+            "Summary",
+            &[
+                Cell::new(0, 0, "=Costs!A1*2".to_string()),
+                Cell::new(0, 1, "=A1+1".to_string()),
+            ],
+        )
+        .unwrap();
+        let traced = |ranges: Vec<TracedRange>| -> Vec<(String, i32, i32, u32)> {
+            ranges
+                .into_iter()
+                .map(|r| (r.table, r.rect.start_row, r.rect.start_col, r.depth))
+                .collect()
+        };
+
+        // References are followed across tables
+        assert_eq!(
+            vec![
+                ("Summary".to_string(), 0, 0, 1),
+                ("Summary".to_string(), 0, 1, 2),
+            ],
+            traced(wb.trace_dependents("Costs", 0, 0, 0))
+        );
+        assert_eq!(
+            vec![
+                ("Summary".to_string(), 0, 0, 1),
+                ("Costs".to_string(), 0, 0, 2),
+            ],
+            traced(wb.trace_precedents("Summary", 0, 1, 0))
+        );
+        assert_eq!(1, wb.trace_precedents("Summary", 0, 1, 1).len());
+        assert!(wb.trace_precedents("Other", 0, 0, 0).is_empty());
+    }
+
+    #[test]
+    fn test_cycles() {
+        let mut wb = Workbook::new(50, 26);
+        // A1 -> C3 -> A1 and B1 -> B2 -> Other!A1 -> B1, with D1 reading the first cycle
+        // and B3 referencing itself
+        wb.insert_cells(
+            "Sheet1",
+            &[
+                Cell::new(0, 0, "=C3+1".to_string()),
+                Cell::new(2, 2, "=A1*2".to_string()),
+                Cell::new(0, 3, "=A1".to_string()),
+                Cell::new(0, 1, "=B2".to_string()),
+                Cell::new(1, 1, "=Other!A1".to_string()),
+                Cell::new(2, 1, "=B3+1".to_string()),
+            ],
+        )
+        .unwrap();
+        wb.insert_cells("Other", &[Cell::new(0, 0, "=Sheet1!B1".to_string())])
+            .unwrap();
+        assert_eq!("#CIRCULAR!", display_value(&wb, "Sheet1", 0, 3));
+
+        let loc = |table: &str, row, col| TableCellLocation {
+            table: table.to_string(),
+            loc: CellLocation { row, col },
+        };
+        // Only the cells of a cycle are reported, not those reading it
+        assert_eq!(
+            vec![
+                vec![
+                    loc("Sheet1", 0, 0),
+                    loc("Sheet1", 2, 2),
+                    loc("Sheet1", 0, 0)
+                ],
+                vec![
+                    loc("Sheet1", 0, 1),
+                    loc("Sheet1", 1, 1),
+                    loc("Other", 0, 0),
+                    loc("Sheet1", 0, 1),
+                ],
+                vec![
+                    loc("Sheet1", 1, 1),
+                    loc("Other", 0, 0),
+                    loc("Sheet1", 0, 1),
+                    loc("Sheet1", 1, 1),
+                ],
+                vec![loc("Sheet1", 2, 1), loc("Sheet1", 2, 1)],
+                vec![
+                    loc("Sheet1", 2, 2),
+                    loc("Sheet1", 0, 0),
+                    loc("Sheet1", 2, 2)
+                ],
+            ],
+            wb.cycles("Sheet1")
+        );
+        assert_eq!(3, wb.cycles("Other")[0].len() - 1);
+
+        // Breaking a cycle removes it
+        wb.insert_cells("Sheet1", &[Cell::new(2, 2, "4".to_string())])
+            .unwrap();
+        assert_eq!("5", display_value(&wb, "Sheet1", 0, 3));
+        assert_eq!(3, wb.cycles("Sheet1").len());
+    }
+
+    #[test]
+    fn test_manual_calculation() {
+        let mut wb = Workbook::new(50, 26);
+        wb.set_calculation_mode("Data", CalculationMode::Manual);
+        wb.insert_cells("Summary", &[Cell::new(0, 0, "=Data!A2+10".to_string())])
+            .unwrap();
+        wb.insert_cells(
+            "Data",
+            &[
+                Cell::new(0, 0, "4".to_string()),
+                Cell::new(1, 0, "=A1+1".to_string()),
+                Cell::new(2, 0, "=A1-1".to_string()),
+            ],
+        )
+        .unwrap();
+        let stale = |wb: &Workbook, table: &str, row| {
+            let r = Rect {
+                start_row: row,
+                stop_row: row + 1,
+                start_col: 0,
+                stop_col: 1,
+            };
+            wb.get_cells(table, r)[0].stale
+        };
+        // Cells of automatically calculated tables reading stale cells are stale too
+        assert!(stale(&wb, "Data", 1));
+        assert!(stale(&wb, "Summary", 0));
+        assert_eq!("10", display_value(&wb, "Summary", 0, 0));
+
+        // Recalculating Summary!A1 recomputes the stale Data!A2 it reads
+        let recalculated = wb.recalculate_range(
+            "Summary",
+            Rect {
+                start_row: 0,
+                stop_row: 1,
+                start_col: 0,
+                stop_col: 1,
+            },
+        );
+        assert_eq!(1, recalculated.len());
+        assert_eq!("15", display_value(&wb, "Summary", 0, 0));
+        assert!(!stale(&wb, "Data", 1));
+        assert!(stale(&wb, "Data", 2));
+
+        wb.insert_cells("Data", &[Cell::new(0, 0, "9".to_string())])
+            .unwrap();
+        assert!(stale(&wb, "Summary", 0));
+        let recalculated = wb.recalculate("Data");
+        assert_eq!(2, recalculated.len());
+        assert_eq!("8", display_value(&wb, "Data", 2, 0));
+        assert_eq!("20", display_value(&wb, "Summary", 0, 0));
+        assert!(!stale(&wb, "Summary", 0));
+
+        wb.insert_cells("Data", &[Cell::new(0, 0, "1".to_string())])
+            .unwrap();
+        wb.set_calculation_mode("Data", CalculationMode::Automatic);
+        assert_eq!("12", display_value(&wb, "Summary", 0, 0));
+        assert_eq!("0", display_value(&wb, "Data", 2, 0));
+    }
+
+    #[test]
+    fn test_on_demand_calculation() {
+        let mut wb = Workbook::new(50, 26);
+        wb.set_calculation_mode("Data", CalculationMode::OnDemand);
+        wb.set_calculation_mode("Inputs", CalculationMode::Manual);
+        wb.insert_cells(
+            "Data",
+            &[
+                Cell::new(0, 0, "4".to_string()),
+                Cell::new(1, 0, "=A1+1".to_string()),
+                Cell::new(2, 0, "=A1-1".to_string()),
+            ],
+        )
+        .unwrap();
+        wb.insert_cells("Summary", &[Cell::new(0, 0, "=Data!A2+10".to_string())])
+            .unwrap();
+        wb.insert_cells("Inputs", &[Cell::new(0, 0, "=Data!A1*2".to_string())])
+            .unwrap();
+        let rect = |row| Rect {
+            start_row: row,
+            stop_row: row + 1,
+            start_col: 0,
+            stop_col: 1,
+        };
+        assert!(wb.get_cells("Summary", rect(0))[0].stale);
+        assert!(wb.needs_recalc("Summary", &rect(0)));
+        assert!(!wb.needs_recalc("Summary", &rect(1)));
+
+        // Reading a cell recomputes the stale cells it reads in other tables
+        let cells = wb.read_cells("Summary", rect(0));
+        assert_eq!("15", cells[0].display_value);
+        assert!(!cells[0].stale);
+        assert!(!wb.needs_recalc("Summary", &rect(0)));
+        assert!(wb.needs_recalc("Data", &rect(2)));
+        assert_eq!("5", display_value(&wb, "Data", 1, 0));
+        assert!(wb.get_cells("Data", rect(2))[0].stale);
+
+        wb.insert_cells("Data", &[Cell::new(0, 0, "9".to_string())])
+            .unwrap();
+        assert_eq!("15", display_value(&wb, "Summary", 0, 0));
+        assert_eq!("8", wb.read_cells("Data", rect(2))[0].display_value);
+        assert_eq!("20", wb.read_cells("Summary", rect(0))[0].display_value);
+
+        // Manually calculated tables are only recomputed by recalculate
+        assert!(!wb.needs_recalc("Inputs", &rect(0)));
+        assert!(wb.read_cells("Inputs", rect(0))[0].stale);
+        wb.recalculate("Inputs");
+        assert_eq!("18", display_value(&wb, "Inputs", 0, 0));
+    }
+
+    #[test]
+    fn test_export_graph() {
+        let mut wb = Workbook::new(50, 26);
+        wb.insert_cells(
+            "Sheet1",
+            &[
+                Cell::new(0, 0, "=Other!B2+1".to_string()),
+                Cell::new(1, 0, "=A1".to_string()),
+            ],
+        )
+        .unwrap();
+        wb.insert_cells(
+            "Other",
+            &[
+                Cell::new(1, 1, "5".to_string()),
+                Cell::new(0, 2, "=Sheet1!A2".to_string()),
+            ],
+        )
+        .unwrap();
+        assert_eq!(
+            concat!(
+                r#"{"table":"Sheet1","cells":["A1","A2"],"#,
+                r#""edges":[{"from":"A1","to":"Other!B2"},{"from":"A2","to":"A1"}],"cycles":[]}"#,
+            ),
+            wb.export_graph("Sheet1", None).to_json()
+        );
+
+        // The cells reading a cell of another table are found across tables
+        let graph = wb.export_graph(
+            "Sheet1",
+            Some(TableCellLocation {
+                table: "Other".to_string(),
+                loc: CellLocation { row: 1, col: 1 },
+            }),
+        );
+        assert_eq!(
+            concat!(
+                r#"{"table":"Sheet1","cells":["Other!C1","A1","A2"],"edges":["#,
+                r#"{"from":"Other!C1","to":"A2"},{"from":"A1","to":"Other!B2"},"#,
+                r#"{"from":"A2","to":"A1"}],"cycles":[]}"#,
+            ),
+            graph.to_json()
+        );
+
+        // Cycles through other tables are reported
+        wb.insert_cells("Other", &[Cell::new(1, 1, "=C1".to_string())])
+            .unwrap();
+        let graph = wb.export_graph("Sheet1", None);
+        assert!(graph.cycles.is_empty());
+        let graph = wb.export_graph(
+            "Sheet1",
+            Some(TableCellLocation {
+                table: "Sheet1".to_string(),
+                loc: CellLocation { row: 0, col: 0 },
+            }),
+        );
+        assert_eq!(1, graph.cycles.len());
+        assert_eq!(4, graph.cycles[0].len());
+    }
+
+    #[test]
+    fn test_aggregate_functions() {
+        let mut wb = Workbook::new(500, 26);
+        let cells: Vec<Cell> = (0..400)
+            .map(|row| Cell::new(row, 0, (row + 1).to_string()))
+            .collect();
+        wb.insert_cells("Data", &cells).unwrap();
+        wb.insert_cells(
+            "Summary",
+            &[
+                Cell::new(0, 0, "=SUM(Data!A:A)".to_string()),
+                Cell::new(1, 0, "=COUNT(Data!A2:A400)".to_string()),
+                Cell::new(2, 0, "=AVG(Data!A1:A400)".to_string()),
+            ],
+        )
+        .unwrap();
+        assert_eq!("80200", display_value(&wb, "Summary", 0, 0));
+        assert_eq!("399", display_value(&wb, "Summary", 1, 0));
+        assert_eq!("200.5", display_value(&wb, "Summary", 2, 0));
+
+        // A single edit updates the totals reading the cell
+        wb.insert_cells("Data", &[Cell::new(199, 0, "1200".to_string())])
+            .unwrap();
+        assert_eq!("81200", display_value(&wb, "Summary", 0, 0));
+        assert_eq!("203", display_value(&wb, "Summary", 2, 0));
+        wb.insert_cells("Data", &[Cell::new(450, 0, "=A1*10".to_string())])
+            .unwrap();
+        assert_eq!("81210", display_value(&wb, "Summary", 0, 0));
+        assert_eq!("399", display_value(&wb, "Summary", 1, 0));
+    }
+
+    #[test]
+    fn test_set_locale() {
+        let mut wb = Workbook::new(10, 5);
+        wb.insert_cells("Other", &[Cell::new(0, 0, "3".to_string())])
+            .unwrap();
+        wb.insert_cells("Sheet", &[Cell::new(0, 0, "=Other!A1+1.5".to_string())])
+            .unwrap();
+        assert_eq!("4.5", display_value(&wb, "Sheet", 0, 0));
+
+        // References to other tables still resolve once the formulas are read again
+        let cells = wb.set_locale("Sheet", Locale::from_name("de-DE").unwrap());
+        assert_eq!(1, cells.len());
+        assert_eq!("4,5", cells[0].display_value);
+        assert_eq!("4,5", display_value(&wb, "Sheet", 0, 0));
+    }
+}